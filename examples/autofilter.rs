@@ -1,5 +1,5 @@
 use std::fs;
-use edit_xlsx::{WorkSheetCol, Filter, Filters, Format, WorkSheetRow, Workbook, WorkbookResult, Write};
+use edit_xlsx::{DynamicFilterType, WorkSheetCol, Filter, Filters, Format, WorkSheetRow, Workbook, WorkbookResult, Write};
 
 fn main() -> WorkbookResult<()> {
     // Prepare autofilter data
@@ -18,6 +18,8 @@ fn main() -> WorkbookResult<()> {
     workbook.add_worksheet()?;
     workbook.add_worksheet()?;
     workbook.add_worksheet()?;
+    workbook.add_worksheet()?;
+    workbook.add_worksheet()?;
 
     // Set up several sheets with the same data.
     for worksheet in workbook.worksheets_mut() {
@@ -254,6 +256,55 @@ fn main() -> WorkbookResult<()> {
         row += 1;
     }
 
+    //
+    // Example 8. Autofilter with the 10 greatest volumes.
+    //
+    let worksheet8 = workbook.get_worksheet_mut(8)?;
+    // Set the autofilter.
+    worksheet8.autofilter("A1:D51");
+    // Add filter criteria.
+    worksheet8.filter_column("C", &Filters::top(10));
+    let mut row = 2;
+    for row_data in &data {
+        let mut col = 1;
+        for data in row_data {
+            if let Ok(num) = data.parse::<i32>() {
+                worksheet8.write((row, col), num)?;
+            } else {
+                worksheet8.write((row, col), *data)?;
+            }
+            col += 1;
+        }
+        // Move on to the next worksheet row.
+        row += 1;
+    }
+    // Hide the rows that don't match the filter criteria.
+    worksheet8.apply_autofilter();
+
+    //
+    // Example 9. Autofilter with the volumes above the average.
+    //
+    let worksheet9 = workbook.get_worksheet_mut(9)?;
+    // Set the autofilter.
+    worksheet9.autofilter("A1:D51");
+    // Add filter criteria.
+    worksheet9.filter_column("C", &Filters::dynamic(DynamicFilterType::AboveAverage));
+    let mut row = 2;
+    for row_data in &data {
+        let mut col = 1;
+        for data in row_data {
+            if let Ok(num) = data.parse::<i32>() {
+                worksheet9.write((row, col), num)?;
+            } else {
+                worksheet9.write((row, col), *data)?;
+            }
+            col += 1;
+        }
+        // Move on to the next worksheet row.
+        row += 1;
+    }
+    // Hide the rows that don't match the filter criteria.
+    worksheet9.apply_autofilter();
 
     workbook.save_as("examples/autofilter.xlsx")?;
 
@@ -0,0 +1,37 @@
+use edit_xlsx::{Format, ProtectionOptions, WorkSheetCol, Workbook, WorkbookResult, Write};
+
+fn main() -> WorkbookResult<()> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.get_worksheet_mut(1)?;
+
+    // Create some format objects.
+    let unlocked = Format::default().set_unlocked();
+    let hidden = Format::default().set_hidden();
+
+    // Format the columns to make the text more visible.
+    worksheet.set_columns_width("A:A", 40.0)?;
+
+    // Turn worksheet protection on, the default options only allow selecting cells.
+    let mut options = ProtectionOptions::default();
+    options.allow_format_cells(true)
+        .allow_format_columns(true);
+    worksheet.protect("password", &options);
+
+    // Write a locked, an unlocked and a hidden cell.
+    worksheet.write("A1", "Cell B1 is locked. It cannot be edited.")?;
+    worksheet.write("A2", "Cell B2 is unlocked. It can be edited.")?;
+    worksheet.write("A3", "Cell B3 is hidden. The formula isn't visible.")?;
+
+    worksheet.write_formula("B1", "=1+2")?; // Locked by default.
+    worksheet.write_formula_with_format("B2", "=1+2", &unlocked)?;
+    worksheet.write_formula_with_format("B3", "=1+2", &hidden)?;
+
+    worksheet.write("A5", "Use Menu -> Review -> Unprotect Sheet")?;
+    worksheet.write("A6", "The password is 'password'")?;
+
+    // Prevent the worksheets from being added, moved, renamed or deleted.
+    workbook.protect_structure("password");
+
+    workbook.save_as("examples/worksheet_protection.xlsx")?;
+    Ok(())
+}
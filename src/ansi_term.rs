@@ -5,7 +5,7 @@ use ansi_term::{ANSIString, Style, Colour};
 use crate::api::cell::location::{Location, LocationRange};
 use crate::api::cell::values::CellDisplay;
 use crate::api::theme::Theme;
-use crate::{FormatColor, FormatFill, FormatFont, Read, WorkSheetCol, WorkSheetResult, WorkSheetRow};
+use crate::{FormatColor, FormatFill, FormatFont, FormatUnderlineType, Read, WorkSheetCol, WorkSheetResult, WorkSheetRow};
 
 #[cfg(feature = "ansi_term_support")]
 impl WorkSheet {
@@ -13,26 +13,15 @@ impl WorkSheet {
         fn add_ansi_font_style(theme: &Theme, format_font: &FormatFont, style: &mut Style) {
             style.is_bold |= format_font.bold;
             style.is_italic |= format_font.italic;
-            style.is_underline |= format_font.underline;
-            let mut color = format_font.color;
-            if let FormatColor::Theme(id, tint) = format_font.color {
-                color = *theme.theme_to_rgb(id, tint);
-            }
-            if let FormatColor::Index(id) = format_font.color {
-                color = theme.index_to_rgb(id);
-            }
+            style.is_underline |= format_font.underline != FormatUnderlineType::None;
+            style.is_strikethrough |= format_font.strike;
+            let color = theme.to_rgb(&format_font.color);
             if let Some(color) = color.to_ansi_term_colour() {
                 style.foreground = Some(color)
             }
         }
         fn add_ansi_color_style(theme: &Theme, format_fill: &FormatFill, style: &mut Style) {
-            let mut color = format_fill.fg_color;
-            if let FormatColor::Theme(id, tint) = format_fill.fg_color {
-                color = *theme.theme_to_rgb(id, tint);
-            }
-            if let FormatColor::Index(id) = format_fill.fg_color {
-                color = theme.index_to_rgb(id);
-            }
+            let color = theme.to_rgb(&format_fill.fg_color);
             if let Some(color) = color.to_ansi_term_colour() {
                 style.background = Some(color)
             }
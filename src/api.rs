@@ -5,4 +5,13 @@ pub mod cell;
 pub mod relationship;
 pub(crate) mod filter;
 pub(crate) mod properties;
-pub mod theme;
\ No newline at end of file
+pub(crate) mod protection;
+pub mod theme;
+pub mod image;
+pub mod shape;
+pub mod sparkline;
+pub mod form_control;
+pub mod hyperlink;
+pub mod range;
+pub mod sort;
+pub mod find;
@@ -48,7 +48,7 @@ impl Word {
     pub fn new(text: &str, font: &FormatFont) -> Word {
         Word {
             text: text.to_string(),
-            font: None,
+            font: Some(font.clone()),
         }
     }
 }
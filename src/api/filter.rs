@@ -1,7 +1,76 @@
+use crate::FormatColor;
+
+///
+/// The criteria of [`Filters::dynamic`], resolved against the data of the column or the current date
+/// each time the autofilter is applied.
+///
+/// | DynamicFilterType | XML value      | Rows shown                                      |
+/// |-------------------|----------------|-------------------------------------------------|
+/// | AboveAverage      | `aboveAverage` | The numbers above the average of the column     |
+/// | BelowAverage      | `belowAverage` | The numbers below the average of the column     |
+/// | Yesterday         | `yesterday`    | The dates of yesterday                          |
+/// | Today             | `today`        | The dates of today                              |
+/// | Tomorrow          | `tomorrow`     | The dates of tomorrow                           |
+/// | LastMonth         | `lastMonth`    | The dates of the previous month                 |
+/// | ThisMonth         | `thisMonth`    | The dates of the current month                  |
+/// | NextMonth         | `nextMonth`    | The dates of the next month                     |
+/// | LastYear          | `lastYear`     | The dates of the previous year                  |
+/// | ThisYear          | `thisYear`     | The dates of the current year                   |
+/// | NextYear          | `nextYear`     | The dates of the next year                      |
+///
+/// The current date is taken in UTC.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DynamicFilterType {
+    AboveAverage,
+    BelowAverage,
+    Yesterday,
+    Today,
+    Tomorrow,
+    LastMonth,
+    ThisMonth,
+    NextMonth,
+    LastYear,
+    ThisYear,
+    NextYear,
+}
+
+impl DynamicFilterType {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            DynamicFilterType::AboveAverage => "aboveAverage",
+            DynamicFilterType::BelowAverage => "belowAverage",
+            DynamicFilterType::Yesterday => "yesterday",
+            DynamicFilterType::Today => "today",
+            DynamicFilterType::Tomorrow => "tomorrow",
+            DynamicFilterType::LastMonth => "lastMonth",
+            DynamicFilterType::ThisMonth => "thisMonth",
+            DynamicFilterType::NextMonth => "nextMonth",
+            DynamicFilterType::LastYear => "lastYear",
+            DynamicFilterType::ThisYear => "thisYear",
+            DynamicFilterType::NextYear => "nextYear",
+        }
+    }
+
+    pub(crate) fn from_str(dynamic_filter_type: &str) -> Option<DynamicFilterType> {
+        [
+            DynamicFilterType::AboveAverage, DynamicFilterType::BelowAverage,
+            DynamicFilterType::Yesterday, DynamicFilterType::Today, DynamicFilterType::Tomorrow,
+            DynamicFilterType::LastMonth, DynamicFilterType::ThisMonth, DynamicFilterType::NextMonth,
+            DynamicFilterType::LastYear, DynamicFilterType::ThisYear, DynamicFilterType::NextYear,
+        ].into_iter().find(|t| t.to_str() == dynamic_filter_type)
+    }
+}
+
 pub struct Filters<'a> {
     pub(crate) and: Option<u8>,
     pub(crate) blank: Option<u8>,
     pub(crate) filters: Vec<Filter<'a>>,
+    // Whether the top or the bottom items are shown, whether `val` is a percent, and `val`
+    pub(crate) top10: Option<(bool, bool, f64)>,
+    pub(crate) dynamic: Option<DynamicFilterType>,
+    // The color, and whether it is the color of the fill rather than of the font
+    pub(crate) color: Option<(FormatColor, bool)>,
 }
 
 impl<'a> Filters<'a> {
@@ -10,27 +79,87 @@ impl<'a> Filters<'a> {
             and: None,
             blank: None,
             filters: vec![],
+            top10: None,
+            dynamic: None,
+            color: None,
         }
     }
     pub fn eq(vals: Vec<&'a str>) -> Self {
         Self {
-            and: None,
-            blank: None,
             filters: vals.iter().map(|&v| Filter::eq(v)).collect(),
+            ..Self::new()
         }
     }
     pub fn blank() -> Self {
         Self {
-            and: None,
             blank: Some(1),
-            filters: vec![],
+            ..Self::new()
         }
     }
     pub fn not_blank() -> Self {
         Self {
-            and: None,
-            blank: None,
             filters: vec![Filter::ne(" ")],
+            ..Self::new()
+        }
+    }
+    ///
+    /// Show the rows with the `n` greatest numbers of the column.
+    ///
+    pub fn top(n: u32) -> Self {
+        Self {
+            top10: Some((true, false, n as f64)),
+            ..Self::new()
+        }
+    }
+    ///
+    /// Show the rows with the `n` smallest numbers of the column.
+    ///
+    pub fn bottom(n: u32) -> Self {
+        Self {
+            top10: Some((false, false, n as f64)),
+            ..Self::new()
+        }
+    }
+    ///
+    /// Show the rows with the greatest numbers of the column, `percent` percent of the numbers.
+    ///
+    pub fn top_percent(percent: f64) -> Self {
+        Self {
+            top10: Some((true, true, percent)),
+            ..Self::new()
+        }
+    }
+    ///
+    /// Show the rows with the smallest numbers of the column, `percent` percent of the numbers.
+    ///
+    pub fn bottom_percent(percent: f64) -> Self {
+        Self {
+            top10: Some((false, true, percent)),
+            ..Self::new()
+        }
+    }
+    pub fn dynamic(dynamic_filter_type: DynamicFilterType) -> Self {
+        Self {
+            dynamic: Some(dynamic_filter_type),
+            ..Self::new()
+        }
+    }
+    ///
+    /// Show the rows whose cells are filled with the color.
+    ///
+    pub fn cell_color(color: FormatColor) -> Self {
+        Self {
+            color: Some((color, true)),
+            ..Self::new()
+        }
+    }
+    ///
+    /// Show the rows whose cells have a font of the color.
+    ///
+    pub fn font_color(color: FormatColor) -> Self {
+        Self {
+            color: Some((color, false)),
+            ..Self::new()
         }
     }
     pub fn or(&mut self, filter: Filter<'a>) -> &mut Self {
@@ -52,6 +181,8 @@ pub struct Filter<'a> {
     pub(crate) val: &'a str,
     pub(crate) operator: Option<&'a str>,
     custom_filter: bool,
+    // The year, the month and the day of a group of dates
+    pub(crate) date_group: Option<(u32, Option<u32>, Option<u32>)>,
 }
 
 impl<'a> Filter<'a> {
@@ -60,6 +191,7 @@ impl<'a> Filter<'a> {
             val,
             operator: None,
             custom_filter: false,
+            date_group: None,
         }
     }
     pub fn gt(val: &'a str) -> Self {
@@ -67,6 +199,7 @@ impl<'a> Filter<'a> {
             val,
             operator: Some("greaterThan"),
             custom_filter: true,
+            date_group: None,
         }
     }
     pub fn lt(val: &'a str) -> Self {
@@ -74,6 +207,7 @@ impl<'a> Filter<'a> {
             val,
             operator: Some("lessThan"),
             custom_filter: true,
+            date_group: None,
         }
     }
 
@@ -82,6 +216,39 @@ impl<'a> Filter<'a> {
             val,
             operator: Some("notEqual"),
             custom_filter: true,
+            date_group: None,
         }
     }
-}
\ No newline at end of file
+
+    ///
+    /// Match the dates of the year.
+    ///
+    pub fn year(year: u32) -> Self {
+        Self {
+            val: "",
+            operator: None,
+            custom_filter: false,
+            date_group: Some((year, None, None)),
+        }
+    }
+
+    ///
+    /// Match the dates of the month, from 1 to 12, of the year.
+    ///
+    pub fn month(year: u32, month: u32) -> Self {
+        Self {
+            date_group: Some((year, Some(month), None)),
+            ..Self::year(year)
+        }
+    }
+
+    ///
+    /// Match the dates of the day.
+    ///
+    pub fn day(year: u32, month: u32, day: u32) -> Self {
+        Self {
+            date_group: Some((year, Some(month), Some(day))),
+            ..Self::year(year)
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::utils::col_helper::to_ref;
+
+///
+/// The options of [`Workbook::find`](crate::Workbook::find), [`Workbook::replace`](crate::Workbook::replace)
+/// and [`Workbook::replace_all`](crate::Workbook::replace_all), by default the pattern is a literal text
+/// found anywhere in the values of the cells of every worksheet, without case.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FindOptions {
+    /// The pattern is a regular expression, and the replacement may refer to its groups by `$1` or `${name}`.
+    pub regex: bool,
+    pub match_case: bool,
+    /// The pattern has to match the whole text of the cell.
+    pub whole_cell: bool,
+    /// The formulas of the cells are searched instead of their calculated values,
+    /// the formulas are written without the leading `=`.
+    pub in_formulas: bool,
+    /// The names of the worksheets to search, `None` for every worksheet.
+    pub sheets: Option<Vec<String>>,
+}
+
+///
+/// A cell matching the pattern of [`Workbook::find`](crate::Workbook::find).
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct FoundCell {
+    pub sheet: String,
+    pub row: u32,
+    pub col: u32,
+}
+
+impl FoundCell {
+    ///
+    /// The reference of the cell, like `A1`.
+    ///
+    pub fn to_ref(&self) -> String {
+        to_ref(self.row, self.col)
+    }
+}
@@ -0,0 +1,217 @@
+use crate::api::cell::location::{Location, LocationRange};
+use crate::utils::col_helper::to_col_name;
+
+///
+/// The kind of a [`FormControl`].
+///
+/// | FormControlType | objectType | Linked cell                                   |
+/// |-----------------|------------|-----------------------------------------------|
+/// | CheckBox        | CheckBox   | TRUE when checked, FALSE when not              |
+/// | OptionButton    | Radio      | The position of the checked button in its group |
+/// | ComboBox        | Drop       | The position of the selected item             |
+/// | ListBox         | List       | The position of the selected item             |
+/// | Spinner         | Spin       | The value                                     |
+/// | Button          | Button     | None, a button runs its macro                 |
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FormControlType {
+    CheckBox,
+    OptionButton,
+    ComboBox,
+    ListBox,
+    Spinner,
+    Button,
+}
+
+impl FormControlType {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            FormControlType::CheckBox => "CheckBox",
+            FormControlType::OptionButton => "Radio",
+            FormControlType::ComboBox => "Drop",
+            FormControlType::ListBox => "List",
+            FormControlType::Spinner => "Spin",
+            FormControlType::Button => "Button",
+        }
+    }
+
+    ///
+    /// The object type of the control in the vml drawing.
+    ///
+    pub(crate) fn to_vml_str(self) -> &'static str {
+        match self {
+            FormControlType::CheckBox => "Checkbox",
+            other => other.to_str(),
+        }
+    }
+
+    pub(crate) fn default_name(self) -> &'static str {
+        match self {
+            FormControlType::CheckBox => "Check Box",
+            FormControlType::OptionButton => "Option Button",
+            FormControlType::ComboBox => "Drop Down",
+            FormControlType::ListBox => "List Box",
+            FormControlType::Spinner => "Spinner",
+            FormControlType::Button => "Button",
+        }
+    }
+
+    ///
+    /// Whether the control shows its text.
+    ///
+    pub(crate) fn has_text(self) -> bool {
+        matches!(self, FormControlType::CheckBox | FormControlType::OptionButton | FormControlType::Button)
+    }
+}
+
+///
+/// A [`FormControl`] is a check box, an option button, a combo box, a list box, a spinner or a button
+/// drawn over the cells by [`WorkSheet::insert_form_control`](crate::WorkSheet::insert_form_control).
+///
+/// The linked cell receives the state of the control when it is used in Excel.
+/// The items of a combo box or a list box are the values of the input range.
+/// The option buttons on a worksheet form one group unless they are placed in group boxes.
+///
+/// # Examples
+/// ```
+/// use edit_xlsx::{FormControl, FormControlType, Workbook, WorkbookResult, Write};
+/// fn main() -> WorkbookResult<()> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.get_worksheet_mut(1)?;
+///     worksheet.write_column("E1", &["Mon", "Tue", "Wed"])?;
+///     let done = FormControl::new(FormControlType::CheckBox)
+///         .set_text("Done")
+///         .set_linked_cell("C2")
+///         .set_checked(true);
+///     worksheet.insert_form_control("A2:B2", &done)?;
+///     let day = FormControl::new(FormControlType::ComboBox)
+///         .set_input_range("E1:E3")
+///         .set_linked_cell("C3");
+///     worksheet.insert_form_control("A3:B3", &day)?;
+///     let hours = FormControl::new(FormControlType::Spinner)
+///         .set_linked_cell("C4")
+///         .set_range(0, 24)
+///         .set_value(8);
+///     worksheet.insert_form_control("A4:A5", &hours)?;
+///     workbook.save_as("./examples/form_control.xlsx")?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormControl {
+    pub(crate) control_type: FormControlType,
+    pub(crate) name: Option<String>,
+    pub(crate) text: Option<String>,
+    pub(crate) linked_cell: Option<String>,
+    pub(crate) input_range: Option<String>,
+    pub(crate) checked: bool,
+    pub(crate) value: Option<u32>,
+    pub(crate) min: Option<u32>,
+    pub(crate) max: Option<u32>,
+    pub(crate) increment: Option<u32>,
+    pub(crate) drop_lines: Option<u32>,
+    pub(crate) macro_name: Option<String>,
+}
+
+impl FormControl {
+    pub fn new(control_type: FormControlType) -> FormControl {
+        FormControl {
+            control_type,
+            name: None,
+            text: None,
+            linked_cell: None,
+            input_range: None,
+            checked: false,
+            value: None,
+            min: None,
+            max: None,
+            increment: None,
+            drop_lines: None,
+            macro_name: None,
+        }
+    }
+
+    ///
+    /// Set the name of the control shown in the selection pane, it is "Check Box N" and so on by default.
+    ///
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    ///
+    /// Set the text of a check box, an option button or a button, it is the name of the control by default.
+    ///
+    pub fn set_text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    ///
+    /// Set the cell on the same worksheet which receives the state of the control.
+    ///
+    pub fn set_linked_cell<L: Location>(mut self, loc: L) -> Self {
+        let (row, col) = loc.to_location();
+        self.linked_cell = Some(format!("${}${row}", to_col_name(col)));
+        self
+    }
+
+    ///
+    /// Set the cells on the same worksheet which hold the items of a combo box or a list box.
+    ///
+    pub fn set_input_range<L: LocationRange>(mut self, loc_range: L) -> Self {
+        let (first_row, first_col, last_row, last_col) = loc_range.to_range();
+        self.input_range = Some(format!("${}${first_row}:${}${last_row}", to_col_name(first_col), to_col_name(last_col)));
+        self
+    }
+
+    ///
+    /// Check a check box or an option button.
+    ///
+    pub fn set_checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    ///
+    /// Set the value of a spinner, or the position of the selected item of a combo box or a list box starting from 1.
+    ///
+    pub fn set_value(mut self, value: u32) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    ///
+    /// Set the minimum and the maximum value of a spinner, they are 0 and 100 by default.
+    ///
+    pub fn set_range(mut self, min: u32, max: u32) -> Self {
+        self.min = Some(min);
+        self.max = Some(max);
+        self
+    }
+
+    ///
+    /// Set the step of a spinner, it is 1 by default.
+    ///
+    pub fn set_increment(mut self, increment: u32) -> Self {
+        self.increment = Some(increment);
+        self
+    }
+
+    ///
+    /// Set the number of the items shown in the drop-down list of a combo box, it is 8 by default.
+    ///
+    pub fn set_drop_lines(mut self, drop_lines: u32) -> Self {
+        self.drop_lines = Some(drop_lines);
+        self
+    }
+
+    ///
+    /// Set the macro run when the control is clicked, such as `"Module1.Submit"`,
+    /// which only works in a macro-enabled workbook.
+    ///
+    pub fn set_macro(mut self, macro_name: &str) -> Self {
+        self.macro_name = Some(macro_name.to_string());
+        self
+    }
+}
@@ -2,11 +2,13 @@
 //! This module contains the [`Format`] struct, which used to edit the style of the [`Cell`],
 //! also defines some methods for working with [`Format`].
 //!
-pub use align::{FormatAlignType, FormatAlign};
+pub use align::{FormatAlignType, FormatAlign, FormatAlignHorizontal, FormatAlignVertical};
 pub use border::{FormatBorder, FormatBorderElement, FormatBorderType};
 pub use color::FormatColor;
-pub use fill::FormatFill;
-pub use font::FormatFont;
+pub use fill::{FillPattern, FormatFill, GradientFill, GradientStop, GradientType};
+pub use font::{FormatFont, FormatFontScheme, FormatUnderlineType, FormatVertAlignType};
+pub use protection::FormatProtection;
+pub use style::BuiltinStyle;
 use crate::Cell;
 
 mod align;
@@ -14,6 +16,8 @@ mod color;
 mod fill;
 mod font;
 mod border;
+mod protection;
+mod style;
 
 ///
 /// [`Format`] struct, which used to edit the style of the [`Cell`].
@@ -24,15 +28,43 @@ mod border;
 /// | `border`    | [`FormatBorder`] | The [`Cell`]'s border formats |
 /// | `fill`      | [`FormatFill`] | The [`Cell`]'s fill(background) formats |
 /// | `align`   | [`FormatAlign`] | The [`Cell`]'s align formats |
+/// | `protection`   | [`FormatProtection`] | The [`Cell`]'s protection formats |
+/// | `style_name`   | [`Option<String>`] | The name of the cell style the [`Cell`] is based on, see [`Workbook::add_named_style`](crate::Workbook::add_named_style) |
+///
+/// The number format of the [`Cell`] is kept by the [`Format`] read from it, so that writing it back
+/// does not change how the value is displayed.
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Format {
     pub font: FormatFont,
     pub border: FormatBorder,
     pub fill: FormatFill,
     pub align: FormatAlign,
+    pub protection: FormatProtection,
+    pub style_name: Option<String>,
+    pub(crate) num_format: Option<NumFormat>,
+}
+
+///
+/// The number format of a [`Format`], `None` in the format is the General format.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum NumFormat {
+    /// A built-in number format, its id means the same in every workbook.
+    Builtin(u32),
+    /// A custom number format, its id is only valid in its own workbook so it is kept by its code.
+    Custom(String),
 }
 
 impl Format {
+    /// Gets the name of the cell style the format is based on.
+    ///
+    /// ## Returns
+    ///
+    /// Returns `None` if the format is based on the Normal style.
+    pub fn get_style_name(&self) -> Option<&str> {
+        self.style_name.as_deref()
+    }
+
     /// Checks whether the font is bold, learn more about it in [`FormatFont`].
     ///
     /// ## Returns
@@ -57,7 +89,31 @@ impl Format {
     /// Returns `true` if the font is underline, `false` otherwise.
 
     pub fn is_underline(&self) -> bool {
-        self.font.underline
+        self.font.underline != FormatUnderlineType::None
+    }
+    /// Retrieves the underline type of the font, learn more about it in [`FormatFont`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the [`FormatUnderlineType`] of the font.
+    pub fn get_underline_type(&self) -> &FormatUnderlineType {
+        &self.font.underline
+    }
+    /// Checks whether the font is strikethrough, learn more about it in [`FormatFont`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns `true` if the font is strikethrough, `false` otherwise.
+    pub fn is_strikethrough(&self) -> bool {
+        self.font.strike
+    }
+    /// Retrieves the vertical alignment of the font, learn more about it in [`FormatFont`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the [`FormatVertAlignType`] of the font.
+    pub fn get_vert_align(&self) -> &FormatVertAlignType {
+        &self.font.vert_align
     }
     /// Retrieves the size of the font, learn more about it in [`FormatFont`].
     ///
@@ -142,6 +198,32 @@ impl Format {
     pub fn get_border_bottom(&self) -> &FormatBorderElement {
         &self.border.bottom
     }
+    /// Retrieves the diagonal border of the cell, learn more about it in [`FormatBorder`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the [`FormatBorderElement`] of the cell's diagonal border.
+    pub fn get_border_diagonal(&self) -> &FormatBorderElement {
+        &self.border.diagonal
+    }
+
+    /// Checks whether the cell is locked, learn more about it in [`FormatProtection`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns `true` if the cell is locked, `false` otherwise.
+    pub fn is_locked(&self) -> bool {
+        self.protection.locked
+    }
+
+    /// Checks whether the formula of the cell is hidden, learn more about it in [`FormatProtection`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns `true` if the formula is hidden, `false` otherwise.
+    pub fn is_hidden(&self) -> bool {
+        self.protection.hidden
+    }
 }
 
 impl Format {
@@ -167,9 +249,69 @@ impl Format {
     ///
     /// ## Returns
     ///
-    /// Returns the modified format with font underline set to true.
+    /// Returns the modified format with font underline set to single.
     pub fn set_underline(mut self) -> Self {
-        self.font.underline = true;
+        self.font.underline = FormatUnderlineType::Single;
+        self
+    }
+    /// Set the underline type of the font, learn more about it in [`FormatFont`].
+    ///
+    /// ## Arguments
+    ///
+    /// | arg | type | meaning                                       |
+    /// |----------|------|-----------------------------------------------|
+    /// | underline_type     | [`FormatUnderlineType`]   | The new underline type of the font. |
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified format with font underline set to the provided type.
+    pub fn set_underline_type(mut self, underline_type: FormatUnderlineType) -> Self {
+        self.font.underline = underline_type;
+        self
+    }
+    /// Add a strikethrough for the font, learn more about it in [`FormatFont`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified format with font strike set to true.
+    pub fn set_strikethrough(mut self) -> Self {
+        self.font.strike = true;
+        self
+    }
+    /// Set the font to outline, learn more about it in [`FormatFont`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified format with font outline set to true.
+    pub fn set_outline(mut self) -> Self {
+        self.font.outline = true;
+        self
+    }
+    /// Set the font to shadow, learn more about it in [`FormatFont`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified format with font shadow set to true.
+    pub fn set_shadow(mut self) -> Self {
+        self.font.shadow = true;
+        self
+    }
+    /// Set the font to superscript, learn more about it in [`FormatFont`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified format with font vertical alignment set to superscript.
+    pub fn set_superscript(mut self) -> Self {
+        self.font.vert_align = FormatVertAlignType::Superscript;
+        self
+    }
+    /// Set the font to subscript, learn more about it in [`FormatFont`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified format with font vertical alignment set to subscript.
+    pub fn set_subscript(mut self) -> Self {
+        self.font.vert_align = FormatVertAlignType::Subscript;
         self
     }
     /// Set the size of the font, learn more about it in [`FormatFont`].
@@ -236,6 +378,54 @@ impl Format {
         self
     }
 
+    /// Set the font family, learn more about it in [`FormatFont`].
+    ///
+    /// ## Arguments
+    ///
+    /// | arg | type | meaning                                       |
+    /// |----------|------|-----------------------------------------------|
+    /// | family     | u8   | The new font family, such as 1 for *Roman*, 2 for *Swiss*. |
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with the family set to the provided value.
+    pub fn set_font_family(mut self, family: u8) -> Self {
+        self.font.family = Some(family);
+        self
+    }
+
+    /// Set the font character set, learn more about it in [`FormatFont`].
+    ///
+    /// ## Arguments
+    ///
+    /// | arg | type | meaning                                       |
+    /// |----------|------|-----------------------------------------------|
+    /// | charset     | u8   | The new font character set, such as 0 for *ANSI*. |
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with the charset set to the provided value.
+    pub fn set_font_charset(mut self, charset: u8) -> Self {
+        self.font.charset = Some(charset);
+        self
+    }
+
+    /// Set the font scheme, learn more about it in [`FormatFont`].
+    ///
+    /// ## Arguments
+    ///
+    /// | arg | type | meaning                                       |
+    /// |----------|------|-----------------------------------------------|
+    /// | scheme     | [`FormatFontScheme`]   | The new font scheme. |
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with the scheme set to the provided value.
+    pub fn set_font_scheme(mut self, scheme: FormatFontScheme) -> Self {
+        self.font.scheme = scheme;
+        self
+    }
+
     /// Set all borders of the format, learn more about it in [`FormatBorder`].
     ///
     /// ## Arguments
@@ -328,6 +518,46 @@ impl Format {
         self.border.bottom = format_border;
         self
     }
+
+    /// Set the diagonal border of the format, learn more about it in [`FormatBorder`].
+    ///
+    /// ## Arguments
+    ///
+    /// | arg            | type                  | meaning                                       |
+    /// |---------------------|-----------------------|-----------------------------------------------|
+    /// | format_border_type | [`FormatBorderType`]      | The type of border to apply to the diagonal of the format. |
+    /// | diagonal_up | `bool`      | Draw the diagonal from the bottom left to the top right. |
+    /// | diagonal_down | `bool`      | Draw the diagonal from the top left to the bottom right. |
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with the diagonal border of the format set to the provided type and directions.
+    pub fn set_border_diagonal(mut self, format_border_type: FormatBorderType, diagonal_up: bool, diagonal_down: bool) -> Self {
+        self.border.diagonal.border_type = format_border_type;
+        self.border.diagonal_up = diagonal_up;
+        self.border.diagonal_down = diagonal_down;
+        self
+    }
+
+    /// Set the color of all borders of the format, learn more about it in [`FormatBorder`].
+    ///
+    /// ## Arguments
+    ///
+    /// | arg            | type                  | meaning                                       |
+    /// |---------------------|-----------------------|-----------------------------------------------|
+    /// | format_color | [`FormatColor`]      | The color to apply to borders of the format. |
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with borders of the format set to the provided color.
+    pub fn set_border_color(mut self, format_color: FormatColor) -> Self {
+        self.border.left.color = format_color;
+        self.border.right.color = format_color;
+        self.border.top.color = format_color;
+        self.border.bottom.color = format_color;
+        self.border.diagonal.color = format_color;
+        self
+    }
     
     /// Set the background color of the format.
     ///
@@ -341,8 +571,46 @@ impl Format {
     ///
     /// Returns the modified Format with the background color of the format set to the provided color.
     pub fn set_background_color(mut self, format_color: FormatColor) -> Self {
-        self.fill.pattern_type = "solid".to_string();
+        self.fill.pattern_type = FillPattern::Solid;
         self.fill.fg_color = format_color;
+        self.fill.gradient = None;
+        self
+    }
+
+    /// Set the pattern of the background, learn more about it in [`FormatFill`].
+    ///
+    /// ## Arguments
+    ///
+    /// | arg       | type         | meaning                                       |
+    /// |----------------|--------------|-----------------------------------------------|
+    /// | pattern   | [`FillPattern`]  | The pattern of the background. |
+    /// | fg_color   | [`FormatColor`]  | The foreground color of the pattern. |
+    /// | bg_color   | [`FormatColor`]  | The background color of the pattern. |
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with the background pattern set to the provided pattern and colors.
+    pub fn set_pattern(mut self, pattern: FillPattern, fg_color: FormatColor, bg_color: FormatColor) -> Self {
+        self.fill.pattern_type = pattern;
+        self.fill.fg_color = fg_color;
+        self.fill.bg_color = bg_color;
+        self.fill.gradient = None;
+        self
+    }
+
+    /// Set the gradient of the background, learn more about it in [`GradientFill`].
+    ///
+    /// ## Arguments
+    ///
+    /// | arg       | type         | meaning                                       |
+    /// |----------------|--------------|-----------------------------------------------|
+    /// | gradient   | [`GradientFill`]  | The gradient of the background. |
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with the background gradient set to the provided gradient.
+    pub fn set_gradient(mut self, gradient: GradientFill) -> Self {
+        self.fill.gradient = Some(gradient);
         self
     }
 
@@ -359,10 +627,95 @@ impl Format {
     /// Returns the modified Format with the alignment of the format set to the provided type.
     pub fn set_align(mut self, format_align_type: FormatAlignType) -> Self {
         match format_align_type {
-            FormatAlignType::Left | FormatAlignType::Center | FormatAlignType::Right =>
-                self.align.horizontal = Some(format_align_type),
-            FormatAlignType::Top | FormatAlignType::VerticalCenter | FormatAlignType::Bottom =>
-                self.align.vertical = Some(format_align_type),
+            FormatAlignType::Left => self.align.horizontal = Some(FormatAlignHorizontal::Left),
+            FormatAlignType::Center => self.align.horizontal = Some(FormatAlignHorizontal::Center),
+            FormatAlignType::Right => self.align.horizontal = Some(FormatAlignHorizontal::Right),
+            FormatAlignType::Top => self.align.vertical = Some(FormatAlignVertical::Top),
+            FormatAlignType::VerticalCenter => self.align.vertical = Some(FormatAlignVertical::Center),
+            FormatAlignType::Bottom => self.align.vertical = Some(FormatAlignVertical::Bottom),
+        }
+        self
+    }
+
+    /// Set the horizontal alignment of the format, learn more about it in [`FormatAlign`].
+    ///
+    /// ## Arguments
+    ///
+    /// | arg             | type                  | meaning                                       |
+    /// |----------------------|-----------------------|-----------------------------------------------|
+    /// | horizontal    | [`FormatAlignHorizontal`]       | The horizontal alignment to apply to the format. |
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with the horizontal alignment set to the provided type.
+    pub fn set_align_horizontal(mut self, horizontal: FormatAlignHorizontal) -> Self {
+        self.align.horizontal = Some(horizontal);
+        self
+    }
+
+    /// Set the vertical alignment of the format, learn more about it in [`FormatAlign`].
+    ///
+    /// ## Arguments
+    ///
+    /// | arg             | type                  | meaning                                       |
+    /// |----------------------|-----------------------|-----------------------------------------------|
+    /// | vertical    | [`FormatAlignVertical`]       | The vertical alignment to apply to the format. |
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with the vertical alignment set to the provided type.
+    pub fn set_align_vertical(mut self, vertical: FormatAlignVertical) -> Self {
+        self.align.vertical = Some(vertical);
+        self
+    }
+
+    /// Wrap the text of the cell, learn more about it in [`FormatAlign`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with text wrap set to true.
+    pub fn set_text_wrap(mut self) -> Self {
+        self.align.wrap_text = true;
+        self
+    }
+
+    /// Shrink the text to fit the cell width, learn more about it in [`FormatAlign`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with shrink to fit set to true.
+    pub fn set_shrink(mut self) -> Self {
+        self.align.shrink_to_fit = true;
+        self
+    }
+
+    /// Justify the last line of the distributed text, learn more about it in [`FormatAlign`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with justify last line set to true.
+    pub fn set_justify_last_line(mut self) -> Self {
+        self.align.justify_last_line = true;
+        self
+    }
+
+    /// Set the rotation of the text, learn more about it in [`FormatAlign`].
+    ///
+    /// ## Arguments
+    ///
+    /// | arg | type | meaning                                       |
+    /// |----------|------|-----------------------------------------------|
+    /// | rotation   | i16   | The rotation angle from -90 to 90 degrees, or 270 for vertical stacked text,<br>other values are ignored. |
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with the text rotation set to the provided value.
+    pub fn set_rotation(mut self, rotation: i16) -> Self {
+        match rotation {
+            0..=90 => self.align.text_rotation = rotation as u8,
+            -90..=-1 => self.align.text_rotation = (90 - rotation) as u8,
+            270 => self.align.text_rotation = 255,
+            _ => {}
         }
         self
     }
@@ -398,4 +751,71 @@ impl Format {
         self.align.indent = indent;
         self
     }
-}
\ No newline at end of file
+
+    /// Set the relative indent of the text alignment, learn more about it in [`FormatAlign`].
+    ///
+    /// ## Arguments
+    ///
+    /// | arg | type | meaning                                       |
+    /// |----------|------|-----------------------------------------------|
+    /// | relative_indent   | i32   | The indent relative to the existing indent. |
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified Format with the relative indent of the text alignment set to the provided value.
+    pub fn set_relative_indent(mut self, relative_indent: i32) -> Self {
+        self.align.relative_indent = relative_indent;
+        self
+    }
+
+    /// Unlock the cell, so that it can be edited when the worksheet is protected,
+    /// learn more about it in [`FormatProtection`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified format with locked set to false.
+    pub fn set_unlocked(mut self) -> Self {
+        self.protection.locked = false;
+        self
+    }
+
+    /// Lock the cell, so that it can not be edited when the worksheet is protected,
+    /// learn more about it in [`FormatProtection`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified format with locked set to true.
+    pub fn set_locked(mut self) -> Self {
+        self.protection.locked = true;
+        self
+    }
+
+    /// Hide the formula of the cell when the worksheet is protected,
+    /// learn more about it in [`FormatProtection`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified format with hidden set to true.
+    pub fn set_hidden(mut self) -> Self {
+        self.protection.hidden = true;
+        self
+    }
+
+    /// Bases the format on a named cell style of the workbook,
+    /// the style should be added by [`Workbook::add_named_style`](crate::Workbook::add_named_style)
+    /// or [`Workbook::add_builtin_style`](crate::Workbook::add_builtin_style).
+    ///
+    /// ## Arguments
+    ///
+    /// | Argument | Type | Description |
+    /// | --- | --- | --- |
+    /// | `style_name` | `&str` | The name of the cell style. |
+    ///
+    /// ## Returns
+    ///
+    /// Returns the modified format with the style name set.
+    pub fn set_style_name(mut self, style_name: &str) -> Self {
+        self.style_name = Some(style_name.to_string());
+        self
+    }
+}
@@ -1,12 +1,12 @@
 //!
 //! This module contains the [`FormatAlign`] struct, which used to edit the align style of the [`Cell`],
-//! including set vertical and horizontal align, reading order and indent.
+//! including set vertical and horizontal align, text wrapping, rotation, shrinking, reading order and indent.
 //!
 //! # Examples
 //!
 //! Set vertical and horizontal align
 //! ```
-//! use edit_xlsx::{Cell, Format, FormatAlign, FormatAlignType, Workbook, Write};
+//! use edit_xlsx::{Cell, Format, FormatAlign, FormatAlignHorizontal, FormatAlignType, FormatAlignVertical, Workbook, Write};
 //! // Set cell text alignment to top left
 //! let format = Format::default()
 //!     .set_align(FormatAlignType::Top)
@@ -14,8 +14,8 @@
 //! // Or use
 //! // let mut format = Format::default();
 //! // let mut format_align = FormatAlign::default();
-//! // format_align.vertical = Some(FormatAlignType::Top);
-//! // format_align.horizontal = Some(FormatAlignType::Left);
+//! // format_align.vertical = Some(FormatAlignVertical::Top);
+//! // format_align.horizontal = Some(FormatAlignHorizontal::Left);
 //! // format.align = format_align;
 //! let mut workbook = Workbook::from_path("./examples/xlsx/accounting.xlsx").unwrap();
 //! let worksheet = workbook.get_worksheet_mut_by_name("worksheet").unwrap();
@@ -60,25 +60,56 @@
 //! }
 //! workbook.save_as("./examples/indent.xlsx").unwrap();
 //! ```
+//!
+//! Wrap, rotate and shrink text
+//! ```
+//! use edit_xlsx::{Format, FormatAlignHorizontal, FormatAlignVertical, Workbook, Write};
+//! let wrap = Format::default().set_text_wrap();
+//! let rotate = Format::default().set_rotation(45);
+//! let stacked = Format::default().set_rotation(270);
+//! let shrink = Format::default().set_shrink();
+//! let distributed = Format::default()
+//!     .set_align_horizontal(FormatAlignHorizontal::Distributed)
+//!     .set_align_vertical(FormatAlignVertical::Justify)
+//!     .set_justify_last_line();
+//! let mut workbook = Workbook::new();
+//! let worksheet = workbook.get_worksheet_mut(1).unwrap();
+//! worksheet.write_with_format("A1", "Some long text to wrap in a cell", &wrap).unwrap();
+//! worksheet.write_with_format("B1", "Rotated", &rotate).unwrap();
+//! worksheet.write_with_format("C1", "Stacked", &stacked).unwrap();
+//! worksheet.write_with_format("D1", "Some long text to shrink in a cell", &shrink).unwrap();
+//! worksheet.write_with_format("E1", "Some distributed text", &distributed).unwrap();
+//! workbook.save_as("./examples/align_wrap_rotate.xlsx").unwrap();
+//! ```
 use crate::Cell;
 use crate::xml::common::FromFormat;
 
 ///
 /// [`FormatAlign`] struct, which used to edit the align style of the [`Cell`],
-/// including set vertical and horizontal align(by [`FormatAlignType`]), reading order and indent.
+/// including set vertical and horizontal align, text wrapping, rotation, shrinking, reading order and indent.
 /// # Fields
 /// | field        | type        | meaning                                                      |
 /// | ------------ | ----------- | ------------------------------------------------------------ |
-/// | `horizontal`     | [`Option<FormatAlignType>`] | The [`Cell`]'s horizontal alignment.<br> It's Some value can be [`FormatAlignType`] Left, Right, Center |
-/// | `vertical`    | [`Option<FormatAlignType>`] | The [`Cell`]'s vertical alignment.<br> It's Some value can be [`FormatAlignType`] Top, Bottom, VerticalCenter |
-/// | `reading_order`      | u8 | The [`Cell`]'s reading order<br>1: left to right<br>2: right to left|
+/// | `horizontal`     | [`Option<FormatAlignHorizontal>`] | The [`Cell`]'s horizontal alignment. |
+/// | `vertical`    | [`Option<FormatAlignVertical>`] | The [`Cell`]'s vertical alignment. |
+/// | `reading_order`      | u8 | The [`Cell`]'s reading order<br>0: context dependent<br>1: left to right<br>2: right to left|
 /// | `indent`   | u8 | The [`Cell`]'s indent               |
+/// | `relative_indent`   | i32 | The indent relative to the existing indent, only used in differential formats |
+/// | `wrap_text`   | bool | Whether the text wraps in the [`Cell`] |
+/// | `shrink_to_fit`   | bool | Whether the text shrinks to fit the [`Cell`] width |
+/// | `text_rotation`   | u8 | The rotation of the text<br>0-90: rotated counterclockwise<br>91-180: rotated clockwise by (value - 90) degrees<br>255: vertical stacked text |
+/// | `justify_last_line`   | bool | Whether the last line of the text is justified, only works with distributed horizontal alignment |
 #[derive(Clone, Debug, PartialEq)]
 pub struct FormatAlign {
-    pub horizontal: Option<FormatAlignType>,
-    pub vertical: Option<FormatAlignType>,
+    pub horizontal: Option<FormatAlignHorizontal>,
+    pub vertical: Option<FormatAlignVertical>,
     pub reading_order: u8,
     pub indent: u8,
+    pub relative_indent: i32,
+    pub wrap_text: bool,
+    pub shrink_to_fit: bool,
+    pub text_rotation: u8,
+    pub justify_last_line: bool,
 }
 
 impl Default for FormatAlign {
@@ -88,11 +119,111 @@ impl Default for FormatAlign {
             vertical: None,
             reading_order: 1,
             indent: 0,
+            relative_indent: 0,
+            wrap_text: false,
+            shrink_to_fit: false,
+            text_rotation: 0,
+            justify_last_line: false,
+        }
+    }
+}
+
+/// [`FormatAlignHorizontal`] determines the horizontal position of the alignment.
+///
+/// # Fields:
+/// | unit | meaning|
+/// | ---- | ---- |
+/// | `General` |general-align, text is left-aligned and numbers are right-aligned|
+/// | `Left` |left-align|
+/// | `Center` |center-align|
+/// | `Right` |right-align|
+/// | `Fill` |repeat the text to fill the cell width|
+/// | `Justify` |justify the wrapped text to both edges|
+/// | `CenterContinuous` |center the text across the selected blank cells|
+/// | `Distributed` |distribute the words evenly across the cell width|
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FormatAlignHorizontal {
+    General,
+    Left,
+    Center,
+    Right,
+    Fill,
+    Justify,
+    CenterContinuous,
+    Distributed,
+}
+
+impl FormatAlignHorizontal {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            FormatAlignHorizontal::General => "general",
+            FormatAlignHorizontal::Left => "left",
+            FormatAlignHorizontal::Center => "center",
+            FormatAlignHorizontal::Right => "right",
+            FormatAlignHorizontal::Fill => "fill",
+            FormatAlignHorizontal::Justify => "justify",
+            FormatAlignHorizontal::CenterContinuous => "centerContinuous",
+            FormatAlignHorizontal::Distributed => "distributed",
+        }
+    }
+
+    pub(crate) fn from_str(horizontal: &str) -> Self {
+        match horizontal {
+            "left" => FormatAlignHorizontal::Left,
+            "center" => FormatAlignHorizontal::Center,
+            "right" => FormatAlignHorizontal::Right,
+            "fill" => FormatAlignHorizontal::Fill,
+            "justify" => FormatAlignHorizontal::Justify,
+            "centerContinuous" => FormatAlignHorizontal::CenterContinuous,
+            "distributed" => FormatAlignHorizontal::Distributed,
+            _ => FormatAlignHorizontal::General,
         }
     }
 }
 
-/// [`FormatAlignType`] determines the position of the alignment.
+/// [`FormatAlignVertical`] determines the vertical position of the alignment.
+///
+/// # Fields:
+/// | unit | meaning|
+/// | ---- | ---- |
+/// | `Top` |top-align|
+/// | `Center` |center-align|
+/// | `Bottom` |bottom-align|
+/// | `Justify` |justify the wrapped lines to both top and bottom|
+/// | `Distributed` |distribute the lines evenly across the cell height|
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FormatAlignVertical {
+    Top,
+    Center,
+    Bottom,
+    Justify,
+    Distributed,
+}
+
+impl FormatAlignVertical {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            FormatAlignVertical::Top => "top",
+            FormatAlignVertical::Center => "center",
+            FormatAlignVertical::Bottom => "bottom",
+            FormatAlignVertical::Justify => "justify",
+            FormatAlignVertical::Distributed => "distributed",
+        }
+    }
+
+    pub(crate) fn from_str(vertical: &str) -> Self {
+        match vertical {
+            "top" => FormatAlignVertical::Top,
+            "center" => FormatAlignVertical::Center,
+            "justify" => FormatAlignVertical::Justify,
+            "distributed" => FormatAlignVertical::Distributed,
+            _ => FormatAlignVertical::Bottom,
+        }
+    }
+}
+
+/// [`FormatAlignType`] determines the position of the alignment,
+/// it's a shorthand of [`FormatAlignHorizontal`] and [`FormatAlignVertical`] used by [`Format::set_align`](crate::Format::set_align).
 ///
 /// # Fields:
 /// | unit | meaning|
@@ -130,22 +261,6 @@ impl FormatAlignType {
             FormatAlignType::Right => "right",
         }
     }
-
-    pub(crate) fn from_str(format_align_type: Option<&String>, is_horizontal: bool) -> Option<FormatAlignType> {
-        if let Some(format_align_type) = format_align_type {
-            let format_align_type = format_align_type.as_str();
-            Some(match format_align_type {
-                "top" => FormatAlignType::Top,
-                "center" => if is_horizontal { FormatAlignType::Center } else { FormatAlignType::VerticalCenter },
-                "bottom" => FormatAlignType::Bottom,
-                "left" => FormatAlignType::Left,
-                "right" => FormatAlignType::Right,
-                _ => FormatAlignType::default()
-            })
-        } else {
-            None
-        }
-    }
 }
 
 impl FromFormat<FormatAlignType> for String {
@@ -37,8 +37,11 @@
 //! let mut workbook = Workbook::new();
 //! let worksheet = workbook.get_worksheet_mut(1).unwrap();
 //! let mut cell: Cell<String> = Cell::default();
-//! let mut format = Format::default();
-//! format.border.diagonal = FormatBorderElement::from_border_type(&FormatBorderType::Thin);
+//! let format = Format::default().set_border_diagonal(FormatBorderType::Thin, false, true);
+//! // Or use
+//! // let mut format = Format::default();
+//! // format.border.diagonal = FormatBorderElement::from_border_type(&FormatBorderType::Thin);
+//! // format.border.diagonal_down = true;
 //! cell.format = Some(format);
 //! worksheet.write_cell("A1", &cell).unwrap();
 //! // todo bug fix
@@ -68,6 +71,18 @@
 //! workbook.save_as("./examples/border_wrap_merged_cells.xlsx").unwrap();
 //! ```
 //!
+//! Draw an outline box and a grid of borders around ranges
+//! ```
+//! use edit_xlsx::{FormatBorderElement, FormatBorderType, FormatColor, Workbook, Write};
+//! let mut workbook = Workbook::new();
+//! let worksheet = workbook.get_worksheet_mut(1).unwrap();
+//! worksheet.write_row("B2", &["Region", "Sales"]).unwrap();
+//! let thick = FormatBorderElement::new(&FormatBorderType::Thick, &FormatColor::RGB(0, 0, 128));
+//! worksheet.draw_border_outline("B2:E6", &thick);
+//! worksheet.draw_border_grid("B8:E12", &FormatBorderElement::from_border_type(&FormatBorderType::Thin));
+//! workbook.save_as("./examples/border_draw_range.xlsx").unwrap();
+//! ```
+//!
 
 use std::fmt::{Display, Formatter};
 use crate::{Cell, FormatColor};
@@ -84,15 +99,42 @@ use crate::xml::style::color::Color;
 /// | `right`    | [`FormatBorderElement`] | The [`Cell`]'s right border style               |
 /// | `top`      | [`FormatBorderElement`] | The [`Cell`]'s top border style               |
 /// | `bottom`   | [`FormatBorderElement`] | The [`Cell`]'s bottom border style               |
-/// | `diagonal` | [`FormatBorderElement`] | The [`Cell`]'s diagonal border style               |
+/// | `diagonal` | [`FormatBorderElement`] | The [`Cell`]'s diagonal border style, only shown when `diagonal_up` or `diagonal_down` is set |
+/// | `vertical` | [`FormatBorderElement`] | The inner vertical border style, only used by differential (conditional) formats |
+/// | `horizontal` | [`FormatBorderElement`] | The inner horizontal border style, only used by differential (conditional) formats |
+/// | `diagonal_up` | `bool` | Draw the diagonal from the bottom left to the top right |
+/// | `diagonal_down` | `bool` | Draw the diagonal from the top left to the bottom right |
+/// | `outline` | `bool` | Apply the borders to the outside of a range of cells, defaults to `true` |
 ///
-#[derive(Clone, Debug, PartialEq, Default)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct FormatBorder {
     pub left: FormatBorderElement,
     pub right: FormatBorderElement,
     pub top: FormatBorderElement,
     pub bottom: FormatBorderElement,
     pub diagonal: FormatBorderElement,
+    pub vertical: FormatBorderElement,
+    pub horizontal: FormatBorderElement,
+    pub diagonal_up: bool,
+    pub diagonal_down: bool,
+    pub outline: bool,
+}
+
+impl Default for FormatBorder {
+    fn default() -> Self {
+        Self {
+            left: Default::default(),
+            right: Default::default(),
+            top: Default::default(),
+            bottom: Default::default(),
+            diagonal: Default::default(),
+            vertical: Default::default(),
+            horizontal: Default::default(),
+            diagonal_up: false,
+            diagonal_down: false,
+            outline: true,
+        }
+    }
 }
 
 ///
@@ -247,49 +289,38 @@ impl FormatBorderType {
 
 impl FromFormat<FormatBorder> for Border {
     fn set_attrs_by_format(&mut self, format: &FormatBorder) {
+        self.diagonal_up = if format.diagonal_up { Some(1) } else { None };
+        self.diagonal_down = if format.diagonal_down { Some(1) } else { None };
+        self.outline = if format.outline { None } else { Some(0) };
         self.left = Some(BorderElement::from_format(&format.left));
         self.right = Some(BorderElement::from_format(&format.right));
         self.top = Some(BorderElement::from_format(&format.top));
         self.bottom = Some(BorderElement::from_format(&format.bottom));
         self.diagonal = Some(BorderElement::from_format(&format.diagonal));
+        // Inner borders are only meaningful in dxf, keep them away from the cell borders.
+        self.vertical = if format.vertical == FormatBorderElement::default() {
+            None
+        } else {
+            Some(BorderElement::from_format(&format.vertical))
+        };
+        self.horizontal = if format.horizontal == FormatBorderElement::default() {
+            None
+        } else {
+            Some(BorderElement::from_format(&format.horizontal))
+        };
     }
 
     fn set_format(&self, format: &mut FormatBorder) {
-        format.left = {
-            if let Some(left) = &self.left {
-                left.get_format()
-            } else {
-                FormatBorderElement::default()
-            }
-        };
-        format.right = {
-            if let Some(right) = &self.right {
-                right.get_format()
-            } else {
-                FormatBorderElement::default()
-            }
-        };
-        format.top = {
-            if let Some(top) = &self.top {
-                top.get_format()
-            } else {
-                FormatBorderElement::default()
-            }
-        };
-        format.bottom = {
-            if let Some(bottom) = &self.bottom {
-                bottom.get_format()
-            } else {
-                FormatBorderElement::default()
-            }
-        };
-        format.diagonal = {
-            if let Some(diagonal) = &self.diagonal {
-                diagonal.get_format()
-            } else {
-                FormatBorderElement::default()
-            }
-        }
+        format.diagonal_up = self.diagonal_up == Some(1);
+        format.diagonal_down = self.diagonal_down == Some(1);
+        format.outline = self.outline != Some(0);
+        format.left = self.left.as_ref().get_format();
+        format.right = self.right.as_ref().get_format();
+        format.top = self.top.as_ref().get_format();
+        format.bottom = self.bottom.as_ref().get_format();
+        format.diagonal = self.diagonal.as_ref().get_format();
+        format.vertical = self.vertical.as_ref().get_format();
+        format.horizontal = self.horizontal.as_ref().get_format();
     }
 }
 
@@ -300,10 +331,10 @@ impl FromFormat<FormatBorderElement> for BorderElement {
     }
 
     fn set_format(&self, format: &mut FormatBorderElement) {
-        // format.color = self.color.unwrap_or_default();
+        format.color = self.color.as_ref().get_format();
         match &self.style {
             None => format.border_type = FormatBorderType::default(),
             Some(style) => format.border_type = FormatBorderType::from_str(style)
         }
     }
-}
\ No newline at end of file
+}
@@ -45,6 +45,7 @@
 
 use crate::xml::common::FromFormat;
 use crate::xml::style::color::Color;
+use crate::utils::color_util;
 
 /// [`FormatColor`] is mainly used for setting fonts, backgrounds, and sheet tab colors.
 ///
@@ -89,17 +90,8 @@ impl FromFormat<FormatColor> for Color {
             FormatColor::Index(id)
         } else if let (Some(theme), tint) = (self.theme, self.tint) {
             FormatColor::Theme(theme, tint.unwrap_or_default())
-        } else if let Some(color) = &self.rgb {
-            let argb: Vec<u8> = color
-                .chars()
-                .collect::<Vec<char>>()
-                .chunks(2)
-                .map(|chunk| {
-                    let hex_string: String = chunk.iter().collect();
-                    u8::from_str_radix(&hex_string, 16).unwrap()
-                })
-                .collect();
-            FormatColor::RGB(argb[1], argb[2], argb[3])
+        } else if let Some((r, g, b)) = self.rgb.as_deref().and_then(color_util::hex_to_rgb) {
+            FormatColor::RGB(r, g, b)
         } else {
             FormatColor::Default
         };
@@ -6,13 +6,13 @@
 //!
 //! Use [`FormatFill`] to fill columns' color
 //! ```
-//! use edit_xlsx::{Format, FormatColor, FormatFill, Workbook, WorkSheetCol, Write};
+//! use edit_xlsx::{FillPattern, Format, FormatColor, FormatFill, Workbook, WorkSheetCol, Write};
 //! let mut workbook = Workbook::from_path("./examples/xlsx/accounting.xlsx").unwrap();
 //! let worksheet = workbook.get_worksheet_mut_by_name("worksheet").unwrap();
 //! let mut white = Format::default();
 //! white.fill.fg_color = FormatColor::RGB(255, 255, 255);
 //! white.fill.bg_color = FormatColor::RGB(0, 0, 200);
-//! white.fill.pattern_type = "lightGrid".to_string();
+//! white.fill.pattern_type = FillPattern::LightGrid;
 //! worksheet.set_columns_width_with_format("A:XFD", 8.12, &white).unwrap();
 //! workbook.save_as("./examples/fill_fill_columns_color.xlsx").unwrap();
 //! ```
@@ -20,13 +20,13 @@
 //! Use [`FormatFill`] to fill row's color
 //!
 //! ```
-//! use edit_xlsx::{Format, FormatColor, FormatFill, Row, Workbook, WorkSheetCol, WorkSheetRow, Write};
+//! use edit_xlsx::{FillPattern, Format, FormatColor, FormatFill, Row, Workbook, WorkSheetCol, WorkSheetRow, Write};
 //! let mut workbook = Workbook::from_path("./examples/xlsx/accounting.xlsx").unwrap();
 //! let worksheet = workbook.get_worksheet_mut_by_name("worksheet").unwrap();
 //! let mut white = Format::default();
 //! white.fill.fg_color = FormatColor::RGB(255, 255, 255);
 //! white.fill.bg_color = FormatColor::RGB(200, 0, 0);
-//! white.fill.pattern_type = "lightGrid".to_string();
+//! white.fill.pattern_type = FillPattern::LightGrid;
 //! worksheet.set_row_height_with_format(1, 15.0, &white).unwrap();
 //! worksheet.set_row_height_with_format(2, 15.0, &white).unwrap();
 //! worksheet.set_row_height_with_format(3, 15.0, &white).unwrap();
@@ -36,22 +36,41 @@
 //! Use [`FormatFill`] to fill cell's color
 //!
 //! ```
-//! use edit_xlsx::{Format, FormatColor, FormatFill, Workbook, Write};
+//! use edit_xlsx::{FillPattern, Format, FormatColor, FormatFill, Workbook, Write};
 //! let mut workbook = Workbook::from_path("./examples/xlsx/accounting.xlsx").unwrap();
 //! let worksheet = workbook.get_worksheet_mut_by_name("worksheet").unwrap();
 //! let mut yellow = Format::default();
 //! yellow.fill.fg_color = FormatColor::RGB(255, 255, 0);
 //! yellow.fill.bg_color = FormatColor::RGB(0, 255, 0);
-//! yellow.fill.pattern_type = "lightGrid".to_string();
+//! yellow.fill.pattern_type = FillPattern::LightGrid;
 //! worksheet.write_with_format("B6", "Yellow fg and green bg", &yellow).unwrap();
 //! workbook.save_as("./examples/fill_fill_cells_color.xlsx").unwrap();
 //! ```
 
+//!
+//! Use [`GradientFill`] to fill cell's color with gradient
+//!
+//! ```
+//! use edit_xlsx::{Format, FormatColor, GradientFill, Workbook, Write};
+//! let mut workbook = Workbook::new();
+//! let worksheet = workbook.get_worksheet_mut(1).unwrap();
+//! let linear = Format::default().set_gradient(GradientFill::linear(90.0, &[
+//!     (0.0, FormatColor::RGB(255, 255, 255)),
+//!     (1.0, FormatColor::RGB(0, 112, 192)),
+//! ]));
+//! let path = Format::default().set_gradient(GradientFill::path(0.5, 0.5, 0.5, 0.5, &[
+//!     (0.0, FormatColor::RGB(255, 255, 255)),
+//!     (1.0, FormatColor::Theme(4, 0.0)),
+//! ]));
+//! worksheet.write_with_format("B2", "Linear gradient", &linear).unwrap();
+//! worksheet.write_with_format("B4", "Path gradient", &path).unwrap();
+//! workbook.save_as("./examples/fill_gradient_cells.xlsx").unwrap();
+//! ```
 
 use crate::FormatColor;
 use crate::xml::common::FromFormat;
 use crate::xml::style::color::Color;
-use crate::xml::style::fill::Fill;
+use crate::xml::style::fill::{Fill, GradientFill as XmlGradientFill, PatternFill, Stop};
 use crate::{Row, Column, Cell, Format};
 
 ///
@@ -60,15 +79,17 @@ use crate::{Row, Column, Cell, Format};
 /// # Fields
 /// | field        | type        | meaning                                                      |
 /// | ------------ | ----------- | ------------------------------------------------------------ |
-/// | `pattern_type` | [`String`]      | The color filling method, the contents of which can be referred to the [official documentation](https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.patternvalues?view=openxml-3.0.1) |
+/// | `pattern_type` | [`FillPattern`]      | The color filling method |
 /// | `fg_color`     | [`FormatColor`] | The foreground color of filling                              |
 /// | `bg_color`     | [`FormatColor`] | The background color of filling                              |
+/// | `gradient`     | [`Option<GradientFill>`] | The gradient filling, it takes precedence over the pattern filling when it's `Some` |
 ///
 #[derive(Clone, Debug, PartialEq)]
 pub struct FormatFill {
-    pub pattern_type: String,
+    pub pattern_type: FillPattern,
     pub fg_color: FormatColor,
-    pub bg_color: FormatColor
+    pub bg_color: FormatColor,
+    pub gradient: Option<GradientFill>,
 }
 
 impl Default for FormatFill {
@@ -79,23 +100,262 @@ impl Default for FormatFill {
     /// When using [`Format::default()`], its `fill` field will be filled by this method.
     fn default() -> Self {
         FormatFill {
-            pattern_type: "none".to_string(),
+            pattern_type: FillPattern::None,
             fg_color: FormatColor::default(),
             bg_color: FormatColor::Index(65),
+            gradient: None,
+        }
+    }
+}
+
+///
+/// Enumeration of the pattern types of filling, learn more in the
+/// [official documentation](https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.patternvalues?view=openxml-3.0.1)
+///
+/// # Fields:
+/// | unit | meaning |
+/// | ---- | ---- |
+/// | `None` | Default, No fill |
+/// | `Solid` | Solid fill by the foreground color |
+/// | `MediumGray` | 50% gray |
+/// | `DarkGray` | 75% gray |
+/// | `LightGray` | 25% gray |
+/// | `DarkHorizontal` | Horizontal stripe |
+/// | `DarkVertical` | Vertical stripe |
+/// | `DarkDown` | Reverse diagonal stripe |
+/// | `DarkUp` | Diagonal stripe |
+/// | `DarkGrid` | Diagonal crosshatch |
+/// | `DarkTrellis` | Thick diagonal crosshatch |
+/// | `LightHorizontal` | Thin horizontal stripe |
+/// | `LightVertical` | Thin vertical stripe |
+/// | `LightDown` | Thin reverse diagonal stripe |
+/// | `LightUp` | Thin diagonal stripe |
+/// | `LightGrid` | Thin horizontal crosshatch |
+/// | `LightTrellis` | Thin diagonal crosshatch |
+/// | `Gray125` | 12.5% gray |
+/// | `Gray0625` | 6.25% gray |
+///
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub enum FillPattern {
+    /// Default, No fill
+    #[default]
+    None,
+    /// Solid fill by the foreground color
+    Solid,
+    /// 50% gray
+    MediumGray,
+    /// 75% gray
+    DarkGray,
+    /// 25% gray
+    LightGray,
+    /// Horizontal stripe
+    DarkHorizontal,
+    /// Vertical stripe
+    DarkVertical,
+    /// Reverse diagonal stripe
+    DarkDown,
+    /// Diagonal stripe
+    DarkUp,
+    /// Diagonal crosshatch
+    DarkGrid,
+    /// Thick diagonal crosshatch
+    DarkTrellis,
+    /// Thin horizontal stripe
+    LightHorizontal,
+    /// Thin vertical stripe
+    LightVertical,
+    /// Thin reverse diagonal stripe
+    LightDown,
+    /// Thin diagonal stripe
+    LightUp,
+    /// Thin horizontal crosshatch
+    LightGrid,
+    /// Thin diagonal crosshatch
+    LightTrellis,
+    /// 12.5% gray
+    Gray125,
+    /// 6.25% gray
+    Gray0625,
+}
+
+impl FillPattern {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            FillPattern::None => "none",
+            FillPattern::Solid => "solid",
+            FillPattern::MediumGray => "mediumGray",
+            FillPattern::DarkGray => "darkGray",
+            FillPattern::LightGray => "lightGray",
+            FillPattern::DarkHorizontal => "darkHorizontal",
+            FillPattern::DarkVertical => "darkVertical",
+            FillPattern::DarkDown => "darkDown",
+            FillPattern::DarkUp => "darkUp",
+            FillPattern::DarkGrid => "darkGrid",
+            FillPattern::DarkTrellis => "darkTrellis",
+            FillPattern::LightHorizontal => "lightHorizontal",
+            FillPattern::LightVertical => "lightVertical",
+            FillPattern::LightDown => "lightDown",
+            FillPattern::LightUp => "lightUp",
+            FillPattern::LightGrid => "lightGrid",
+            FillPattern::LightTrellis => "lightTrellis",
+            FillPattern::Gray125 => "gray125",
+            FillPattern::Gray0625 => "gray0625",
+        }
+    }
+
+    pub(crate) fn from_str(pattern_str: &str) -> Self {
+        match pattern_str {
+            "solid" => FillPattern::Solid,
+            "mediumGray" => FillPattern::MediumGray,
+            "darkGray" => FillPattern::DarkGray,
+            "lightGray" => FillPattern::LightGray,
+            "darkHorizontal" => FillPattern::DarkHorizontal,
+            "darkVertical" => FillPattern::DarkVertical,
+            "darkDown" => FillPattern::DarkDown,
+            "darkUp" => FillPattern::DarkUp,
+            "darkGrid" => FillPattern::DarkGrid,
+            "darkTrellis" => FillPattern::DarkTrellis,
+            "lightHorizontal" => FillPattern::LightHorizontal,
+            "lightVertical" => FillPattern::LightVertical,
+            "lightDown" => FillPattern::LightDown,
+            "lightUp" => FillPattern::LightUp,
+            "lightGrid" => FillPattern::LightGrid,
+            "lightTrellis" => FillPattern::LightTrellis,
+            "gray125" => FillPattern::Gray125,
+            "gray0625" => FillPattern::Gray0625,
+            _ => FillPattern::None,
         }
     }
 }
 
+///
+/// [`GradientFill`] fills the cell with colors gradually changing between the stops.
+/// # Fields
+/// | field        | type        | meaning                                                      |
+/// | ------------ | ----------- | ------------------------------------------------------------ |
+/// | `gradient_type` | [`GradientType`] | Linear gradient or path gradient |
+/// | `degree`     | f64 | The angle of the linear gradient |
+/// | `left`, `right`, `top`, `bottom` | f64 | The position of the inner rectangle of the path gradient, from 0.0 to 1.0 |
+/// | `stops`     | [`Vec<GradientStop>`] | The color stops of the gradient |
+///
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct GradientFill {
+    pub gradient_type: GradientType,
+    pub degree: f64,
+    pub left: f64,
+    pub right: f64,
+    pub top: f64,
+    pub bottom: f64,
+    pub stops: Vec<GradientStop>,
+}
+
+impl GradientFill {
+    ///
+    /// Create a linear [`GradientFill`] by the angle and the (position, color) of stops.
+    ///
+    pub fn linear(degree: f64, stops: &[(f64, FormatColor)]) -> GradientFill {
+        GradientFill {
+            gradient_type: GradientType::Linear,
+            degree,
+            stops: stops.iter().map(|&(position, color)| GradientStop { position, color }).collect(),
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Create a path [`GradientFill`] by the inner rectangle and the (position, color) of stops.
+    ///
+    pub fn path(left: f64, right: f64, top: f64, bottom: f64, stops: &[(f64, FormatColor)]) -> GradientFill {
+        GradientFill {
+            gradient_type: GradientType::Path,
+            left,
+            right,
+            top,
+            bottom,
+            stops: stops.iter().map(|&(position, color)| GradientStop { position, color }).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+///
+/// The type of [`GradientFill`], `Linear` by default.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub enum GradientType {
+    #[default]
+    Linear,
+    Path,
+}
+
+///
+/// The color stop of [`GradientFill`], `position` is from 0.0 to 1.0.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct GradientStop {
+    pub position: f64,
+    pub color: FormatColor,
+}
+
+impl FromFormat<GradientFill> for XmlGradientFill {
+    fn set_attrs_by_format(&mut self, format: &GradientFill) {
+        let non_zero = |val: f64| if val != 0.0 { Some(val) } else { None };
+        match format.gradient_type {
+            GradientType::Linear => {
+                self.gradient_type = None;
+                self.degree = non_zero(format.degree);
+            },
+            GradientType::Path => {
+                self.gradient_type = Some("path".to_string());
+                self.left = non_zero(format.left);
+                self.right = non_zero(format.right);
+                self.top = non_zero(format.top);
+                self.bottom = non_zero(format.bottom);
+            },
+        }
+        self.stops = format.stops.iter().map(|stop| Stop {
+            position: stop.position,
+            color: Color::from_format(&stop.color),
+        }).collect();
+    }
+
+    fn set_format(&self, format: &mut GradientFill) {
+        format.gradient_type = match self.gradient_type.as_deref() {
+            Some("path") => GradientType::Path,
+            _ => GradientType::Linear,
+        };
+        format.degree = self.degree.unwrap_or_default();
+        format.left = self.left.unwrap_or_default();
+        format.right = self.right.unwrap_or_default();
+        format.top = self.top.unwrap_or_default();
+        format.bottom = self.bottom.unwrap_or_default();
+        format.stops = self.stops.iter().map(|stop| GradientStop {
+            position: stop.position,
+            color: stop.color.get_format(),
+        }).collect();
+    }
+}
+
 impl FromFormat<FormatFill> for Fill {
     fn set_attrs_by_format(&mut self, format: &FormatFill) {
-        self.pattern_fill.fg_color = Color::from_format(&format.fg_color);
-        self.pattern_fill.bg_color = Color::from_format(&format.bg_color);
-        self.pattern_fill.pattern_type = String::from(&format.pattern_type);
+        if let Some(gradient) = &format.gradient {
+            self.pattern_fill = None;
+            self.gradient_fill = Some(XmlGradientFill::from_format(gradient));
+        } else {
+            let pattern_fill = self.pattern_fill.get_or_insert(PatternFill::default());
+            pattern_fill.fg_color = Color::from_format(&format.fg_color);
+            pattern_fill.bg_color = Color::from_format(&format.bg_color);
+            pattern_fill.pattern_type = String::from(format.pattern_type.to_str());
+            self.gradient_fill = None;
+        }
     }
 
     fn set_format(&self, format: &mut FormatFill) {
-        format.fg_color = self.pattern_fill.fg_color.get_format();
-        format.bg_color = self.pattern_fill.bg_color.get_format();
-        format.pattern_type = self.pattern_fill.pattern_type.to_string();
+        if let Some(pattern_fill) = &self.pattern_fill {
+            format.fg_color = pattern_fill.fg_color.get_format();
+            format.bg_color = pattern_fill.bg_color.get_format();
+            format.pattern_type = FillPattern::from_str(&pattern_fill.pattern_type);
+        }
+        format.gradient = self.gradient_fill.as_ref().map(|gradient_fill| gradient_fill.get_format());
     }
-}
\ No newline at end of file
+}
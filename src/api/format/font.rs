@@ -26,19 +26,33 @@ use crate::Cell;
 /// | --------- | ----------- | -------------------------------------- |
 /// | `bold`      | bool        | Whether the font is bold.              |
 /// | `italic`    | bool        | Whether the font is italic.            |
-/// | `underline` | bool        | Whether the font is underlined.        |
+/// | `underline` | [`FormatUnderlineType`] | The underline style of the font. |
+/// | `strike`    | bool        | Whether the font is strikethrough.     |
+/// | `outline`   | bool        | Whether the font is outlined, only works on macOS. |
+/// | `shadow`    | bool        | Whether the font is shadowed, only works on macOS. |
+/// | `vert_align` | [`FormatVertAlignType`] | The superscript or subscript of the font. |
 /// | `size`      | f64         | Font size in point.                    |
 /// | `color`     | [`FormatColor`] | Font color.                            |
 /// | `name`      | [`String`]      | Font name, same as displayed in Excel. |
+/// | `family`    | `Option<u8>` | Font family, such as 1 for *Roman*, 2 for *Swiss* and 3 for *Modern*. |
+/// | `charset`   | `Option<u8>` | Font character set, such as 0 for *ANSI* and 134 for *GB2312*. |
+/// | `scheme`    | [`FormatFontScheme`] | Whether the font follows the major or minor font of the theme. |
 ///
 #[derive(Clone, Debug, PartialEq)]
 pub struct FormatFont {
     pub bold: bool,
     pub italic: bool,
-    pub underline: bool,
+    pub underline: FormatUnderlineType,
+    pub strike: bool,
+    pub outline: bool,
+    pub shadow: bool,
+    pub vert_align: FormatVertAlignType,
     pub size: f64,
     pub color: FormatColor,
     pub name: String,
+    pub family: Option<u8>,
+    pub charset: Option<u8>,
+    pub scheme: FormatFontScheme,
 }
 
 impl Default for FormatFont {
@@ -51,10 +65,145 @@ impl Default for FormatFont {
         FormatFont {
             bold: false,
             italic: false,
-            underline: false,
+            underline: FormatUnderlineType::None,
+            strike: false,
+            outline: false,
+            shadow: false,
+            vert_align: FormatVertAlignType::Baseline,
             size: 11.0,
             color: Default::default(),
             name: "Calibri".to_string(),
+            family: None,
+            charset: None,
+            scheme: FormatFontScheme::None,
         }
     }
-}
\ No newline at end of file
+}
+
+///
+/// Enumeration of different underline type
+///
+/// # Fields:
+/// | unit | meaning |
+/// | ---- | ---- |
+/// | `None` | Default, No underline |
+/// | `Single` | Single underline |
+/// | `Double` | Double underline |
+/// | `SingleAccounting` | Single accounting underline, which underlines the whole cell width |
+/// | `DoubleAccounting` | Double accounting underline, which underlines the whole cell width |
+///
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub enum FormatUnderlineType {
+    /// Default, No underline
+    #[default]
+    None,
+    /// Single underline
+    Single,
+    /// Double underline
+    Double,
+    /// Single accounting underline
+    SingleAccounting,
+    /// Double accounting underline
+    DoubleAccounting,
+}
+
+impl FormatUnderlineType {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            FormatUnderlineType::None => "none",
+            FormatUnderlineType::Single => "single",
+            FormatUnderlineType::Double => "double",
+            FormatUnderlineType::SingleAccounting => "singleAccounting",
+            FormatUnderlineType::DoubleAccounting => "doubleAccounting",
+        }
+    }
+
+    pub(crate) fn from_str(underline_str: &str) -> Self {
+        match underline_str {
+            "single" => FormatUnderlineType::Single,
+            "double" => FormatUnderlineType::Double,
+            "singleAccounting" => FormatUnderlineType::SingleAccounting,
+            "doubleAccounting" => FormatUnderlineType::DoubleAccounting,
+            _ => FormatUnderlineType::None,
+        }
+    }
+}
+
+///
+/// Enumeration of the vertical alignment of the font, which used to write superscript and subscript.
+///
+/// # Fields:
+/// | unit | meaning |
+/// | ---- | ---- |
+/// | `Baseline` | Default, the regular vertical position |
+/// | `Superscript` | Superscript, e.g. the 2 in *x²* |
+/// | `Subscript` | Subscript, e.g. the 2 in *H₂O* |
+///
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub enum FormatVertAlignType {
+    /// Default, the regular vertical position
+    #[default]
+    Baseline,
+    /// Superscript
+    Superscript,
+    /// Subscript
+    Subscript,
+}
+
+impl FormatVertAlignType {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            FormatVertAlignType::Baseline => "baseline",
+            FormatVertAlignType::Superscript => "superscript",
+            FormatVertAlignType::Subscript => "subscript",
+        }
+    }
+
+    pub(crate) fn from_str(vert_align_str: &str) -> Self {
+        match vert_align_str {
+            "superscript" => FormatVertAlignType::Superscript,
+            "subscript" => FormatVertAlignType::Subscript,
+            _ => FormatVertAlignType::Baseline,
+        }
+    }
+}
+
+///
+/// Enumeration of the font scheme, a font with major or minor scheme
+/// will change with the fonts of the workbook's theme.
+///
+/// # Fields:
+/// | unit | meaning |
+/// | ---- | ---- |
+/// | `None` | Default, the font does not follow the theme |
+/// | `Major` | The font follows the major (headings) font of the theme |
+/// | `Minor` | The font follows the minor (body) font of the theme |
+///
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub enum FormatFontScheme {
+    /// Default, the font does not follow the theme
+    #[default]
+    None,
+    /// The major font of the theme
+    Major,
+    /// The minor font of the theme
+    Minor,
+}
+
+impl FormatFontScheme {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            FormatFontScheme::None => "none",
+            FormatFontScheme::Major => "major",
+            FormatFontScheme::Minor => "minor",
+        }
+    }
+
+    pub(crate) fn from_str(scheme_str: &str) -> Self {
+        match scheme_str {
+            "major" => FormatFontScheme::Major,
+            "minor" => FormatFontScheme::Minor,
+            _ => FormatFontScheme::None,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//!
+//! This module contains the [`FormatProtection`] struct, which used to set the protection of a format.
+//! The protection only takes effect when the [`WorkSheet`](crate::WorkSheet) is protected.
+//! # Examples
+//! ```
+//! use edit_xlsx::{Format, ProtectionOptions, Workbook, Write};
+//! let mut workbook = Workbook::new();
+//! let worksheet = workbook.get_worksheet_mut(1).unwrap();
+//! let unlocked = Format::default().set_unlocked();
+//! let hidden = Format::default().set_hidden();
+//! worksheet.write_with_format("A1", "This cell can be edited", &unlocked).unwrap();
+//! worksheet.write_formula_with_format("A2", "1+1", &hidden).unwrap();
+//! worksheet.protect("", &ProtectionOptions::default());
+//! workbook.save_as("./examples/protection_unlocked_cells.xlsx").unwrap();
+//! ```
+
+use crate::xml::common::FromFormat;
+use crate::xml::style::protection::Protection;
+
+///
+/// [`FormatProtection`] decides whether a cell is locked and whether its formula is hidden
+/// when the worksheet is protected.
+/// # Fields
+/// | field        | type        | meaning                                                      |
+/// | ------------ | ----------- | ------------------------------------------------------------ |
+/// | `locked` | [`bool`] | The cell can not be edited, the default value is `true` |
+/// | `hidden` | [`bool`] | The formula of the cell is hidden, the default value is `false` |
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormatProtection {
+    pub locked: bool,
+    pub hidden: bool,
+}
+
+impl Default for FormatProtection {
+    ///
+    /// Method [`FormatProtection::default()`] creates a locked and not hidden [`FormatProtection`],
+    /// which is the same as the default cell protection in Excel.
+    ///
+    /// When using [`Format::default()`], its `protection` field will be filled by this method.
+    fn default() -> Self {
+        FormatProtection {
+            locked: true,
+            hidden: false,
+        }
+    }
+}
+
+impl FromFormat<FormatProtection> for Protection {
+    fn set_attrs_by_format(&mut self, format: &FormatProtection) {
+        self.locked = if format.locked { None } else { Some(0) };
+        self.hidden = if format.hidden { Some(1) } else { None };
+    }
+
+    fn set_format(&self, format: &mut FormatProtection) {
+        format.locked = self.locked.unwrap_or(1) != 0;
+        format.hidden = self.hidden.unwrap_or(0) != 0;
+    }
+}
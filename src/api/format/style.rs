@@ -0,0 +1,302 @@
+//!
+//! This module contains the [`BuiltinStyle`] enum,
+//! which lists the built-in cell styles shown in Excel's style gallery.
+//!
+//! # Examples
+//! ```
+//! use edit_xlsx::{BuiltinStyle, Format, FormatColor, Workbook, WorkbookResult, Write};
+//! fn main() -> WorkbookResult<()> {
+//!     let mut workbook = Workbook::new();
+//!     workbook.add_builtin_style(BuiltinStyle::Good)?;
+//!     workbook.add_builtin_style(BuiltinStyle::Heading1)?;
+//!     workbook.add_named_style("Currency Bold", &Format::default().set_bold().set_color(FormatColor::RGB(0, 97, 0)))?;
+//!     let worksheet = workbook.get_worksheet_mut(1)?;
+//!     worksheet.write("A1", "Sales")?;
+//!     worksheet.write("A2", 100)?;
+//!     worksheet.apply_named_style("A1:A1", "Heading 1")?;
+//!     worksheet.apply_named_style("A2:A2", "Good")?;
+//!     let format = workbook.get_named_style("Currency Bold").unwrap();
+//!     let worksheet = workbook.get_worksheet_mut(1)?;
+//!     worksheet.write_with_format("A3", 200, &format)?;
+//!     workbook.save_as("./examples/named_style.xlsx")?;
+//!     Ok(())
+//! }
+//! ```
+//!
+
+use crate::{Format, FormatBorderElement, FormatBorderType, FormatColor, FormatFontScheme, FormatUnderlineType};
+
+///
+/// Enumeration of the built-in cell styles, use [`Workbook::add_builtin_style`](crate::Workbook::add_builtin_style)
+/// to add them into the workbook.
+///
+/// # Fields:
+/// | unit | name | builtinId |
+/// | ---- | ---- | ---- |
+/// | `Normal` | Normal | 0 |
+/// | `Comma` | Comma | 3 |
+/// | `Currency` | Currency | 4 |
+/// | `Percent` | Percent | 5 |
+/// | `Comma0` | Comma [0] | 6 |
+/// | `Currency0` | Currency [0] | 7 |
+/// | `Hyperlink` | Hyperlink | 8 |
+/// | `FollowedHyperlink` | Followed Hyperlink | 9 |
+/// | `Note` | Note | 10 |
+/// | `WarningText` | Warning Text | 11 |
+/// | `Title` | Title | 15 |
+/// | `Heading1` ~ `Heading4` | Heading 1 ~ Heading 4 | 16 ~ 19 |
+/// | `Input` | Input | 20 |
+/// | `Output` | Output | 21 |
+/// | `Calculation` | Calculation | 22 |
+/// | `CheckCell` | Check Cell | 23 |
+/// | `LinkedCell` | Linked Cell | 24 |
+/// | `Total` | Total | 25 |
+/// | `Good` | Good | 26 |
+/// | `Bad` | Bad | 27 |
+/// | `Neutral` | Neutral | 28 |
+/// | `Accent(n)` | Accent*n* | 29, 33, 37, 41, 45, 49 |
+/// | `Accent20(n)` | 20% - Accent*n* | 30, 34, 38, 42, 46, 50 |
+/// | `Accent40(n)` | 40% - Accent*n* | 31, 35, 39, 43, 47, 51 |
+/// | `Accent60(n)` | 60% - Accent*n* | 32, 36, 40, 44, 48, 52 |
+/// | `ExplanatoryText` | Explanatory Text | 53 |
+///
+/// The accent number *n* is from 1 to 6.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BuiltinStyle {
+    Normal,
+    Comma,
+    Currency,
+    Percent,
+    Comma0,
+    Currency0,
+    Hyperlink,
+    FollowedHyperlink,
+    Note,
+    WarningText,
+    Title,
+    Heading1,
+    Heading2,
+    Heading3,
+    Heading4,
+    Input,
+    Output,
+    Calculation,
+    CheckCell,
+    LinkedCell,
+    Total,
+    Good,
+    Bad,
+    Neutral,
+    Accent(u8),
+    Accent20(u8),
+    Accent40(u8),
+    Accent60(u8),
+    ExplanatoryText,
+}
+
+impl BuiltinStyle {
+    ///
+    /// The name of the style shown in Excel.
+    ///
+    pub fn name(self) -> String {
+        match self {
+            BuiltinStyle::Normal => "Normal".to_string(),
+            BuiltinStyle::Comma => "Comma".to_string(),
+            BuiltinStyle::Currency => "Currency".to_string(),
+            BuiltinStyle::Percent => "Percent".to_string(),
+            BuiltinStyle::Comma0 => "Comma [0]".to_string(),
+            BuiltinStyle::Currency0 => "Currency [0]".to_string(),
+            BuiltinStyle::Hyperlink => "Hyperlink".to_string(),
+            BuiltinStyle::FollowedHyperlink => "Followed Hyperlink".to_string(),
+            BuiltinStyle::Note => "Note".to_string(),
+            BuiltinStyle::WarningText => "Warning Text".to_string(),
+            BuiltinStyle::Title => "Title".to_string(),
+            BuiltinStyle::Heading1 => "Heading 1".to_string(),
+            BuiltinStyle::Heading2 => "Heading 2".to_string(),
+            BuiltinStyle::Heading3 => "Heading 3".to_string(),
+            BuiltinStyle::Heading4 => "Heading 4".to_string(),
+            BuiltinStyle::Input => "Input".to_string(),
+            BuiltinStyle::Output => "Output".to_string(),
+            BuiltinStyle::Calculation => "Calculation".to_string(),
+            BuiltinStyle::CheckCell => "Check Cell".to_string(),
+            BuiltinStyle::LinkedCell => "Linked Cell".to_string(),
+            BuiltinStyle::Total => "Total".to_string(),
+            BuiltinStyle::Good => "Good".to_string(),
+            BuiltinStyle::Bad => "Bad".to_string(),
+            BuiltinStyle::Neutral => "Neutral".to_string(),
+            BuiltinStyle::Accent(n) => format!("Accent{}", accent(n)),
+            BuiltinStyle::Accent20(n) => format!("20% - Accent{}", accent(n)),
+            BuiltinStyle::Accent40(n) => format!("40% - Accent{}", accent(n)),
+            BuiltinStyle::Accent60(n) => format!("60% - Accent{}", accent(n)),
+            BuiltinStyle::ExplanatoryText => "Explanatory Text".to_string(),
+        }
+    }
+
+    ///
+    /// The `builtinId` of the style.
+    ///
+    pub fn builtin_id(self) -> u32 {
+        match self {
+            BuiltinStyle::Normal => 0,
+            BuiltinStyle::Comma => 3,
+            BuiltinStyle::Currency => 4,
+            BuiltinStyle::Percent => 5,
+            BuiltinStyle::Comma0 => 6,
+            BuiltinStyle::Currency0 => 7,
+            BuiltinStyle::Hyperlink => 8,
+            BuiltinStyle::FollowedHyperlink => 9,
+            BuiltinStyle::Note => 10,
+            BuiltinStyle::WarningText => 11,
+            BuiltinStyle::Title => 15,
+            BuiltinStyle::Heading1 => 16,
+            BuiltinStyle::Heading2 => 17,
+            BuiltinStyle::Heading3 => 18,
+            BuiltinStyle::Heading4 => 19,
+            BuiltinStyle::Input => 20,
+            BuiltinStyle::Output => 21,
+            BuiltinStyle::Calculation => 22,
+            BuiltinStyle::CheckCell => 23,
+            BuiltinStyle::LinkedCell => 24,
+            BuiltinStyle::Total => 25,
+            BuiltinStyle::Good => 26,
+            BuiltinStyle::Bad => 27,
+            BuiltinStyle::Neutral => 28,
+            BuiltinStyle::Accent(n) => 25 + 4 * accent(n) as u32,
+            BuiltinStyle::Accent20(n) => 26 + 4 * accent(n) as u32,
+            BuiltinStyle::Accent40(n) => 27 + 4 * accent(n) as u32,
+            BuiltinStyle::Accent60(n) => 28 + 4 * accent(n) as u32,
+            BuiltinStyle::ExplanatoryText => 53,
+        }
+    }
+
+    ///
+    /// The built-in number format id of the style.
+    ///
+    pub(crate) fn num_fmt_id(self) -> u32 {
+        match self {
+            BuiltinStyle::Comma => 43,
+            BuiltinStyle::Currency => 44,
+            BuiltinStyle::Percent => 9,
+            BuiltinStyle::Comma0 => 41,
+            BuiltinStyle::Currency0 => 42,
+            _ => 0,
+        }
+    }
+
+    ///
+    /// The [`Format`] of the style, which is the same as the Office theme of Excel.
+    ///
+    pub fn format(self) -> Format {
+        let thin = |color| FormatBorderElement::new(&FormatBorderType::Thin, &color);
+        let all_borders = |mut format: Format, border: FormatBorderElement| {
+            format.border.left = border;
+            format.border.right = border;
+            format.border.top = border;
+            format.border.bottom = border;
+            format
+        };
+        let title_color = FormatColor::Theme(3, 0.0);
+        let accent_color = FormatColor::Theme(4, 0.0);
+        match self {
+            BuiltinStyle::Normal | BuiltinStyle::Comma | BuiltinStyle::Currency | BuiltinStyle::Percent
+            | BuiltinStyle::Comma0 | BuiltinStyle::Currency0 => Format::default(),
+            BuiltinStyle::Hyperlink => Format::default()
+                .set_underline_type(FormatUnderlineType::Single)
+                .set_color(FormatColor::Theme(10, 0.0)),
+            BuiltinStyle::FollowedHyperlink => Format::default()
+                .set_underline_type(FormatUnderlineType::Single)
+                .set_color(FormatColor::Theme(11, 0.0)),
+            BuiltinStyle::Note => all_borders(
+                Format::default().set_background_color(FormatColor::RGB(0xFF, 0xFF, 0xCC)),
+                thin(FormatColor::RGB(0xB2, 0xB2, 0xB2)),
+            ),
+            BuiltinStyle::WarningText => Format::default().set_color(FormatColor::RGB(0xFF, 0x00, 0x00)),
+            BuiltinStyle::Title => Format::default()
+                .set_font("Calibri Light")
+                .set_font_scheme(FormatFontScheme::Major)
+                .set_size(18)
+                .set_color(title_color),
+            BuiltinStyle::Heading1 => {
+                let mut format = Format::default().set_bold().set_size(15).set_color(title_color);
+                format.border.bottom = FormatBorderElement::new(&FormatBorderType::Thick, &accent_color);
+                format
+            }
+            BuiltinStyle::Heading2 => {
+                let mut format = Format::default().set_bold().set_size(13).set_color(title_color);
+                format.border.bottom = FormatBorderElement::new(&FormatBorderType::Thick, &FormatColor::Theme(4, 0.4999542222357858));
+                format
+            }
+            BuiltinStyle::Heading3 => {
+                let mut format = Format::default().set_bold().set_color(title_color);
+                format.border.bottom = FormatBorderElement::new(&FormatBorderType::Medium, &FormatColor::Theme(4, 0.3999755851924192));
+                format
+            }
+            BuiltinStyle::Heading4 => Format::default().set_bold().set_color(title_color),
+            BuiltinStyle::Input => all_borders(
+                Format::default()
+                    .set_background_color(FormatColor::RGB(0xFF, 0xCC, 0x99))
+                    .set_color(FormatColor::RGB(0x3F, 0x3F, 0x76)),
+                thin(FormatColor::RGB(0x7F, 0x7F, 0x7F)),
+            ),
+            BuiltinStyle::Output => all_borders(
+                Format::default()
+                    .set_background_color(FormatColor::RGB(0xF2, 0xF2, 0xF2))
+                    .set_bold()
+                    .set_color(FormatColor::RGB(0x3F, 0x3F, 0x3F)),
+                thin(FormatColor::RGB(0x3F, 0x3F, 0x3F)),
+            ),
+            BuiltinStyle::Calculation => all_borders(
+                Format::default()
+                    .set_background_color(FormatColor::RGB(0xF2, 0xF2, 0xF2))
+                    .set_bold()
+                    .set_color(FormatColor::RGB(0xFA, 0x7D, 0x00)),
+                thin(FormatColor::RGB(0x7F, 0x7F, 0x7F)),
+            ),
+            BuiltinStyle::CheckCell => all_borders(
+                Format::default()
+                    .set_background_color(FormatColor::RGB(0xA5, 0xA5, 0xA5))
+                    .set_bold()
+                    .set_color(FormatColor::Theme(0, 0.0)),
+                FormatBorderElement::new(&FormatBorderType::Double, &FormatColor::RGB(0x3F, 0x3F, 0x3F)),
+            ),
+            BuiltinStyle::LinkedCell => {
+                let mut format = Format::default().set_color(FormatColor::RGB(0xFA, 0x7D, 0x00));
+                format.border.bottom = FormatBorderElement::new(&FormatBorderType::Double, &FormatColor::RGB(0xFF, 0x80, 0x01));
+                format
+            }
+            BuiltinStyle::Total => {
+                let mut format = Format::default().set_bold().set_color(FormatColor::Theme(1, 0.0));
+                format.border.top = thin(accent_color);
+                format.border.bottom = FormatBorderElement::new(&FormatBorderType::Double, &accent_color);
+                format
+            }
+            BuiltinStyle::Good => Format::default()
+                .set_background_color(FormatColor::RGB(0xC6, 0xEF, 0xCE))
+                .set_color(FormatColor::RGB(0x00, 0x61, 0x00)),
+            BuiltinStyle::Bad => Format::default()
+                .set_background_color(FormatColor::RGB(0xFF, 0xC7, 0xCE))
+                .set_color(FormatColor::RGB(0x9C, 0x00, 0x06)),
+            BuiltinStyle::Neutral => Format::default()
+                .set_background_color(FormatColor::RGB(0xFF, 0xEB, 0x9C))
+                .set_color(FormatColor::RGB(0x9C, 0x57, 0x00)),
+            BuiltinStyle::Accent(n) => Format::default()
+                .set_background_color(FormatColor::Theme(3 + accent(n), 0.0))
+                .set_color(FormatColor::Theme(0, 0.0)),
+            BuiltinStyle::Accent20(n) => Format::default()
+                .set_background_color(FormatColor::Theme(3 + accent(n), 0.7999816888943144)),
+            BuiltinStyle::Accent40(n) => Format::default()
+                .set_background_color(FormatColor::Theme(3 + accent(n), 0.5999938962981048)),
+            BuiltinStyle::Accent60(n) => Format::default()
+                .set_background_color(FormatColor::Theme(3 + accent(n), 0.3999755851924192))
+                .set_color(FormatColor::Theme(0, 0.0)),
+            BuiltinStyle::ExplanatoryText => Format::default()
+                .set_italic()
+                .set_color(FormatColor::RGB(0x7F, 0x7F, 0x7F)),
+        }
+    }
+}
+
+fn accent(n: u8) -> u8 {
+    n.clamp(1, 6)
+}
@@ -0,0 +1,34 @@
+///
+/// A hyperlink on a worksheet, returned by [`WorkSheet::hyperlinks`](crate::WorkSheet::hyperlinks).
+///
+/// `cell` is the cell or the range the hyperlink is on, such as `"A1"` or `"A1:B2"`.
+/// A link to a web page, a mail address or a file has a `url`,
+/// a link to a place in the workbook has a `location` such as `"Sheet2!A1"` instead.
+///
+/// # Examples
+/// ```
+/// use edit_xlsx::{Workbook, WorkbookResult, Write};
+/// fn main() -> WorkbookResult<()> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.get_worksheet_mut(1)?;
+///     worksheet.write_url("A1", "https://www.rust-lang.org")?;
+///     worksheet.write_internal_link("A2", "Sheet1!C3", "Go to C3")?;
+///     worksheet.write_mailto("A3", "someone@example.com", "Mail me")?;
+///     worksheet.set_hyperlink_tooltip("A1", "The Rust home page")?;
+///     let hyperlinks = worksheet.hyperlinks();
+///     assert_eq!(hyperlinks[0].tooltip.as_deref(), Some("The Rust home page"));
+///     assert_eq!(hyperlinks[1].location.as_deref(), Some("Sheet1!C3"));
+///     // The text of the cell stays
+///     worksheet.remove_hyperlink("A3")?;
+///     workbook.save_as("./examples/hyperlink.xlsx")?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hyperlink {
+    pub cell: String,
+    pub url: Option<String>,
+    pub location: Option<String>,
+    pub display: Option<String>,
+    pub tooltip: Option<String>,
+}
@@ -0,0 +1,236 @@
+///
+/// The format of the image data passed to
+/// [`WorkSheet::insert_image_from_buffer`](crate::WorkSheet::insert_image_from_buffer).
+///
+/// | ImageFormat | Extension | Content type  |
+/// |-------------|-----------|---------------|
+/// | Png         | png       | image/png     |
+/// | Jpeg        | jpeg      | image/jpeg    |
+/// | Gif         | gif       | image/gif     |
+/// | Bmp         | bmp       | image/bmp     |
+/// | Svg         | svg       | image/svg+xml |
+///
+/// The svg images are recognized but cannot be inserted, Excel needs a bitmap fallback to show them.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Gif,
+    Bmp,
+    Svg,
+}
+
+impl ImageFormat {
+    pub(crate) fn from_extension(extension: &str) -> Option<ImageFormat> {
+        match extension.to_ascii_lowercase().as_str() {
+            "png" => Some(ImageFormat::Png),
+            "jpeg" | "jpg" => Some(ImageFormat::Jpeg),
+            "gif" => Some(ImageFormat::Gif),
+            "bmp" => Some(ImageFormat::Bmp),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Jpeg => "jpeg",
+            ImageFormat::Gif => "gif",
+            ImageFormat::Bmp => "bmp",
+            ImageFormat::Svg => "svg",
+        }
+    }
+
+    pub(crate) fn content_type(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "image/png",
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Gif => "image/gif",
+            ImageFormat::Bmp => "image/bmp",
+            ImageFormat::Svg => "image/svg+xml",
+        }
+    }
+}
+
+///
+/// How an image anchored over a range behaves when the cells under it are resized,
+/// i.e. the "Properties" of the picture in Excel.
+///
+/// | ImageEditAs | editAs   | Excel                                |
+/// |-------------|----------|--------------------------------------|
+/// | TwoCell     | twoCell  | Move and size with cells             |
+/// | OneCell     | oneCell  | Move but don't size with cells       |
+/// | Absolute    | absolute | Don't move or size with cells        |
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ImageEditAs {
+    TwoCell,
+    OneCell,
+    Absolute,
+}
+
+impl ImageEditAs {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            ImageEditAs::TwoCell => "twoCell",
+            ImageEditAs::OneCell => "oneCell",
+            ImageEditAs::Absolute => "absolute",
+        }
+    }
+}
+
+///
+/// An image placed on a worksheet, returned by [`WorkSheet::images`](crate::WorkSheet::images).
+///
+/// The cells are `(row, col)` starting from 1, `from` is `None` for an image with an absolute position,
+/// and `to` is only set for an image which is stretched between two cells.
+///
+/// # Examples
+/// ```
+/// use edit_xlsx::{Workbook, WorkbookResult};
+/// fn main() -> WorkbookResult<()> {
+///     let mut workbook = Workbook::from_path("./tests/xlsx/image_nao.xlsx")?;
+///     let worksheet = workbook.get_worksheet_mut(1)?;
+///     let image = &worksheet.images()[0];
+///     assert!(!image.buffer.is_empty());
+///     // Swap the image but keep its place and size
+///     let logo = std::fs::read("./examples/pics/rust.png")?;
+///     worksheet.replace_image(0, &logo)?;
+///     workbook.save_as("./examples/image_replace.xlsx")?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    pub name: String,
+    pub description: Option<String>,
+    pub from: Option<(u32, u32)>,
+    pub to: Option<(u32, u32)>,
+    pub format: Option<ImageFormat>,
+    pub buffer: Vec<u8>,
+}
+
+///
+/// [`ImageOptions`] decides where an image is placed relative to its anchor and how large it is.
+///
+/// The offsets are in pixels, the scales are applied to the real size of the image,
+/// which is read from the image header with its resolution taken into account.
+/// The alt text, the hyperlink and the lock and print properties apply to every way of inserting an image.
+///
+/// # Examples
+/// ```
+/// use edit_xlsx::{ImageFormat, ImageOptions, Workbook, WorkbookResult};
+/// fn main() -> WorkbookResult<()> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.get_worksheet_mut(1)?;
+///     let buffer = std::fs::read("./examples/pics/rust.png")?;
+///     let options = ImageOptions::default()
+///         .set_offset(10, 5)
+///         .set_scale(0.5, 0.5)
+///         .set_description("The Rust logo")
+///         .set_hyperlink("https://www.rust-lang.org")
+///         .set_prints_with_sheet(false);
+///     worksheet.insert_image_with_options("B2", &buffer, ImageFormat::Png, &options)?;
+///     workbook.save_as("./examples/image_from_buffer.xlsx")?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageOptions {
+    pub(crate) x_offset: u32,
+    pub(crate) y_offset: u32,
+    pub(crate) x_scale: f64,
+    pub(crate) y_scale: f64,
+    pub(crate) description: Option<String>,
+    pub(crate) title: Option<String>,
+    pub(crate) hyperlink: Option<String>,
+    pub(crate) edit_as: ImageEditAs,
+    pub(crate) locks_with_sheet: bool,
+    pub(crate) prints_with_sheet: bool,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        Self {
+            x_offset: 0,
+            y_offset: 0,
+            x_scale: 1.0,
+            y_scale: 1.0,
+            description: None,
+            title: None,
+            hyperlink: None,
+            edit_as: ImageEditAs::OneCell,
+            locks_with_sheet: true,
+            prints_with_sheet: true,
+        }
+    }
+}
+
+impl ImageOptions {
+    ///
+    /// Set the offset of the image from the top left corner of its anchor, in pixels.
+    ///
+    pub fn set_offset(mut self, x_offset: u32, y_offset: u32) -> Self {
+        self.x_offset = x_offset;
+        self.y_offset = y_offset;
+        self
+    }
+
+    ///
+    /// Set the horizontal and the vertical scale of the image, 1.0 keeps its real size.
+    ///
+    pub fn set_scale(mut self, x_scale: f64, y_scale: f64) -> Self {
+        self.x_scale = x_scale;
+        self.y_scale = y_scale;
+        self
+    }
+
+    ///
+    /// Set the alt text of the image, which is read by screen readers.
+    ///
+    pub fn set_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn set_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    ///
+    /// Open the url when the image is clicked.
+    ///
+    pub fn set_hyperlink(mut self, url: &str) -> Self {
+        self.hyperlink = Some(url.to_string());
+        self
+    }
+
+    ///
+    /// Set how the image behaves when the cells under it are resized, it is [`ImageEditAs::OneCell`] by default.
+    /// Only images stretched over a range by [`WorkSheet::insert_image_over_range`](crate::WorkSheet::insert_image_over_range) use it,
+    /// an image inserted at a single cell always moves but doesn't size with the cells.
+    ///
+    pub fn set_edit_as(mut self, edit_as: ImageEditAs) -> Self {
+        self.edit_as = edit_as;
+        self
+    }
+
+    ///
+    /// Whether the image is locked when the worksheet is protected, `true` by default.
+    ///
+    pub fn set_locks_with_sheet(mut self, locks_with_sheet: bool) -> Self {
+        self.locks_with_sheet = locks_with_sheet;
+        self
+    }
+
+    ///
+    /// Whether the image is printed with the worksheet, `true` by default.
+    ///
+    pub fn set_prints_with_sheet(mut self, prints_with_sheet: bool) -> Self {
+        self.prints_with_sheet = prints_with_sheet;
+        self
+    }
+}
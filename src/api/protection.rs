@@ -0,0 +1,163 @@
+use crate::utils::hash_util::DEFAULT_SPIN_COUNT;
+
+///
+/// [`ProtectionOptions`] decides what users are still allowed to do on a protected [`WorkSheet`](crate::WorkSheet).
+///
+/// By default, only selecting locked and unlocked cells is allowed,
+/// and the password will be hashed by the SHA-512 algorithm with 100000 spins.
+///
+/// # Examples
+/// ```
+/// use edit_xlsx::{Workbook, ProtectionOptions, WorkbookResult};
+/// fn main() -> WorkbookResult<()> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.get_worksheet_mut(1)?;
+///     let mut options = ProtectionOptions::default();
+///     options.allow_format_cells(true)
+///         .allow_insert_rows(true)
+///         .allow_sort(true)
+///         .allow_autofilter(true);
+///     worksheet.protect("password", &options);
+///     workbook.protect_structure("password");
+///     workbook.save_as("./examples/protection.xlsx")?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProtectionOptions {
+    pub(crate) objects: bool,
+    pub(crate) scenarios: bool,
+    pub(crate) format_cells: bool,
+    pub(crate) format_columns: bool,
+    pub(crate) format_rows: bool,
+    pub(crate) insert_columns: bool,
+    pub(crate) insert_rows: bool,
+    pub(crate) insert_hyperlinks: bool,
+    pub(crate) delete_columns: bool,
+    pub(crate) delete_rows: bool,
+    pub(crate) select_locked_cells: bool,
+    pub(crate) sort: bool,
+    pub(crate) autofilter: bool,
+    pub(crate) pivot_tables: bool,
+    pub(crate) select_unlocked_cells: bool,
+    pub(crate) legacy_password: bool,
+    pub(crate) spin_count: u32,
+}
+
+impl Default for ProtectionOptions {
+    fn default() -> Self {
+        Self {
+            objects: false,
+            scenarios: false,
+            format_cells: false,
+            format_columns: false,
+            format_rows: false,
+            insert_columns: false,
+            insert_rows: false,
+            insert_hyperlinks: false,
+            delete_columns: false,
+            delete_rows: false,
+            select_locked_cells: true,
+            sort: false,
+            autofilter: false,
+            pivot_tables: false,
+            select_unlocked_cells: true,
+            legacy_password: false,
+            spin_count: DEFAULT_SPIN_COUNT,
+        }
+    }
+}
+
+impl ProtectionOptions {
+    pub fn allow_edit_objects(&mut self, allow: bool) -> &mut Self {
+        self.objects = allow;
+        self
+    }
+
+    pub fn allow_edit_scenarios(&mut self, allow: bool) -> &mut Self {
+        self.scenarios = allow;
+        self
+    }
+
+    pub fn allow_format_cells(&mut self, allow: bool) -> &mut Self {
+        self.format_cells = allow;
+        self
+    }
+
+    pub fn allow_format_columns(&mut self, allow: bool) -> &mut Self {
+        self.format_columns = allow;
+        self
+    }
+
+    pub fn allow_format_rows(&mut self, allow: bool) -> &mut Self {
+        self.format_rows = allow;
+        self
+    }
+
+    pub fn allow_insert_columns(&mut self, allow: bool) -> &mut Self {
+        self.insert_columns = allow;
+        self
+    }
+
+    pub fn allow_insert_rows(&mut self, allow: bool) -> &mut Self {
+        self.insert_rows = allow;
+        self
+    }
+
+    pub fn allow_insert_hyperlinks(&mut self, allow: bool) -> &mut Self {
+        self.insert_hyperlinks = allow;
+        self
+    }
+
+    pub fn allow_delete_columns(&mut self, allow: bool) -> &mut Self {
+        self.delete_columns = allow;
+        self
+    }
+
+    pub fn allow_delete_rows(&mut self, allow: bool) -> &mut Self {
+        self.delete_rows = allow;
+        self
+    }
+
+    pub fn allow_select_locked_cells(&mut self, allow: bool) -> &mut Self {
+        self.select_locked_cells = allow;
+        self
+    }
+
+    pub fn allow_sort(&mut self, allow: bool) -> &mut Self {
+        self.sort = allow;
+        self
+    }
+
+    pub fn allow_autofilter(&mut self, allow: bool) -> &mut Self {
+        self.autofilter = allow;
+        self
+    }
+
+    pub fn allow_pivot_tables(&mut self, allow: bool) -> &mut Self {
+        self.pivot_tables = allow;
+        self
+    }
+
+    pub fn allow_select_unlocked_cells(&mut self, allow: bool) -> &mut Self {
+        self.select_unlocked_cells = allow;
+        self
+    }
+
+    ///
+    /// Hash the password by the legacy 16-bit algorithm instead of SHA-512,
+    /// which is compatible with Excel 2007 and earlier.
+    ///
+    pub fn use_legacy_password(&mut self) -> &mut Self {
+        self.legacy_password = true;
+        self
+    }
+
+    ///
+    /// Set the spin count of the SHA-512 algorithm, the default value is 100000.
+    ///
+    pub fn set_spin_count(&mut self, spin_count: u32) -> &mut Self {
+        self.spin_count = spin_count;
+        self
+    }
+}
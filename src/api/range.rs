@@ -0,0 +1,407 @@
+use std::fmt;
+use crate::api::cell::location::{Location, LocationRange};
+use crate::api::cell::values::{CellDisplay, CellValue};
+use crate::api::worksheet::range::_Range;
+use crate::result::{WorkSheetError, WorkSheetResult};
+use crate::utils::col_helper::{to_col, to_sheet_ref};
+use crate::utils::date_util::{add_months, add_weekdays};
+use crate::{Format, WorkSheet};
+
+///
+/// How [`Range::fill_series`] computes the next value of a series from the previous one.
+///
+/// | SeriesType | Next value                                   |
+/// |------------|----------------------------------------------|
+/// | Linear     | The previous value plus the step             |
+/// | Growth     | The previous value times the step            |
+/// | Day        | The previous date plus the step in days      |
+/// | Weekday    | The previous date plus the step in weekdays  |
+/// | Month      | The first date plus the step in months       |
+/// | Year       | The first date plus the step in years        |
+///
+/// The dates are the serial numbers Excel stores, format the cells with a date number format to show them as dates.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SeriesType {
+    Linear,
+    Growth,
+    Day,
+    Weekday,
+    Month,
+    Year,
+}
+
+impl SeriesType {
+    ///
+    /// The `index`-th value of the series, starting from 0.
+    ///
+    pub(crate) fn nth(self, start: f64, step: f64, index: u32) -> f64 {
+        let steps = step.round() as i64 * index as i64;
+        match self {
+            SeriesType::Linear => start + step * index as f64,
+            SeriesType::Growth => start * step.powi(index as i32),
+            SeriesType::Day => start + steps as f64,
+            SeriesType::Weekday => add_weekdays(start, steps),
+            SeriesType::Month => add_months(start, steps),
+            SeriesType::Year => add_months(start, steps * 12),
+        }
+    }
+}
+
+///
+/// A rectangular range of cells, optionally qualified with the name of its sheet.
+///
+/// A [`Range`] is parsed from and formatted as a reference like `A1:C3`, `$A$1` or `'My Sheet'!A1:C3`,
+/// and is accepted wherever a worksheet method takes a range.
+/// The bulk operations go through the cells of the worksheet once, so formatting a large range is cheap.
+/// They act on the worksheet they are given, and return [`WorkSheetError::SheetMismatch`]
+/// if the range names another worksheet.
+///
+/// # Examples
+/// ```
+/// use edit_xlsx::{Format, Range, SeriesType, Workbook, WorkbookResult};
+/// fn main() -> WorkbookResult<()> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.get_worksheet_mut(1)?;
+///     let range = Range::parse("A1:A10").unwrap();
+///     range.fill_series(worksheet, 1.0, 2.0, SeriesType::Linear)?;
+///     range.set_format(worksheet, &Format::default().set_bold())?;
+///     range.copy_to(worksheet, "C1")?;
+///     let header = Range::new(1, 1, 1, 3).unwrap().set_sheet("Sheet1");
+///     assert_eq!(header.to_string(), "Sheet1!A1:C1");
+///     assert_eq!(range.intersection(&header).unwrap().to_string(), "Sheet1!A1");
+///     workbook.save_as("./examples/range.xlsx")?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Range {
+    sheet: Option<String>,
+    first_row: u32,
+    first_col: u32,
+    last_row: u32,
+    last_col: u32,
+}
+
+impl Range {
+    ///
+    /// The range between two corners, the rows and the columns start from 1 and may be given in any order.
+    /// Returns `None` if a corner is not a cell of a worksheet, whose rows are 1 to 1048576 and columns 1 to 16384.
+    ///
+    pub fn new(first_row: u32, first_col: u32, last_row: u32, last_col: u32) -> Option<Range> {
+        if !is_cell(first_row, first_col) || !is_cell(last_row, last_col) {
+            return None;
+        }
+        Some(Range {
+            sheet: None,
+            first_row: first_row.min(last_row),
+            first_col: first_col.min(last_col),
+            last_row: first_row.max(last_row),
+            last_col: first_col.max(last_col),
+        })
+    }
+
+    ///
+    /// Parse a reference like `A1`, `A1:C3`, `$A$1:$C$3`, `Sheet1!A1:C3` or `'My Sheet'!A1`,
+    /// returns `None` if it is not a valid reference.
+    ///
+    pub fn parse(range_ref: &str) -> Option<Range> {
+        let (sheet, cells) = match range_ref.rsplit_once('!') {
+            Some((sheet, cells)) => (Some(unquote(sheet)?), cells),
+            None => (None, range_ref),
+        };
+        let (first, last) = cells.split_once(':').unwrap_or((cells, cells));
+        let (first_row, first_col) = parse_cell(first)?;
+        let (last_row, last_col) = parse_cell(last)?;
+        let mut range = Range::new(first_row, first_col, last_row, last_col)?;
+        range.sheet = sheet;
+        Some(range)
+    }
+
+    pub fn set_sheet(mut self, sheet: &str) -> Self {
+        self.sheet = Some(sheet.to_string());
+        self
+    }
+
+    pub fn sheet(&self) -> Option<&str> {
+        self.sheet.as_deref()
+    }
+
+    ///
+    /// The top left cell as `(row, col)`.
+    ///
+    pub fn first(&self) -> (u32, u32) {
+        (self.first_row, self.first_col)
+    }
+
+    ///
+    /// The bottom right cell as `(row, col)`.
+    ///
+    pub fn last(&self) -> (u32, u32) {
+        (self.last_row, self.last_col)
+    }
+
+    pub fn height(&self) -> u32 {
+        self.last_row - self.first_row + 1
+    }
+
+    pub fn width(&self) -> u32 {
+        self.last_col - self.first_col + 1
+    }
+
+    pub fn contains<L: Location>(&self, loc: L) -> bool {
+        let (row, col) = loc.to_location();
+        (self.first_row..=self.last_row).contains(&row) && (self.first_col..=self.last_col).contains(&col)
+    }
+
+    ///
+    /// The cells shared by the two ranges, `None` if they do not overlap or are on different sheets.
+    ///
+    pub fn intersection(&self, other: &Range) -> Option<Range> {
+        if !self.same_sheet(other) {
+            return None;
+        }
+        let first_row = self.first_row.max(other.first_row);
+        let first_col = self.first_col.max(other.first_col);
+        let last_row = self.last_row.min(other.last_row);
+        let last_col = self.last_col.min(other.last_col);
+        if first_row > last_row || first_col > last_col {
+            return None;
+        }
+        Some(Range { sheet: self.sheet.clone().or(other.sheet.clone()), first_row, first_col, last_row, last_col })
+    }
+
+    ///
+    /// The smallest range which covers both ranges, `None` if they are on different sheets.
+    ///
+    pub fn union(&self, other: &Range) -> Option<Range> {
+        if !self.same_sheet(other) {
+            return None;
+        }
+        Some(Range {
+            sheet: self.sheet.clone().or(other.sheet.clone()),
+            first_row: self.first_row.min(other.first_row),
+            first_col: self.first_col.min(other.first_col),
+            last_row: self.last_row.max(other.last_row),
+            last_col: self.last_col.max(other.last_col),
+        })
+    }
+
+    ///
+    /// The cells of the range as `(row, col)`, row by row.
+    ///
+    pub fn cells(&self) -> impl Iterator<Item = (u32, u32)> {
+        let (first_col, last_col) = (self.first_col, self.last_col);
+        (self.first_row..=self.last_row).flat_map(move |row| (first_col..=last_col).map(move |col| (row, col)))
+    }
+
+    fn same_sheet(&self, other: &Range) -> bool {
+        match (&self.sheet, &other.sheet) {
+            (Some(sheet), Some(other_sheet)) => sheet == other_sheet,
+            _ => true,
+        }
+    }
+}
+
+///
+/// Bulk operations
+///
+impl Range {
+    ///
+    /// Set the format of every cell in the range, the values are kept.
+    ///
+    pub fn set_format(&self, worksheet: &mut WorkSheet, format: &Format) -> WorkSheetResult<()> {
+        self.check_sheet(worksheet)?;
+        worksheet.set_range_format(self.to_range(), format);
+        Ok(())
+    }
+
+    ///
+    /// Clear the values and the formulas of the cells in the range, the formats are kept.
+    ///
+    pub fn clear_contents(&self, worksheet: &mut WorkSheet) -> WorkSheetResult<()> {
+        self.check_sheet(worksheet)?;
+        worksheet.clear_range_contents(self.to_range());
+        Ok(())
+    }
+
+    ///
+    /// Reset the cells in the range to the default format, the values are kept.
+    ///
+    pub fn clear_formats(&self, worksheet: &mut WorkSheet) -> WorkSheetResult<()> {
+        self.check_sheet(worksheet)?;
+        worksheet.clear_range_formats(self.to_range());
+        Ok(())
+    }
+
+    ///
+    /// Write the value in every cell of the range.
+    ///
+    pub fn fill_value<T: CellDisplay + CellValue>(&self, worksheet: &mut WorkSheet, value: T) -> WorkSheetResult<()> {
+        self.check_sheet(worksheet)?;
+        worksheet.fill_range_value(self.to_range(), &value);
+        Ok(())
+    }
+
+    ///
+    /// Fill the range with a series starting from `start`, see [`SeriesType`] for how `step` is applied.
+    /// A range of a single row is filled from left to right, otherwise every column is filled from top to bottom.
+    ///
+    pub fn fill_series(&self, worksheet: &mut WorkSheet, start: f64, step: f64, series_type: SeriesType) -> WorkSheetResult<()> {
+        self.check_sheet(worksheet)?;
+        worksheet.fill_range_series(self.to_range(), start, step, series_type);
+        Ok(())
+    }
+
+    ///
+    /// Copy the range so that its top left cell is at `loc`, see [`WorkSheet::copy_range`].
+    ///
+    pub fn copy_to<L: Location>(&self, worksheet: &mut WorkSheet, loc: L) -> WorkSheetResult<()> {
+        self.check_sheet(worksheet)?;
+        worksheet.copy_range(self, loc)
+    }
+
+    ///
+    /// Move the range so that its top left cell is at `loc`, see [`WorkSheet::move_range`].
+    ///
+    pub fn move_to<L: Location>(&self, worksheet: &mut WorkSheet, loc: L) -> WorkSheetResult<()> {
+        self.check_sheet(worksheet)?;
+        worksheet.move_range(self, loc)
+    }
+
+    ///
+    /// Whether the range may act on the worksheet, a range without a sheet name acts on any worksheet.
+    /// Sheet names are compared without case, like Excel does.
+    ///
+    fn check_sheet(&self, worksheet: &WorkSheet) -> WorkSheetResult<()> {
+        match &self.sheet {
+            Some(sheet) if sheet.to_lowercase() != worksheet.get_name().to_lowercase() => Err(WorkSheetError::SheetMismatch),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let range_ref = match (self.first_row, self.first_col) == (self.last_row, self.last_col) {
+            true => self.start_ref(),
+            false => self.to_range_ref(),
+        };
+        match &self.sheet {
+            Some(sheet) => write!(f, "{}", to_sheet_ref(sheet, &range_ref)),
+            None => write!(f, "{range_ref}"),
+        }
+    }
+}
+
+impl LocationRange for Range {
+    fn to_range_ref(&self) -> String {
+        self.to_range().to_range_ref()
+    }
+
+    fn to_range(&self) -> (u32, u32, u32, u32) {
+        (self.first_row, self.first_col, self.last_row, self.last_col)
+    }
+
+    fn to_row_range_ref(&self) -> String {
+        self.to_range().to_row_range_ref()
+    }
+
+    fn to_row_range(&self) -> (u32, u32) {
+        (self.first_row, self.last_row)
+    }
+
+    fn to_col_range_ref(&self) -> String {
+        self.to_range().to_col_range_ref()
+    }
+
+    fn to_col_range(&self) -> (u32, u32) {
+        (self.first_col, self.last_col)
+    }
+
+    fn start_ref(&self) -> String {
+        self.to_range().start_ref()
+    }
+
+    fn end_ref(&self) -> String {
+        self.to_range().end_ref()
+    }
+}
+
+impl LocationRange for &Range {
+    fn to_range_ref(&self) -> String {
+        (*self).to_range_ref()
+    }
+
+    fn to_range(&self) -> (u32, u32, u32, u32) {
+        (*self).to_range()
+    }
+
+    fn to_row_range_ref(&self) -> String {
+        (*self).to_row_range_ref()
+    }
+
+    fn to_row_range(&self) -> (u32, u32) {
+        (*self).to_row_range()
+    }
+
+    fn to_col_range_ref(&self) -> String {
+        (*self).to_col_range_ref()
+    }
+
+    fn to_col_range(&self) -> (u32, u32) {
+        (*self).to_col_range()
+    }
+
+    fn start_ref(&self) -> String {
+        (*self).start_ref()
+    }
+
+    fn end_ref(&self) -> String {
+        (*self).end_ref()
+    }
+}
+
+///
+/// The name of a sheet in a reference, which is quoted with its quotes doubled if it has special characters.
+///
+fn unquote(sheet: &str) -> Option<String> {
+    let sheet = match sheet.strip_prefix('\'') {
+        Some(quoted) => quoted.strip_suffix('\'')?.replace("''", "'"),
+        None => sheet.to_string(),
+    };
+    (!sheet.is_empty()).then_some(sheet)
+}
+
+///
+/// Parse a cell like `A1` or `$A$1` as `(row, col)`.
+///
+fn parse_cell(cell_ref: &str) -> Option<(u32, u32)> {
+    let cell_ref = cell_ref.replace('$', "").to_ascii_uppercase();
+    let digits = cell_ref.find(|c: char| c.is_ascii_digit())?;
+    let (col, row) = cell_ref.split_at(digits);
+    if col.is_empty() || col.len() > 3 || !col.chars().all(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+    if !row.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let row: u32 = row.parse().ok()?;
+    let col = to_col(col);
+    is_cell(row, col).then_some((row, col))
+}
+
+fn is_cell(row: u32, col: u32) -> bool {
+    (1..=1048576).contains(&row) && (1..=16384).contains(&col)
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(Range::parse("A1:C3"), Range::new(1, 1, 3, 3));
+    assert_eq!(Range::parse("$c$3:a1"), Range::new(1, 1, 3, 3));
+    assert_eq!(Range::parse("'Tom''s'!B2").map(|range| range.to_string()), Some("'Tom''s'!B2".to_string()));
+    assert_eq!(Range::parse("Sheet1!XFD1048576").map(|range| range.last()), Some((1048576, 16384)));
+    assert_eq!(Range::parse("A0"), None);
+    assert_eq!(Range::parse("XFE1"), None);
+    assert_eq!(Range::parse("A1B"), None);
+}
@@ -0,0 +1,187 @@
+use crate::{FormatAlignHorizontal, FormatAlignVertical, FormatColor, RichText, Word};
+
+///
+/// The preset geometry of a [`Shape`].
+///
+/// | ShapeType         | Preset geometry       |
+/// |-------------------|-----------------------|
+/// | Rectangle         | rect                  |
+/// | RoundedRectangle  | roundRect             |
+/// | Ellipse           | ellipse               |
+/// | RightArrow        | rightArrow            |
+/// | LeftArrow         | leftArrow             |
+/// | UpArrow           | upArrow               |
+/// | DownArrow         | downArrow             |
+/// | Callout           | wedgeRectCallout      |
+/// | RoundedCallout    | wedgeRoundRectCallout |
+/// | TextBox           | rect                  |
+/// | StraightConnector | straightConnector1    |
+/// | ElbowConnector    | bentConnector3        |
+/// | CurvedConnector   | curvedConnector3      |
+///
+/// The connectors are lines from the top left corner to the bottom right corner of the range,
+/// they have no text.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ShapeType {
+    Rectangle,
+    RoundedRectangle,
+    Ellipse,
+    RightArrow,
+    LeftArrow,
+    UpArrow,
+    DownArrow,
+    Callout,
+    RoundedCallout,
+    TextBox,
+    StraightConnector,
+    ElbowConnector,
+    CurvedConnector,
+}
+
+impl ShapeType {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            ShapeType::Rectangle => "rect",
+            ShapeType::RoundedRectangle => "roundRect",
+            ShapeType::Ellipse => "ellipse",
+            ShapeType::RightArrow => "rightArrow",
+            ShapeType::LeftArrow => "leftArrow",
+            ShapeType::UpArrow => "upArrow",
+            ShapeType::DownArrow => "downArrow",
+            ShapeType::Callout => "wedgeRectCallout",
+            ShapeType::RoundedCallout => "wedgeRoundRectCallout",
+            ShapeType::TextBox => "rect",
+            ShapeType::StraightConnector => "straightConnector1",
+            ShapeType::ElbowConnector => "bentConnector3",
+            ShapeType::CurvedConnector => "curvedConnector3",
+        }
+    }
+
+    pub(crate) fn is_connector(self) -> bool {
+        matches!(self, ShapeType::StraightConnector | ShapeType::ElbowConnector | ShapeType::CurvedConnector)
+    }
+}
+
+///
+/// A [`Shape`] is a box, an arrow or a connector drawn over the cells by
+/// [`WorkSheet::insert_shape`](crate::WorkSheet::insert_shape).
+///
+/// Without a fill or an outline color, a shape is filled with the theme's accent 1 color,
+/// and a text box is white with a grey outline.
+///
+/// # Examples
+/// ```
+/// use edit_xlsx::{FormatAlignHorizontal, FormatAlignVertical, FormatColor, FormatFont, RichText, Shape, ShapeType, Workbook, WorkbookResult, Word};
+/// fn main() -> WorkbookResult<()> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.get_worksheet_mut(1)?;
+///     let mut bold = FormatFont::default();
+///     bold.bold = true;
+///     let label = Shape::new(ShapeType::RoundedRectangle)
+///         .set_rich_text(&(RichText::new_word("Revenue ", &bold) + Word::new("+12%", &FormatFont::default())))
+///         .set_fill_color(FormatColor::RGB(0xE2, 0xEF, 0xDA))
+///         .set_line_color(FormatColor::RGB(0x70, 0xAD, 0x47))
+///         .set_align_horizontal(FormatAlignHorizontal::Center)
+///         .set_align_vertical(FormatAlignVertical::Center);
+///     worksheet.insert_shape("B2:D4", &label)?;
+///     let arrow = Shape::new(ShapeType::StraightConnector).set_end_arrow(true);
+///     worksheet.insert_shape("D4:F8", &arrow)?;
+///     workbook.save_as("./examples/shape.xlsx")?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shape {
+    pub(crate) shape_type: ShapeType,
+    pub(crate) name: Option<String>,
+    pub(crate) text: RichText,
+    pub(crate) fill_color: Option<FormatColor>,
+    pub(crate) line_color: Option<FormatColor>,
+    pub(crate) line_width: Option<f64>,
+    pub(crate) end_arrow: bool,
+    pub(crate) align_horizontal: Option<FormatAlignHorizontal>,
+    pub(crate) align_vertical: Option<FormatAlignVertical>,
+}
+
+impl Shape {
+    pub fn new(shape_type: ShapeType) -> Shape {
+        Shape {
+            shape_type,
+            name: None,
+            text: RichText::default(),
+            fill_color: None,
+            line_color: None,
+            line_width: None,
+            end_arrow: false,
+            align_horizontal: None,
+            align_vertical: None,
+        }
+    }
+
+    ///
+    /// Set the name of the shape shown in the selection pane, it is "Shape N" by default.
+    ///
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    ///
+    /// Set the text of the shape in the default font, a line break starts a new paragraph.
+    ///
+    pub fn set_text(mut self, text: &str) -> Self {
+        self.text = RichText { words: vec![Word { text: text.to_string(), font: None }] };
+        self
+    }
+
+    ///
+    /// Set the text of the shape, every [`Word`] is written as a run with its own font.
+    ///
+    pub fn set_rich_text(mut self, rich_text: &RichText) -> Self {
+        self.text = rich_text.clone();
+        self
+    }
+
+    ///
+    /// Set the fill color, [`FormatColor::Default`] leaves the shape unfilled.
+    ///
+    pub fn set_fill_color(mut self, color: FormatColor) -> Self {
+        self.fill_color = Some(color);
+        self
+    }
+
+    ///
+    /// Set the outline color, [`FormatColor::Default`] removes the outline.
+    ///
+    pub fn set_line_color(mut self, color: FormatColor) -> Self {
+        self.line_color = Some(color);
+        self
+    }
+
+    ///
+    /// Set the width of the outline in points.
+    ///
+    pub fn set_line_width(mut self, width: f64) -> Self {
+        self.line_width = Some(width);
+        self
+    }
+
+    ///
+    /// Draw an arrowhead at the end of the outline, which is mostly used by the connectors.
+    ///
+    pub fn set_end_arrow(mut self, end_arrow: bool) -> Self {
+        self.end_arrow = end_arrow;
+        self
+    }
+
+    pub fn set_align_horizontal(mut self, align: FormatAlignHorizontal) -> Self {
+        self.align_horizontal = Some(align);
+        self
+    }
+
+    pub fn set_align_vertical(mut self, align: FormatAlignVertical) -> Self {
+        self.align_vertical = Some(align);
+        self
+    }
+}
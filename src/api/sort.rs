@@ -0,0 +1,83 @@
+use crate::FormatColor;
+
+///
+/// The direction in which [`WorkSheet::sort_range`](crate::WorkSheet::sort_range) orders the rows by a key.
+///
+/// | SortOrder  | By value                                        | By color                            |
+/// |------------|-------------------------------------------------|-------------------------------------|
+/// | Ascending  | Numbers, texts, booleans, errors, then blanks   | The rows with the color on top      |
+/// | Descending | Errors, booleans, texts, numbers, then blanks   | The rows with the color at bottom   |
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+///
+/// What the cells of the key column are compared by.
+///
+/// | SortBy          | XML value   | Comparison                                                           |
+/// |-----------------|-------------|----------------------------------------------------------------------|
+/// | Value           | `value`     | The values, texts are compared without case                          |
+/// | CellColor       | `cellColor` | Whether the cell is filled with the color                            |
+/// | FontColor       | `fontColor` | Whether the font of the cell has the color                           |
+/// | CustomList      | `value`     | The position of the text in the list, other values follow by value   |
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum SortBy {
+    Value,
+    CellColor(FormatColor),
+    FontColor(FormatColor),
+    CustomList(Vec<String>),
+}
+
+impl SortBy {
+    pub(crate) fn to_str(&self) -> &'static str {
+        match self {
+            SortBy::Value | SortBy::CustomList(_) => "value",
+            SortBy::CellColor(_) => "cellColor",
+            SortBy::FontColor(_) => "fontColor",
+        }
+    }
+}
+
+///
+/// A key of [`WorkSheet::sort_range`](crate::WorkSheet::sort_range),
+/// `col` is the column of the worksheet, counted from 1, and must be inside the sorted range.
+///
+/// # Examples
+/// ```
+/// use edit_xlsx::{SortBy, SortKey, SortOrder};
+/// let by_name = SortKey::new(1);
+/// let by_priority = SortKey { col: 3, order: SortOrder::Ascending, by: SortBy::CustomList(vec!["High".to_string(), "Low".to_string()]) };
+/// assert_eq!(by_name.order, SortOrder::Ascending);
+/// assert_eq!(by_priority.col, 3);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SortKey {
+    pub col: u32,
+    pub order: SortOrder,
+    pub by: SortBy,
+}
+
+impl SortKey {
+    ///
+    /// Sort the column by its values in ascending order.
+    ///
+    pub fn new(col: u32) -> SortKey {
+        SortKey {
+            col,
+            order: SortOrder::Ascending,
+            by: SortBy::Value,
+        }
+    }
+
+    ///
+    /// The same key in descending order.
+    ///
+    pub fn descending(mut self) -> SortKey {
+        self.order = SortOrder::Descending;
+        self
+    }
+}
@@ -0,0 +1,287 @@
+use crate::FormatColor;
+
+///
+/// The chart drawn by a sparkline.
+///
+/// | SparklineType | type    |
+/// |---------------|---------|
+/// | Line          | line    |
+/// | Column        | column  |
+/// | WinLoss       | stacked |
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SparklineType {
+    Line,
+    Column,
+    WinLoss,
+}
+
+impl SparklineType {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            SparklineType::Line => "line",
+            SparklineType::Column => "column",
+            SparklineType::WinLoss => "stacked",
+        }
+    }
+}
+
+///
+/// How the empty cells in the data range are shown.
+///
+/// | SparklineEmptyCells | displayEmptyCellsAs | Excel                                  |
+/// |---------------------|---------------------|----------------------------------------|
+/// | Gaps                | gap                 | Gaps                                   |
+/// | Zero                | zero                | Zero                                   |
+/// | Connect             | span                | Connect data points with line          |
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SparklineEmptyCells {
+    Gaps,
+    Zero,
+    Connect,
+}
+
+impl SparklineEmptyCells {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            SparklineEmptyCells::Gaps => "gap",
+            SparklineEmptyCells::Zero => "zero",
+            SparklineEmptyCells::Connect => "span",
+        }
+    }
+}
+
+///
+/// The minimum or the maximum of the vertical axis of a sparkline.
+///
+/// `Individual` fits every sparkline to its own data, `Group` uses the same value for all the sparklines
+/// added with equal [`SparklineOptions`], and `Custom` fixes the value.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SparklineAxis {
+    Individual,
+    Group,
+    Custom(f64),
+}
+
+impl SparklineAxis {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            SparklineAxis::Individual => "individual",
+            SparklineAxis::Group => "group",
+            SparklineAxis::Custom(_) => "custom",
+        }
+    }
+}
+
+///
+/// [`SparklineOptions`] decides how the sparklines added by [`WorkSheet::add_sparkline`](crate::WorkSheet::add_sparkline) look.
+///
+/// The colors which are not set follow the default sparkline style of Excel, based on the theme's accent 1 color.
+/// Sparklines added with equal options are saved as one group, which is edited together in Excel.
+///
+/// # Examples
+/// ```
+/// use edit_xlsx::{FormatColor, SparklineAxis, SparklineOptions, SparklineType, Workbook, WorkbookResult, Write};
+/// fn main() -> WorkbookResult<()> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.get_worksheet_mut(1)?;
+///     worksheet.write_row("A1", &[-2, 2, 3, -1, 0])?;
+///     worksheet.write_row("A2", &[4, 1, 5, 2, 6])?;
+///     let mut options = SparklineOptions::default();
+///     options.set_type(SparklineType::Column)
+///         .set_high_point(true)
+///         .set_negative_points(true)
+///         .set_high_color(FormatColor::RGB(0x00, 0xB0, 0x50))
+///         .set_min_axis(SparklineAxis::Group);
+///     worksheet.add_sparkline("F1", "A1:E1", &options)?;
+///     worksheet.add_sparkline("F2", "A2:E2", &options)?;
+///     workbook.save_as("./examples/sparkline.xlsx")?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparklineOptions {
+    pub(crate) sparkline_type: SparklineType,
+    pub(crate) markers: bool,
+    pub(crate) high_point: bool,
+    pub(crate) low_point: bool,
+    pub(crate) first_point: bool,
+    pub(crate) last_point: bool,
+    pub(crate) negative_points: bool,
+    pub(crate) series_color: Option<FormatColor>,
+    pub(crate) negative_color: Option<FormatColor>,
+    pub(crate) axis_color: Option<FormatColor>,
+    pub(crate) markers_color: Option<FormatColor>,
+    pub(crate) first_color: Option<FormatColor>,
+    pub(crate) last_color: Option<FormatColor>,
+    pub(crate) high_color: Option<FormatColor>,
+    pub(crate) low_color: Option<FormatColor>,
+    pub(crate) line_weight: Option<f64>,
+    pub(crate) show_axis: bool,
+    pub(crate) show_hidden: bool,
+    pub(crate) empty_cells: SparklineEmptyCells,
+    pub(crate) min_axis: SparklineAxis,
+    pub(crate) max_axis: SparklineAxis,
+    pub(crate) right_to_left: bool,
+}
+
+impl Default for SparklineOptions {
+    fn default() -> Self {
+        Self {
+            sparkline_type: SparklineType::Line,
+            markers: false,
+            high_point: false,
+            low_point: false,
+            first_point: false,
+            last_point: false,
+            negative_points: false,
+            series_color: None,
+            negative_color: None,
+            axis_color: None,
+            markers_color: None,
+            first_color: None,
+            last_color: None,
+            high_color: None,
+            low_color: None,
+            line_weight: None,
+            show_axis: false,
+            show_hidden: false,
+            empty_cells: SparklineEmptyCells::Gaps,
+            min_axis: SparklineAxis::Individual,
+            max_axis: SparklineAxis::Individual,
+            right_to_left: false,
+        }
+    }
+}
+
+impl SparklineOptions {
+    pub fn set_type(&mut self, sparkline_type: SparklineType) -> &mut Self {
+        self.sparkline_type = sparkline_type;
+        self
+    }
+
+    ///
+    /// Show a marker on every point of a line sparkline.
+    ///
+    pub fn set_markers(&mut self, markers: bool) -> &mut Self {
+        self.markers = markers;
+        self
+    }
+
+    pub fn set_high_point(&mut self, high_point: bool) -> &mut Self {
+        self.high_point = high_point;
+        self
+    }
+
+    pub fn set_low_point(&mut self, low_point: bool) -> &mut Self {
+        self.low_point = low_point;
+        self
+    }
+
+    pub fn set_first_point(&mut self, first_point: bool) -> &mut Self {
+        self.first_point = first_point;
+        self
+    }
+
+    pub fn set_last_point(&mut self, last_point: bool) -> &mut Self {
+        self.last_point = last_point;
+        self
+    }
+
+    pub fn set_negative_points(&mut self, negative_points: bool) -> &mut Self {
+        self.negative_points = negative_points;
+        self
+    }
+
+    pub fn set_series_color(&mut self, color: FormatColor) -> &mut Self {
+        self.series_color = Some(color);
+        self
+    }
+
+    pub fn set_negative_color(&mut self, color: FormatColor) -> &mut Self {
+        self.negative_color = Some(color);
+        self
+    }
+
+    pub fn set_axis_color(&mut self, color: FormatColor) -> &mut Self {
+        self.axis_color = Some(color);
+        self
+    }
+
+    pub fn set_markers_color(&mut self, color: FormatColor) -> &mut Self {
+        self.markers_color = Some(color);
+        self
+    }
+
+    pub fn set_first_color(&mut self, color: FormatColor) -> &mut Self {
+        self.first_color = Some(color);
+        self
+    }
+
+    pub fn set_last_color(&mut self, color: FormatColor) -> &mut Self {
+        self.last_color = Some(color);
+        self
+    }
+
+    pub fn set_high_color(&mut self, color: FormatColor) -> &mut Self {
+        self.high_color = Some(color);
+        self
+    }
+
+    pub fn set_low_color(&mut self, color: FormatColor) -> &mut Self {
+        self.low_color = Some(color);
+        self
+    }
+
+    ///
+    /// Set the weight of a line sparkline in points, it is 0.75 by default.
+    ///
+    pub fn set_line_weight(&mut self, line_weight: f64) -> &mut Self {
+        self.line_weight = Some(line_weight);
+        self
+    }
+
+    ///
+    /// Show the horizontal axis, which is only drawn if the data crosses zero.
+    ///
+    pub fn set_show_axis(&mut self, show_axis: bool) -> &mut Self {
+        self.show_axis = show_axis;
+        self
+    }
+
+    ///
+    /// Plot the data in hidden rows and columns.
+    ///
+    pub fn set_show_hidden(&mut self, show_hidden: bool) -> &mut Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+
+    ///
+    /// Set how the empty cells are shown, they are gaps by default.
+    ///
+    pub fn set_empty_cells(&mut self, empty_cells: SparklineEmptyCells) -> &mut Self {
+        self.empty_cells = empty_cells;
+        self
+    }
+
+    pub fn set_min_axis(&mut self, min_axis: SparklineAxis) -> &mut Self {
+        self.min_axis = min_axis;
+        self
+    }
+
+    pub fn set_max_axis(&mut self, max_axis: SparklineAxis) -> &mut Self {
+        self.max_axis = max_axis;
+        self
+    }
+
+    ///
+    /// Plot the data from right to left.
+    ///
+    pub fn set_right_to_left(&mut self, right_to_left: bool) -> &mut Self {
+        self.right_to_left = right_to_left;
+        self
+    }
+}
@@ -1,18 +1,140 @@
-use crate::{Cell, FormatColor, FormatFont};
+use crate::{Cell, FormatColor, FormatFont, FormatFontScheme};
+use crate::utils::color_util;
 
+///
+/// [`Theme`] holds the color scheme and the font scheme of the workbook's theme,
+/// which are used to resolve [`FormatColor::Theme`] colors and [`FormatFontScheme`] fonts.
+///
+/// # Examples
+/// ```
+/// use edit_xlsx::{FormatColor, FormatFontScheme, Workbook, WorkbookResult};
+/// fn main() -> WorkbookResult<()> {
+///     let workbook = Workbook::from_path("./examples/xlsx/accounting.xlsx")?;
+///     let worksheet = workbook.get_worksheet(1)?;
+///     let theme = worksheet.get_theme(0);
+///     // Blue, Accent 1, Lighter 40%
+///     assert_eq!(theme.theme_to_rgb(4, 0.3999755851924192), FormatColor::RGB(0x9D, 0xC3, 0xE6));
+///     assert_eq!(theme.get_font(FormatFontScheme::Minor), Some("Calibri"));
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
 pub struct Theme {
     colors_rgb: Vec<FormatColor>,
+    major_font: String,
+    minor_font: String,
+}
+
+impl Default for Theme {
+    ///
+    /// The default Office theme.
+    ///
+    fn default() -> Self {
+        let colors_rgb = [
+            (0xFF, 0xFF, 0xFF), (0x00, 0x00, 0x00), (0xE7, 0xE6, 0xE6), (0x44, 0x54, 0x6A),
+            (0x5B, 0x9B, 0xD5), (0xED, 0x7D, 0x31), (0xA5, 0xA5, 0xA5), (0xFF, 0xC0, 0x00),
+            (0x44, 0x72, 0xC4), (0x70, 0xAD, 0x47), (0x05, 0x63, 0xC1), (0x95, 0x4F, 0x72),
+        ].iter().map(|&(r, g, b)| FormatColor::RGB(r, g, b)).collect();
+        Theme::new(colors_rgb, "Calibri Light", "Calibri")
+    }
 }
 
 impl Theme {
-    pub(crate) fn new(colors_rgb: Vec<FormatColor>) -> Theme {
+    pub(crate) fn new(colors_rgb: Vec<FormatColor>, major_font: &str, minor_font: &str) -> Theme {
         Theme {
-            colors_rgb
+            colors_rgb,
+            major_font: major_font.to_string(),
+            minor_font: minor_font.to_string(),
+        }
+    }
+
+    ///
+    /// Get the color of the theme by the theme color id used by [`FormatColor::Theme`],
+    /// i.e. 0: lt1, 1: dk1, 2: lt2, 3: dk2, 4~9: accent1~6, 10: hlink, 11: folHlink.
+    /// Returns [`FormatColor::Default`] if the theme has no such color.
+    ///
+    pub fn get_color(&self, theme_id: u8) -> FormatColor {
+        self.colors_rgb.get(theme_id as usize).copied().unwrap_or_default()
+    }
+
+    ///
+    /// Get the RGB color of a theme color with the tint applied, in the same way as Excel.
+    /// A negative tint darkens the color, and a positive tint lightens it.
+    ///
+    pub fn theme_to_rgb(&self, theme_id: u8, tint: f64) -> FormatColor {
+        match self.get_color(theme_id) {
+            FormatColor::RGB(r, g, b) => {
+                let (r, g, b) = color_util::apply_tint((r, g, b), tint);
+                FormatColor::RGB(r, g, b)
+            }
+            color => color,
+        }
+    }
+
+    ///
+    /// Resolve [`FormatColor::Theme`] and [`FormatColor::Index`] colors to [`FormatColor::RGB`],
+    /// other colors are returned as they are.
+    ///
+    pub fn to_rgb(&self, color: &FormatColor) -> FormatColor {
+        match *color {
+            FormatColor::Theme(theme_id, tint) => self.theme_to_rgb(theme_id, tint),
+            FormatColor::Index(index) => self.index_to_rgb(index),
+            color => color,
         }
     }
 
-    pub(crate) fn theme_to_rgb(&self, color_theme: u8, tint: f64) -> &FormatColor {
-        self.colors_rgb.get(color_theme as usize).unwrap()
+    ///
+    /// Set the color of the theme by the theme color id used by [`FormatColor::Theme`],
+    /// only [`FormatColor::RGB`] and [`FormatColor::Index`] colors can be used in a theme.
+    /// Apply it to the workbook by [`Workbook::set_theme`](crate::Workbook::set_theme).
+    ///
+    pub fn set_color(&mut self, theme_id: u8, color: FormatColor) -> &mut Self {
+        if let Some(theme_color) = self.colors_rgb.get_mut(theme_id as usize) {
+            *theme_color = color;
+        }
+        self
+    }
+
+    ///
+    /// Set the typeface of the theme's major (headings) font.
+    ///
+    pub fn set_major_font(&mut self, typeface: &str) -> &mut Self {
+        self.major_font = typeface.to_string();
+        self
+    }
+
+    ///
+    /// Set the typeface of the theme's minor (body) font.
+    ///
+    pub fn set_minor_font(&mut self, typeface: &str) -> &mut Self {
+        self.minor_font = typeface.to_string();
+        self
+    }
+
+    ///
+    /// Get the typeface of the theme's major (headings) font.
+    ///
+    pub fn get_major_font(&self) -> &str {
+        &self.major_font
+    }
+
+    ///
+    /// Get the typeface of the theme's minor (body) font.
+    ///
+    pub fn get_minor_font(&self) -> &str {
+        &self.minor_font
+    }
+
+    ///
+    /// Get the typeface that a [`FormatFontScheme`] refers to,
+    /// returns `None` for [`FormatFontScheme::None`].
+    ///
+    pub fn get_font(&self, scheme: FormatFontScheme) -> Option<&str> {
+        match scheme {
+            FormatFontScheme::None => None,
+            FormatFontScheme::Major => Some(&self.major_font),
+            FormatFontScheme::Minor => Some(&self.minor_font),
+        }
     }
 
     pub(crate) fn index_to_rgb(&self, index: u8) -> FormatColor {
@@ -99,7 +221,7 @@ impl Theme {
 //             style.is_underline = format_font.underline;
 //             let mut color = format_font.color;
 //             if let FormatColor::Theme(id, tint) = format_font.color {
-//                 color = *theme.theme_to_rgb(id, tint);
+//                 color = theme.theme_to_rgb(id, tint);
 //             }
 //             style.foreground = color.to_ansi_term_colour();
 //             style
@@ -109,7 +231,7 @@ impl Theme {
 //             let mut default_style = to_ansi_style(&self, &format.font);
 //             let mut color = format.fill.fg_color;
 //             if let FormatColor::Theme(id, tint) = format.fill.fg_color {
-//                 color = *self.theme_to_rgb(id, tint);
+//                 color = self.theme_to_rgb(id, tint);
 //             }
 //             default_style.background = color.to_ansi_term_colour();
 //             default_style
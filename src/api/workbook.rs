@@ -1,17 +1,26 @@
 use std::{fs, slice};
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::fs::File;
+use std::io::Read;
 use std::hash::{Hash, Hasher};
 use std::path::Path;
 use std::rc::Rc;
 use futures::executor::block_on;
 use futures::join;
 use zip::result::ZipError;
+use crate::api::cell::location::{Location, LocationRange};
+use crate::api::find::{FindOptions, FoundCell};
+use crate::api::worksheet::copy::_Copy;
+use crate::api::worksheet::find::_Find;
+use crate::api::worksheet::import::PartImporter;
 use crate::api::worksheet::WorkSheet;
 use crate::file::XlsxFileType;
 use crate::utils::{id_util, zip_util};
+use crate::utils::formula_util::rename_sheet;
+use crate::utils::find_util::Matcher;
 use crate::result::{WorkSheetError, WorkbookError, WorkbookResult};
-use crate::{Properties, xml};
+use crate::{BuiltinStyle, Format, Properties, Theme, xml};
 use crate::xml::content_types::ContentTypes;
 use crate::xml::core_properties::CoreProperties;
 use crate::xml::app_properties::AppProperties;
@@ -21,7 +30,8 @@ use crate::xml::metadata::Metadata;
 use crate::xml::style::StyleSheet;
 use crate::xml::relationships::Relationships;
 use crate::xml::shared_string::SharedString;
-use crate::xml::theme::{Theme, Themes};
+use crate::xml::theme::{Theme as XmlTheme, Themes};
+use crate::xml::workbook::WorkbookProtection;
 
 #[derive(Debug)]
 pub struct Workbook {
@@ -38,7 +48,7 @@ pub struct Workbook {
     pub(crate) metadata: Rc<RefCell<Metadata>>,
     pub(crate) core_properties: Option<CoreProperties>,
     pub(crate) app_properties: Option<AppProperties>,
-    pub(crate) shared_string: Rc<SharedString>,
+    pub(crate) shared_string: Rc<RefCell<SharedString>>,
 }
 
 ///
@@ -52,6 +62,53 @@ impl Workbook {
     fn get_app_properties(&mut self) -> &mut AppProperties {
         self.app_properties.get_or_insert(AppProperties::from_path(&self.file_path).unwrap())
     }
+
+    ///
+    /// Add the default theme to the workbook if it has no theme.
+    ///
+    fn get_or_new_theme(&mut self) -> WorkbookResult<()> {
+        if !self.themes.borrow().themes.is_empty() {
+            return Ok(());
+        }
+        let file = File::open(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/new.xlsx"))?;
+        let mut archive = zip::ZipArchive::new(file)?;
+        let mut theme = XmlTheme::from_zip_file(&mut archive, "xl/theme/theme1.xml")
+            .ok_or(WorkbookError::FileNotFound)?;
+        theme.modified = true;
+        self.workbook_rel.borrow_mut().get_or_add_theme();
+        self.content_types.borrow_mut().add_theme(theme.id);
+        self.themes.borrow_mut().add_theme(theme);
+        Ok(())
+    }
+
+    ///
+    /// The indexes of the worksheets named by the options, or of every worksheet.
+    ///
+    fn get_searched_sheets(&self, options: &FindOptions) -> WorkbookResult<Vec<usize>> {
+        match &options.sheets {
+            Some(names) => names.iter()
+                .map(|name| self.sheets
+                    .iter()
+                    .position(|sheet| &sheet.name == name)
+                    .ok_or(WorkbookError::SheetError(WorkSheetError::FileNotFound)))
+                .collect(),
+            None => Ok((0..self.sheets.len()).collect()),
+        }
+    }
+
+    fn replace_cells(&mut self, pattern: &str, replacement: &str, options: &FindOptions, limit: usize) -> WorkbookResult<Vec<FoundCell>> {
+        let matcher = Matcher::new(pattern, options)?;
+        let mut replaced = vec![];
+        for index in self.get_searched_sheets(options)? {
+            if replaced.len() >= limit {
+                break;
+            }
+            let worksheet = &mut self.sheets[index];
+            let locs = worksheet.replace_cells(&matcher, replacement, options.in_formulas, limit - replaced.len());
+            replaced.extend(locs.into_iter().map(|(row, col)| FoundCell { sheet: worksheet.name.clone(), row, col }));
+        }
+        Ok(replaced)
+    }
 }
 
 impl Workbook {
@@ -135,6 +192,78 @@ impl Workbook {
         self.get_worksheet_mut(sheet_id)
     }
 
+    ///
+    /// Copy the worksheet `id` of another workbook into this workbook as the worksheet `name`.
+    /// The styles, the dxfs and the shared strings it uses are added to this workbook, its medias, drawings,
+    /// comments and tables are copied with new ids, and the names local to the worksheet are defined for the copy.
+    ///
+    pub fn import_worksheet(&mut self, source: &Workbook, id: u32, name: &str) -> WorkbookResult<&mut WorkSheet> {
+        let (index, source_worksheet) = source.sheets
+            .iter()
+            .enumerate()
+            .find(|(_, sheet)| sheet.id == id).ok_or(WorkSheetError::FileNotFound)?;
+        if self.sheets.iter().any(|sheet| sheet.name == name) {
+            return Err(WorkbookError::SheetError(WorkSheetError::DuplicatedSheets));
+        }
+        let (r_id, target_id) = self.workbook_rel.borrow_mut().add_worksheet_v2();
+        let (sheet_id, name) = self.workbook.borrow_mut().add_worksheet_v2(r_id, Some(name))?;
+        let mut importer = PartImporter::new(source, self);
+        let worksheet = WorkSheet::from_other_workbook(sheet_id, &name, target_id, source_worksheet, self, &mut importer);
+        let local_names = source.workbook.borrow().defined_names.get_local_names(index as u32);
+        for (local_name, value) in local_names {
+            let value = rename_sheet(&value, &source_worksheet.name, &name);
+            self.workbook.borrow_mut().defined_names.add_define_name(&local_name, &value, Some(self.sheets.len() as u32));
+        }
+        self.sheets.push(worksheet);
+        self.get_worksheet_mut(sheet_id)
+    }
+
+    ///
+    /// Copy a range of the worksheet `src_id` so that its top left cell is at `loc` on the worksheet `dst_id`,
+    /// see [`WorkSheet::copy_range`] for what is copied.
+    ///
+    pub fn copy_range_between_sheets<R: LocationRange, L: Location>(&mut self, src_id: u32, loc_range: R, dst_id: u32, loc: L) -> WorkbookResult<()> {
+        let clip = self.get_worksheet(src_id)?.clip_range(loc_range.to_range());
+        self.get_worksheet_mut(dst_id)?.paste_clip(&clip, loc.to_location())?;
+        Ok(())
+    }
+
+    ///
+    /// Find the cells whose texts match the pattern, worksheet by worksheet and row by row.
+    /// The shared strings, the inline strings and the rich texts are searched as plain texts,
+    /// see [`FindOptions`] for how the pattern is matched.
+    ///
+    pub fn find(&self, pattern: &str, options: &FindOptions) -> WorkbookResult<Vec<FoundCell>> {
+        let matcher = Matcher::new(pattern, options)?;
+        let found = self.get_searched_sheets(options)?
+            .into_iter()
+            .flat_map(|index| {
+                let worksheet = &self.sheets[index];
+                worksheet.find_cells(&matcher, options.in_formulas)
+                    .into_iter()
+                    .map(|(row, col)| FoundCell { sheet: worksheet.name.clone(), row, col })
+            })
+            .collect();
+        Ok(found)
+    }
+
+    ///
+    /// Replace the matches of the pattern in the first cell found, and return the cell.
+    /// Every match in the cell is replaced, the runs of a rich text keep their formats.
+    /// A string of the table of shared strings becomes an inline string of the cell, so other cells sharing it are not changed.
+    /// The formulas are only rewritten if [`FindOptions::in_formulas`] is set, the booleans and the errors are never rewritten.
+    ///
+    pub fn replace(&mut self, pattern: &str, replacement: &str, options: &FindOptions) -> WorkbookResult<Option<FoundCell>> {
+        Ok(self.replace_cells(pattern, replacement, options, 1)?.pop())
+    }
+
+    ///
+    /// Same as [`Workbook::replace`], but every cell found is rewritten, and the rewritten cells are returned.
+    ///
+    pub fn replace_all(&mut self, pattern: &str, replacement: &str, options: &FindOptions) -> WorkbookResult<Vec<FoundCell>> {
+        self.replace_cells(pattern, replacement, options, usize::MAX)
+    }
+
     pub fn set_size(&mut self, width: u32, height: u32) -> WorkbookResult<()> {
         let workbook = &mut self.workbook.borrow_mut();
         let book_view = workbook.book_views.book_views.get_mut(0).unwrap();
@@ -200,6 +329,133 @@ impl Workbook {
         Ok(())
     }
 
+    ///
+    /// Protect the structure of the workbook, so that worksheets can not be added, moved, renamed or deleted.
+    /// The password is hashed by the SHA-512 algorithm, an empty password protects the workbook without password.
+    ///
+    pub fn protect_structure(&mut self, password: &str) {
+        let workbook = &mut self.workbook.borrow_mut();
+        workbook.workbook_protection = Some(WorkbookProtection::lock_structure(password, false));
+    }
+
+    ///
+    /// Same as [`Workbook::protect_structure`], but the password is hashed by the legacy 16-bit algorithm.
+    ///
+    pub fn protect_structure_legacy(&mut self, password: &str) {
+        let workbook = &mut self.workbook.borrow_mut();
+        workbook.workbook_protection = Some(WorkbookProtection::lock_structure(password, true));
+    }
+
+    pub fn unprotect_structure(&mut self) {
+        let workbook = &mut self.workbook.borrow_mut();
+        workbook.workbook_protection = None;
+    }
+
+    pub fn is_structure_protected(&self) -> bool {
+        let workbook = self.workbook.borrow();
+        match &workbook.workbook_protection {
+            Some(workbook_protection) => workbook_protection.lock_structure == Some(1),
+            None => false,
+        }
+    }
+
+    ///
+    /// Get the [`Theme`] of the workbook.
+    ///
+    pub fn get_theme(&self) -> Theme {
+        match self.themes.borrow().themes.first() {
+            Some(theme) => theme.to_api_theme(),
+            None => Theme::default(),
+        }
+    }
+
+    ///
+    /// Replace the color scheme and the font scheme of the workbook's theme,
+    /// [`FormatColor::Theme`](crate::FormatColor::Theme) colors across the workbook will follow the new theme.
+    ///
+    pub fn set_theme(&mut self, theme: &Theme) -> WorkbookResult<()> {
+        self.get_or_new_theme()?;
+        let mut themes = self.themes.borrow_mut();
+        if let Some(xml_theme) = themes.themes.first_mut() {
+            xml_theme.update_by_api_theme(theme);
+        }
+        Ok(())
+    }
+
+    ///
+    /// Replace the workbook's theme by an Office theme file (`.thmx`).
+    ///
+    pub fn load_theme<P: AsRef<Path>>(&mut self, file_path: P) -> WorkbookResult<()> {
+        let file = File::open(&file_path)?;
+        let mut archive = zip::ZipArchive::new(file)?;
+        let theme_path = archive.file_names()
+            .find(|name| name.starts_with("theme/theme/theme") && name.ends_with(".xml"))
+            .map(|name| name.to_string())
+            .ok_or(WorkbookError::FileNotFound)?;
+        let mut xml = String::new();
+        archive.by_name(&theme_path)?.read_to_string(&mut xml)?;
+        self.get_or_new_theme()?;
+        let mut themes = self.themes.borrow_mut();
+        match themes.themes.first_mut() {
+            Some(xml_theme) => xml_theme.replace_by_xml(&xml).ok_or(WorkbookError::FileNotFound),
+            None => Err(WorkbookError::FileNotFound),
+        }
+    }
+
+    ///
+    /// Add a named cell style to the workbook, it will be shown in Excel's style gallery.
+    /// If a style with the same name already exists, its format will be replaced,
+    /// cells based on the style will follow the new format.
+    ///
+    pub fn add_named_style(&mut self, name: &str, format: &Format) -> WorkbookResult<()> {
+        self.style_sheet.borrow_mut().add_named_style(name, format, None, 0);
+        Ok(())
+    }
+
+    ///
+    /// Add one of Excel's [`BuiltinStyle`]s to the workbook, the style is added by its Excel name.
+    ///
+    pub fn add_builtin_style(&mut self, builtin_style: BuiltinStyle) -> WorkbookResult<()> {
+        self.style_sheet.borrow_mut().add_named_style(
+            &builtin_style.name(),
+            &builtin_style.format(),
+            Some(builtin_style.builtin_id()),
+            builtin_style.num_fmt_id(),
+        );
+        Ok(())
+    }
+
+    ///
+    /// Get the [`Format`] of a named cell style, cells written with this format are linked to the style.
+    ///
+    pub fn get_named_style(&self, name: &str) -> Option<Format> {
+        self.style_sheet.borrow().get_named_style_format(name)
+    }
+
+    ///
+    /// Get the names of all cell styles in the workbook.
+    ///
+    pub fn named_styles(&self) -> Vec<String> {
+        self.style_sheet.borrow().named_styles()
+    }
+
+    ///
+    /// Remove the unused formats from the workbook's style sheet and merge the duplicated ones,
+    /// the styles of the cells, rows and columns in every worksheet are remapped.
+    /// This keeps `styles.xml` small after a file is opened and re-saved many times.
+    ///
+    pub fn compact_styles(&mut self) -> WorkbookResult<()> {
+        let mut used_styles = HashSet::new();
+        self.worksheets_mut()
+            .for_each(|worksheet| worksheet.for_each_style_mut(|style| { used_styles.insert(*style); }));
+        let style_map = self.style_sheet.borrow_mut().compact(&used_styles);
+        self.worksheets_mut()
+            .for_each(|worksheet| worksheet.for_each_style_mut(|style| {
+                *style = style_map.get(*style as usize).copied().unwrap_or_default();
+            }));
+        Ok(())
+    }
+
     pub fn set_properties(&mut self, properties: &Properties) -> WorkbookResult<()> {
         let core_properties = self.get_core_properties();
         core_properties.update_by_properties(properties);
@@ -215,7 +471,7 @@ impl Workbook {
         let tmp_path = format!("./~${}_{:X}", file_name.to_str().ok_or(ZipError::FileNotFound)?, id_util::new_id());
         let file = File::open(&file_path)?;
         let mut archive = zip::ZipArchive::new(file)?;
-        let mut medias = Medias::default();
+        let mut medias = Medias::from_path(&file_path);
         let mut themes = Themes::default();
         let workbook_xml = xml::workbook::Workbook::from_zip_file(&mut archive, "xl/workbook.xml");
         let workbook_rel = Relationships::from_zip_file(&mut archive, "xl/_rels/workbook.xml.rels");
@@ -224,6 +480,7 @@ impl Workbook {
         let metadata = Metadata::from_zip_file(&mut archive, "xl/metadata.xml");
         let shared_string = SharedString::from_zip_file(&mut archive, "xl/sharedStrings.xml");
         let mut theme_paths = Vec::new();
+        let mut vml_drawing_ids = Vec::new();
         for i in 0..archive.len() {
             let mut file = archive.by_index(i)?;
             if file.is_file() {
@@ -234,18 +491,27 @@ impl Workbook {
                 else if file_name.starts_with("xl/theme/") {
                     theme_paths.push(file_name.to_string());
                 }
+                else if let Some(id) = file_name.strip_prefix("xl/drawings/vmlDrawing")
+                    .and_then(|name| name.strip_suffix(".vml"))
+                    .and_then(|id| id.parse::<u32>().ok()) {
+                    vml_drawing_ids.push(id);
+                }
             }
         }
+        theme_paths.sort();
         theme_paths.iter().for_each(|file_name| {
-            let theme = Theme::from_zip_file(&mut archive, &file_name).unwrap();
-            themes.add_theme(theme);
+            if let Some(theme) = XmlTheme::from_zip_file(&mut archive, file_name) {
+                themes.add_theme(theme);
+            }
         });
         let workbook = Rc::new(RefCell::new(workbook_xml.unwrap_or_default()));
         let workbook_rel = Rc::new(RefCell::new(workbook_rel.unwrap_or_default()));
-        let content_types = Rc::new(RefCell::new(content_types.unwrap_or_default()));
+        let mut content_types = content_types.unwrap_or_default();
+        vml_drawing_ids.into_iter().for_each(|id| content_types.reserve_vml_drawing_id(id));
+        let content_types = Rc::new(RefCell::new(content_types));
         let style_sheet = Rc::new(RefCell::new(style_sheet.unwrap_or_default()));
         let metadata = Rc::new(RefCell::new(metadata.unwrap_or_default()));
-        let shared_string = Rc::new(shared_string.unwrap_or_default());
+        let shared_string = Rc::new(RefCell::new(shared_string.unwrap_or_default()));
         let medias = Rc::new(RefCell::new(medias));
         let themes = Rc::new(RefCell::new(themes));
         let sheets = workbook.borrow().sheets.sheets.iter().map(
@@ -307,7 +573,10 @@ impl Workbook {
         let medias = medias.save_async(&self.tmp_path);
         let metadata = self.metadata.borrow();
         let metadata = metadata.save_async(&self.tmp_path);
-        join!(workbook, style_sheet, workbook_rel, content_types, medias, metadata);
+        let themes = self.themes.borrow();
+        let themes = themes.save_async(&self.tmp_path);
+        join!(workbook, style_sheet, workbook_rel, content_types, medias, metadata, themes);
+        self.shared_string.borrow().save(&self.tmp_path);
         Ok(())
     }
 
@@ -319,6 +588,9 @@ impl Workbook {
         zip_util::extract_dir(&self.file_path, &self.tmp_path)?;
         // save sheets
         self.sheets.iter().for_each(|s| s.save_as(&self.tmp_path).unwrap());
+        // delete the medias which are no longer referred after the images were replaced or removed
+        let unused_extensions = self.medias.borrow_mut().clean_orphans(&self.tmp_path);
+        unused_extensions.iter().for_each(|extension| self.content_types.borrow_mut().remove_extension(extension));
         block_on(self.save_async()).unwrap();
         // save if modified
         if let Some(core_propertises) = &self.core_properties {
@@ -5,6 +5,13 @@ pub(crate) mod read;
 mod format;
 mod hyperlink;
 mod image;
+mod form_control;
+mod filter;
+pub(crate) mod copy;
+pub(crate) mod import;
+pub(crate) mod range;
+pub(crate) mod sort;
+pub(crate) mod find;
 mod theme;
 
 use std::cell::RefCell;
@@ -13,17 +20,27 @@ use std::fs::File;
 use std::path::Path;
 use std::rc::Rc;
 use zip::ZipArchive;
-use crate::{Cell, Filters, Format, FormatColor, FormatFill, FormatFont, xml};
+use crate::{Cell, Filters, SortKey, FormControl, FormatBorderElement, FormatColor, FormatFill, FormatFont, Hyperlink, Image, ImageFormat, ImageOptions, ProtectionOptions, Shape, SparklineOptions, xml};
 use crate::api::cell::location::{Location, LocationRange};
 use crate::api::worksheet::col::WorkSheetCol;
-use crate::api::worksheet::image::Image;
+use crate::api::worksheet::copy::_Copy;
+use crate::api::worksheet::filter::_Filter;
+use crate::api::worksheet::form_control::_FormControl;
+use crate::api::worksheet::format::_Format;
+use crate::api::worksheet::hyperlink::_Hyperlink;
+use crate::api::worksheet::image::_Image;
 use crate::api::worksheet::read::Read;
 use crate::api::worksheet::row::WorkSheetRow;
+use crate::api::worksheet::sort::_Sort;
 use crate::api::worksheet::write::Write;
 use crate::api::workbook::Workbook as ApiWorkbook;
-use crate::file::XlsxFileType;
+use crate::file::{XlsxFileType, XlsxFileWriter};
 use crate::result::{WorkSheetError, WorkSheetResult};
+use crate::utils::col_helper::to_sheet_ref;
+use crate::xml::ctrl_prop::FormControlPr;
 use crate::xml::drawings::Drawings;
+use crate::xml::comments::Comments;
+use crate::api::worksheet::import::resolve;
 use crate::xml::drawings::vml_drawing::VmlDrawing;
 use crate::xml::io::IoV2;
 use crate::xml::metadata::Metadata;
@@ -48,10 +65,17 @@ pub struct WorkSheet {
     medias: Rc<RefCell<xml::medias::Medias>>,
     themes: Rc<RefCell<xml::theme::Themes>>,
     vml_drawing: Option<VmlDrawing>,
+    comments: Option<Comments>,
+    // The properties of the form controls added to the worksheet, by their relationship ids
+    ctrl_props: Vec<(u32, FormControlPr)>,
     drawings: Option<Drawings>,
     drawings_rel: Option<Relationships>,
+    // The drawing parts other than the one in the worksheet's `drawing` element, by their relationship ids
+    other_drawings: Vec<(u32, Drawings, Option<Relationships>)>,
     metadata: Rc<RefCell<Metadata>>,
-    shared_string: Rc<SharedString>,
+    shared_string: Rc<RefCell<SharedString>>,
+    // The parts imported as they are from another workbook, by their names in the package
+    parts: Vec<(String, Vec<u8>)>,
 }
 
 impl Write for WorkSheet {}
@@ -63,25 +87,37 @@ impl WorkSheet {
     pub(crate) fn save_as<P: AsRef<Path>>(&self, file_path: P) -> WorkSheetResult<()> {
         self.worksheet.save(&file_path, &self.target);
         self.worksheet_rel.save(&file_path, XlsxFileType::WorksheetRels(self.target_id));
-        // self.worksheet_rel.get_drawings_rids().iter().for_each(|id|{
-        //     if let Some(drawings) = &self.drawings {
-        //         drawings.save(&file_path, *id);
-        //     }
-        //     if let Some(drawings_rel) = &self.drawings_rel {
-        //         drawings_rel.save(&file_path, XlsxFileType::DrawingRels(*id));
-        //     }
-        // });
-        if let Some(id) = self.worksheet_rel.get_drawings_rids().first() {
-            if let Some(drawings) = &self.drawings {
-                drawings.save(&file_path, *id);
+        let main_drawings = self.drawings_r_id().map(|r_id| (r_id, self.drawings.as_ref(), self.drawings_rel.as_ref()));
+        let other_drawings = self.other_drawings.iter()
+            .map(|(r_id, drawings, drawings_rel)| (*r_id, Some(drawings), drawings_rel.as_ref()));
+        for (r_id, drawings, drawings_rel) in main_drawings.into_iter().chain(other_drawings) {
+            let Some(id) = self.worksheet_rel.get_drawing_id(r_id) else { continue };
+            if let Some(drawings) = drawings {
+                drawings.save(&file_path, id);
             }
-            if let Some(drawings_rel) = &self.drawings_rel {
-                drawings_rel.save(&file_path, XlsxFileType::DrawingRels(*id));
+            if let Some(drawings_rel) = drawings_rel {
+                drawings_rel.save(&file_path, XlsxFileType::DrawingRels(id));
+            }
+        }
+        let vml_drawing_id = self.worksheet.get_legacy_drawing_r_id()
+            .and_then(|r_id| self.worksheet_rel.get_vml_drawing_id(r_id));
+        if let (Some(vml_drawing), Some(id)) = (&self.vml_drawing, vml_drawing_id) {
+            vml_drawing.save(&file_path, id);
+        }
+        let ctrl_prop_ids = self.worksheet_rel.get_ctrl_props();
+        for (r_id, ctrl_prop) in &self.ctrl_props {
+            if let Some((_, id)) = ctrl_prop_ids.iter().find(|(ctrl_prop_r_id, _)| ctrl_prop_r_id == r_id) {
+                ctrl_prop.save(&file_path, *id);
+            }
+        }
+        for (name, buffer) in &self.parts {
+            let mut file = XlsxFileWriter::from_path(&file_path, XlsxFileType::Part(name.clone()))?;
+            file.write_all(buffer)?;
+        }
+        if let (Some(comments), Some((_, target))) = (&self.comments, self.worksheet_rel.get_comments()) {
+            if comments.modified {
+                comments.save(&file_path, &resolve("xl/worksheets", &target));
             }
-        };
-        if let Some(id) = self.worksheet_rel.get_vml_drawing_rid() {
-            // self.vml_drawing.as_ref().unwrap().save(&file_path, id);
-            // self.vml_drawing.as_ref().unwrap().save(&file_path, id);
         }
         Ok(())
     }
@@ -92,8 +128,92 @@ impl WorkSheet {
         self.worksheet.autofilter(loc_range);
     }
 
+    ///
+    /// Filter the column of the autofilter by the criteria, which replace the ones it had.
+    /// The rows are not hidden until [`WorkSheet::apply_autofilter`] is called, so the data may be written after the criteria.
+    ///
     pub fn filter_column<L: Location>(&mut self, col: L, filters: &Filters) {
-        self.worksheet.filter_column(col, filters);
+        self.add_filter_column(col.to_col(), filters);
+    }
+
+    ///
+    /// Hide the rows of the autofilter that do not pass the criteria of every filtered column and show the others,
+    /// like reapplying the filter in Excel. Texts are compared without case,
+    /// and the colors are the ones of the fills or of the fonts of the cells.
+    ///
+    pub fn apply_autofilter(&mut self) {
+        self.apply_filters();
+    }
+}
+
+impl WorkSheet {
+    ///
+    /// Draw a box around the range, only the outer edges of the cells on the boundary are changed,
+    /// other formats of the cells are kept.
+    ///
+    pub fn draw_border_outline<L: LocationRange>(&mut self, loc_range: L, border: &FormatBorderElement) {
+        self.draw_border(loc_range, border, false);
+    }
+
+    ///
+    /// Draw borders on every edge of every cell in the range, other formats of the cells are kept.
+    ///
+    pub fn draw_border_grid<L: LocationRange>(&mut self, loc_range: L, border: &FormatBorderElement) {
+        self.draw_border(loc_range, border, true);
+    }
+
+    fn draw_border<L: LocationRange>(&mut self, loc_range: L, border: &FormatBorderElement, inner: bool) {
+        let (first_row, first_col, last_row, last_col) = loc_range.to_range();
+        for row in first_row..=last_row {
+            for col in first_col..=last_col {
+                let on_edge = row == first_row || row == last_row || col == first_col || col == last_col;
+                if !inner && !on_edge {
+                    continue;
+                }
+                let style = self.worksheet.get_default_style(&(row, col)).unwrap_or_default();
+                let mut format_border = self.get_format(style).border;
+                if inner || row == first_row {
+                    format_border.top = *border;
+                }
+                if inner || row == last_row {
+                    format_border.bottom = *border;
+                }
+                if inner || col == first_col {
+                    format_border.left = *border;
+                }
+                if inner || col == last_col {
+                    format_border.right = *border;
+                }
+                // Only the border of the xf is replaced, its other records are kept
+                let style = self.style_sheet.borrow_mut().set_xf_border(style, &format_border);
+                self.worksheet.sheet_data.set_style(&(row, col), style);
+            }
+        }
+    }
+}
+
+impl WorkSheet {
+    ///
+    /// Apply a named cell style of the workbook to every cell in the range, the values are kept.
+    /// Returns [`WorkSheetError::StyleNotFound`] if the workbook has no style with this name.
+    ///
+    pub fn apply_named_style<L: LocationRange>(&mut self, loc_range: L, style_name: &str) -> WorkSheetResult<()> {
+        let format = self.style_sheet.borrow().get_named_style_format(style_name)
+            .ok_or(WorkSheetError::StyleNotFound)?;
+        let style = self.add_format(&format);
+        let (first_row, first_col, last_row, last_col) = loc_range.to_range();
+        for row in first_row..=last_row {
+            for col in first_col..=last_col {
+                self.worksheet.sheet_data.set_style(&(row, col), style);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl WorkSheet {
+    pub(crate) fn for_each_style_mut<F: FnMut(&mut u32)>(&mut self, f: F) {
+        self.worksheet.for_each_style_mut(f);
     }
 }
 
@@ -134,6 +254,7 @@ impl WorkSheet {
                 .iter_mut()
                 .filter(|sheet| sheet.name == self.name)
                 .for_each(|sheet| sheet.name = name.to_string());
+            self.worksheet.rename_sparkline_sheet(&self.name, name);
             self.name = name.to_string();
             Ok(())
         }
@@ -247,16 +368,56 @@ impl WorkSheet {
         sheet.state = Some(String::from("hidden"));
     }
 
+    ///
+    /// Protect the worksheet, the password is hashed by the SHA-512 algorithm unless
+    /// [`ProtectionOptions::use_legacy_password`] is set. An empty password protects the worksheet without password.
+    ///
+    pub fn protect(&mut self, password: &str, options: &ProtectionOptions) {
+        let worksheet = &mut self.worksheet;
+        worksheet.protect(password, options);
+    }
+
+    pub fn unprotect(&mut self) {
+        let worksheet = &mut self.worksheet;
+        worksheet.unprotect();
+    }
+
+    ///
+    /// Get the [`ProtectionOptions`] of the worksheet, returns `None` if the worksheet is not protected.
+    ///
+    pub fn get_protection(&self) -> Option<ProtectionOptions> {
+        let worksheet = &self.worksheet;
+        worksheet.get_protection()
+    }
+
     pub fn set_tab_color(&mut self, tab_color: &FormatColor) {
         self.worksheet.set_tab_color(tab_color);
     }
 
+    ///
+    /// Set a PNG, JPEG, GIF or BMP image as the background of the worksheet, which replaces the old background.
+    /// Returns [`WorkSheetError::FormatError`] for other formats.
+    ///
     pub fn set_background<P: AsRef<Path>>(&mut self, filename: P) -> WorkSheetResult<()> {
         let r_id = self.add_background(&filename)?;
         self.worksheet.set_background(r_id);
         Ok(())
     }
 
+    ///
+    /// Get the content of the background image, returns `None` if the worksheet has no background.
+    ///
+    pub fn get_background(&self) -> Option<Vec<u8>> {
+        self.get_background_image()
+    }
+
+    ///
+    /// Remove the background of the worksheet, its image is deleted on saving if nothing else refers to it.
+    ///
+    pub fn remove_background(&mut self) {
+        self.remove_background_image()
+    }
+
     pub fn insert_image<L: LocationRange, P: AsRef<Path>>(&mut self, loc_range: L, filename: &P) -> WorkSheetResult<()> {
         let (from_row, from_col, to_row, to_col) = loc_range.to_range();
         let r_id = self.add_drawing((from_row, from_col, to_row, to_col), filename)?;
@@ -264,6 +425,201 @@ impl WorkSheet {
         Ok(())
     }
 
+    ///
+    /// Insert an image from a memory buffer at a single cell, in its real size.
+    ///
+    /// The pixel size and the resolution are read from the image header,
+    /// returns [`WorkSheetError::FormatError`] if the buffer is not an image of `format`, or is a svg image.
+    ///
+    pub fn insert_image_from_buffer<L: Location>(&mut self, loc: L, buffer: &[u8], format: ImageFormat) -> WorkSheetResult<()> {
+        self.insert_image_with_options(loc, buffer, format, &ImageOptions::default())
+    }
+
+    ///
+    /// Insert an image from a memory buffer at a single cell, offset and scaled by the [`ImageOptions`].
+    /// The image keeps its size when the rows and the columns under it are resized.
+    ///
+    pub fn insert_image_with_options<L: Location>(&mut self, loc: L, buffer: &[u8], format: ImageFormat, options: &ImageOptions) -> WorkSheetResult<()> {
+        let r_id = self.add_one_cell_drawing(loc, buffer, format, options)?;
+        self.worksheet.insert_image(r_id);
+        Ok(())
+    }
+
+    ///
+    /// Insert an image from a memory buffer at the position `(x, y)` in pixels from the top left corner of the worksheet.
+    /// The image is not moved with the cells.
+    ///
+    pub fn insert_image_absolute(&mut self, x: u32, y: u32, buffer: &[u8], format: ImageFormat, options: &ImageOptions) -> WorkSheetResult<()> {
+        let r_id = self.add_absolute_drawing(x, y, buffer, format, options)?;
+        self.worksheet.insert_image(r_id);
+        Ok(())
+    }
+
+    ///
+    /// Insert an image from a memory buffer stretched over the cells of the range,
+    /// the [`ImageEditAs`](crate::ImageEditAs) of the options decides whether it moves and sizes with the cells.
+    /// The offset and the scale of the options are not used.
+    ///
+    pub fn insert_image_over_range<L: LocationRange>(&mut self, loc_range: L, buffer: &[u8], format: ImageFormat, options: &ImageOptions) -> WorkSheetResult<()> {
+        let r_id = self.add_range_drawing(loc_range, buffer, format, options)?;
+        self.worksheet.insert_image(r_id);
+        Ok(())
+    }
+
+    ///
+    /// Insert a [`Shape`] stretched over the cells of the range, it moves and sizes with the cells.
+    /// A connector is drawn from the top left corner to the bottom right corner of the range.
+    ///
+    pub fn insert_shape<L: LocationRange>(&mut self, loc_range: L, shape: &Shape) -> WorkSheetResult<()> {
+        let r_id = self.add_shape(loc_range, shape)?;
+        self.worksheet.insert_image(r_id);
+        Ok(())
+    }
+
+    ///
+    /// Insert a [`FormControl`] stretched over the cells of the range, it moves with the cells.
+    ///
+    pub fn insert_form_control<L: LocationRange>(&mut self, loc_range: L, control: &FormControl) -> WorkSheetResult<()> {
+        self.add_form_control(loc_range, control)
+    }
+
+    ///
+    /// Add a sparkline in the cell `loc` which plots the data of a single row or column on the worksheet.
+    /// Returns [`WorkSheetError::FormatError`] if the data range has more than one row and more than one column.
+    ///
+    pub fn add_sparkline<L: Location, R: LocationRange>(&mut self, loc: L, data_range: R, options: &SparklineOptions) -> WorkSheetResult<()> {
+        let (first_row, first_col, last_row, last_col) = data_range.to_range();
+        if first_row != last_row && first_col != last_col {
+            return Err(WorkSheetError::FormatError);
+        }
+        let formula = to_sheet_ref(&self.name, &data_range.to_range_ref());
+        self.worksheet.add_sparkline(options, &formula, &loc.to_ref());
+        Ok(())
+    }
+
+    ///
+    /// Get the images on the worksheet with their content, in the order of the drawing part.
+    /// The index of an image in the returned list is used by [`WorkSheet::replace_image`] and [`WorkSheet::remove_image`].
+    ///
+    pub fn images(&self) -> Vec<Image> {
+        self.get_images()
+    }
+
+    ///
+    /// Replace the content of the image at `index` and keep its place, size and name.
+    /// The format of the new image is detected from its header, svg images are refused with [`WorkSheetError::FormatError`],
+    /// the old media is deleted on saving if nothing refers to it any more.
+    ///
+    pub fn replace_image(&mut self, index: usize, buffer: &[u8]) -> WorkSheetResult<()> {
+        self.replace_drawing(index, buffer)
+    }
+
+    ///
+    /// Remove the image at `index`, together with its relationships and its media if nothing else refers to them.
+    ///
+    pub fn remove_image(&mut self, index: usize) -> WorkSheetResult<()> {
+        self.remove_drawing(index)
+    }
+
+    ///
+    /// Get the merged ranges of the worksheet as `(first_row, first_col, last_row, last_col)`.
+    ///
+    pub fn merged_ranges(&self) -> Vec<(u32, u32, u32, u32)> {
+        self.worksheet.get_merged_ranges()
+    }
+
+    ///
+    /// Get the merged range which covers the cell, its first cell is the one holding the value,
+    /// or `None` if the cell is not merged.
+    ///
+    pub fn is_merged<L: Location>(&self, loc: L) -> Option<(u32, u32, u32, u32)> {
+        let (row, col) = loc.to_location();
+        self.worksheet.get_merged_range(row, col)
+    }
+
+    ///
+    /// Unmerge every merged range which intersects the range, the cells keep their values and formats.
+    ///
+    pub fn unmerge<L: LocationRange>(&mut self, loc_range: L) {
+        self.worksheet.remove_merge_cells(loc_range.to_range());
+    }
+
+    ///
+    /// Get the hyperlinks on the worksheet, in the order they are saved.
+    ///
+    pub fn hyperlinks(&self) -> Vec<Hyperlink> {
+        self.get_hyperlinks()
+    }
+
+    ///
+    /// Set the text shown when the mouse is over the hyperlink on the cell.
+    /// Returns [`WorkSheetError::HyperlinkNotFound`] if the cell has no hyperlink.
+    ///
+    pub fn set_hyperlink_tooltip<L: Location>(&mut self, loc: L, tooltip: &str) -> WorkSheetResult<()> {
+        let hyperlink = self.worksheet.get_hyperlink_mut(&loc).ok_or(WorkSheetError::HyperlinkNotFound)?;
+        hyperlink.tooltip = Some(tooltip.to_string());
+        Ok(())
+    }
+
+    ///
+    /// Remove the hyperlink on the cell but keep its value and format,
+    /// the relationship of the link is removed if nothing else refers to it.
+    /// Returns [`WorkSheetError::HyperlinkNotFound`] if the cell has no hyperlink.
+    ///
+    pub fn remove_hyperlink<L: Location>(&mut self, loc: L) -> WorkSheetResult<()> {
+        self.delete_hyperlink(&loc)
+    }
+
+    ///
+    /// Copy the range so that its top left cell is at `loc`, with the values, the formulas, the formats,
+    /// the row heights and the column widths, the merged ranges, the hyperlinks, the data validations and the comments inside it.
+    /// The relative references of the formulas are re-based, and what was in the destination is replaced.
+    /// Returns [`WorkSheetError::OutOfRange`] if the destination goes beyond the last row or column of a worksheet.
+    ///
+    pub fn copy_range<R: LocationRange, L: Location>(&mut self, loc_range: R, loc: L) -> WorkSheetResult<()> {
+        let clip = self.clip_range(loc_range.to_range());
+        self.paste_clip(&clip, loc.to_location())
+    }
+
+    ///
+    /// Like [`WorkSheet::copy_range`] from another worksheet, which may belong to another workbook,
+    /// the formats of the cells are added to the styles of this workbook if the workbooks are not the same.
+    ///
+    pub fn copy_range_from<R: LocationRange, L: Location>(&mut self, worksheet: &WorkSheet, loc_range: R, loc: L) -> WorkSheetResult<()> {
+        let clip = worksheet.clip_range(loc_range.to_range());
+        self.paste_clip(&clip, loc.to_location())
+    }
+
+    ///
+    /// Move the range so that its top left cell is at `loc`, like Excel cuts and pastes it:
+    /// what [`WorkSheet::copy_range`] copies is moved and cleared from the range,
+    /// the row heights and the column widths of the range are kept.
+    /// The formulas of the range are not re-based, and the references of the formulas of this worksheet follow the moved cells:
+    /// a reference to a cell of the range, relative or absolute, points to its new location,
+    /// a range reference is moved only if it lies inside the range, the references to the other cells are kept,
+    /// and a reference to a cell replaced by the move becomes `#REF!`.
+    /// The formulas of other worksheets, the defined names, the data validations and the conditional formats are not updated.
+    ///
+    pub fn move_range<R: LocationRange, L: Location>(&mut self, loc_range: R, loc: L) -> WorkSheetResult<()> {
+        self.cut_range(loc_range.to_range(), loc.to_location())
+    }
+
+    ///
+    /// Sort the rows of the range by the keys, the first key decides and the next ones break its ties,
+    /// the rows which compare equal keep their order. The first row is left in place if `has_header` is true.
+    /// The cells of the range are moved with their formats, and the relative references of their formulas are re-based,
+    /// the cells outside the range, the row heights, the hyperlinks and the comments stay in place.
+    /// By value, the cells are ordered as Excel does: numbers, texts without case, booleans, errors, then blanks,
+    /// and the blanks stay at the bottom in descending order.
+    /// The sort is recorded in the worksheet, or in its autofilter if the range is the one of the autofilter,
+    /// so that Excel shows the sort indicator.
+    /// Returns [`WorkSheetError::SortKeyOutOfRange`] if a key column is outside the range,
+    /// and [`WorkSheetError::SortMergedCells`] if merged cells would be sorted.
+    ///
+    pub fn sort_range<R: LocationRange>(&mut self, loc_range: R, keys: &[SortKey], has_header: bool) -> WorkSheetResult<()> {
+        self.sort_rows(loc_range.to_range(), keys, has_header)
+    }
+
     pub fn id(&self) -> u32 {
         self.id
     }
@@ -290,10 +646,14 @@ impl WorkSheet {
             medias: workbook.medias.clone(),
             themes: workbook.themes.clone(),
             vml_drawing: None,
+            comments: None,
+            ctrl_props: vec![],
             drawings: None,
             drawings_rel: None,
+            other_drawings: vec![],
             metadata: workbook.metadata.clone(),
             shared_string: workbook.shared_string.clone(),
+            parts: vec![],
         }
     }
 
@@ -303,27 +663,94 @@ impl WorkSheet {
         target_id: u32,
         worksheet: &WorkSheet,
     ) -> WorkSheet {
-        Self {
+        let mut xml_worksheet = worksheet.worksheet.clone();
+        xml_worksheet.rename_sparkline_sheet(&worksheet.name, name);
+        let mut worksheet = Self {
             id: sheet_id,
             name: name.to_string(),
             target: format!("worksheets/sheet{target_id}.xml"),
             target_id,
             workbook: worksheet.workbook.clone(),
             workbook_rel: worksheet.workbook_rel.clone(),
-            worksheet: worksheet.worksheet.clone(),
+            worksheet: xml_worksheet,
             worksheet_rel: worksheet.worksheet_rel.clone(),
             style_sheet: worksheet.style_sheet.clone(),
             content_types: worksheet.content_types.clone(),
             medias: worksheet.medias.clone(),
             themes: worksheet.themes.clone(),
-            vml_drawing: None,
+            vml_drawing: worksheet.vml_drawing.clone(),
+            comments: worksheet.comments.clone(),
+            ctrl_props: worksheet.ctrl_props.clone(),
             drawings: worksheet.drawings.clone(),
             drawings_rel: worksheet.drawings_rel.clone(),
+            other_drawings: worksheet.other_drawings.clone(),
             metadata: worksheet.metadata.clone(),
             shared_string: worksheet.shared_string.clone(),
+            parts: worksheet.parts.clone(),
+        };
+        worksheet.renumber_drawings();
+        worksheet.renumber_controls();
+        worksheet.renumber_comments();
+        worksheet
+    }
+
+    ///
+    /// Give the copied drawing parts new ids, so that the copy does not share them with the original worksheet.
+    /// The medias are still shared.
+    ///
+    fn renumber_drawings(&mut self) {
+        let main_r_id = self.drawings_r_id().filter(|_| self.drawings.is_some());
+        let r_ids = main_r_id.into_iter().chain(self.other_drawings.iter().map(|(r_id, _, _)| *r_id));
+        let mut content_types = self.content_types.borrow_mut();
+        for r_id in r_ids {
+            let id = content_types.next_drawing_id();
+            content_types.add_drawing(id);
+            self.worksheet_rel.set_drawing_id(r_id, id);
+        }
+    }
+
+    ///
+    /// Give the copied vml drawing and the properties of the added form controls new ids,
+    /// the shapes of the vml drawing get a block of shape ids of their own, which the controls follow.
+    /// The loaded properties of the form controls are still shared.
+    ///
+    fn renumber_controls(&mut self) {
+        let mut content_types = self.content_types.borrow_mut();
+        if let (Some(vml_drawing), Some(r_id)) = (&mut self.vml_drawing, self.worksheet.get_legacy_drawing_r_id()) {
+            let id = content_types.next_vml_drawing_id();
+            content_types.add_vml_drawing(id);
+            self.worksheet_rel.set_vml_drawing_id(r_id, id);
+            let shape_ids = vml_drawing.renumber(id);
+            self.worksheet.renumber_control_shape_ids(&shape_ids);
+        }
+        for (r_id, _) in &self.ctrl_props {
+            let id = content_types.next_ctrl_prop_id();
+            content_types.add_ctrl_prop(id);
+            self.worksheet_rel.set_ctrl_prop_id(*r_id, id);
+        }
+    }
+
+    ///
+    /// Give the copied comments a part of their own, which is written when the workbook is saved.
+    ///
+    fn renumber_comments(&mut self) {
+        if let (Some(comments), Some((r_id, _))) = (&mut self.comments, self.worksheet_rel.get_comments()) {
+            let mut content_types = self.content_types.borrow_mut();
+            let id = content_types.next_comments_id();
+            content_types.add_comments(id);
+            self.worksheet_rel.set_comments_id(r_id, id);
+            comments.modified = true;
         }
     }
 
+    ///
+    /// The relationship id of the drawing part in the worksheet's `drawing` element.
+    ///
+    pub(crate) fn drawings_r_id(&self) -> Option<u32> {
+        self.worksheet.get_drawing_r_id()
+            .or(self.worksheet_rel.get_drawings_r_ids().first().copied())
+    }
+
     pub(crate) fn from_archive<P: AsRef<Path>>(
         sheet_id: u32,
         name: &str,
@@ -338,27 +765,32 @@ impl WorkSheet {
         medias: Rc<RefCell<xml::medias::Medias>>,
         themes: Rc<RefCell<xml::theme::Themes>>,
         metadata: Rc<RefCell<Metadata>>,
-        shared_string: Rc<SharedString>,
+        shared_string: Rc<RefCell<SharedString>>,
     ) -> WorkSheet {
         // Read worksheet from zip dir
         let mut worksheet = XmlWorkSheet::from_zip_file(archive, &format!("xl/{target}")).unwrap_or_default();
         worksheet.sheet_data.clean_formula_value();
+        worksheet.clean_ext_lst();
         let worksheet_rel_id: String = target.chars().filter(|&c| c >= '0' && c <= '9').collect();
         let worksheet_rel = Relationships::from_zip_file(archive, &format!("xl/worksheets/_rels/sheet{worksheet_rel_id}.xml.rels")).unwrap_or_default();
         // load drawings
-        let (mut drawings, mut drawings_rel) = (None, None);
-        // worksheet_rel.get_drawings_rids().iter().for_each(|&drawings_id|{
-        //     drawings = Drawings::from_zip_file(archive, &format!("xl/drawings/drawing{drawings_id}.xml"));
-        //     drawings_rel = Relationships::from_zip_file(archive, &format!("xl/drawings/_rels/drawing{drawings_id}.xml.rels"));
-        // });
-        if let Some(&drawings_id) = worksheet_rel.get_drawings_rids().first() {
-            drawings = Drawings::from_zip_file(archive, &format!("xl/drawings/drawing{drawings_id}.xml"));
-            drawings_rel = Relationships::from_zip_file(archive, &format!("xl/drawings/_rels/drawing{drawings_id}.xml.rels"));
-        };
-        let vml_drawing = match worksheet_rel.get_vml_drawing_rid() {
-            Some(vml_drawing_id) => VmlDrawing::from_zip_file(archive, &format!("xl/drawings/vmlDrawing{vml_drawing_id}.xml")),
-            None => None
-        };
+        let (mut drawings, mut drawings_rel, mut other_drawings) = (None, None, vec![]);
+        let main_r_id = worksheet.get_drawing_r_id();
+        for r_id in worksheet_rel.get_drawings_r_ids() {
+            let Some(drawings_id) = worksheet_rel.get_drawing_id(r_id) else { continue };
+            let part = Drawings::from_zip_file(archive, &format!("xl/drawings/drawing{drawings_id}.xml"));
+            let part_rel = Relationships::from_zip_file(archive, &format!("xl/drawings/_rels/drawing{drawings_id}.xml.rels"));
+            if main_r_id.unwrap_or(r_id) == r_id && drawings.is_none() {
+                (drawings, drawings_rel) = (part, part_rel);
+            } else if let Some(part) = part {
+                other_drawings.push((r_id, part, part_rel));
+            }
+        }
+        let vml_drawing = worksheet.get_legacy_drawing_r_id()
+            .and_then(|r_id| worksheet_rel.get_vml_drawing_id(r_id))
+            .and_then(|id| VmlDrawing::from_zip_file(archive, &format!("xl/drawings/vmlDrawing{id}.vml"), id));
+        let comments = worksheet_rel.get_comments()
+            .and_then(|(_, target)| Comments::from_zip_file(archive, &resolve("xl/worksheets", &target)));
         WorkSheet {
             id: sheet_id,
             name: String::from(name),
@@ -373,10 +805,14 @@ impl WorkSheet {
             medias,
             themes,
             vml_drawing,
+            comments,
+            ctrl_props: vec![],
             drawings,
             drawings_rel,
+            other_drawings,
             metadata,
             shared_string,
+            parts: vec![],
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,228 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use crate::api::hyperlink::Hyperlink;
+use crate::api::worksheet::form_control::get_or_add_vml_drawing;
+use crate::api::worksheet::format::_Format;
+use crate::api::worksheet::hyperlink::_Hyperlink;
+use crate::api::worksheet::row::Row as ApiRow;
+use crate::api::worksheet::WorkSheet;
+use crate::result::{WorkSheetError, WorkSheetResult};
+use crate::utils::range_util;
+use crate::xml::comments::{Comment, Comments};
+use crate::xml::style::StyleSheet;
+use crate::xml::worksheet::data_validations::DataValidation;
+use crate::xml::worksheet::sheet_data::cell::Cell;
+use crate::{Column, Format};
+
+///
+/// Everything a range carries when it is copied, taken from the source worksheet
+/// so that it can be pasted into the same worksheet, another one or another workbook.
+///
+pub(crate) struct Clip {
+    range: (u32, u32, u32, u32),
+    cells: Vec<Cell>,
+    // The shared formulas of the source, by their ids
+    shared_formulas: HashMap<i32, (u32, u32, String)>,
+    row_heights: Vec<(u32, f64)>,
+    col_widths: Vec<(u32, u32, f64)>,
+    merged_ranges: Vec<(u32, u32, u32, u32)>,
+    hyperlinks: Vec<Hyperlink>,
+    data_validations: Vec<DataValidation>,
+    comments: Vec<Comment>,
+    // The shapes of the comments in the vml drawing and their shape types
+    notes: Vec<((u32, u32), String, Option<String>)>,
+    // The style sheet the style ids of the cells refer to
+    style_sheet: Rc<RefCell<StyleSheet>>,
+    // Whether the cells are moved, their formulas are pasted as they are
+    cut: bool,
+}
+
+pub(crate) trait _Copy {
+    fn clip_range(&self, range: (u32, u32, u32, u32)) -> Clip;
+    fn clear_range(&mut self, range: (u32, u32, u32, u32));
+    fn paste_clip(&mut self, clip: &Clip, loc: (u32, u32)) -> WorkSheetResult<()>;
+    fn cut_range(&mut self, range: (u32, u32, u32, u32), loc: (u32, u32)) -> WorkSheetResult<()>;
+    fn import_style(&mut self, style_sheet: &Rc<RefCell<StyleSheet>>, style: u32, styles: &mut HashMap<u32, u32>) -> u32;
+}
+
+impl _Copy for WorkSheet {
+    fn clip_range(&self, range: (u32, u32, u32, u32)) -> Clip {
+        let (first_row, first_col, last_row, last_col) = range;
+        let worksheet = &self.worksheet;
+        let mut cells = worksheet.sheet_data.get_cells(range);
+        cells.iter_mut().for_each(|cell| cell.resolve_shared_string(&self.shared_string.borrow()));
+        Clip {
+            range,
+            cells,
+            shared_formulas: worksheet.sheet_data.get_shared_formulas(),
+            row_heights: worksheet.sheet_data.get_row_heights(first_row, last_row),
+            col_widths: worksheet.get_col_widths(first_col, last_col),
+            merged_ranges: worksheet.get_merged_ranges().into_iter()
+                .filter(|&merged_range| range_util::contains(range, merged_range))
+                .collect(),
+            hyperlinks: self.get_hyperlinks().into_iter()
+                .filter(|hyperlink| range_util::contains(range, range_util::from_range_ref(&hyperlink.cell)))
+                .collect(),
+            data_validations: worksheet.get_data_validations(range),
+            comments: self.comments.as_ref().map(|comments| comments.get_comments(range)).unwrap_or_default(),
+            notes: self.vml_drawing.as_ref().map(|vml_drawing| vml_drawing.get_notes(range)).unwrap_or_default(),
+            style_sheet: Rc::clone(&self.style_sheet),
+            cut: false,
+        }
+    }
+
+    ///
+    /// Remove the cells, the merged ranges, the hyperlinks, the data validations and the comments of the range,
+    /// the relationships of the removed hyperlinks are removed if no other hyperlink refers to them.
+    ///
+    fn clear_range(&mut self, range: (u32, u32, u32, u32)) {
+        self.worksheet.sheet_data.remove_cells(range);
+        self.worksheet.remove_merge_cells(range);
+        self.worksheet.remove_data_validations(range);
+        for hyperlink in self.worksheet.remove_hyperlinks(range) {
+            if let Some(r_id) = hyperlink.get_r_id() {
+                if !self.worksheet.has_hyperlink_r_id(r_id) {
+                    self.worksheet_rel.remove_by_id(r_id);
+                }
+            }
+        }
+        if let Some(comments) = &mut self.comments {
+            comments.remove_range(range);
+        }
+        if let Some(vml_drawing) = &mut self.vml_drawing {
+            vml_drawing.remove_notes(range);
+        }
+    }
+
+    ///
+    /// Paste the clip so that the top left cell of its range is at `loc`, what was in the destination is replaced.
+    ///
+    fn paste_clip(&mut self, clip: &Clip, loc: (u32, u32)) -> WorkSheetResult<()> {
+        let (first_row, first_col, _, _) = clip.range;
+        let (row, col) = loc;
+        self.clear_range(destination(clip.range, loc)?);
+        let row_offset = row as i64 - first_row as i64;
+        let col_offset = col as i64 - first_col as i64;
+
+        let mut styles = HashMap::new();
+        let mut cells = vec![];
+        for cell in &clip.cells {
+            let (to_row, to_col) = (cell.loc.row - first_row + row, cell.loc.col - first_col + col);
+            let mut cell = match clip.cut {
+                true => cell.move_to(to_row, to_col),
+                false => cell.copy_to(to_row, to_col, &clip.shared_formulas),
+            };
+            if let Some(style) = cell.style {
+                cell.style = Some(self.import_style(&clip.style_sheet, style, &mut styles));
+            }
+            cells.push(cell);
+        }
+        self.worksheet.sheet_data.put_cells(cells);
+
+        for &(height_row, height) in &clip.row_heights {
+            let row = ApiRow { height: Some(height), ..Default::default() };
+            self.worksheet.sheet_data.set_by_row((height_row as i64 + row_offset) as u32, &row);
+        }
+        for &(min, max, width) in &clip.col_widths {
+            let column = Column { width: Some(width), ..Default::default() };
+            let (_, min, _, max) = range_util::offset((1, min, 1, max), 0, col_offset);
+            self.worksheet.set_col_by_column((1, min, 1, max), &column)?;
+        }
+        for &merged_range in &clip.merged_ranges {
+            let (first_row, first_col, last_row, last_col) = range_util::offset(merged_range, row_offset, col_offset);
+            self.worksheet.add_merge_cell(first_row, first_col, last_row, last_col);
+        }
+        for hyperlink in &clip.hyperlinks {
+            let hyperlink_range = range_util::offset(range_util::from_range_ref(&hyperlink.cell), row_offset, col_offset);
+            let target = match (&hyperlink.url, &hyperlink.location) {
+                (Some(url), _) => url.clone(),
+                (None, Some(location)) => format!("#{location}"),
+                (None, None) => continue,
+            };
+            let loc = (hyperlink_range.0, hyperlink_range.1);
+            self.set_hyperlink(&loc, &target);
+            if let Some(pasted) = self.worksheet.get_hyperlink_mut(&loc) {
+                pasted.hyperlink_ref = range_util::to_range_ref(hyperlink_range);
+                pasted.location = hyperlink.location.clone();
+                pasted.display = hyperlink.display.clone();
+                pasted.tooltip = hyperlink.tooltip.clone();
+            }
+        }
+        for data_validation in &clip.data_validations {
+            self.worksheet.add_data_validation(data_validation.offset(row_offset, col_offset));
+        }
+        for comment in &clip.comments {
+            get_or_add_comments(self).add_comment(comment, row_offset, col_offset);
+        }
+        for (_, shape, shape_type) in &clip.notes {
+            get_or_add_vml_drawing(self).add_note(shape, shape_type.as_deref(), row_offset, col_offset);
+        }
+        Ok(())
+    }
+
+    ///
+    /// Move the range so that its top left cell is at `loc`, the references of the formulas of the worksheet
+    /// to the cells of the range follow them and the formulas of the range are kept.
+    ///
+    fn cut_range(&mut self, range: (u32, u32, u32, u32), loc: (u32, u32)) -> WorkSheetResult<()> {
+        destination(range, loc)?;
+        let row_offset = loc.0 as i64 - range.0 as i64;
+        let col_offset = loc.1 as i64 - range.1 as i64;
+        self.worksheet.sheet_data.move_references(&self.name, range, row_offset, col_offset);
+        let clip = Clip { cut: true, ..self.clip_range(range) };
+        self.clear_range(range);
+        self.paste_clip(&clip, loc)
+    }
+
+    ///
+    /// The style id in this workbook of a style of the style sheet,
+    /// the style and its named cell style are added to the style sheet of this workbook if they are not the same.
+    ///
+    fn import_style(&mut self, style_sheet: &Rc<RefCell<StyleSheet>>, style: u32, styles: &mut HashMap<u32, u32>) -> u32 {
+        if Rc::ptr_eq(style_sheet, &self.style_sheet) {
+            return style;
+        }
+        if let Some(&imported) = styles.get(&style) {
+            return imported;
+        }
+        let mut format = Format::default();
+        style_sheet.borrow().update_format(&mut format, style);
+        if let Some(name) = &format.style_name {
+            self.style_sheet.borrow_mut().import_named_style(&style_sheet.borrow(), name);
+        }
+        let imported = self.add_format(&format);
+        styles.insert(style, imported);
+        imported
+    }
+}
+
+///
+/// The comments of the worksheet, a comments part and its relationship are added if it has none.
+///
+fn get_or_add_comments(worksheet: &mut WorkSheet) -> &mut Comments {
+    if worksheet.comments.is_none() {
+        let id = worksheet.content_types.borrow().next_comments_id();
+        worksheet.content_types.borrow_mut().add_comments(id);
+        match worksheet.worksheet_rel.get_comments() {
+            // the part of the relationship could not be read, it is replaced by a new one
+            Some((r_id, _)) => worksheet.worksheet_rel.set_comments_id(r_id, id),
+            None => { worksheet.worksheet_rel.add_comments(id); }
+        }
+        worksheet.comments = Some(Comments::default());
+    }
+    worksheet.comments.as_mut().unwrap()
+}
+
+///
+/// The range of the same size as `range` whose top left cell is `loc`,
+/// [`WorkSheetError::OutOfRange`] if it goes beyond the last row or column of a worksheet.
+///
+pub(crate) fn destination(range: (u32, u32, u32, u32), loc: (u32, u32)) -> WorkSheetResult<(u32, u32, u32, u32)> {
+    let (first_row, first_col, last_row, last_col) = range;
+    let (row, col) = loc;
+    match row + last_row - first_row <= 1048576 && col + last_col - first_col <= 16384 {
+        true => Ok((row, col, row + last_row - first_row, col + last_col - first_col)),
+        false => Err(WorkSheetError::OutOfRange),
+    }
+}
@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use crate::api::worksheet::format::_Format;
+use crate::api::worksheet::sort::{SortValue, _Sort};
+use crate::api::worksheet::WorkSheet;
+use crate::utils::date_util;
+use crate::utils::range_util;
+use crate::xml::worksheet::sheet_data::cell::Cell;
+use crate::Filters;
+
+pub(crate) trait _Filter {
+    fn add_filter_column(&mut self, col: u32, filters: &Filters);
+    fn apply_filters(&mut self);
+}
+
+impl _Filter for WorkSheet {
+    fn add_filter_column(&mut self, col: u32, filters: &Filters) {
+        let dxf_id = filters.color
+            .map(|(color, cell_color)| self.style_sheet.borrow_mut().add_color_dxf(&color, !cell_color));
+        self.worksheet.filter_column((1, col), filters, dxf_id);
+    }
+
+    ///
+    /// Hide the rows of the autofilter which do not pass the criteria of every filtered column, and show the others.
+    ///
+    fn apply_filters(&mut self) {
+        let Some(auto_filter) = self.worksheet.get_auto_filter() else { return };
+        if auto_filter.sqref.is_empty() {
+            return;
+        }
+        let (first_row, first_col, last_row, _) = range_util::from_range_ref(&auto_filter.sqref);
+        // The autofilter may be whole columns, the rows past the data are not filtered
+        let (first_row, last_row) = (first_row + 1, last_row.min(self.worksheet.sheet_data.max_row()));
+        if first_row > last_row {
+            return;
+        }
+        let columns: Vec<Vec<(SortValue, bool)>> = auto_filter.filter_column.iter()
+            .map(|filter_column| {
+                let col = first_col + filter_column.get_col_id();
+                let color = filter_column.get_color_filter()
+                    .and_then(|(dxf_id, cell_color)| Some((self.style_sheet.borrow().get_dxf_color(dxf_id, cell_color)?, cell_color)));
+                let cells: HashMap<u32, Cell> = self.worksheet.sheet_data.get_cells((first_row, col, last_row, col)).into_iter()
+                    .map(|cell| (cell.loc.row, cell))
+                    .collect();
+                (first_row..=last_row)
+                    .map(|row| {
+                        let cell = cells.get(&row);
+                        let value = cell.map_or(SortValue::Blank, |cell| self.sort_value(cell));
+                        let colored = color.is_some_and(|(color, cell_color)| {
+                            let format = self.get_format(cell.and_then(|cell| cell.style).unwrap_or_default());
+                            match cell_color {
+                                true => format.fill.fg_color == color,
+                                false => format.font.color == color,
+                            }
+                        });
+                        (value, colored)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let today = date_util::today();
+        let mut shown = vec![true; (last_row - first_row + 1) as usize];
+        if let Some(auto_filter) = self.worksheet.get_auto_filter_mut() {
+            for (filter_column, cells) in auto_filter.filter_column.iter_mut().zip(&columns) {
+                let passes = filter_column.filter(cells, today);
+                shown.iter_mut().zip(passes).for_each(|(shown, passes)| *shown &= passes);
+            }
+        }
+        for (row, shown) in (first_row..=last_row).zip(shown) {
+            self.worksheet.sheet_data.set_row_hidden(row, !shown);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::{HashMap, HashSet};
+use crate::api::cell::values::CellType;
+use crate::api::worksheet::WorkSheet;
+use crate::utils::find_util::Matcher;
+use crate::utils::formula_util::offset_formula;
+use crate::xml::shared_string::SharedString;
+use crate::xml::worksheet::sheet_data::cell::Cell;
+
+pub(crate) trait _Find {
+    fn find_cells(&self, matcher: &Matcher, in_formulas: bool) -> Vec<(u32, u32)>;
+    fn replace_cells(&mut self, matcher: &Matcher, replacement: &str, in_formulas: bool, limit: usize) -> Vec<(u32, u32)>;
+}
+
+impl _Find for WorkSheet {
+    ///
+    /// The locations of the cells whose texts match, row by row.
+    ///
+    fn find_cells(&self, matcher: &Matcher, in_formulas: bool) -> Vec<(u32, u32)> {
+        let sheet_data = &self.worksheet.sheet_data;
+        let shared_formulas = sheet_data.get_shared_formulas();
+        sheet_data.get_cells((1, 1, sheet_data.max_row(), sheet_data.max_col()))
+            .into_iter()
+            .filter(|cell| searched_text(self, cell, in_formulas, &shared_formulas).is_some_and(|text| matcher.is_match(&text)))
+            .map(|cell| (cell.loc.row, cell.loc.col))
+            .collect()
+    }
+
+    ///
+    /// Rewrite the first `limit` cells whose texts match, and return their locations.
+    /// The rewritten strings of the table of shared strings are added to the table, the old ones are kept for the other cells,
+    /// the runs of a rich text keep their formats, a number which is no longer a number becomes a string.
+    /// The formulas are rewritten only if `in_formulas`, the cached values of the rewritten formulas are removed.
+    /// The booleans and the errors are never rewritten.
+    ///
+    fn replace_cells(&mut self, matcher: &Matcher, replacement: &str, in_formulas: bool, limit: usize) -> Vec<(u32, u32)> {
+        let sheet_data = &self.worksheet.sheet_data;
+        let range = (1, 1, sheet_data.max_row(), sheet_data.max_col());
+        let shared_formulas = sheet_data.get_shared_formulas();
+        let cells: Vec<Cell> = sheet_data.get_cells(range)
+            .into_iter()
+            .filter(|cell| match (&cell.formula, &cell.cell_type) {
+                (Some(_), _) => in_formulas,
+                (None, Some(CellType::Boolean | CellType::Error)) => false,
+                (None, _) => true,
+            })
+            .filter(|cell| searched_text(self, cell, in_formulas, &shared_formulas).is_some_and(|text| matcher.is_match(&text)))
+            .take(limit)
+            .collect();
+        // A formula shared with cells which do not match can not be rewritten in place
+        if cells.iter().any(|cell| cell.formula.as_ref().is_some_and(|formula| formula.get_si().is_some())) {
+            self.worksheet.sheet_data.unshare_formulas(range);
+        }
+        let locs: Vec<(u32, u32)> = cells.iter().map(|cell| (cell.loc.row, cell.loc.col)).collect();
+        let replaced: HashSet<(u32, u32)> = locs.iter().copied().collect();
+        let shared_string = &mut self.shared_string.borrow_mut();
+        self.worksheet.sheet_data.for_each_cell_mut(range, false, |cell| {
+            if replaced.contains(&(cell.loc.row, cell.loc.col)) {
+                replace_cell(cell, shared_string, matcher, replacement);
+            }
+        });
+        locs
+    }
+}
+
+///
+/// The text of the cell which is searched, its formula if it has one and `in_formulas`, otherwise its value.
+///
+fn searched_text(worksheet: &WorkSheet, cell: &Cell, in_formulas: bool, shared_formulas: &HashMap<i32, (u32, u32, String)>) -> Option<String> {
+    match &cell.formula {
+        Some(formula) if in_formulas => match formula.is_shared_follower() {
+            true => formula.get_si()
+                .and_then(|si| shared_formulas.get(&si))
+                .map(|(row, col, master)| offset_formula(master, cell.loc.row as i64 - *row as i64, cell.loc.col as i64 - *col as i64)),
+            false => Some(formula.formula.clone()),
+        },
+        _ => {
+            let mut cell = cell.clone();
+            cell.resolve_shared_string(&worksheet.shared_string.borrow());
+            match &cell.inline_string {
+                Some(inline_string) => Some(inline_string.get_texts().concat()),
+                None => cell.text,
+            }
+        }
+    }
+}
+
+///
+/// Replace the matches in the formula or the value of the cell, see [`_Find::replace_cells`].
+///
+fn replace_cell(cell: &mut Cell, shared_string: &mut SharedString, matcher: &Matcher, replacement: &str) {
+    if let Some(formula) = &mut cell.formula {
+        if let Some(new_formula) = matcher.replace(&formula.formula, replacement) {
+            formula.formula = new_formula;
+            cell.text = None;
+        }
+        return;
+    }
+    let shared = matches!(cell.cell_type, Some(CellType::SharedString));
+    cell.resolve_shared_string(shared_string);
+    if let Some(inline_string) = &mut cell.inline_string {
+        let mut texts = inline_string.get_texts();
+        if matcher.replace_runs(&mut texts, replacement) {
+            inline_string.set_texts(texts);
+        }
+    } else if let Some(text) = cell.text.as_ref().and_then(|text| matcher.replace(text, replacement)) {
+        if let Some(CellType::Number | CellType::Undefined) | None = cell.cell_type {
+            if text.parse::<f64>().is_err() {
+                cell.cell_type = Some(CellType::String);
+            }
+        }
+        cell.text = Some(text);
+    }
+    if shared {
+        cell.share_string(shared_string);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::api::cell::location::LocationRange;
+use crate::api::worksheet::WorkSheet;
+use crate::{FormControl, FormControlType, WorkSheetResult};
+use crate::xml::ctrl_prop::FormControlPr;
+use crate::xml::drawings::vml_drawing::VmlDrawing;
+use crate::xml::namespaces::excel::{ClientData, Flag};
+
+pub(crate) trait _FormControl {
+    fn add_form_control<L: LocationRange>(&mut self, loc_range: L, control: &FormControl) -> WorkSheetResult<()>;
+}
+
+impl _FormControl for WorkSheet {
+    fn add_form_control<L: LocationRange>(&mut self, loc_range: L, control: &FormControl) -> WorkSheetResult<()> {
+        let range = loc_range.to_range();
+        // the properties part
+        let ctrl_prop_id = {
+            let mut content_types = self.content_types.borrow_mut();
+            let id = content_types.next_ctrl_prop_id();
+            content_types.add_ctrl_prop(id);
+            id
+        };
+        let r_id = self.worksheet_rel.add_ctrl_prop(ctrl_prop_id);
+        self.ctrl_props.push((r_id, form_control_pr(control)));
+        // the shape in the vml drawing
+        let control_type = control.control_type;
+        let macro_name = control.macro_name.as_ref().map(|macro_name| format!("[0]!{macro_name}"));
+        let mut client_data = client_data(control, range);
+        client_data.fmla_macro = macro_name.clone();
+        let vml_drawing = get_or_add_vml_drawing(self);
+        let text = control.text.clone().unwrap_or(control.name.clone().unwrap_or(control_type.default_name().to_string()));
+        let text = control_type.has_text().then_some(text.as_str());
+        let shape_id = vml_drawing.add_form_control(&shape_style(range), text, control_type == FormControlType::Button, client_data);
+        // the control in the worksheet
+        let name = control.name.clone().unwrap_or(format!("{} {}", control_type.default_name(), shape_id % 1024));
+        self.worksheet.add_control(&loc_range, shape_id, r_id, &name, macro_name.as_deref());
+        Ok(())
+    }
+}
+
+pub(crate) fn get_or_add_vml_drawing(worksheet: &mut WorkSheet) -> &mut VmlDrawing {
+    if worksheet.vml_drawing.is_none() {
+        let id = worksheet.content_types.borrow().next_vml_drawing_id();
+        worksheet.content_types.borrow_mut().add_vml_drawing(id);
+        match worksheet.worksheet.get_legacy_drawing_r_id() {
+            // the part of the relationship could not be read, it is replaced by a new one
+            Some(r_id) => worksheet.worksheet_rel.set_vml_drawing_id(r_id, id),
+            None => {
+                let r_id = worksheet.worksheet_rel.add_vml_drawing(id);
+                worksheet.worksheet.set_legacy_drawing(r_id);
+            }
+        }
+        worksheet.vml_drawing = Some(VmlDrawing::new(id));
+    }
+    worksheet.vml_drawing.as_mut().unwrap()
+}
+
+///
+/// The position of the shape, in which the columns are 48pt wide and the rows are 15pt high.
+/// Excel places the control by the anchor of its client data.
+///
+fn shape_style((first_row, first_col, last_row, last_col): (u32, u32, u32, u32)) -> String {
+    format!(
+        "position:absolute;margin-left:{}pt;margin-top:{}pt;width:{}pt;height:{}pt;z-index:1;mso-wrap-style:tight",
+        48 * (first_col - 1), 15 * (first_row - 1), 48 * (last_col - first_col + 1), 15 * (last_row - first_row + 1)
+    )
+}
+
+fn client_data(control: &FormControl, (first_row, first_col, last_row, last_col): (u32, u32, u32, u32)) -> ClientData {
+    let mut client_data = ClientData::new(
+        control.control_type.to_vml_str(),
+        [first_col - 1, 0, first_row - 1, 0, last_col, 0, last_row, 0]
+    );
+    let off = || Some("False".to_string());
+    client_data.fmla_link = control.linked_cell.clone();
+    match control.control_type {
+        FormControlType::CheckBox | FormControlType::OptionButton => {
+            client_data.auto_fill = off();
+            client_data.auto_line = off();
+            client_data.text_v_align = Some("Center".to_string());
+            client_data.checked = control.checked.then_some(1);
+            client_data.no_three_d = Some(Flag::default());
+        }
+        FormControlType::ComboBox | FormControlType::ListBox => {
+            client_data.print_object = off();
+            client_data.auto_line = off();
+            client_data.fmla_range = control.input_range.clone();
+            client_data.sel = control.value;
+            client_data.no_three_d2 = Some(Flag::default());
+            client_data.val = Some(0);
+            if control.control_type == FormControlType::ComboBox {
+                client_data.drop_style = Some("Combo".to_string());
+                client_data.drop_lines = Some(control.drop_lines.unwrap_or(8));
+            } else {
+                client_data.sel_type = Some("Single".to_string());
+                client_data.lct = Some("Normal".to_string());
+            }
+        }
+        FormControlType::Spinner => {
+            client_data.print_object = off();
+            client_data.auto_fill = off();
+            client_data.auto_line = off();
+            client_data.val = Some(control.value.unwrap_or_default());
+            client_data.min = Some(control.min.unwrap_or(0));
+            client_data.max = Some(control.max.unwrap_or(100));
+            client_data.inc = Some(control.increment.unwrap_or(1));
+            client_data.page = Some(10);
+            client_data.no_three_d = Some(Flag::default());
+        }
+        FormControlType::Button => {
+            client_data.fmla_link = None;
+            client_data.print_object = off();
+            client_data.auto_fill = off();
+            client_data.text_h_align = Some("Center".to_string());
+            client_data.text_v_align = Some("Center".to_string());
+        }
+    }
+    client_data
+}
+
+fn form_control_pr(control: &FormControl) -> FormControlPr {
+    let mut pr = FormControlPr::new(control.control_type.to_str());
+    pr.fmla_link = control.linked_cell.clone();
+    match control.control_type {
+        FormControlType::CheckBox | FormControlType::OptionButton => {
+            pr.checked = control.checked.then(|| "Checked".to_string());
+            pr.lock_text = Some(1);
+            pr.no_three_d = Some(1);
+        }
+        FormControlType::ComboBox | FormControlType::ListBox => {
+            pr.dx = Some(22);
+            pr.fmla_range = control.input_range.clone();
+            pr.no_three_d = Some(1);
+            pr.sel = control.value;
+            pr.val = Some(0);
+            if control.control_type == FormControlType::ComboBox {
+                pr.drop_lines = Some(control.drop_lines.unwrap_or(8));
+                pr.drop_style = Some("combo".to_string());
+            }
+        }
+        FormControlType::Spinner => {
+            pr.dx = Some(22);
+            pr.inc = Some(control.increment.unwrap_or(1));
+            pr.max = Some(control.max.unwrap_or(100));
+            pr.min = Some(control.min.unwrap_or(0));
+            pr.page = Some(10);
+            pr.val = Some(control.value.unwrap_or_default());
+        }
+        FormControlType::Button => {
+            pr.fmla_link = None;
+            pr.lock_text = Some(1);
+        }
+    }
+    pr
+}
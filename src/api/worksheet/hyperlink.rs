@@ -1,11 +1,71 @@
+use crate::api::cell::location::Location;
+use crate::api::hyperlink::Hyperlink;
 use crate::api::worksheet::WorkSheet;
+use crate::result::{WorkSheetError, WorkSheetResult};
 
 pub(crate) trait _Hyperlink {
     fn add_hyperlink(&mut self, hyperlink: &str) -> u32;
+    fn set_hyperlink<L: Location>(&mut self, loc: &L, hyperlink: &str);
+    fn get_hyperlink<L: Location>(&self, loc: &L) -> Option<String>;
+    fn delete_hyperlink<L: Location>(&mut self, loc: &L) -> WorkSheetResult<()>;
+    fn get_hyperlinks(&self) -> Vec<Hyperlink>;
 }
 
 impl _Hyperlink for WorkSheet {
     fn add_hyperlink(&mut self, hyperlink: &str) -> u32 {
         self.worksheet_rel.add_hyperlink(hyperlink)
     }
-}
\ No newline at end of file
+
+    ///
+    /// Link the cell to a url, or to a place in the workbook if the hyperlink starts with `#`,
+    /// the hyperlink which was on the cell is replaced.
+    ///
+    fn set_hyperlink<L: Location>(&mut self, loc: &L, hyperlink: &str) {
+        let _ = self.delete_hyperlink(loc);
+        match hyperlink.strip_prefix('#') {
+            Some(location) => self.worksheet.add_hyperlink_location(loc, location),
+            None => {
+                let r_id = self.add_hyperlink(hyperlink);
+                self.worksheet.add_hyperlink(loc, r_id);
+            }
+        }
+    }
+
+    ///
+    /// The url of the hyperlink on the cell, or its location in the workbook prefixed with `#`.
+    ///
+    fn get_hyperlink<L: Location>(&self, loc: &L) -> Option<String> {
+        let hyperlink = self.worksheet.get_hyperlink(loc)?;
+        match hyperlink.get_r_id() {
+            Some(r_id) => self.worksheet_rel.get_target_by_id(r_id).map(|target| target.to_string()),
+            None => hyperlink.location.as_ref().map(|location| format!("#{location}")),
+        }
+    }
+
+    ///
+    /// Remove the hyperlink on the cell and its relationship if no other hyperlink refers to it.
+    ///
+    fn delete_hyperlink<L: Location>(&mut self, loc: &L) -> WorkSheetResult<()> {
+        let hyperlink = self.worksheet.remove_hyperlink(loc).ok_or(WorkSheetError::HyperlinkNotFound)?;
+        if let Some(r_id) = hyperlink.get_r_id() {
+            if !self.worksheet.has_hyperlink_r_id(r_id) {
+                self.worksheet_rel.remove_by_id(r_id);
+            }
+        }
+        Ok(())
+    }
+
+    fn get_hyperlinks(&self) -> Vec<Hyperlink> {
+        self.worksheet.hyperlinks().iter()
+            .map(|hyperlink| Hyperlink {
+                cell: hyperlink.hyperlink_ref.clone(),
+                url: hyperlink.get_r_id()
+                    .and_then(|r_id| self.worksheet_rel.get_target_by_id(r_id))
+                    .map(|target| target.to_string()),
+                location: hyperlink.location.clone(),
+                display: hyperlink.display.clone(),
+                tooltip: hyperlink.tooltip.clone(),
+            })
+            .collect()
+    }
+}
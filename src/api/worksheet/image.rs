@@ -1,28 +1,54 @@
 use std::path::Path;
 use futures::io;
-use crate::api::cell::location::LocationRange;
+use crate::api::cell::location::{Location, LocationRange};
 use crate::api::worksheet::WorkSheet;
 use crate::result::WorkSheetError;
-use crate::WorkSheetResult;
+use crate::{Image, ImageFormat, ImageOptions, Shape, WorkSheetResult};
+use crate::utils::image_util::{detect_format, ImageInfo, read_image_info};
 use crate::xml::drawings::Drawings;
+use crate::xml::medias::Medias;
 use crate::xml::relationships::Relationships;
 
-pub(crate) trait Image {
+pub(crate) trait _Image {
     fn add_background<P: AsRef<Path>>(&mut self, filename: &P) -> WorkSheetResult<u32> ;
+    fn get_background_image(&self) -> Option<Vec<u8>>;
+    fn remove_background_image(&mut self);
     fn add_drawing<L: LocationRange,P: AsRef<Path>>(&mut self, loc: L, filename: &P) -> WorkSheetResult<u32>;
+    fn add_one_cell_drawing<L: Location>(&mut self, loc: L, buffer: &[u8], format: ImageFormat, options: &ImageOptions) -> WorkSheetResult<u32>;
+    fn add_absolute_drawing(&mut self, x: u32, y: u32, buffer: &[u8], format: ImageFormat, options: &ImageOptions) -> WorkSheetResult<u32>;
+    fn add_range_drawing<L: LocationRange>(&mut self, loc: L, buffer: &[u8], format: ImageFormat, options: &ImageOptions) -> WorkSheetResult<u32>;
+    fn add_shape<L: LocationRange>(&mut self, loc: L, shape: &Shape) -> WorkSheetResult<u32>;
+    fn get_images(&self) -> Vec<Image>;
+    fn replace_drawing(&mut self, index: usize, buffer: &[u8]) -> WorkSheetResult<()>;
+    fn remove_drawing(&mut self, index: usize) -> WorkSheetResult<()>;
 }
 
-impl Image for WorkSheet {
+impl _Image for WorkSheet {
     fn add_background<P: AsRef<Path>>(&mut self, image_path: &P) -> WorkSheetResult<u32> {
         let extension = get_extension(image_path)?;
-        if extension != "png" {
-            return Err(WorkSheetError::FormatError);
-        }
-        self.content_types.borrow_mut().add_png();
+        // Excel only shows bitmap backgrounds
+        let format = match ImageFormat::from_extension(extension) {
+            Some(ImageFormat::Svg) | None => return Err(WorkSheetError::FormatError),
+            Some(format) => format,
+        };
+        self.content_types.borrow_mut().add_image(extension, format.content_type());
         let image_id = self.medias.borrow_mut().add_media(image_path);
+        self.remove_background_image();
         Ok(self.worksheet_rel.add_image(image_id, extension))
     }
 
+    fn get_background_image(&self) -> Option<Vec<u8>> {
+        let target = self.worksheet_rel.get_target_by_id(self.worksheet.get_background_r_id()?)?;
+        let name = Path::new(target).file_name()?.to_str()?;
+        self.medias.borrow().read_media(name)
+    }
+
+    fn remove_background_image(&mut self) {
+        if let Some(r_id) = self.worksheet.remove_background() {
+            remove_rel(&mut self.worksheet_rel, &mut self.medias.borrow_mut(), r_id);
+        }
+    }
+
     fn add_drawing<L: LocationRange, P: AsRef<Path>>(&mut self, loc: L, image_path: &P) -> WorkSheetResult<u32> {
         // get extension
         let extension = get_extension(image_path)?;
@@ -32,12 +58,157 @@ impl Image for WorkSheet {
         let drawings = self.drawings.get_or_insert(Drawings::default());
         let drawings_rel = &mut self.drawings_rel.get_or_insert(Relationships::default());
         self.content_types.borrow_mut().add_bin(extension);
-        self.content_types.borrow_mut().add_drawing(self.id);
         let image_r_id = drawings_rel.add_image(image_id, extension);
         drawings.add_drawing(loc, image_r_id);
-        let r_id = self.worksheet_rel.add_drawings(self.id);
-        Ok(r_id)
+        Ok(get_or_add_drawings(self))
+    }
+
+    fn add_one_cell_drawing<L: Location>(&mut self, loc: L, buffer: &[u8], format: ImageFormat, options: &ImageOptions) -> WorkSheetResult<u32> {
+        let (image_r_id, info) = add_image_buffer(self, buffer, format)?;
+        let hyperlink_r_id = add_image_hyperlink(self, options);
+        let offset = (pixels_to_emu(options.x_offset as f64), pixels_to_emu(options.y_offset as f64));
+        let drawings = self.drawings.get_or_insert(Drawings::default());
+        drawings.add_one_cell_drawing(loc, offset, image_extent(&info, options), image_r_id)
+            .set_options(options, hyperlink_r_id);
+        Ok(get_or_add_drawings(self))
+    }
+
+    fn add_absolute_drawing(&mut self, x: u32, y: u32, buffer: &[u8], format: ImageFormat, options: &ImageOptions) -> WorkSheetResult<u32> {
+        let (image_r_id, info) = add_image_buffer(self, buffer, format)?;
+        let hyperlink_r_id = add_image_hyperlink(self, options);
+        let position = (pixels_to_emu((x + options.x_offset) as f64), pixels_to_emu((y + options.y_offset) as f64));
+        let drawings = self.drawings.get_or_insert(Drawings::default());
+        drawings.add_absolute_drawing(position, image_extent(&info, options), image_r_id)
+            .set_options(options, hyperlink_r_id);
+        Ok(get_or_add_drawings(self))
+    }
+
+    fn add_range_drawing<L: LocationRange>(&mut self, loc: L, buffer: &[u8], format: ImageFormat, options: &ImageOptions) -> WorkSheetResult<u32> {
+        let (image_r_id, _) = add_image_buffer(self, buffer, format)?;
+        let hyperlink_r_id = add_image_hyperlink(self, options);
+        let drawings = self.drawings.get_or_insert(Drawings::default());
+        drawings.add_drawing(loc, image_r_id).set_options(options, hyperlink_r_id);
+        Ok(get_or_add_drawings(self))
+    }
+
+    fn add_shape<L: LocationRange>(&mut self, loc: L, shape: &Shape) -> WorkSheetResult<u32> {
+        let drawings = self.drawings.get_or_insert(Drawings::default());
+        drawings.add_shape(loc, shape);
+        Ok(get_or_add_drawings(self))
+    }
+
+    fn get_images(&self) -> Vec<Image> {
+        let (Some(drawings), Some(drawings_rel)) = (&self.drawings, &self.drawings_rel) else {
+            return vec![];
+        };
+        let medias = self.medias.borrow();
+        drawings.pictures().iter().map(|drawing| {
+            let target = drawing.picture_r_id().and_then(|r_id| drawings_rel.get_target_by_id(r_id));
+            let media_name = target.and_then(|target| Path::new(target).file_name()?.to_str());
+            Image {
+                name: drawing.name().unwrap_or_default().to_string(),
+                description: drawing.description().map(String::from),
+                from: drawing.top_left_cell(),
+                to: drawing.bottom_right_cell(),
+                format: media_name
+                    .and_then(|name| Path::new(name).extension()?.to_str())
+                    .and_then(ImageFormat::from_extension),
+                buffer: media_name.and_then(|name| medias.read_media(name)).unwrap_or_default(),
+            }
+        }).collect()
+    }
+
+    fn replace_drawing(&mut self, index: usize, buffer: &[u8]) -> WorkSheetResult<()> {
+        if self.drawings.as_ref().map_or(0, |drawings| drawings.pictures().len()) <= index {
+            return Err(WorkSheetError::ImageNotFound);
+        }
+        let format = detect_format(buffer).ok_or(WorkSheetError::FormatError)?;
+        let (image_r_id, _) = add_image_buffer(self, buffer, format)?;
+        if let (Some(drawings), Some(drawings_rel)) = (&mut self.drawings, &mut self.drawings_rel) {
+            if let Some(old_r_id) = drawings.replace_picture(index, image_r_id) {
+                if !drawings.refers(old_r_id) {
+                    remove_rel(drawings_rel, &mut self.medias.borrow_mut(), old_r_id);
+                }
+            }
+        }
+        Ok(())
     }
+
+    fn remove_drawing(&mut self, index: usize) -> WorkSheetResult<()> {
+        let (Some(drawings), Some(drawings_rel)) = (&mut self.drawings, &mut self.drawings_rel) else {
+            return Err(WorkSheetError::ImageNotFound);
+        };
+        let r_ids = drawings.remove_picture(index).ok_or(WorkSheetError::ImageNotFound)?;
+        let mut medias = self.medias.borrow_mut();
+        r_ids.into_iter().for_each(|r_id| remove_rel(drawings_rel, &mut medias, r_id));
+        Ok(())
+    }
+}
+
+///
+/// Returns the relationship id of the worksheet's drawing part,
+/// a new drawing part with a workbook-wide unique id is added if the worksheet has none.
+///
+fn get_or_add_drawings(worksheet: &mut WorkSheet) -> u32 {
+    if let Some(r_id) = worksheet.drawings_r_id() {
+        return r_id;
+    }
+    let mut content_types = worksheet.content_types.borrow_mut();
+    let id = content_types.next_drawing_id();
+    content_types.add_drawing(id);
+    worksheet.worksheet_rel.add_drawings(id)
+}
+
+///
+/// Remove the relationship of the drawing, the media it points to is marked as removed.
+///
+fn remove_rel(drawings_rel: &mut Relationships, medias: &mut Medias, r_id: u32) {
+    if let Some(target) = drawings_rel.remove_by_id(r_id) {
+        if let Some(name) = target.contains("media/").then(|| Path::new(&target).file_name()).flatten() {
+            medias.remove_media(&name.to_string_lossy());
+        }
+    }
+}
+
+///
+/// Add the image to the medias and the drawing relationships,
+/// returns the relationship id of the image and the size read from its header.
+///
+fn add_image_buffer(worksheet: &mut WorkSheet, buffer: &[u8], format: ImageFormat) -> WorkSheetResult<(u32, ImageInfo)> {
+    // Excel only shows a svg picture through a bitmap fallback, which is not written
+    if format == ImageFormat::Svg {
+        return Err(WorkSheetError::FormatError);
+    }
+    let info = read_image_info(buffer, format).ok_or(WorkSheetError::FormatError)?;
+    let extension = format.extension();
+    let image_id = worksheet.medias.borrow_mut().add_media_buffer(buffer, extension);
+    let mut content_types = worksheet.content_types.borrow_mut();
+    content_types.add_image(extension, format.content_type());
+    let drawings_rel = worksheet.drawings_rel.get_or_insert(Relationships::default());
+    Ok((drawings_rel.add_image(image_id, extension), info))
+}
+
+///
+/// Add the relationship of the hyperlink of the image to the drawing relationships if the options have one.
+///
+fn add_image_hyperlink(worksheet: &mut WorkSheet, options: &ImageOptions) -> Option<u32> {
+    let url = options.hyperlink.as_ref()?;
+    let drawings_rel = worksheet.drawings_rel.get_or_insert(Relationships::default());
+    Some(drawings_rel.add_hyperlink(url))
+}
+
+// Excel works at 96 dpi, a pixel is 9525 EMUs
+fn pixels_to_emu(pixels: f64) -> u32 {
+    (pixels * 9525.0).round() as u32
+}
+
+///
+/// The size of the image in EMUs, scaled by the options and its resolution.
+///
+fn image_extent(info: &ImageInfo, options: &ImageOptions) -> (u32, u32) {
+    let width = info.width as f64 * options.x_scale * 96.0 / info.x_dpi;
+    let height = info.height as f64 * options.y_scale * 96.0 / info.y_dpi;
+    (pixels_to_emu(width), pixels_to_emu(height))
 }
 
 fn get_extension<P: AsRef<Path>>(image_path: &P) -> WorkSheetResult<&str> {
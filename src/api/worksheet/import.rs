@@ -0,0 +1,475 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::rc::Rc;
+use zip::ZipArchive;
+use crate::api::cell::values::CellType;
+use crate::api::workbook::Workbook as ApiWorkbook;
+use crate::api::worksheet::copy::_Copy;
+use crate::api::worksheet::WorkSheet;
+use crate::utils::col_helper::to_sheet_ref;
+use crate::xml::content_types::ContentTypes;
+use crate::xml::extension::{AddExtension, ExtensionType};
+use crate::xml::medias::Medias;
+use crate::xml::shared_string::SharedString;
+use crate::xml::style::StyleSheet;
+
+///
+/// Copies the parts a worksheet refers to from the package of another workbook,
+/// each part gets a name free in the package of this workbook, its content type, and its own relationships.
+///
+pub(crate) struct PartImporter {
+    archive: Option<ZipArchive<File>>,
+    source_content_types: Rc<RefCell<ContentTypes>>,
+    source_medias: Rc<RefCell<Medias>>,
+    content_types: Rc<RefCell<ContentTypes>>,
+    medias: Rc<RefCell<Medias>>,
+    source_style_sheet: Rc<RefCell<StyleSheet>>,
+    style_sheet: Rc<RefCell<StyleSheet>>,
+    // The new ids of the dxfs already imported, by their ids in the other workbook
+    dxf_ids: HashMap<u32, Option<u32>>,
+    source_shared_string: Rc<RefCell<SharedString>>,
+    shared_string: Rc<RefCell<SharedString>>,
+    // The new indexes of the shared strings already imported, by their indexes in the other workbook
+    string_ids: HashMap<usize, Option<usize>>,
+    // The names of the parts in the package of this workbook
+    taken: HashSet<String>,
+    // The new names of the parts already imported, by their names in the other package
+    imported: HashMap<String, Option<String>>,
+    table_ids: HashSet<u32>,
+    table_names: HashSet<String>,
+    // The reference to the worksheet by its name in the other workbook, and by its new name
+    sheet_refs: (String, String),
+    pub(crate) parts: Vec<(String, Vec<u8>)>,
+}
+
+impl PartImporter {
+    pub(crate) fn new(source: &ApiWorkbook, workbook: &ApiWorkbook) -> PartImporter {
+        let mut importer = PartImporter {
+            archive: File::open(&source.file_path).ok().and_then(|file| ZipArchive::new(file).ok()),
+            source_content_types: Rc::clone(&source.content_types),
+            source_medias: Rc::clone(&source.medias),
+            content_types: Rc::clone(&workbook.content_types),
+            medias: Rc::clone(&workbook.medias),
+            source_style_sheet: Rc::clone(&source.style_sheet),
+            style_sheet: Rc::clone(&workbook.style_sheet),
+            dxf_ids: HashMap::new(),
+            source_shared_string: Rc::clone(&source.shared_string),
+            shared_string: Rc::clone(&workbook.shared_string),
+            string_ids: HashMap::new(),
+            taken: HashSet::new(),
+            imported: HashMap::new(),
+            table_ids: HashSet::new(),
+            table_names: HashSet::new(),
+            sheet_refs: Default::default(),
+            parts: vec![],
+        };
+        // The tables already in this workbook, saved or imported
+        let mut tables = vec![];
+        if let Some(mut archive) = File::open(&workbook.file_path).ok().and_then(|file| ZipArchive::new(file).ok()) {
+            for i in 0..archive.len() {
+                let Ok(mut file) = archive.by_index(i) else { continue };
+                importer.taken.insert(file.name().to_string());
+                let mut xml = String::new();
+                if file.name().starts_with("xl/tables/") && file.read_to_string(&mut xml).is_ok() {
+                    tables.push(xml);
+                }
+            }
+        }
+        for (name, buffer) in workbook.sheets.iter().flat_map(|sheet| sheet.parts.iter()) {
+            importer.taken.insert(name.clone());
+            if name.starts_with("xl/tables/") {
+                tables.push(String::from_utf8_lossy(buffer).to_string());
+            }
+        }
+        for table in &tables {
+            let Some(tag) = start_tag(table, "table") else { continue };
+            if let Some(id) = get_attribute(tag, "id").and_then(|id| id.parse().ok()) {
+                importer.table_ids.insert(id);
+            }
+            if let Some(name) = get_attribute(tag, "name") {
+                importer.table_names.insert(name.to_lowercase());
+            }
+            if let Some(name) = get_attribute(tag, "displayName") {
+                importer.table_names.insert(name.to_lowercase());
+            }
+        }
+        importer
+    }
+
+    ///
+    /// The id in this workbook of a dxf of the other workbook, the dxf is added once.
+    ///
+    pub(crate) fn import_dxf(&mut self, dxf_id: u32) -> Option<u32> {
+        if let Some(imported) = self.dxf_ids.get(&dxf_id) {
+            return *imported;
+        }
+        let imported = self.style_sheet.borrow_mut().import_dxf(&self.source_style_sheet.borrow(), dxf_id);
+        self.dxf_ids.insert(dxf_id, imported);
+        imported
+    }
+
+    ///
+    /// The index in the table of this workbook of a shared string of the other workbook, the string is added once.
+    ///
+    pub(crate) fn import_string(&mut self, id: usize) -> Option<usize> {
+        if let Some(imported) = self.string_ids.get(&id) {
+            return *imported;
+        }
+        let imported = self.shared_string.borrow_mut().import_string(&self.source_shared_string.borrow(), id);
+        self.string_ids.insert(id, imported);
+        imported
+    }
+
+    ///
+    /// Import the part a relationship of `part` points to and return the target pointing to the imported part.
+    ///
+    pub(crate) fn import_target(&mut self, part: &str, target: &str) -> Option<String> {
+        let dir = parent(part);
+        let imported = self.import_part(&resolve(dir, target))?;
+        Some(relative(dir, &imported))
+    }
+
+    ///
+    /// Import the part and the parts it refers to, and return its new name.
+    ///
+    fn import_part(&mut self, part: &str) -> Option<String> {
+        if let Some(imported) = self.imported.get(part) {
+            return imported.clone();
+        }
+        let imported = match part.strip_prefix("xl/media/") {
+            Some(name) => self.import_media(name),
+            None => self.import_raw_part(part),
+        };
+        self.imported.insert(part.to_string(), imported.clone());
+        imported
+    }
+
+    fn import_media(&mut self, name: &str) -> Option<String> {
+        let buffer = self.source_medias.borrow().read_media(name)?;
+        let extension = Path::new(name).extension()?.to_str()?;
+        let id = self.medias.borrow_mut().add_media_buffer(&buffer, extension);
+        let content_type = self.source_content_types.borrow().get_part_content_type(&format!("xl/media/{name}"))
+            .unwrap_or(format!("image/{extension}"));
+        self.content_types.borrow_mut().add_image(extension, &content_type);
+        Some(format!("xl/media/image{id}.{extension}"))
+    }
+
+    fn import_raw_part(&mut self, part: &str) -> Option<String> {
+        let mut buffer = self.read(part)?;
+        let name = self.free_name(part);
+        // Registered before its relationships are followed, in case they point back to it
+        self.imported.insert(part.to_string(), Some(name.clone()));
+        if let Some(content_type) = self.source_content_types.borrow().get_part_content_type(part) {
+            self.content_types.borrow_mut().add_part(&name, &content_type);
+        }
+        if name.starts_with("xl/tables/") {
+            buffer = self.renumber_table(&String::from_utf8_lossy(&buffer)).into_bytes();
+        } else if name.starts_with("xl/charts/chart") {
+            // The charts which plot the data of the worksheet itself
+            let (old_ref, new_ref) = &self.sheet_refs;
+            buffer = String::from_utf8_lossy(&buffer).replace(&format!(">{old_ref}"), &format!(">{new_ref}")).into_bytes();
+        }
+        self.parts.push((name.clone(), buffer));
+        self.import_rels(part, &name);
+        Some(name)
+    }
+
+    ///
+    /// Copy the relationships of the part of the other package as the relationships of `name`,
+    /// the parts they point to are imported too.
+    ///
+    pub(crate) fn import_rels(&mut self, part: &str, name: &str) {
+        let Some(buffer) = self.read(&rels_name(part)) else { return };
+        let xml = String::from_utf8_lossy(&buffer).to_string();
+        let mut rels = String::with_capacity(xml.len());
+        let mut rest = xml.as_str();
+        while let Some(start) = rest.find("<Relationship ") {
+            let end = start + rest[start..].find('>').unwrap_or(rest.len() - start - 1) + 1;
+            let relationship = &rest[start..end];
+            rels.push_str(&rest[..start]);
+            match get_attribute(relationship, "Target") {
+                Some(target) if get_attribute(relationship, "TargetMode") != Some("External") => {
+                    let target = target.to_string();
+                    match self.import_target(part, &target) {
+                        Some(imported) => rels.push_str(&set_attribute(relationship, "Target", &imported)),
+                        None => rels.push_str(relationship),
+                    }
+                }
+                _ => rels.push_str(relationship),
+            }
+            rest = &rest[end..];
+        }
+        rels.push_str(rest);
+        self.parts.push((rels_name(name), rels.into_bytes()));
+    }
+
+    fn read(&mut self, part: &str) -> Option<Vec<u8>> {
+        let mut file = self.archive.as_mut()?.by_name(part).ok()?;
+        let mut buffer = vec![];
+        file.read_to_end(&mut buffer).ok()?;
+        Some(buffer)
+    }
+
+    ///
+    /// The name of the part with the smallest number that is not used yet, `xl/tables/table3.xml` may become `xl/tables/table5.xml`.
+    ///
+    fn free_name(&mut self, part: &str) -> String {
+        let (stem, extension) = match part.rfind('.').filter(|&dot| dot > part.rfind('/').unwrap_or(0)) {
+            Some(dot) => part.split_at(dot),
+            None => (part, ""),
+        };
+        let stem = stem.trim_end_matches(|c: char| c.is_ascii_digit());
+        let content_types = self.content_types.borrow();
+        let name = (1..)
+            .map(|n| format!("{stem}{n}{extension}"))
+            .find(|name| !self.taken.contains(name) && !content_types.has_part(name))
+            .unwrap();
+        self.taken.insert(name.clone());
+        name
+    }
+
+    ///
+    /// Give the table an id and a name not used by the tables of this workbook, and refer to the imported dxfs.
+    ///
+    fn renumber_table(&mut self, xml: &str) -> String {
+        let xml = &self.import_dxf_ids(xml);
+        let Some(tag) = start_tag(xml, "table") else { return xml.to_string() };
+        let id = (1..).find(|id| !self.table_ids.contains(id)).unwrap();
+        self.table_ids.insert(id);
+        let mut new_tag = set_attribute(tag, "id", &id.to_string());
+        let name = get_attribute(tag, "displayName").or(get_attribute(tag, "name")).unwrap_or("Table").to_string();
+        if self.table_names.contains(&name.to_lowercase()) {
+            let stem = name.trim_end_matches(|c: char| c.is_ascii_digit());
+            let name = (1..)
+                .map(|n| format!("{stem}{n}"))
+                .find(|name| !self.table_names.contains(&name.to_lowercase()))
+                .unwrap();
+            new_tag = set_attribute(&new_tag, "name", &name);
+            new_tag = set_attribute(&new_tag, "displayName", &name);
+            self.table_names.insert(name.to_lowercase());
+        } else {
+            self.table_names.insert(name.to_lowercase());
+        }
+        xml.replacen(tag, &new_tag, 1)
+    }
+
+    ///
+    /// Replace the values of the attributes like `dataDxfId` with the ids of the imported dxfs.
+    ///
+    fn import_dxf_ids(&mut self, xml: &str) -> String {
+        let mut result = String::with_capacity(xml.len());
+        let mut rest = xml;
+        while let Some(start) = rest.find("DxfId=\"") {
+            let start = start + "DxfId=\"".len();
+            let end = start + rest[start..].find('"').unwrap_or(0);
+            result.push_str(&rest[..start]);
+            match rest[start..end].parse().ok().and_then(|dxf_id| self.import_dxf(dxf_id)) {
+                Some(dxf_id) => result.push_str(&dxf_id.to_string()),
+                None => result.push_str(&rest[start..end]),
+            }
+            rest = &rest[end..];
+        }
+        result.push_str(rest);
+        result
+    }
+}
+
+impl WorkSheet {
+    ///
+    /// A copy of a worksheet of another workbook, whose styles, shared strings, medias and parts are added to this workbook.
+    ///
+    pub(crate) fn from_other_workbook(
+        sheet_id: u32,
+        name: &str,
+        target_id: u32,
+        worksheet: &WorkSheet,
+        workbook: &ApiWorkbook,
+        importer: &mut PartImporter,
+    ) -> WorkSheet {
+        let mut imported = WorkSheet::add_worksheet(sheet_id, name, target_id, workbook);
+        importer.sheet_refs = (to_sheet_ref(&worksheet.name, ""), to_sheet_ref(name, ""));
+        imported.worksheet = worksheet.worksheet.clone();
+        imported.worksheet.rename_sheet(&worksheet.name, name);
+        imported.worksheet_rel = worksheet.worksheet_rel.clone();
+        imported.vml_drawing = worksheet.vml_drawing.clone();
+        imported.comments = worksheet.comments.clone();
+        imported.ctrl_props = worksheet.ctrl_props.clone();
+        imported.drawings = worksheet.drawings.clone();
+        imported.drawings_rel = worksheet.drawings_rel.clone();
+        imported.other_drawings = worksheet.other_drawings.clone();
+        // The shared strings of the other workbook are added to the table of this workbook
+        let (mut cell_metadata, mut shared_strings) = (false, false);
+        imported.worksheet.sheet_data.for_each_cell_mut((1, 1, 1048576, 16384), false, |cell| {
+            if let Some(CellType::SharedString) = cell.cell_type {
+                match cell.text.as_ref().and_then(|text| text.parse().ok()).and_then(|id| importer.import_string(id)) {
+                    Some(id) => {
+                        cell.text = Some(id.to_string());
+                        shared_strings = true;
+                    }
+                    None => cell.resolve_shared_string(&worksheet.shared_string.borrow()),
+                }
+            }
+            cell_metadata |= cell.cell_meta_index.is_some();
+        });
+        if shared_strings {
+            imported.workbook_rel.borrow_mut().get_or_add_shared_strings();
+            imported.content_types.borrow_mut().add_shared_strings();
+        }
+        if cell_metadata {
+            imported.metadata.borrow_mut().add_extension(ExtensionType::XdaDynamicArrayProperties);
+            imported.workbook_rel.borrow_mut().get_or_add_metadata();
+            imported.content_types.borrow_mut().add_metadata();
+        }
+        imported.import_styles(worksheet, importer);
+        imported.import_parts(worksheet, importer);
+        imported.deselect();
+        imported
+    }
+
+    ///
+    /// Add the styles and the dxfs of the other workbook the worksheet uses to this workbook, and refer to them.
+    ///
+    fn import_styles(&mut self, worksheet: &WorkSheet, importer: &mut PartImporter) {
+        let mut style_ids = HashSet::new();
+        self.for_each_style_mut(|style| { style_ids.insert(*style); });
+        let mut styles = HashMap::new();
+        for style in style_ids {
+            let imported = self.import_style(&worksheet.style_sheet, style, &mut styles);
+            styles.insert(style, imported);
+        }
+        self.for_each_style_mut(|style| *style = styles[style]);
+        self.worksheet.for_each_dxf_id_mut(|dxf_id| {
+            if let Some(imported) = importer.import_dxf(*dxf_id) {
+                *dxf_id = imported;
+            }
+        });
+    }
+
+    ///
+    /// Import the parts the worksheet and its drawings refer to, the drawings, the vml drawing
+    /// and the properties of the added form controls are given new ids like a duplicated worksheet.
+    ///
+    fn import_parts(&mut self, worksheet: &WorkSheet, importer: &mut PartImporter) {
+        let part = format!("xl/{}", worksheet.target);
+        let main_r_id = self.drawings_r_id().filter(|_| self.drawings.is_some());
+        let vml_r_id = self.worksheet.get_legacy_drawing_r_id().filter(|_| self.vml_drawing.is_some());
+        let modelled: Vec<u32> = main_r_id.into_iter()
+            .chain(self.other_drawings.iter().map(|(r_id, _, _)| *r_id))
+            .chain(vml_r_id)
+            .chain(self.ctrl_props.iter().map(|(r_id, _)| *r_id))
+            .collect();
+        for (r_id, target) in self.worksheet_rel.get_internal_targets() {
+            if modelled.contains(&r_id) {
+                continue;
+            }
+            if let Some(imported) = importer.import_target(&part, &target) {
+                self.worksheet_rel.set_target(r_id, &imported);
+            }
+        }
+        let main_drawings_rel = main_r_id.zip(self.drawings_rel.as_mut());
+        let other_drawings_rels = self.other_drawings.iter_mut()
+            .filter_map(|(r_id, _, drawings_rel)| Some((*r_id, drawings_rel.as_mut()?)));
+        for (r_id, drawings_rel) in main_drawings_rel.into_iter().chain(other_drawings_rels) {
+            let Some(id) = worksheet.worksheet_rel.get_drawing_id(r_id) else { continue };
+            let drawing_part = format!("xl/drawings/drawing{id}.xml");
+            for (r_id, target) in drawings_rel.get_internal_targets() {
+                if let Some(imported) = importer.import_target(&drawing_part, &target) {
+                    drawings_rel.set_target(r_id, &imported);
+                }
+            }
+        }
+        self.renumber_drawings();
+        self.renumber_controls();
+        if let Some(r_id) = vml_r_id {
+            let source_id = worksheet.worksheet_rel.get_vml_drawing_id(r_id);
+            let id = self.worksheet_rel.get_vml_drawing_id(r_id);
+            if let (Some(source_id), Some(id)) = (source_id, id) {
+                importer.import_rels(&format!("xl/drawings/vmlDrawing{source_id}.vml"), &format!("xl/drawings/vmlDrawing{id}.vml"));
+            }
+        }
+        self.parts.append(&mut importer.parts);
+    }
+}
+
+///
+/// The start tag of the first element named `name`.
+///
+fn start_tag<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    let start = xml.find(&format!("<{name} "))?;
+    let end = start + xml[start..].find('>')?;
+    Some(&xml[start..=end])
+}
+
+fn get_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!(" {name}=\""))? + name.len() + 3;
+    let end = start + tag[start..].find('"')?;
+    Some(&tag[start..end])
+}
+
+fn set_attribute(tag: &str, name: &str, value: &str) -> String {
+    match tag.find(&format!(" {name}=\"")) {
+        Some(start) => {
+            let start = start + name.len() + 3;
+            let end = start + tag[start..].find('"').unwrap_or(0);
+            format!("{}{value}{}", &tag[..start], &tag[end..])
+        }
+        None => tag.to_string(),
+    }
+}
+
+fn parent(part: &str) -> &str {
+    part.rfind('/').map(|slash| &part[..slash]).unwrap_or("")
+}
+
+///
+/// The relationships part of a part, `xl/charts/_rels/chart1.xml.rels` for `xl/charts/chart1.xml`.
+///
+fn rels_name(part: &str) -> String {
+    let dir = parent(part);
+    let file_name = part.strip_prefix(dir).unwrap_or(part).trim_start_matches('/');
+    match dir.is_empty() {
+        true => format!("_rels/{file_name}.rels"),
+        false => format!("{dir}/_rels/{file_name}.rels"),
+    }
+}
+
+///
+/// The name of the part a target relative to the directory points to.
+///
+pub(crate) fn resolve(dir: &str, target: &str) -> String {
+    if let Some(target) = target.strip_prefix('/') {
+        return target.to_string();
+    }
+    let mut segments: Vec<&str> = dir.split('/').filter(|segment| !segment.is_empty()).collect();
+    for segment in target.split('/') {
+        match segment {
+            ".." => { segments.pop(); }
+            "." | "" => {}
+            segment => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
+///
+/// The target relative to the directory which points to the part.
+///
+fn relative(dir: &str, part: &str) -> String {
+    let dir: Vec<&str> = dir.split('/').filter(|segment| !segment.is_empty()).collect();
+    let segments: Vec<&str> = part.split('/').collect();
+    let common = dir.iter().zip(&segments[..segments.len() - 1]).take_while(|(a, b)| a == b).count();
+    format!("{}{}", "../".repeat(dir.len() - common), segments[common..].join("/"))
+}
+
+#[test]
+fn test_relative_target() {
+    assert_eq!(resolve("xl/worksheets", "../tables/table1.xml"), "xl/tables/table1.xml");
+    assert_eq!(resolve("xl/worksheets", "/xl/comments1.xml"), "xl/comments1.xml");
+    assert_eq!(relative("xl/worksheets", "xl/tables/table2.xml"), "../tables/table2.xml");
+    assert_eq!(relative("xl/worksheets", "xl/comments2.xml"), "../comments2.xml");
+    assert_eq!(relative("xl/charts", "xl/charts/style2.xml"), "style2.xml");
+    assert_eq!(rels_name("xl/charts/chart1.xml"), "xl/charts/_rels/chart1.xml.rels");
+}
@@ -0,0 +1,43 @@
+use crate::api::cell::values::{CellDisplay, CellValue};
+use crate::api::range::SeriesType;
+use crate::api::worksheet::format::_Format;
+use crate::api::worksheet::WorkSheet;
+use crate::Format;
+
+pub(crate) trait _Range {
+    fn set_range_format(&mut self, range: (u32, u32, u32, u32), format: &Format);
+    fn clear_range_contents(&mut self, range: (u32, u32, u32, u32));
+    fn clear_range_formats(&mut self, range: (u32, u32, u32, u32));
+    fn fill_range_value<T: CellDisplay + CellValue>(&mut self, range: (u32, u32, u32, u32), value: &T);
+    fn fill_range_series(&mut self, range: (u32, u32, u32, u32), start: f64, step: f64, series_type: SeriesType);
+}
+
+impl _Range for WorkSheet {
+    fn set_range_format(&mut self, range: (u32, u32, u32, u32), format: &Format) {
+        let style = self.add_format(format);
+        self.worksheet.sheet_data.for_each_cell_mut(range, true, |cell| cell.style = Some(style));
+    }
+
+    fn clear_range_contents(&mut self, range: (u32, u32, u32, u32)) {
+        self.worksheet.sheet_data.for_each_cell_mut(range, false, |cell| cell.clear(None));
+    }
+
+    fn clear_range_formats(&mut self, range: (u32, u32, u32, u32)) {
+        self.worksheet.sheet_data.for_each_cell_mut(range, false, |cell| cell.style = None);
+    }
+
+    fn fill_range_value<T: CellDisplay + CellValue>(&mut self, range: (u32, u32, u32, u32), value: &T) {
+        self.worksheet.sheet_data.for_each_cell_mut(range, true, |cell| cell.update_by_display(value, None));
+    }
+
+    fn fill_range_series(&mut self, range: (u32, u32, u32, u32), start: f64, step: f64, series_type: SeriesType) {
+        let (first_row, first_col, last_row, _) = range;
+        self.worksheet.sheet_data.for_each_cell_mut(range, true, |cell| {
+            let index = match first_row == last_row {
+                true => cell.loc.col - first_col,
+                false => cell.loc.row - first_row,
+            };
+            cell.update_by_display(&series_type.nth(start, step, index), None);
+        });
+    }
+}
@@ -2,6 +2,7 @@ use crate::api::cell::location::Location;
 use crate::{Cell, Format, WorkSheet, WorkSheetResult};
 use crate::api::cell::values::{CellDisplay, CellType, CellValue};
 use crate::api::worksheet::format::_Format;
+use crate::api::worksheet::hyperlink::_Hyperlink;
 
 pub trait Read: _Read {
     fn read_cell<L: Location>(&self, loc: L) -> WorkSheetResult<Cell<String>> {
@@ -32,10 +33,10 @@ impl _Read for WorkSheet {
             let id: usize = if let Some(s) = &cell.text {
                 s.parse().unwrap_or_default()
             } else { 0 };
-            if let Some(rich_text) = self.shared_string.get_rich_text(id) {
+            if let Some(rich_text) = self.shared_string.borrow().get_rich_text(id) {
                 cell.cell_type = Some(CellType::InlineString);
                 cell.rich_text = Some(rich_text);
-            } else if let Some(text) = self.shared_string.get_text(id) {
+            } else if let Some(text) = self.shared_string.borrow().get_text(id) {
                 cell.cell_type = Some(CellType::String);
                 cell.text = Some(text.to_string());
             } else {
@@ -43,7 +44,7 @@ impl _Read for WorkSheet {
                 cell.text = Some(String::new());
             }
         };
-        cell.hyperlink = self.worksheet.get_hyperlink(loc);
+        cell.hyperlink = self.get_hyperlink(loc);
         Ok(cell)
     }
 
@@ -61,7 +62,7 @@ impl _Read for WorkSheet {
     //     let text = match cell_type {
     //         Some(CellType::SharedString) => {
     //             let id: usize = value.unwrap_or("0").parse().unwrap();
-    //             self.shared_string.get_text(id)
+    //             self.shared_string.borrow().get_text(id)
     //         },
     //         _ => value
     //     };
@@ -0,0 +1,162 @@
+use std::cmp::Ordering;
+use crate::api::cell::values::CellType;
+use crate::api::sort::{SortBy, SortKey, SortOrder};
+use crate::api::worksheet::format::_Format;
+use crate::api::worksheet::WorkSheet;
+use crate::result::{WorkSheetError, WorkSheetResult};
+use crate::utils::range_util;
+use crate::xml::worksheet::sheet_data::cell::Cell;
+use crate::xml::worksheet::sort_state::SortState;
+
+///
+/// The value of a cell as Excel sorts it, the variants are in ascending order.
+///
+#[derive(Debug, PartialEq, PartialOrd)]
+pub(crate) enum SortValue {
+    Number(f64),
+    // Texts are compared without case
+    Text(String),
+    Boolean(bool),
+    Error,
+    Blank,
+}
+
+///
+/// What a key compares in the cell of a row.
+///
+struct SortItem {
+    value: SortValue,
+    // The position of the text in the custom list of the key
+    rank: Option<usize>,
+    // Whether the cell has the color of the key
+    colored: bool,
+}
+
+impl SortItem {
+    fn cmp(&self, other: &SortItem, key: &SortKey) -> Ordering {
+        let descending = key.order == SortOrder::Descending;
+        match (&key.by, self.rank, other.rank) {
+            (SortBy::CellColor(_) | SortBy::FontColor(_), _, _) => match descending {
+                false => other.colored.cmp(&self.colored),
+                true => self.colored.cmp(&other.colored),
+            },
+            (SortBy::CustomList(_), Some(rank), Some(other_rank)) => match descending {
+                false => rank.cmp(&other_rank),
+                true => other_rank.cmp(&rank),
+            },
+            (SortBy::CustomList(_), Some(_), None) => Ordering::Less,
+            (SortBy::CustomList(_), None, Some(_)) => Ordering::Greater,
+            _ => match (&self.value, &other.value) {
+                (SortValue::Blank, SortValue::Blank) => Ordering::Equal,
+                (SortValue::Blank, _) => Ordering::Greater,
+                (_, SortValue::Blank) => Ordering::Less,
+                (value, other_value) if descending => other_value.partial_cmp(value).unwrap_or(Ordering::Equal),
+                (value, other_value) => value.partial_cmp(other_value).unwrap_or(Ordering::Equal),
+            },
+        }
+    }
+}
+
+pub(crate) trait _Sort {
+    fn sort_rows(&mut self, range: (u32, u32, u32, u32), keys: &[SortKey], has_header: bool) -> WorkSheetResult<()>;
+    fn sort_value(&self, cell: &Cell) -> SortValue;
+}
+
+impl _Sort for WorkSheet {
+    ///
+    /// Reorder the rows of the range by the keys, the rows which compare equal keep their order.
+    /// The cells are moved with their formats, and the relative references of their formulas are re-based.
+    ///
+    fn sort_rows(&mut self, range: (u32, u32, u32, u32), keys: &[SortKey], has_header: bool) -> WorkSheetResult<()> {
+        let (first_row, first_col, last_row, last_col) = range;
+        if keys.iter().any(|key| !(first_col..=last_col).contains(&key.col)) {
+            return Err(WorkSheetError::SortKeyOutOfRange);
+        }
+        let first_row = first_row + has_header as u32;
+        if first_row > last_row {
+            return Ok(());
+        }
+        let data_range = (first_row, first_col, last_row, last_col);
+        if self.worksheet.get_merged_ranges().into_iter().any(|merged_range| range_util::intersects(data_range, merged_range)) {
+            return Err(WorkSheetError::SortMergedCells);
+        }
+
+        let sheet_data = &mut self.worksheet.sheet_data;
+        sheet_data.unshare_formulas(data_range);
+        let shared_formulas = sheet_data.get_shared_formulas();
+        let mut rows: Vec<Vec<Cell>> = vec![vec![]; (last_row - first_row + 1) as usize];
+        for cell in sheet_data.get_cells(data_range) {
+            rows[(cell.loc.row - first_row) as usize].push(cell);
+        }
+        let items: Vec<Vec<SortItem>> = rows.iter()
+            .map(|cells| keys.iter()
+                .map(|key| sort_item(self, cells.iter().find(|cell| cell.loc.col == key.col), key))
+                .collect())
+            .collect();
+        let mut order: Vec<usize> = (0..rows.len()).collect();
+        order.sort_by(|&a, &b| keys.iter().enumerate()
+            .map(|(i, key)| items[a][i].cmp(&items[b][i], key))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal));
+
+        let mut cells = vec![];
+        for (index, &from) in order.iter().enumerate() {
+            let row = first_row + index as u32;
+            cells.extend(rows[from].iter().map(|cell| cell.copy_to(row, cell.loc.col, &shared_formulas)));
+        }
+        self.worksheet.sheet_data.remove_cells(data_range);
+        self.worksheet.sheet_data.put_cells(cells);
+
+        let mut sort_state = SortState::new(&range_util::to_range_ref(data_range));
+        for key in keys {
+            let dxf_id = match &key.by {
+                SortBy::CellColor(color) => Some(self.style_sheet.borrow_mut().add_color_dxf(color, false)),
+                SortBy::FontColor(color) => Some(self.style_sheet.borrow_mut().add_color_dxf(color, true)),
+                _ => None,
+            };
+            sort_state.add_sort_condition(&range_util::to_range_ref((first_row, key.col, last_row, key.col)), key, dxf_id);
+        }
+        self.worksheet.set_sort_state(range, sort_state);
+        Ok(())
+    }
+
+    fn sort_value(&self, cell: &Cell) -> SortValue {
+        let mut cell = cell.clone();
+        cell.resolve_shared_string(&self.shared_string.borrow());
+        let api_cell = cell.to_api_cell();
+        let text = match (api_cell.rich_text, api_cell.text) {
+            (Some(rich_text), _) => rich_text.words.iter().map(|word| word.text.as_str()).collect(),
+            (None, Some(text)) => text,
+            (None, None) => return SortValue::Blank,
+        };
+        if text.is_empty() {
+            return SortValue::Blank;
+        }
+        match cell.cell_type {
+            Some(CellType::Boolean) => SortValue::Boolean(text == "1" || text.eq_ignore_ascii_case("true")),
+            Some(CellType::Error) => SortValue::Error,
+            Some(CellType::Number) | Some(CellType::Undefined) | None => match text.parse() {
+                Ok(number) => SortValue::Number(number),
+                Err(_) => SortValue::Text(text.to_lowercase()),
+            },
+            _ => SortValue::Text(text.to_lowercase()),
+        }
+    }
+}
+
+///
+/// The cell of a row as the key compares it.
+///
+fn sort_item(worksheet: &WorkSheet, cell: Option<&Cell>, key: &SortKey) -> SortItem {
+    let value = cell.map_or(SortValue::Blank, |cell| worksheet.sort_value(cell));
+    let rank = match (&key.by, &value) {
+        (SortBy::CustomList(list), SortValue::Text(text)) => list.iter().position(|item| item.to_lowercase() == *text),
+        _ => None,
+    };
+    let colored = match &key.by {
+        SortBy::CellColor(color) => worksheet.get_format(cell.and_then(|cell| cell.style).unwrap_or_default()).fill.fg_color == *color,
+        SortBy::FontColor(color) => worksheet.get_format(cell.and_then(|cell| cell.style).unwrap_or_default()).font.color == *color,
+        _ => false,
+    };
+    SortItem { value, rank, colored }
+}
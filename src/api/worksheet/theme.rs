@@ -4,7 +4,9 @@ use crate::api::theme::Theme;
 impl WorkSheet {
     pub fn get_theme(&self, theme_id: u32) -> Theme {
         let binding = self.themes.borrow();
-        let theme = binding.themes.get(theme_id as usize).unwrap();
-        theme.to_api_theme()
+        match binding.themes.get(theme_id as usize) {
+            Some(theme) => theme.to_api_theme(),
+            None => Theme::default(),
+        }
     }
 }
\ No newline at end of file
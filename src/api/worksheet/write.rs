@@ -8,7 +8,7 @@ use crate::api::worksheet::format::_Format;
 use crate::api::worksheet::hyperlink::_Hyperlink;
 use crate::Format;
 use crate::api::worksheet::WorkSheet;
-use crate::result::WorkSheetResult;
+use crate::result::{WorkSheetError, WorkSheetResult};
 use crate::xml::extension::{AddExtension, ExtensionType};
 
 pub trait Write: _Write {
@@ -98,6 +98,40 @@ pub trait Write: _Write {
         self.write_by_api_cell(&loc, &cell)
     }
 
+    ///
+    /// Write a link to a place in the workbook, such as `"Sheet2!A1"` or a defined name.
+    ///
+    fn write_internal_link<L: Location>(&mut self, loc: L, location: &str, data: &str) -> WorkSheetResult<()> {
+        let mut cell = Cell::default();
+        cell.text = Some(data);
+        cell.hyperlink = Some(format!("#{location}"));
+        self.write_by_api_cell(&loc, &cell)
+    }
+
+    ///
+    /// Write a link which writes a mail to the address.
+    ///
+    fn write_mailto<L: Location>(&mut self, loc: L, address: &str, data: &str) -> WorkSheetResult<()> {
+        let mut cell = Cell::default();
+        cell.text = Some(data);
+        cell.hyperlink = Some(format!("mailto:{address}"));
+        self.write_by_api_cell(&loc, &cell)
+    }
+
+    ///
+    /// Write a link to a file, a relative path is resolved from the folder of the workbook.
+    ///
+    fn write_file_link<L: Location>(&mut self, loc: L, path: &str, data: &str) -> WorkSheetResult<()> {
+        let mut cell = Cell::default();
+        cell.text = Some(data);
+        cell.hyperlink = Some(to_file_url(path));
+        self.write_by_api_cell(&loc, &cell)
+    }
+
+    ///
+    /// Merge the cells of the range and write the data in its first cell, the other cells are blanked.
+    /// Returns [`WorkSheetError::MergeOverlapped`] if the range overlaps a merged range.
+    ///
     fn merge_range<L: LocationRange, T: CellDisplay + CellValue>(&mut self, loc: L, data: T) -> WorkSheetResult<()> {
         self.merge_range_all(loc, data, None)
     }
@@ -247,8 +281,7 @@ impl _Write for WorkSheet {
             cell.style = Some(style);
         }
         if let Some(url) = &cell.hyperlink {
-            let url_r_id = self.worksheet_rel.add_hyperlink(url);
-            self.worksheet.add_hyperlink(loc, url_r_id);
+            self.set_hyperlink(loc, url);
         }
         if let Some(_) = &cell.formula {
             self.metadata.borrow_mut().add_extension(ExtensionType::XdaDynamicArrayProperties);
@@ -297,12 +330,31 @@ impl _Write for WorkSheet {
     fn merge_range_all<L: LocationRange, T: CellDisplay + CellValue>(&mut self, loc: L, data: T, format: Option<&Format>) -> WorkSheetResult<()> {
         let (first_row, first_col, last_row, last_col) = loc.to_range();
         let worksheet = &mut self.worksheet;
+        if worksheet.overlaps_merged_range((first_row, first_col, last_row, last_col)) {
+            return Err(WorkSheetError::MergeOverlapped);
+        }
         worksheet.add_merge_cell(first_row, first_col, last_row, last_col);
+        self.write_display_all(&(first_row, first_col), &data, format)?;
+        // The other cells only keep the format, so that the borders of the merged cell are drawn
+        let style = format.map(|format| self.add_format(format));
         for row in first_row..=last_row {
             for col in first_col..=last_col {
-                self.write_display_all(&(row, col), &data, format)?;
+                if (row, col) != (first_row, first_col) {
+                    self.worksheet.sheet_data.write_blank(&(row, col), style);
+                }
             }
         }
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+///
+/// An absolute path becomes a `file:///` url, a relative path or a network path is kept as it is.
+///
+fn to_file_url(path: &str) -> String {
+    let is_absolute = path.starts_with('/') || path.get(1..2) == Some(":");
+    match is_absolute {
+        true => format!("file:///{}", path.trim_start_matches('/')),
+        false => path.to_string(),
+    }
+}
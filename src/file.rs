@@ -15,10 +15,13 @@ pub enum XlsxFileType {
     Drawings(u32),
     DrawingRels(u32),
     VmlDrawings(u32),
+    CtrlProps(u32),
     Comments(u32),
+    Theme(u32),
     MetaData,
     CoreProperties,
     AppProperties,
+    Part(String),
 }
 pub struct XlsxFileWriter {
     file_type: XlsxFileType,
@@ -39,12 +42,6 @@ impl XlsxFileWriter {
         })
     }
 
-    pub(crate) fn read_from<P: AsRef<Path>>(base_path: P, file_type: XlsxFileType, content: &mut String) -> io::Result<()> {
-        let file_path = file_type.get_path(&base_path);
-        *content = fs::read_to_string(file_path).unwrap_or_default();
-        Ok(())
-    }
-
     fn mkdir<P: AsRef<Path>>(base_path: P, file_type: &XlsxFileType) -> io::Result<()> {
         let file_path = file_type.get_path(base_path);
         let mut dirs = file_path.clone();
@@ -85,7 +82,10 @@ impl XlsxFileType {
             XlsxFileType::Medias(_) => "./xl/media",
             XlsxFileType::Drawings(_) | XlsxFileType::VmlDrawings(_) => "./xl/drawings",
             XlsxFileType::DrawingRels(_) => "./xl/drawings/_rels",
+            XlsxFileType::CtrlProps(_) => "./xl/ctrlProps",
+            XlsxFileType::Theme(_) => "./xl/theme",
             XlsxFileType::CoreProperties | XlsxFileType::AppProperties => "./docProps",
+            XlsxFileType::Part(_) => ".",
         }
     }
     fn get_filename(&self) -> String {
@@ -102,10 +102,13 @@ impl XlsxFileType {
             XlsxFileType::Drawings(id) => format!("drawing{id}.xml"),
             XlsxFileType::VmlDrawings(id) => format!("vmlDrawing{id}.vml"),
             XlsxFileType::DrawingRels(id) => format!("drawing{id}.xml.rels"),
+            XlsxFileType::CtrlProps(id) => format!("ctrlProp{id}.xml"),
             XlsxFileType::MetaData => "metadata.xml".to_string(),
             XlsxFileType::CoreProperties => "core.xml".to_string(),
             XlsxFileType::AppProperties => "app.xml".to_string(),
             XlsxFileType::Comments(id) => format!("comments{id}.xml"),
+            XlsxFileType::Theme(id) => format!("theme{id}.xml"),
+            XlsxFileType::Part(name) => name.to_string(),
         }
     }
     pub(crate) fn get_path<P: AsRef<Path>>(&self, base_path: P) -> PathBuf {
@@ -13,10 +13,14 @@ pub use api::format::Format;
 pub use api::format::{FormatBorderElement, FormatBorderType};
 pub use api::format::FormatAlignType;
 pub use api::format::FormatAlign;
+pub use api::format::{FormatAlignHorizontal, FormatAlignVertical};
 pub use api::format::FormatBorder;
 pub use api::format::FormatColor;
-pub use api::format::FormatFont;
+pub use api::format::{FormatFont, FormatFontScheme, FormatUnderlineType, FormatVertAlignType};
 pub use api::format::FormatFill;
+pub use api::format::{FillPattern, GradientFill, GradientStop, GradientType};
+pub use api::format::FormatProtection;
+pub use api::format::BuiltinStyle;
 pub use api::worksheet::write::Write;
 pub use api::worksheet::read::Read;
 pub use api::cell::Cell;
@@ -26,6 +30,17 @@ pub use api::worksheet::col::{Column, WorkSheetCol};
 pub use api::properties::Properties;
 pub use api::filter::Filter;
 pub use api::filter::Filters;
+pub use api::filter::DynamicFilterType;
+pub use api::protection::ProtectionOptions;
+pub use api::theme::Theme;
+pub use api::image::{Image, ImageEditAs, ImageFormat, ImageOptions};
+pub use api::shape::{Shape, ShapeType};
+pub use api::sparkline::{SparklineAxis, SparklineEmptyCells, SparklineOptions, SparklineType};
+pub use api::form_control::{FormControl, FormControlType};
+pub use api::hyperlink::Hyperlink;
+pub use api::range::{Range, SeriesType};
+pub use api::sort::{SortBy, SortKey, SortOrder};
+pub use api::find::{FindOptions, FoundCell};
 
 pub use result::CellError;
 pub use result::ColError;
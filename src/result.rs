@@ -85,6 +85,14 @@ pub enum WorkSheetError {
     ColError(ColError),
     DuplicatedSheets,
     FormatError,
+    StyleNotFound,
+    ImageNotFound,
+    HyperlinkNotFound,
+    MergeOverlapped,
+    OutOfRange,
+    SortKeyOutOfRange,
+    SortMergedCells,
+    SheetMismatch,
 }
 
 
@@ -99,6 +107,14 @@ impl fmt::Display for WorkSheetError {
             WorkSheetError::ColError(ref err) => write!(f, "Column Error: {:?}", err),
             WorkSheetError::DuplicatedSheets => write!(f, "Duplicated Sheets"),
             WorkSheetError::FormatError => write!(f, "Format Error"),
+            WorkSheetError::StyleNotFound => write!(f, "Style not found"),
+            WorkSheetError::ImageNotFound => write!(f, "Image not found"),
+            WorkSheetError::HyperlinkNotFound => write!(f, "Hyperlink not found"),
+            WorkSheetError::MergeOverlapped => write!(f, "Merged range overlaps an existing one"),
+            WorkSheetError::OutOfRange => write!(f, "Range out of the worksheet"),
+            WorkSheetError::SortKeyOutOfRange => write!(f, "Sort key column out of the sorted range"),
+            WorkSheetError::SortMergedCells => write!(f, "Sorted range contains merged cells"),
+            WorkSheetError::SheetMismatch => write!(f, "Range of another worksheet"),
         }
     }
 }
@@ -114,6 +130,14 @@ impl error::Error for WorkSheetError {
             WorkSheetError::ColError(ref err) => Some(err),
             WorkSheetError::DuplicatedSheets => None,
             WorkSheetError::FormatError => None,
+            WorkSheetError::StyleNotFound => None,
+            WorkSheetError::ImageNotFound => None,
+            WorkSheetError::HyperlinkNotFound => None,
+            WorkSheetError::MergeOverlapped => None,
+            WorkSheetError::OutOfRange => None,
+            WorkSheetError::SortKeyOutOfRange => None,
+            WorkSheetError::SortMergedCells => None,
+            WorkSheetError::SheetMismatch => None,
         }
     }
 }
@@ -133,6 +157,7 @@ pub enum WorkbookError {
     SheetError(WorkSheetError),
     FileNotFound,
     RelationshipError(RelationshipError),
+    PatternError(regex::Error),
 }
 
 
@@ -145,6 +170,7 @@ impl fmt::Display for WorkbookError {
             WorkbookError::SheetError(ref err) => write!(f, "Worksheet Error: {:?}", err),
             WorkbookError::FileNotFound => write!(f, "File not found"),
             WorkbookError::RelationshipError(ref err) => write!(f, "Relationship Error: {:?}", err),
+            WorkbookError::PatternError(ref err) => write!(f, "Pattern Error: {}", err),
         }
     }
 }
@@ -157,6 +183,7 @@ impl error::Error for WorkbookError {
             WorkbookError::SheetError(ref err) => Some(err),
             WorkbookError::FileNotFound => None,  // No underlying source error
             WorkbookError::RelationshipError(ref err) => Some(err),
+            WorkbookError::PatternError(ref err) => Some(err),
         }
     }
 }
@@ -173,6 +200,12 @@ impl From<ZipError> for WorkbookError {
     }
 }
 
+impl From<regex::Error> for WorkbookError {
+    fn from(err: regex::Error) -> WorkbookError {
+        WorkbookError::PatternError(err)
+    }
+}
+
 impl From<WorkSheetError> for WorkbookError {
     fn from(err: WorkSheetError) -> WorkbookError {
         WorkbookError::SheetError(err)
@@ -1,3 +1,10 @@
 pub(crate) mod col_helper;
 pub(crate) mod zip_util;
-pub(crate) mod id_util;
\ No newline at end of file
+pub(crate) mod id_util;
+pub(crate) mod hash_util;
+pub(crate) mod color_util;
+pub(crate) mod image_util;
+pub(crate) mod date_util;
+pub(crate) mod formula_util;
+pub(crate) mod range_util;
+pub(crate) mod find_util;
@@ -39,6 +39,30 @@ pub(crate) fn to_loc(loc_ref: &str) -> (u32, u32) {
     (row.parse().unwrap(), to_col(&col))
 }
 
+///
+/// Qualify the range reference with the sheet name, which is quoted if a formula needs it to be.
+///
+pub(crate) fn to_sheet_ref(sheet_name: &str, range_ref: &str) -> String {
+    let letters = sheet_name.trim_end_matches(|c: char| c.is_ascii_digit());
+    let plain = sheet_name.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && !sheet_name.starts_with(|c: char| c.is_ascii_digit())
+        // A name like A1 is a cell reference
+        && !(letters.len() < sheet_name.len() && letters.len() <= 3 && letters.chars().all(|c| c.is_ascii_alphabetic()));
+    match plain {
+        true => format!("{sheet_name}!{range_ref}"),
+        false => format!("'{}'!{range_ref}", sheet_name.replace('\'', "''")),
+    }
+}
+
+#[test]
+fn test_sheet_ref() {
+    assert_eq!(to_sheet_ref("Sheet1", "A1:E1"), "Sheet1!A1:E1");
+    assert_eq!(to_sheet_ref("My Sheet", "A1"), "'My Sheet'!A1");
+    assert_eq!(to_sheet_ref("Tom's", "A1"), "'Tom''s'!A1");
+    assert_eq!(to_sheet_ref("AB12", "A1"), "'AB12'!A1");
+    assert_eq!(to_sheet_ref("2024", "A1"), "'2024'!A1");
+}
+
 #[test]
 fn test_max_col () {
     let s = to_col_name(16384);
@@ -0,0 +1,103 @@
+///
+/// Apply the `tint` of a theme color the way Excel does:
+/// the color is converted to HLS, the luminance is darkened (`tint < 0`) or lightened (`tint > 0`),
+/// then converted back to RGB.
+///
+pub(crate) fn apply_tint((r, g, b): (u8, u8, u8), tint: f64) -> (u8, u8, u8) {
+    if tint == 0.0 {
+        return (r, g, b);
+    }
+    let tint = tint.clamp(-1.0, 1.0);
+    let (h, l, s) = rgb_to_hls((r, g, b));
+    let l = if tint < 0.0 {
+        l * (1.0 + tint)
+    } else {
+        l * (1.0 - tint) + tint
+    };
+    hls_to_rgb((h, l, s))
+}
+
+pub(crate) fn rgb_to_hls((r, g, b): (u8, u8, u8)) -> (f64, f64, f64) {
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    if max == min {
+        return (0.0, l, 0.0);
+    }
+    let delta = max - min;
+    let s = if l <= 0.5 { delta / (max + min) } else { delta / (2.0 - max - min) };
+    let h = if max == r {
+        (g - b) / delta
+    } else if max == g {
+        2.0 + (b - r) / delta
+    } else {
+        4.0 + (r - g) / delta
+    };
+    let h = (h / 6.0).rem_euclid(1.0);
+    (h, l, s)
+}
+
+pub(crate) fn hls_to_rgb((h, l, s): (f64, f64, f64)) -> (u8, u8, u8) {
+    let to_u8 = |v: f64| (v * 255.0).round().clamp(0.0, 255.0) as u8;
+    if s == 0.0 {
+        return (to_u8(l), to_u8(l), to_u8(l));
+    }
+    let m2 = if l <= 0.5 { l * (1.0 + s) } else { l + s - l * s };
+    let m1 = 2.0 * l - m2;
+    let value = |hue: f64| {
+        let hue = hue.rem_euclid(1.0);
+        if hue < 1.0 / 6.0 {
+            m1 + (m2 - m1) * hue * 6.0
+        } else if hue < 0.5 {
+            m2
+        } else if hue < 2.0 / 3.0 {
+            m1 + (m2 - m1) * (2.0 / 3.0 - hue) * 6.0
+        } else {
+            m1
+        }
+    };
+    (to_u8(value(h + 1.0 / 3.0)), to_u8(value(h)), to_u8(value(h - 1.0 / 3.0)))
+}
+
+///
+/// Parse a `RRGGBB` or `AARRGGBB` hex string.
+///
+pub(crate) fn hex_to_rgb(hex: &str) -> Option<(u8, u8, u8)> {
+    let hex = match hex.len() {
+        6 => hex,
+        8 => &hex[2..],
+        _ => return None,
+    };
+    let r = u8::from_str_radix(hex.get(0..2)?, 16).ok()?;
+    let g = u8::from_str_radix(hex.get(2..4)?, 16).ok()?;
+    let b = u8::from_str_radix(hex.get(4..6)?, 16).ok()?;
+    Some((r, g, b))
+}
+
+#[test]
+fn test_hls_round_trip() {
+    for rgb in [(0, 0, 0), (255, 255, 255), (0x5B, 0x9B, 0xD5), (0xED, 0x7D, 0x31), (0x70, 0xAD, 0x47), (255, 0, 0)] {
+        assert_eq!(hls_to_rgb(rgb_to_hls(rgb)), rgb);
+    }
+}
+
+#[test]
+fn test_apply_tint() {
+    // Blue, Accent 1 of the Office theme
+    let accent1 = (0x5B, 0x9B, 0xD5);
+    assert_eq!(apply_tint(accent1, 0.0), accent1);
+    assert_eq!(apply_tint(accent1, 0.7999816888943144), (0xDE, 0xEB, 0xF7));
+    assert_eq!(apply_tint(accent1, 0.3999755851924192), (0x9D, 0xC3, 0xE6));
+    assert_eq!(apply_tint(accent1, -0.249977111117893), (0x2E, 0x75, 0xB6));
+    assert_eq!(apply_tint(accent1, -0.499984740745262), (0x1F, 0x4E, 0x79));
+    // White, Background 1, Darker 5%
+    assert_eq!(apply_tint((255, 255, 255), -4.9989318521683403E-2), (0xF2, 0xF2, 0xF2));
+}
+
+#[test]
+fn test_hex_to_rgb() {
+    assert_eq!(hex_to_rgb("FF5B9BD5"), Some((0x5B, 0x9B, 0xD5)));
+    assert_eq!(hex_to_rgb("000000"), Some((0, 0, 0)));
+    assert_eq!(hex_to_rgb("windowText"), None);
+}
@@ -0,0 +1,116 @@
+//! Calendar arithmetic on the serial numbers Excel stores dates as,
+//! the days since 1899-12-30, which are right for the dates after 1900-02-28.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+///
+/// The days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+///
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+///
+/// The date of the days since 1970-01-01.
+///
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    let next = if month == 12 { days_from_civil(year + 1, 1, 1) } else { days_from_civil(year, month + 1, 1) };
+    (next - days_from_civil(year, month, 1)) as u32
+}
+
+fn serial_epoch() -> i64 {
+    days_from_civil(1899, 12, 30)
+}
+
+///
+/// Add months to a date, the day is kept unless the month is shorter, like Excel's EDATE.
+///
+pub(crate) fn add_months(serial: f64, months: i64) -> f64 {
+    let days = serial.floor() as i64;
+    let time = serial - days as f64;
+    let (year, month, day) = civil_from_days(days + serial_epoch());
+    let month_index = year * 12 + month as i64 - 1 + months;
+    let (year, month) = (month_index.div_euclid(12), month_index.rem_euclid(12) as u32 + 1);
+    let day = day.min(days_in_month(year, month));
+    (days_from_civil(year, month, day) - serial_epoch()) as f64 + time
+}
+
+///
+/// The serial number of the current date in UTC.
+///
+pub(crate) fn today() -> f64 {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or_default();
+    (seconds as i64 / 86400 + days_from_civil(1970, 1, 1) - serial_epoch()) as f64
+}
+
+///
+/// The year, the month and the day of a serial number.
+///
+pub(crate) fn to_date(serial: f64) -> (i64, u32, u32) {
+    civil_from_days(serial.floor() as i64 + serial_epoch())
+}
+
+///
+/// The serial number of a date.
+///
+pub(crate) fn from_date(year: i64, month: u32, day: u32) -> f64 {
+    (days_from_civil(year, month, day) - serial_epoch()) as f64
+}
+
+///
+/// Add working days to a date, skipping Saturdays and Sundays.
+///
+pub(crate) fn add_weekdays(serial: f64, weekdays: i64) -> f64 {
+    let step = weekdays.signum() as f64;
+    let mut serial = serial;
+    for _ in 0..weekdays.abs() {
+        serial += step;
+        // The serial numbers of Saturdays are multiples of 7
+        while (serial.floor() as i64).rem_euclid(7) < 2 {
+            serial += step;
+        }
+    }
+    serial
+}
+
+#[test]
+fn test_add_months() {
+    // 2024-01-31 and 2024-02-29
+    assert_eq!(add_months(45322.0, 1), 45351.0);
+    // 2024-01-31 and 2025-01-31
+    assert_eq!(add_months(45322.0, 12), 45688.0);
+    // 2024-03-31 and 2023-12-31
+    assert_eq!(add_months(45382.0, -3), 45291.0);
+}
+
+#[test]
+fn test_add_weekdays() {
+    // Friday 2024-01-05 and Monday 2024-01-08
+    assert_eq!(add_weekdays(45296.0, 1), 45299.0);
+    assert_eq!(add_weekdays(45299.0, -1), 45296.0);
+}
+
+#[test]
+fn test_to_date() {
+    assert_eq!(to_date(45322.5), (2024, 1, 31));
+    assert_eq!(from_date(2024, 2, 29), 45351.0);
+}
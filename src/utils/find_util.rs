@@ -0,0 +1,107 @@
+//! Match the texts of the cells for [`Workbook::find`](crate::Workbook::find) and rewrite them,
+//! a literal pattern is escaped so that both kinds of patterns are run by the same regular expression.
+
+use regex::{Regex, RegexBuilder};
+use crate::api::find::FindOptions;
+
+pub(crate) struct Matcher {
+    regex: Regex,
+    // Whether `$1` or `${name}` in the replacement refer to the groups of the pattern
+    expand: bool,
+}
+
+impl Matcher {
+    pub(crate) fn new(pattern: &str, options: &FindOptions) -> Result<Matcher, regex::Error> {
+        let pattern = match options.regex {
+            true => pattern.to_string(),
+            false => regex::escape(pattern),
+        };
+        let pattern = match options.whole_cell {
+            true => format!("^(?:{pattern})$"),
+            false => pattern,
+        };
+        let regex = RegexBuilder::new(&pattern)
+            .case_insensitive(!options.match_case)
+            .build()?;
+        Ok(Matcher { regex, expand: options.regex })
+    }
+
+    pub(crate) fn is_match(&self, text: &str) -> bool {
+        self.regex.is_match(text)
+    }
+
+    ///
+    /// The text with every match replaced, `None` if nothing matches.
+    ///
+    pub(crate) fn replace(&self, text: &str, replacement: &str) -> Option<String> {
+        let mut texts = [text.to_string()];
+        match self.replace_runs(&mut texts, replacement) {
+            true => texts.into_iter().next(),
+            false => None,
+        }
+    }
+
+    ///
+    /// Replace the matches in the text made of the runs, a match may span several runs.
+    /// The replacement goes to the run where the match starts, the rest of every run is kept in place,
+    /// so the runs keep their own formats. Returns whether anything matched.
+    ///
+    pub(crate) fn replace_runs(&self, runs: &mut [String], replacement: &str) -> bool {
+        if runs.is_empty() {
+            return false;
+        }
+        let text = runs.concat();
+        let mut bounds = Vec::with_capacity(runs.len());
+        let mut end = 0;
+        for run in runs.iter() {
+            bounds.push((end, end + run.len()));
+            end += run.len();
+        }
+        // The run holding the byte at the offset, the last run for the end of the text
+        let run_at = |offset: usize| bounds.iter().position(|&(_, end)| offset < end).unwrap_or(runs.len() - 1);
+        let mut new_runs = vec![String::new(); runs.len()];
+        let keep = |from: usize, to: usize, new_runs: &mut Vec<String>| {
+            for (i, &(start, end)) in bounds.iter().enumerate() {
+                let (start, end) = (start.max(from), end.min(to));
+                if start < end {
+                    new_runs[i].push_str(&text[start..end]);
+                }
+            }
+        };
+        let mut matched = false;
+        let mut last = 0;
+        for captures in self.regex.captures_iter(&text) {
+            let Some(found) = captures.get(0) else { continue };
+            matched = true;
+            keep(last, found.start(), &mut new_runs);
+            let new_run = &mut new_runs[run_at(found.start())];
+            match self.expand {
+                true => captures.expand(replacement, new_run),
+                false => new_run.push_str(replacement),
+            }
+            last = found.end();
+        }
+        if !matched {
+            return false;
+        }
+        keep(last, text.len(), &mut new_runs);
+        runs.iter_mut().zip(new_runs).for_each(|(run, new_run)| *run = new_run);
+        true
+    }
+}
+
+#[test]
+fn test_replace_runs() {
+    let options = FindOptions::default();
+    let matcher = Matcher::new("total", &options).unwrap();
+    let mut runs = ["Sub".to_string(), "to".to_string(), "tal: Total".to_string()];
+    assert!(matcher.replace_runs(&mut runs, "Summe"));
+    assert_eq!(runs, ["Sub", "Summe", ": Summe"]);
+    assert!(!matcher.replace_runs(&mut runs, "Summe"));
+
+    let options = FindOptions { regex: true, whole_cell: true, match_case: true, ..Default::default() };
+    let matcher = Matcher::new(r"(\d+) items?", &options).unwrap();
+    assert_eq!(matcher.replace("12 items", "$1 Stück"), Some("12 Stück".to_string()));
+    assert_eq!(matcher.replace("12 items left", "$1 Stück"), None);
+    assert_eq!(matcher.replace("12 Items", "$1 Stück"), None);
+}
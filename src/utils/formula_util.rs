@@ -0,0 +1,330 @@
+//! Re-base the references of a formula when it is copied to another cell,
+//! the relative rows and columns move with the formula while the ones marked with `$` stay.
+//! Rename the worksheet named by the references of a formula when the worksheet is copied under another name.
+//! Follow the cells of a range with the references of a formula when the range is moved.
+
+use crate::utils::col_helper::{to_col, to_col_name, to_sheet_ref};
+use crate::utils::range_util;
+
+const MAX_ROW: i64 = 1048576;
+const MAX_COL: i64 = 16384;
+
+///
+/// Move the relative references of the formula by the offsets, like Excel does when a cell is copied.
+/// The strings and the quoted sheet names are kept, a reference moved out of the worksheet becomes `#REF!`.
+///
+pub(crate) fn offset_formula(formula: &str, row_offset: i64, col_offset: i64) -> String {
+    if row_offset == 0 && col_offset == 0 {
+        return formula.to_string();
+    }
+    let chars: Vec<char> = formula.chars().collect();
+    let mut result = String::with_capacity(formula.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '"' || c == '\'' {
+            // A string or a quoted sheet name, whose quotes are escaped by doubling them
+            let start = i;
+            i += 1;
+            while i < chars.len() {
+                if chars[i] == c {
+                    if chars.get(i + 1) == Some(&c) {
+                        i += 2;
+                        continue;
+                    }
+                    break;
+                }
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            result.extend(&chars[start..i]);
+        } else if is_word_char(c) {
+            let start = i;
+            while i < chars.len() && is_word_char(chars[i]) {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            let next = chars.get(i).copied();
+            // A function or a sheet name
+            if next == Some('(') || next == Some('!') {
+                result.push_str(&word);
+                continue;
+            }
+            // A range of whole columns or whole rows, such as A:C or 1:3
+            if next == Some(':') {
+                let end = (i + 1..chars.len()).find(|&j| !is_word_char(chars[j])).unwrap_or(chars.len());
+                let other: String = chars[i + 1..end].iter().collect();
+                if let Some(range) = offset_line_range(&word, &other, row_offset, col_offset) {
+                    result.push_str(&range);
+                    i = end;
+                    continue;
+                }
+            }
+            match offset_cell(&word, row_offset, col_offset) {
+                Some(cell) => result.push_str(&cell),
+                None => result.push_str(&word),
+            }
+        } else {
+            result.push(c);
+            i += 1;
+        }
+    }
+    result
+}
+
+///
+/// Rename the worksheet in the references of the formula, like `Data!$A$1` or `'My Data'!A1`.
+/// Only a whole sheet name is renamed, at the start of the formula or after an operator, a comma or a bracket,
+/// so `RawData!A1` is kept when `Data` is renamed. The strings are kept, sheet names are compared without case.
+///
+pub(crate) fn rename_sheet(formula: &str, old_name: &str, new_name: &str) -> String {
+    let old_ref: Vec<char> = to_sheet_ref(old_name, "").chars().collect();
+    let new_ref = to_sheet_ref(new_name, "");
+    let chars: Vec<char> = formula.chars().collect();
+    let mut result = String::with_capacity(formula.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let at_boundary = i == 0 || matches!(chars[i - 1], '=' | '+' | '-' | '*' | '/' | '^' | '&' | '<' | '>' | ',' | ';' | ':' | '(' | '{')
+            || chars[i - 1].is_whitespace();
+        let named = at_boundary && chars.len() - i >= old_ref.len()
+            && chars[i..i + old_ref.len()].iter().zip(&old_ref).all(|(a, b)| a.to_lowercase().eq(b.to_lowercase()));
+        if named {
+            result.push_str(&new_ref);
+            i += old_ref.len();
+        } else if c == '"' || c == '\'' {
+            // A string or the quoted name of another sheet, whose quotes are escaped by doubling them
+            let start = i;
+            i += 1;
+            while i < chars.len() {
+                if chars[i] == c {
+                    if chars.get(i + 1) == Some(&c) {
+                        i += 2;
+                        continue;
+                    }
+                    break;
+                }
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            result.extend(&chars[start..i]);
+        } else {
+            result.push(c);
+            i += 1;
+        }
+    }
+    result
+}
+
+///
+/// Update the references of a formula of the worksheet `sheet_name` when the cells of the range are moved by the offsets,
+/// like Excel does when they are cut and pasted: a reference to a cell of the range follows it, whether it is relative
+/// or absolute, a range reference follows only if it lies inside the range, and a reference to a cell replaced by
+/// the move becomes `#REF!`. The references to other worksheets and the strings are kept.
+///
+pub(crate) fn move_references(formula: &str, sheet_name: &str, range: (u32, u32, u32, u32), row_offset: i64, col_offset: i64) -> String {
+    let destination = range_util::offset(range, row_offset, col_offset);
+    let chars: Vec<char> = formula.chars().collect();
+    let mut result = String::with_capacity(formula.len());
+    // Whether the next reference is to this worksheet, it is not after the name of another one
+    let mut local = true;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '"' || c == '\'' {
+            // A string or a quoted sheet name, whose quotes are escaped by doubling them
+            let start = i;
+            i += 1;
+            while i < chars.len() {
+                if chars[i] == c {
+                    if chars.get(i + 1) == Some(&c) {
+                        i += 2;
+                        continue;
+                    }
+                    break;
+                }
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            let text: String = chars[start..i].iter().collect();
+            if c == '\'' && chars.get(i) == Some(&'!') {
+                let name = text.trim_matches('\'').replace("''", "'");
+                local = name.to_lowercase() == sheet_name.to_lowercase();
+                result.push_str(&text);
+                result.push('!');
+                i += 1;
+                continue;
+            }
+            result.push_str(&text);
+        } else if is_word_char(c) {
+            let start = i;
+            while i < chars.len() && is_word_char(chars[i]) {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            let next = chars.get(i).copied();
+            if next == Some('!') {
+                local = word.to_lowercase() == sheet_name.to_lowercase();
+                result.push_str(&word);
+                result.push('!');
+                i += 1;
+                continue;
+            }
+            let is_local = std::mem::replace(&mut local, true);
+            // A range of cells, such as A1:B2
+            if next == Some(':') {
+                let end = (i + 1..chars.len()).find(|&j| !is_word_char(chars[j])).unwrap_or(chars.len());
+                let other: String = chars[i + 1..end].iter().collect();
+                if let (Some(first), Some(last)) = (parse_cell(&word), parse_cell(&other)) {
+                    let inside = range_util::contains(range, (first.0.1, first.1.1, last.0.1, last.1.1));
+                    match is_local && inside {
+                        true => result.push_str(&format!("{}:{}", move_cell(first, row_offset, col_offset), move_cell(last, row_offset, col_offset))),
+                        false => result.push_str(&format!("{word}:{other}")),
+                    }
+                    i = end;
+                    continue;
+                }
+            }
+            match parse_cell(&word) {
+                Some(cell) if is_local && next != Some('(') => {
+                    let (row, col) = (cell.0.1, cell.1.1);
+                    if range_util::contains(range, (row, col, row, col)) {
+                        result.push_str(&move_cell(cell, row_offset, col_offset));
+                    } else if range_util::contains(destination, (row, col, row, col)) {
+                        result.push_str("#REF!");
+                    } else {
+                        result.push_str(&word);
+                    }
+                }
+                _ => result.push_str(&word),
+            }
+        } else {
+            local = true;
+            result.push(c);
+            i += 1;
+        }
+    }
+    result
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '$' || c == '_' || c == '.'
+}
+
+///
+/// Split a reference part like `$AB` or `12` into whether it is absolute and its text.
+///
+fn split_absolute(part: &str) -> (bool, &str) {
+    match part.strip_prefix('$') {
+        Some(part) => (true, part),
+        None => (false, part),
+    }
+}
+
+fn offset_col(col: &str, absolute: bool, col_offset: i64) -> Option<String> {
+    let col = to_col(col) as i64 + if absolute { 0 } else { col_offset };
+    if !(1..=MAX_COL).contains(&col) {
+        return None;
+    }
+    Some(format!("{}{}", if absolute { "$" } else { "" }, to_col_name(col as u32)))
+}
+
+fn offset_row(row: &str, absolute: bool, row_offset: i64) -> Option<String> {
+    let row = row.parse::<i64>().ok()? + if absolute { 0 } else { row_offset };
+    if !(1..=MAX_ROW).contains(&row) {
+        return None;
+    }
+    Some(format!("{}{row}", if absolute { "$" } else { "" }))
+}
+
+fn is_col(col: &str) -> bool {
+    (1..=3).contains(&col.len()) && col.chars().all(|c| c.is_ascii_uppercase()) && to_col(col) as i64 <= MAX_COL
+}
+
+fn is_row(row: &str) -> bool {
+    !row.is_empty() && row.chars().all(|c| c.is_ascii_digit())
+}
+
+///
+/// Move a cell reference like `A1`, `$A1` or `A$1`, `None` if the word is not a cell reference.
+///
+fn offset_cell(word: &str, row_offset: i64, col_offset: i64) -> Option<String> {
+    let (col_absolute, rest) = split_absolute(word);
+    let digits = rest.find(|c: char| c == '$' || c.is_ascii_digit())?;
+    let (col, row) = rest.split_at(digits);
+    let (row_absolute, row) = split_absolute(row);
+    if !is_col(col) || !is_row(row) {
+        return None;
+    }
+    match (offset_col(col, col_absolute, col_offset), offset_row(row, row_absolute, row_offset)) {
+        (Some(col), Some(row)) => Some(format!("{col}{row}")),
+        _ => Some("#REF!".to_string()),
+    }
+}
+
+///
+/// The row and the column of a cell reference like `$A1`, with whether they are absolute.
+///
+fn parse_cell(word: &str) -> Option<((bool, u32), (bool, u32))> {
+    let (col_absolute, rest) = split_absolute(word);
+    let digits = rest.find(|c: char| c == '$' || c.is_ascii_digit())?;
+    let (col, row) = rest.split_at(digits);
+    let (row_absolute, row) = split_absolute(row);
+    if !is_col(col) || !is_row(row) {
+        return None;
+    }
+    Some(((row_absolute, row.parse().ok()?), (col_absolute, to_col(col))))
+}
+
+fn move_cell(((row_absolute, row), (col_absolute, col)): ((bool, u32), (bool, u32)), row_offset: i64, col_offset: i64) -> String {
+    format!(
+        "{}{}{}{}",
+        if col_absolute { "$" } else { "" },
+        to_col_name((col as i64 + col_offset) as u32),
+        if row_absolute { "$" } else { "" },
+        row as i64 + row_offset,
+    )
+}
+
+///
+/// Move a range of whole columns or whole rows, `None` if the words are not such a range.
+///
+fn offset_line_range(first: &str, last: &str, row_offset: i64, col_offset: i64) -> Option<String> {
+    let (first_absolute, first) = split_absolute(first);
+    let (last_absolute, last) = split_absolute(last);
+    let moved = if is_col(first) && is_col(last) {
+        (offset_col(first, first_absolute, col_offset), offset_col(last, last_absolute, col_offset))
+    } else if is_row(first) && is_row(last) {
+        (offset_row(first, first_absolute, row_offset), offset_row(last, last_absolute, row_offset))
+    } else {
+        return None;
+    };
+    match moved {
+        (Some(first), Some(last)) => Some(format!("{first}:{last}")),
+        _ => Some("#REF!".to_string()),
+    }
+}
+
+#[test]
+fn test_offset_formula() {
+    assert_eq!(offset_formula("SUM(A1:B2)*$C$3+C$4+$D5", 1, 2), "SUM(C2:D3)*$C$3+E$4+$D6");
+    assert_eq!(offset_formula("Sheet2!A1&\"A1\"&'My Sheet'!B2", 1, 0), "Sheet2!A2&\"A1\"&'My Sheet'!B3");
+    assert_eq!(offset_formula("LOG10(A1)+_xlfn.XLOOKUP(A1,B:B,$C:$C)", 0, 1), "LOG10(B1)+_xlfn.XLOOKUP(B1,C:C,$C:$C)");
+    assert_eq!(offset_formula("SUM(2:3)+TRUE", 2, 0), "SUM(4:5)+TRUE");
+    assert_eq!(offset_formula("A1+1", -1, 0), "#REF!+1");
+}
+
+#[test]
+fn test_rename_sheet() {
+    assert_eq!(rename_sheet("Data!$A$1:$A$9", "Data", "January"), "January!$A$1:$A$9");
+    assert_eq!(rename_sheet("SUM(RawData!A1,data!B2)&\"Data!A1\"", "Data", "My Data"), "SUM(RawData!A1,'My Data'!B2)&\"Data!A1\"");
+    assert_eq!(rename_sheet("'My Data'!A1+'Other Data'!A1", "My Data", "Data"), "Data!A1+'Other Data'!A1");
+}
+
+#[test]
+fn test_move_references() {
+    let range = (1, 1, 2, 2);
+    assert_eq!(move_references("A1+$B$2+C3+D1", "Data", range, 2, 2), "C3+$D$4+#REF!+D1");
+    assert_eq!(move_references("SUM(A1:B2)+SUM(A1:C3)", "Data", range, 0, 1), "SUM(B1:C2)+SUM(A1:C3)");
+    assert_eq!(move_references("Data!A1+'Other Data'!A1+\"A1\"+LOG10(A1)", "data", range, 1, 0), "Data!A2+'Other Data'!A1+\"A1\"+LOG10(A2)");
+}
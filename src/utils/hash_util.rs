@@ -0,0 +1,80 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use sha2::{Digest, Sha512};
+
+pub(crate) const SHA512_ALGORITHM_NAME: &str = "SHA-512";
+pub(crate) const DEFAULT_SPIN_COUNT: u32 = 100000;
+
+///
+/// The legacy 16-bit password verifier used by the `password` attribute of `sheetProtection`
+/// and the `workbookPassword` attribute of `workbookProtection`.
+///
+pub(crate) fn legacy_password_hash(password: &str) -> String {
+    let chars: Vec<u16> = password.encode_utf16().collect();
+    let mut hash: u16 = 0;
+    for &c in chars.iter().rev() {
+        hash = ((hash >> 14) & 0x01) | ((hash << 1) & 0x7FFF);
+        hash ^= c;
+    }
+    hash = ((hash >> 14) & 0x01) | ((hash << 1) & 0x7FFF);
+    hash ^= chars.len() as u16;
+    hash ^= 0xCE4B;
+    format!("{:04X}", hash)
+}
+
+///
+/// Hash the password with the agile SHA-512 scheme,
+/// returns the base64 encoded (hash value, salt value).
+///
+pub(crate) fn sha512_password_hash(password: &str, spin_count: u32) -> (String, String) {
+    let salt = new_salt();
+    let hash = sha512_password_hash_with_salt(password, &salt, spin_count);
+    (STANDARD.encode(hash), STANDARD.encode(salt))
+}
+
+fn sha512_password_hash_with_salt(password: &str, salt: &[u8], spin_count: u32) -> Vec<u8> {
+    let password: Vec<u8> = password.encode_utf16().flat_map(|c| c.to_le_bytes()).collect();
+    let mut hash = Sha512::new()
+        .chain_update(salt)
+        .chain_update(&password)
+        .finalize()
+        .to_vec();
+    for i in 0..spin_count {
+        hash = Sha512::new()
+            .chain_update(&hash)
+            .chain_update(i.to_le_bytes())
+            .finalize()
+            .to_vec();
+    }
+    hash
+}
+
+///
+/// A random salt from the random number generator of the operating system.
+///
+fn new_salt() -> [u8; 16] {
+    let mut salt = [0u8; 16];
+    getrandom::getrandom(&mut salt).expect("the random number generator of the operating system is unavailable");
+    salt
+}
+
+#[test]
+fn test_legacy_password_hash() {
+    assert_eq!(legacy_password_hash("password"), "83AF");
+    assert_eq!(legacy_password_hash(""), "CE4B");
+}
+
+#[test]
+fn test_sha512_password_hash() {
+    let salt = STANDARD.decode("lqQsIHEvd5vfNOlo6pgyYg==").unwrap();
+    let hash = sha512_password_hash_with_salt("password", &salt, DEFAULT_SPIN_COUNT);
+    assert_eq!(STANDARD.encode(hash), "7Gq/L0QJfxi39CdznmB+bPJfF6p/GoYnbBA2IVJtk+8PnJcgTt+4JBbUARXnv9y7ZrNrCYMpnORog7iD8mku6g==");
+    let (hash, salt) = sha512_password_hash("password", DEFAULT_SPIN_COUNT);
+    assert_eq!(STANDARD.decode(hash).unwrap().len(), 64);
+    assert_eq!(STANDARD.decode(salt).unwrap().len(), 16);
+}
+
+#[test]
+fn test_new_salt() {
+    assert_ne!(new_salt(), new_salt());
+}
@@ -0,0 +1,235 @@
+//!
+//! Read the pixel size and the resolution from the header of an image,
+//! the image data itself is never decoded.
+//!
+use crate::ImageFormat;
+
+const DEFAULT_DPI: f64 = 96.0;
+const INCHES_PER_METER: f64 = 0.0254;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ImageInfo {
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) x_dpi: f64,
+    pub(crate) y_dpi: f64,
+}
+
+impl ImageInfo {
+    fn new(width: u32, height: u32, x_dpi: f64, y_dpi: f64) -> ImageInfo {
+        // Some encoders write a zero or a nonsense resolution
+        let dpi = |dpi: f64| if dpi.is_finite() && dpi >= 1.0 { dpi } else { DEFAULT_DPI };
+        ImageInfo { width, height, x_dpi: dpi(x_dpi), y_dpi: dpi(y_dpi) }
+    }
+}
+
+///
+/// Returns `None` if the buffer is not an image of the format.
+///
+pub(crate) fn read_image_info(buffer: &[u8], format: ImageFormat) -> Option<ImageInfo> {
+    match format {
+        ImageFormat::Png => read_png(buffer),
+        ImageFormat::Jpeg => read_jpeg(buffer),
+        ImageFormat::Gif => read_gif(buffer),
+        ImageFormat::Bmp => read_bmp(buffer),
+        ImageFormat::Svg => read_svg(buffer),
+    }
+}
+
+///
+/// Guess the format of the image from its header.
+///
+pub(crate) fn detect_format(buffer: &[u8]) -> Option<ImageFormat> {
+    [ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::Gif, ImageFormat::Bmp, ImageFormat::Svg]
+        .into_iter()
+        .find(|&format| read_image_info(buffer, format).is_some())
+}
+
+fn be_u16(buffer: &[u8], pos: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(buffer.get(pos..pos + 2)?.try_into().ok()?))
+}
+
+fn be_u32(buffer: &[u8], pos: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(buffer.get(pos..pos + 4)?.try_into().ok()?))
+}
+
+fn le_u16(buffer: &[u8], pos: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(buffer.get(pos..pos + 2)?.try_into().ok()?))
+}
+
+fn le_i32(buffer: &[u8], pos: usize) -> Option<i32> {
+    Some(i32::from_le_bytes(buffer.get(pos..pos + 4)?.try_into().ok()?))
+}
+
+fn read_png(buffer: &[u8]) -> Option<ImageInfo> {
+    if !buffer.starts_with(b"\x89PNG\r\n\x1a\n") || buffer.get(12..16)? != b"IHDR" {
+        return None;
+    }
+    let (width, height) = (be_u32(buffer, 16)?, be_u32(buffer, 20)?);
+    let (mut x_dpi, mut y_dpi) = (DEFAULT_DPI, DEFAULT_DPI);
+    // Walk the chunks for the physical pixel dimensions
+    let mut pos = 8;
+    while let (Some(length), Some(chunk_type)) = (be_u32(buffer, pos), buffer.get(pos + 4..pos + 8)) {
+        match chunk_type {
+            b"pHYs" => {
+                // unit 1 means pixels per meter
+                if buffer.get(pos + 16) == Some(&1) {
+                    x_dpi = be_u32(buffer, pos + 8)? as f64 * INCHES_PER_METER;
+                    y_dpi = be_u32(buffer, pos + 12)? as f64 * INCHES_PER_METER;
+                }
+                break;
+            }
+            b"IDAT" | b"IEND" => break,
+            _ => pos += 12 + length as usize,
+        }
+    }
+    Some(ImageInfo::new(width, height, x_dpi, y_dpi))
+}
+
+fn read_jpeg(buffer: &[u8]) -> Option<ImageInfo> {
+    if !buffer.starts_with(&[0xFF, 0xD8]) {
+        return None;
+    }
+    let (mut x_dpi, mut y_dpi) = (DEFAULT_DPI, DEFAULT_DPI);
+    let mut pos = 2;
+    loop {
+        if *buffer.get(pos)? != 0xFF {
+            return None;
+        }
+        let marker = *buffer.get(pos + 1)?;
+        let length = be_u16(buffer, pos + 2)? as usize;
+        match marker {
+            // JFIF header
+            0xE0 if buffer.get(pos + 4..pos + 9)? == b"JFIF\0" => {
+                let (x_density, y_density) = (be_u16(buffer, pos + 12)? as f64, be_u16(buffer, pos + 14)? as f64);
+                match buffer.get(pos + 11)? {
+                    1 => (x_dpi, y_dpi) = (x_density, y_density),
+                    2 => (x_dpi, y_dpi) = (x_density * 2.54, y_density * 2.54),
+                    _ => {}
+                }
+            }
+            // Start of frame, except DHT, JPG and DAC
+            0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                let height = be_u16(buffer, pos + 5)? as u32;
+                let width = be_u16(buffer, pos + 7)? as u32;
+                return Some(ImageInfo::new(width, height, x_dpi, y_dpi));
+            }
+            _ => {}
+        }
+        pos += 2 + length;
+    }
+}
+
+fn read_gif(buffer: &[u8]) -> Option<ImageInfo> {
+    if !buffer.starts_with(b"GIF87a") && !buffer.starts_with(b"GIF89a") {
+        return None;
+    }
+    let (width, height) = (le_u16(buffer, 6)? as u32, le_u16(buffer, 8)? as u32);
+    Some(ImageInfo::new(width, height, DEFAULT_DPI, DEFAULT_DPI))
+}
+
+fn read_bmp(buffer: &[u8]) -> Option<ImageInfo> {
+    if !buffer.starts_with(b"BM") {
+        return None;
+    }
+    let width = le_i32(buffer, 18)?.unsigned_abs();
+    // A negative height means the rows are stored top-down
+    let height = le_i32(buffer, 22)?.unsigned_abs();
+    let (x_dpi, y_dpi) = match (le_i32(buffer, 38), le_i32(buffer, 42)) {
+        (Some(x_ppm), Some(y_ppm)) => (x_ppm as f64 * INCHES_PER_METER, y_ppm as f64 * INCHES_PER_METER),
+        _ => (DEFAULT_DPI, DEFAULT_DPI),
+    };
+    Some(ImageInfo::new(width, height, x_dpi, y_dpi))
+}
+
+fn read_svg(buffer: &[u8]) -> Option<ImageInfo> {
+    let text = std::str::from_utf8(buffer).ok()?;
+    let start = text.find("<svg")?;
+    let tag = &text[start..start + text[start..].find('>')?];
+    let length = |name: &str| svg_attribute(tag, name).and_then(svg_length);
+    let (width, height) = match (length("width"), length("height")) {
+        (Some(width), Some(height)) => (width, height),
+        _ => {
+            // Fall back to the size of the viewBox
+            let view_box: Vec<f64> = svg_attribute(tag, "viewBox")?
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter_map(|v| v.parse().ok())
+                .collect();
+            (*view_box.get(2)?, *view_box.get(3)?)
+        }
+    };
+    Some(ImageInfo::new(width.round() as u32, height.round() as u32, DEFAULT_DPI, DEFAULT_DPI))
+}
+
+fn svg_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!(" {name}=");
+    let start = tag.find(&pattern)? + pattern.len();
+    let quote = tag[start..].chars().next().filter(|&quote| quote == '"' || quote == '\'')?;
+    let value = &tag[start + quote.len_utf8()..];
+    Some(&value[..value.find(quote)?])
+}
+
+///
+/// Convert a svg length to pixels, percentages are not supported.
+///
+fn svg_length(length: &str) -> Option<f64> {
+    let length = length.trim();
+    let split = length.find(|c: char| c.is_ascii_alphabetic() || c == '%').unwrap_or(length.len());
+    let value: f64 = length[..split].parse().ok()?;
+    let pixels_per_unit = match &length[split..] {
+        "" | "px" => 1.0,
+        "pt" => DEFAULT_DPI / 72.0,
+        "pc" => DEFAULT_DPI / 6.0,
+        "in" => DEFAULT_DPI,
+        "cm" => DEFAULT_DPI / 2.54,
+        "mm" => DEFAULT_DPI / 25.4,
+        _ => return None,
+    };
+    Some(value * pixels_per_unit)
+}
+
+#[test]
+fn test_png() {
+    let mut png = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR".to_vec();
+    png.extend_from_slice(&[0, 0, 1, 0, 0, 0, 0, 200, 8, 6, 0, 0, 0, 0, 0, 0, 0]);
+    png.extend_from_slice(b"\x00\x00\x00\x09pHYs");
+    // 5906 pixels per meter is 150 dpi
+    png.extend_from_slice(&[0, 0, 0x17, 0x12, 0, 0, 0x17, 0x12, 1]);
+    let info = read_image_info(&png, ImageFormat::Png).unwrap();
+    assert_eq!((info.width, info.height), (256, 200));
+    assert_eq!(info.x_dpi.round(), 150.0);
+}
+
+#[test]
+fn test_jpeg() {
+    let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10];
+    jpeg.extend_from_slice(b"JFIF\0");
+    jpeg.extend_from_slice(&[1, 1, 1, 0, 72, 0, 72, 0, 0]);
+    jpeg.extend_from_slice(&[0xFF, 0xC0, 0x00, 0x11, 8, 0x01, 0x90, 0x02, 0x58]);
+    let info = read_image_info(&jpeg, ImageFormat::Jpeg).unwrap();
+    assert_eq!((info.width, info.height, info.x_dpi), (600, 400, 72.0));
+    assert!(read_image_info(&jpeg, ImageFormat::Png).is_none());
+}
+
+#[test]
+fn test_gif_bmp_svg() {
+    let gif = b"GIF89a\x20\x00\x10\x00";
+    assert_eq!(read_image_info(gif, ImageFormat::Gif).unwrap().width, 32);
+    let mut bmp = b"BM".to_vec();
+    bmp.resize(46, 0);
+    bmp[18] = 64;
+    bmp[22..26].copy_from_slice(&(-48i32).to_le_bytes());
+    let info = read_image_info(&bmp, ImageFormat::Bmp).unwrap();
+    assert_eq!((info.width, info.height, info.x_dpi), (64, 48, 96.0));
+    let svg = br#"<?xml version="1.0"?><svg xmlns="http://www.w3.org/2000/svg" width="1in" viewBox="0 0 300 150">"#;
+    let info = read_image_info(svg, ImageFormat::Svg).unwrap();
+    assert_eq!((info.width, info.height), (300, 150));
+    let svg = br#"<svg width="120" height="30pt">"#;
+    assert_eq!(read_image_info(svg, ImageFormat::Svg).unwrap().height, 40);
+    assert_eq!(detect_format(svg), Some(ImageFormat::Svg));
+    assert_eq!(detect_format(&bmp), Some(ImageFormat::Bmp));
+    assert_eq!(detect_format(b"GIF"), None);
+    // The values are quoted by either quote, an unquoted value is ignored
+    assert_eq!(svg_attribute("<svg width=é10 height='30'>", "width"), None);
+    assert_eq!(svg_attribute("<svg width=é10 height='30'>", "height"), Some("30"));
+}
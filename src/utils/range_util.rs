@@ -0,0 +1,101 @@
+//! Helpers for the ranges of `(first_row, first_col, last_row, last_col)`.
+
+use crate::utils::col_helper::{to_col, to_loc, to_ref};
+
+///
+/// Whether two ranges share any cell.
+///
+pub(crate) fn intersects(a: (u32, u32, u32, u32), b: (u32, u32, u32, u32)) -> bool {
+    a.0 <= b.2 && b.0 <= a.2 && a.1 <= b.3 && b.1 <= a.3
+}
+
+///
+/// Whether the range `inner` lies inside the range `outer`.
+///
+pub(crate) fn contains(outer: (u32, u32, u32, u32), inner: (u32, u32, u32, u32)) -> bool {
+    outer.0 <= inner.0 && inner.2 <= outer.2 && outer.1 <= inner.1 && inner.3 <= outer.3
+}
+
+///
+/// The cells shared by two ranges.
+///
+pub(crate) fn intersection(a: (u32, u32, u32, u32), b: (u32, u32, u32, u32)) -> Option<(u32, u32, u32, u32)> {
+    match intersects(a, b) {
+        true => Some((a.0.max(b.0), a.1.max(b.1), a.2.min(b.2), a.3.min(b.3))),
+        false => None,
+    }
+}
+
+///
+/// The cells of `a` which are not in `b`, as at most four ranges.
+///
+pub(crate) fn subtract(a: (u32, u32, u32, u32), b: (u32, u32, u32, u32)) -> Vec<(u32, u32, u32, u32)> {
+    let Some((first_row, first_col, last_row, last_col)) = intersection(a, b) else {
+        return vec![a];
+    };
+    let mut ranges = vec![];
+    if a.0 < first_row {
+        ranges.push((a.0, a.1, first_row - 1, a.3));
+    }
+    if last_row < a.2 {
+        ranges.push((last_row + 1, a.1, a.2, a.3));
+    }
+    if a.1 < first_col {
+        ranges.push((first_row, a.1, last_row, first_col - 1));
+    }
+    if last_col < a.3 {
+        ranges.push((first_row, last_col + 1, last_row, a.3));
+    }
+    ranges
+}
+
+///
+/// Move the range by the offsets, which keep it inside the worksheet.
+///
+pub(crate) fn offset(range: (u32, u32, u32, u32), row_offset: i64, col_offset: i64) -> (u32, u32, u32, u32) {
+    let row = |row: u32| (row as i64 + row_offset) as u32;
+    let col = |col: u32| (col as i64 + col_offset) as u32;
+    (row(range.0), col(range.1), row(range.2), col(range.3))
+}
+
+///
+/// Parse a reference like `A1` or `A1:B2`, the `$` marks are ignored.
+///
+pub(crate) fn from_range_ref(range_ref: &str) -> (u32, u32, u32, u32) {
+    let range_ref = range_ref.replace('$', "");
+    let (first, last) = range_ref.split_once(':').unwrap_or((&range_ref, &range_ref));
+    let is_col = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphabetic());
+    let is_row = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+    // The whole columns, such as A:B, and the whole rows, such as 1:2
+    if is_col(first) && is_col(last) {
+        return (1, to_col(first), 1048576, to_col(last));
+    }
+    if is_row(first) && is_row(last) {
+        return (first.parse().unwrap_or(1), 1, last.parse().unwrap_or(1048576), 16384);
+    }
+    let (first_row, first_col) = to_loc(first);
+    let (last_row, last_col) = to_loc(last);
+    (first_row, first_col, last_row, last_col)
+}
+
+///
+/// Format the range as `A1:B2`, or `A1` for a single cell.
+///
+pub(crate) fn to_range_ref(range: (u32, u32, u32, u32)) -> String {
+    let (first_row, first_col, last_row, last_col) = range;
+    match (first_row, first_col) == (last_row, last_col) {
+        true => to_ref(first_row, first_col),
+        false => format!("{}:{}", to_ref(first_row, first_col), to_ref(last_row, last_col)),
+    }
+}
+
+#[test]
+fn test_subtract() {
+    assert_eq!(subtract((1, 1, 3, 3), (2, 2, 2, 2)), vec![(1, 1, 1, 3), (3, 1, 3, 3), (2, 1, 2, 1), (2, 3, 2, 3)]);
+    assert_eq!(subtract((1, 1, 3, 3), (1, 1, 5, 5)), vec![]);
+    assert_eq!(subtract((1, 1, 3, 3), (4, 4, 5, 5)), vec![(1, 1, 3, 3)]);
+    assert_eq!(from_range_ref("$B$2:C3"), (2, 2, 3, 3));
+    assert_eq!(from_range_ref("A:$B"), (1, 1, 1048576, 2));
+    assert_eq!(from_range_ref("$2:3"), (2, 1, 3, 16384));
+    assert_eq!(to_range_ref((2, 2, 2, 2)), "B2");
+}
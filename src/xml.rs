@@ -8,9 +8,11 @@ pub(crate) mod content_types;
 pub(crate) mod medias;
 pub(crate) mod relationships;
 pub(crate) mod drawings;
+pub(crate) mod comments;
+pub(crate) mod ctrl_prop;
 pub(crate) mod metadata;
 pub(crate) mod extension;
 pub(crate) mod core_properties;
 pub(crate) mod app_properties;
-mod namespaces;
+pub(crate) mod namespaces;
 pub(crate) mod theme;
@@ -0,0 +1,190 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use zip::ZipArchive;
+use crate::file::{XlsxFileType, XlsxFileWriter};
+use crate::utils::col_helper::{to_loc, to_ref};
+use crate::utils::range_util;
+
+///
+/// A comments part, which holds the comments of the cells of a worksheet.
+///
+/// The authors and the texts of the comments are kept as they were read, as the reader would trim their whitespace,
+/// and the part is only written when its comments changed.
+///
+#[derive(Debug, Clone)]
+pub(crate) struct Comments {
+    // The xml up to the end of the start tag of `<comments>`
+    head: String,
+    authors: Vec<String>,
+    comments: Vec<Comment>,
+    // The xml after the end of `<commentList>`
+    tail: String,
+    pub(crate) modified: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Comment {
+    row: u32,
+    col: u32,
+    author: String,
+    // The attributes other than `ref` and `authorId`
+    attrs: String,
+    // The xml inside `<comment>`, its text
+    body: String,
+}
+
+impl Default for Comments {
+    fn default() -> Self {
+        Self {
+            head: "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+                <comments xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\">".to_string(),
+            authors: vec![],
+            comments: vec![],
+            tail: "</comments>".to_string(),
+            modified: false,
+        }
+    }
+}
+
+impl Comments {
+    pub(crate) fn from_zip_file(archive: &mut ZipArchive<File>, path: &str) -> Option<Comments> {
+        let mut file = archive.by_name(path).ok()?;
+        let mut xml = String::new();
+        file.read_to_string(&mut xml).ok()?;
+        Self::from_xml(&xml)
+    }
+
+    fn from_xml(xml: &str) -> Option<Comments> {
+        let root = xml.find("<comments")?;
+        let head_end = root + xml[root..].find('>')? + 1;
+        let authors: Vec<String> = elements(&xml[head_end..], "author")
+            .map(|(_, content)| content.to_string())
+            .collect();
+        let (list_start, list_end) = match (xml.find("<commentList>"), xml.find("</commentList>")) {
+            (Some(start), Some(end)) => (start + "<commentList>".len(), end),
+            _ => return None,
+        };
+        let comments = elements(&xml[list_start..list_end], "comment")
+            .filter_map(|(tag, body)| {
+                let (row, col) = to_loc(get_attribute(tag, "ref")?);
+                let author_id: usize = get_attribute(tag, "authorId")?.parse().ok()?;
+                let attrs = tag.split_whitespace()
+                    .skip(1)
+                    .filter(|attr| !attr.starts_with("ref=") && !attr.starts_with("authorId="))
+                    .collect::<Vec<&str>>()
+                    .join(" ");
+                Some(Comment {
+                    row,
+                    col,
+                    author: authors.get(author_id)?.clone(),
+                    attrs: attrs.trim_end_matches('>').trim_end().to_string(),
+                    body: body.to_string(),
+                })
+            })
+            .collect();
+        Some(Comments {
+            head: xml[..head_end].to_string(),
+            authors,
+            comments,
+            tail: xml[list_end + "</commentList>".len()..].to_string(),
+            modified: false,
+        })
+    }
+
+    ///
+    /// The comments of the cells of the range.
+    ///
+    pub(crate) fn get_comments(&self, range: (u32, u32, u32, u32)) -> Vec<Comment> {
+        self.comments.iter()
+            .filter(|comment| range_util::contains(range, (comment.row, comment.col, comment.row, comment.col)))
+            .cloned()
+            .collect()
+    }
+
+    pub(crate) fn remove_range(&mut self, range: (u32, u32, u32, u32)) {
+        let count = self.comments.len();
+        self.comments.retain(|comment| !range_util::contains(range, (comment.row, comment.col, comment.row, comment.col)));
+        self.modified |= count != self.comments.len();
+    }
+
+    ///
+    /// Add a copy of the comment moved by the offsets, which replaces the comment of the cell it lands on.
+    /// The copy does not keep the unique id of the comment.
+    ///
+    pub(crate) fn add_comment(&mut self, comment: &Comment, row_offset: i64, col_offset: i64) {
+        let (row, col) = ((comment.row as i64 + row_offset) as u32, (comment.col as i64 + col_offset) as u32);
+        self.comments.retain(|comment| (comment.row, comment.col) != (row, col));
+        if !self.authors.contains(&comment.author) {
+            self.authors.push(comment.author.clone());
+        }
+        let attrs = comment.attrs.split_whitespace()
+            .filter(|attr| !attr.starts_with("xr:uid="))
+            .collect::<Vec<&str>>()
+            .join(" ");
+        let index = self.comments.iter().position(|c| (c.row, c.col) > (row, col)).unwrap_or(self.comments.len());
+        self.comments.insert(index, Comment { row, col, author: comment.author.clone(), attrs, body: comment.body.clone() });
+        self.modified = true;
+    }
+
+    fn to_xml(&self) -> String {
+        let authors: String = self.authors.iter().map(|author| format!("<author>{author}</author>")).collect();
+        let comments: String = self.comments.iter()
+            .map(|comment| {
+                let author_id = self.authors.iter().position(|author| *author == comment.author).unwrap_or_default();
+                let attrs = match comment.attrs.is_empty() {
+                    true => String::new(),
+                    false => format!(" {}", comment.attrs),
+                };
+                format!("<comment ref=\"{}\" authorId=\"{author_id}\"{attrs}>{}</comment>", to_ref(comment.row, comment.col), comment.body)
+            })
+            .collect();
+        format!("{}<authors>{authors}</authors><commentList>{comments}</commentList>{}", self.head, self.tail)
+    }
+
+    pub(crate) fn save<P: AsRef<Path>>(&self, file_path: P, part_name: &str) {
+        let mut file = XlsxFileWriter::from_path(file_path, XlsxFileType::Part(part_name.to_string())).unwrap();
+        file.write_all(self.to_xml().as_ref()).unwrap();
+    }
+}
+
+///
+/// The start tags and the contents of the elements `name` in the xml, an empty element has an empty content.
+///
+fn elements<'a>(xml: &'a str, name: &'a str) -> impl Iterator<Item = (&'a str, &'a str)> {
+    let mut rest = xml;
+    std::iter::from_fn(move || {
+        let start = rest.match_indices(&format!("<{name}"))
+            .map(|(i, _)| i)
+            .find(|&i| matches!(rest[i + 1 + name.len()..].chars().next(), Some('>' | '/' | ' ')))?;
+        let tag_end = start + rest[start..].find('>')? + 1;
+        let tag = &rest[start..tag_end];
+        if tag.ends_with("/>") {
+            rest = &rest[tag_end..];
+            return Some((tag, ""));
+        }
+        let close = format!("</{name}>");
+        let end = tag_end + rest[tag_end..].find(&close)?;
+        let content = &rest[tag_end..end];
+        rest = &rest[end + close.len()..];
+        Some((tag, content))
+    })
+}
+
+fn get_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!(" {name}=\""))? + name.len() + 3;
+    let end = start + tag[start..].find('"')?;
+    Some(&tag[start..end])
+}
+
+#[test]
+fn test_comments() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<comments xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><authors><author>Ann</author></authors><commentList><comment ref="B2" authorId="0" shapeId="0"><text><t xml:space="preserve">Note </t></text></comment></commentList></comments>"#;
+    let mut comments = Comments::from_xml(xml).unwrap();
+    assert_eq!(comments.to_xml(), xml);
+    let comment = comments.get_comments((1, 1, 2, 2)).remove(0);
+    comments.add_comment(&comment, 2, 1);
+    comments.remove_range((2, 2, 2, 2));
+    assert!(comments.to_xml().contains(r#"<commentList><comment ref="C4" authorId="0" shapeId="0"><text><t xml:space="preserve">Note </t></text></comment></commentList>"#));
+}
@@ -75,7 +75,7 @@ impl XmlnsAttrs {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default, Hash)]
 pub(crate) struct Element<T: Clone + PartialEq + Default> {
     #[serde(rename = "@val")]
     pub(crate) val: T
@@ -189,6 +189,16 @@ impl XmlnsAttrs {
 }
 
 impl XmlnsAttrs {
+    ///
+    /// Declare the namespaces used by the `<controls>` element of a worksheet.
+    ///
+    pub(crate) fn add_controls(&mut self) {
+        self.xmlns_r.get_or_insert("http://schemas.openxmlformats.org/officeDocument/2006/relationships".to_string());
+        self.xmlns_mc.get_or_insert("http://schemas.openxmlformats.org/markup-compatibility/2006".to_string());
+        self.xmlns_x14.get_or_insert("http://schemas.microsoft.com/office/spreadsheetml/2009/9/main".to_string());
+        self.xmlns_xdr.get_or_insert("http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing".to_string());
+    }
+
     pub(crate) fn add_xr(&mut self) {
         match self.xmlns_xr {
             None => {
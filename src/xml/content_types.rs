@@ -18,6 +18,9 @@ pub(crate) struct ContentTypes {
     xmlns: String,
     #[serde(rename = "$value")]
     content_types: HashSet<ContentType>,
+    // The vml drawings have no overrides, so their ids are collected from the parts
+    #[serde(skip)]
+    vml_drawing_ids: HashSet<u32>,
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
@@ -53,24 +56,117 @@ impl ContentTypes {
 }
 
 impl ContentTypes {
-    pub(crate) fn add_png(&mut self) {
-        self.content_types.insert(ContentType::png_default());
-    }
     pub(crate) fn add_bin(&mut self, extension: &str) {
         if self.get_mut_by_extension(extension) {
             return;
         }
         self.content_types.insert(ContentType::octet_stream_default(extension));
     }
+    pub(crate) fn add_image(&mut self, extension: &str, content_type: &str) {
+        if self.get_mut_by_extension(extension) {
+            return;
+        }
+        self.content_types.insert(ContentType::image_default(extension, content_type));
+    }
+    pub(crate) fn remove_extension(&mut self, extension: &str) {
+        self.content_types.retain(|c| c.get_extension() != Some(extension));
+    }
+    ///
+    /// The next unused N of `/xl/drawings/drawingN.xml` in the whole workbook.
+    ///
+    pub(crate) fn next_drawing_id(&self) -> u32 {
+        self.next_override_id("/xl/drawings/drawing")
+    }
+    ///
+    /// The next unused N of `/xl/ctrlProps/ctrlPropN.xml` in the whole workbook.
+    ///
+    pub(crate) fn next_ctrl_prop_id(&self) -> u32 {
+        self.next_override_id("/xl/ctrlProps/ctrlProp")
+    }
+    ///
+    /// The next unused N of `/xl/commentsN.xml` in the whole workbook.
+    ///
+    pub(crate) fn next_comments_id(&self) -> u32 {
+        self.next_override_id("/xl/comments")
+    }
+    fn next_override_id(&self, prefix: &str) -> u32 {
+        1 + self.content_types.iter()
+            .filter_map(|c| match c {
+                ContentType::Override { part_name, .. } => part_name
+                    .strip_prefix(prefix)?
+                    .strip_suffix(".xml")?
+                    .parse::<u32>()
+                    .ok(),
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default()
+    }
+    ///
+    /// The next unused N of `/xl/drawings/vmlDrawingN.vml` in the whole workbook.
+    ///
+    pub(crate) fn next_vml_drawing_id(&self) -> u32 {
+        1 + self.vml_drawing_ids.iter().max().copied().unwrap_or_default()
+    }
+    ///
+    /// Mark the N of an existing `vmlDrawingN.vml` as used.
+    ///
+    pub(crate) fn reserve_vml_drawing_id(&mut self, id: u32) {
+        self.vml_drawing_ids.insert(id);
+    }
+    pub(crate) fn add_vml_drawing(&mut self, id: u32) {
+        self.vml_drawing_ids.insert(id);
+        if !self.get_mut_by_extension("vml") {
+            self.content_types.insert(ContentType::vml_drawing_default());
+        }
+    }
     pub(crate) fn add_drawing(&mut self, id: u32) { self.content_types.insert(ContentType::drawing_override(id)); }
+    pub(crate) fn add_ctrl_prop(&mut self, id: u32) { self.content_types.insert(ContentType::ctrl_prop_override(id)); }
+    pub(crate) fn add_comments(&mut self, id: u32) { self.content_types.insert(ContentType::comments_override(id)); }
     pub(crate) fn add_metadata(&mut self) { self.content_types.insert(ContentType::metadata_override()); }
+    pub(crate) fn add_shared_strings(&mut self) { self.content_types.insert(ContentType::shared_strings_override()); }
+    pub(crate) fn add_theme(&mut self, id: u32) { self.content_types.insert(ContentType::theme_override(id)); }
+
+    ///
+    /// The content type of the part, like `xl/tables/table1.xml`, given by its override or by the default of its extension.
+    ///
+    pub(crate) fn get_part_content_type(&self, part_name: &str) -> Option<String> {
+        let part_name = format!("/{part_name}");
+        let extension = Path::new(&part_name).extension()?.to_str()?;
+        let content_type = self.content_types.iter().find_map(|c| match c {
+            ContentType::Override { part_name: name, content_type } if *name == part_name => Some(content_type),
+            _ => None,
+        });
+        content_type.or(self.content_types.iter().find_map(|c| match c {
+            ContentType::Default { extension: e, content_type } if e == extension => Some(content_type),
+            _ => None,
+        })).cloned()
+    }
+
+    pub(crate) fn has_part(&self, part_name: &str) -> bool {
+        let part_name = format!("/{part_name}");
+        self.content_types.iter().any(|c| matches!(c, ContentType::Override { part_name: name, .. } if *name == part_name))
+    }
+
+    ///
+    /// Give the part its content type, an override is only added if the default of its extension does not match.
+    ///
+    pub(crate) fn add_part(&mut self, part_name: &str, content_type: &str) {
+        if self.get_part_content_type(part_name).as_deref() == Some(content_type) {
+            return;
+        }
+        self.content_types.insert(ContentType::Override {
+            part_name: format!("/{part_name}"),
+            content_type: content_type.to_string(),
+        });
+    }
 }
 
 impl ContentType {
-    fn png_default() -> ContentType {
+    fn image_default(extension: &str, content_type: &str) -> ContentType {
         ContentType::Default {
-            extension: "png".to_string(),
-            content_type: "image/png".to_string(),
+            extension: extension.to_string(),
+            content_type: content_type.to_string(),
         }
     }
 
@@ -88,12 +184,47 @@ impl ContentType {
         }
     }
 
+    fn vml_drawing_default() -> ContentType {
+        ContentType::Default {
+            extension: "vml".to_string(),
+            content_type: "application/vnd.openxmlformats-officedocument.vmlDrawing".to_string(),
+        }
+    }
+
+    fn ctrl_prop_override(id: u32) -> ContentType {
+        ContentType::Override {
+            part_name: format!("/xl/ctrlProps/ctrlProp{id}.xml"),
+            content_type: "application/vnd.ms-excel.controlproperties+xml".to_string(),
+        }
+    }
+
+    fn theme_override(id: u32) -> ContentType {
+        ContentType::Override {
+            part_name: format!("/xl/theme/theme{id}.xml"),
+            content_type: "application/vnd.openxmlformats-officedocument.theme+xml".to_string(),
+        }
+    }
+
+    fn comments_override(id: u32) -> ContentType {
+        ContentType::Override {
+            part_name: format!("/xl/comments{id}.xml"),
+            content_type: "application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml".to_string(),
+        }
+    }
+
     fn metadata_override() -> ContentType {
         ContentType::Override {
             part_name: "/xl/metadata.xml".to_string(),
             content_type: "application/vnd.openxmlformats-officedocument.spreadsheetml.sheetMetadata+xml".to_string(),
         }
     }
+
+    fn shared_strings_override() -> ContentType {
+        ContentType::Override {
+            part_name: "/xl/sharedStrings.xml".to_string(),
+            content_type: "application/vnd.openxmlformats-officedocument.spreadsheetml.sharedStrings+xml".to_string(),
+        }
+    }
 }
 
 impl ContentTypes {
@@ -0,0 +1,65 @@
+use std::path::Path;
+use quick_xml::se;
+use serde::{Deserialize, Serialize};
+use crate::file::{XlsxFileType, XlsxFileWriter};
+
+///
+/// The properties of a form control, saved in `xl/ctrlProps/ctrlPropN.xml`.
+///
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename = "formControlPr")]
+pub(crate) struct FormControlPr {
+    #[serde(rename = "@xmlns", default)]
+    xmlns: String,
+    #[serde(rename = "@objectType")]
+    object_type: String,
+    #[serde(rename = "@checked", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) checked: Option<String>,
+    #[serde(rename = "@dropLines", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) drop_lines: Option<u32>,
+    #[serde(rename = "@dropStyle", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) drop_style: Option<String>,
+    #[serde(rename = "@dx", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) dx: Option<u32>,
+    #[serde(rename = "@firstButton", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) first_button: Option<u8>,
+    #[serde(rename = "@fmlaLink", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) fmla_link: Option<String>,
+    #[serde(rename = "@fmlaRange", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) fmla_range: Option<String>,
+    #[serde(rename = "@inc", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) inc: Option<u32>,
+    #[serde(rename = "@lockText", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) lock_text: Option<u8>,
+    #[serde(rename = "@max", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) max: Option<u32>,
+    #[serde(rename = "@min", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) min: Option<u32>,
+    #[serde(rename = "@noThreeD", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) no_three_d: Option<u8>,
+    #[serde(rename = "@page", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) page: Option<u32>,
+    #[serde(rename = "@sel", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) sel: Option<u32>,
+    #[serde(rename = "@selType", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) sel_type: Option<String>,
+    #[serde(rename = "@val", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) val: Option<u32>,
+}
+
+impl FormControlPr {
+    pub(crate) fn new(object_type: &str) -> FormControlPr {
+        FormControlPr {
+            xmlns: "http://schemas.microsoft.com/office/spreadsheetml/2009/9/main".to_string(),
+            object_type: object_type.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn save<P: AsRef<Path>>(&self, file_path: P, ctrl_prop_id: u32) {
+        let xml = se::to_string_with_root("formControlPr", &self).unwrap();
+        let xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n{}", xml);
+        let mut file = XlsxFileWriter::from_path(file_path, XlsxFileType::CtrlProps(ctrl_prop_id)).unwrap();
+        file.write_all(xml.as_ref()).unwrap();
+    }
+}
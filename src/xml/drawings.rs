@@ -1,74 +1,406 @@
 pub(crate) mod vml_drawing;
+pub(crate) mod shape;
 
+use std::cmp;
+use std::collections::HashSet;
+use std::fs::File;
 use std::io::Read;
 use std::path::Path;
-use quick_xml::{se};
+use quick_xml::{de, se};
+use quick_xml::events::Event;
+use quick_xml::Reader;
 use serde::{Deserialize, Serialize};
+use zip::ZipArchive;
 use crate::api::cell::location::{Location, LocationRange};
 use crate::api::relationship::Rel;
+use crate::{ImageOptions, Shape as ApiShape};
+use crate::xml::drawings::shape::{Connector, Shape};
 use crate::file::{XlsxFileType, XlsxFileWriter};
-use crate::xml::io::insert::Insert;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
-#[serde(rename(serialize = "xdr:wsDr", deserialize = "wsDr"))]
+const DRAWINGS_HEAD: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+<xdr:wsDr xmlns:xdr=\"http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing\" \
+xmlns:a=\"http://schemas.openxmlformats.org/drawingml/2006/main\">";
+const DRAWINGS_TAIL: &str = "</xdr:wsDr>";
+
+///
+/// The drawing part of a worksheet, which holds the anchors of images, shapes, charts and so on.
+/// Only a part of the anchor's content is modelled,
+/// so the original xml of every anchor is kept and written back unless the anchor is modified.
+///
+#[derive(Debug, Clone)]
 pub(crate) struct Drawings {
-    #[serde(rename(serialize = "@xmlns:xdr", deserialize = "@xmlns:xdr"), default, skip_serializing_if = "String::is_empty")]
-    xmlns_xdr: String,
-    #[serde(rename(serialize = "@xmlns:a", deserialize = "@xmlns:a"), default, skip_serializing_if = "String::is_empty")]
-    xmlns_a: String,
-    #[serde(rename(serialize = "xdr:twoCellAnchor", deserialize = "twoCellAnchor"), default)]
-    pub(crate) drawing: Vec<Drawing>
+    // The xml declaration and the start tag of `wsDr`
+    head: String,
+    // The end tag of `wsDr`
+    tail: String,
+    pub(crate) anchors: Vec<Anchor>,
 }
 
 impl Default for Drawings {
     fn default() -> Self {
         Self {
-            xmlns_xdr: "http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing".to_string(),
-            xmlns_a: "http://schemas.openxmlformats.org/drawingml/2006/main".to_string(),
-            drawing: vec![],
+            head: DRAWINGS_HEAD.to_string(),
+            tail: DRAWINGS_TAIL.to_string(),
+            anchors: vec![],
         }
     }
 }
 
 impl Drawings {
+    pub(crate) fn from_xml(xml: &str) -> Option<Drawings> {
+        let (head, children, tail) = split_children(xml)?;
+        let anchors = children.into_iter()
+            .map(|(tag, raw)| Anchor::from_raw(tag, raw))
+            .collect();
+        Some(Drawings { head, tail, anchors })
+    }
+
+    pub(crate) fn from_zip_file(archive: &mut ZipArchive<File>, path: &str) -> Option<Drawings> {
+        let mut xml = String::new();
+        archive.by_name(path).ok()?.read_to_string(&mut xml).ok()?;
+        Drawings::from_xml(&xml)
+    }
+
+    pub(crate) fn to_xml(&self) -> String {
+        let mut xml = self.head.clone();
+        self.anchors.iter().for_each(|anchor| xml.push_str(&anchor.to_xml()));
+        xml.push_str(&self.tail);
+        xml
+    }
+
     pub(crate) fn next_id(&self) -> u32 {
-        let id = 1 + self.drawing.len() as u32;
-        id
+        // The ids of the pictures, shapes and connectors, including those which are not modelled
+        let max_id = self.anchors.iter()
+            .flat_map(|anchor| {
+                let xml = anchor.to_xml();
+                xml.match_indices("cNvPr id=\"")
+                    .filter_map(|(start, pattern)| {
+                        let id = &xml[start + pattern.len()..];
+                        id[..id.find('"')?].parse().ok()
+                    })
+                    .collect::<Vec<u32>>()
+            })
+            .max()
+            .unwrap_or_default();
+        1 + cmp::max(max_id, self.anchors.len() as u32)
     }
 
-    pub(crate) fn add_drawing<L: LocationRange>(&mut self, from_to: L, r_id: u32) {
-        self.drawing.push(Drawing::new(from_to, r_id));
+    pub(crate) fn add_drawing<L: LocationRange>(&mut self, from_to: L, r_id: u32) -> &mut Drawing {
+        let id = self.next_id();
+        self.push(Drawing::new(from_to, Picture::new(id, r_id)))
+    }
+
+    pub(crate) fn add_one_cell_drawing<L: Location>(&mut self, loc: L, offset: (u32, u32), size: (u32, u32), r_id: u32) -> &mut Drawing {
+        let id = self.next_id();
+        self.push(Drawing::one_cell(loc, offset, size, Picture::new(id, r_id)))
+    }
+
+    pub(crate) fn add_absolute_drawing(&mut self, position: (u32, u32), size: (u32, u32), r_id: u32) -> &mut Drawing {
+        let id = self.next_id();
+        self.push(Drawing::absolute(position, size, Picture::new(id, r_id)))
+    }
+
+    fn push(&mut self, drawing: Drawing) -> &mut Drawing {
+        self.anchors.push(Anchor::new(drawing));
+        self.anchors.last_mut().and_then(|anchor| anchor.drawing.as_mut()).unwrap()
+    }
+
+    pub(crate) fn add_shape<L: LocationRange>(&mut self, from_to: L, shape: &ApiShape) {
+        let id = self.next_id();
+        self.push(Drawing::shape(from_to, shape, id));
+    }
+
+    ///
+    /// The anchors which hold a picture, in the order of the drawing part.
+    ///
+    pub(crate) fn pictures(&self) -> Vec<&Drawing> {
+        self.anchors.iter()
+            .filter_map(|anchor| anchor.drawing.as_ref())
+            .filter(|drawing| drawing.pic.is_some())
+            .collect()
+    }
+
+    fn picture_position(&self, index: usize) -> Option<usize> {
+        self.anchors.iter()
+            .enumerate()
+            .filter(|(_, anchor)| anchor.drawing.as_ref().is_some_and(|drawing| drawing.pic.is_some()))
+            .nth(index)
+            .map(|(position, _)| position)
+    }
+
+    ///
+    /// Whether any anchor refers to the relationship.
+    ///
+    pub(crate) fn refers(&self, r_id: u32) -> bool {
+        self.anchors.iter().any(|anchor| anchor.r_ids().contains(&r_id))
+    }
+
+    ///
+    /// Point the picture at another image relationship, returns the old relationship id.
+    ///
+    pub(crate) fn replace_picture(&mut self, index: usize, r_id: u32) -> Option<u32> {
+        let position = self.picture_position(index)?;
+        self.anchors[position].replace_embed(r_id)
+    }
+
+    ///
+    /// Remove the picture, returns the relationship ids which no remaining anchor refers to.
+    ///
+    pub(crate) fn remove_picture(&mut self, index: usize) -> Option<Vec<u32>> {
+        let position = self.picture_position(index)?;
+        let anchor = self.anchors.remove(position);
+        let mut r_ids: Vec<u32> = anchor.r_ids().into_iter()
+            .filter(|&r_id| !self.refers(r_id))
+            .collect();
+        r_ids.sort();
+        Some(r_ids)
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+///
+/// One of `twoCellAnchor`, `oneCellAnchor` and `absoluteAnchor`.
+///
+#[derive(Debug, Clone)]
+pub(crate) struct Anchor {
+    // The local name of the anchor element
+    tag: String,
+    // The original xml of the anchor, `None` if the anchor is new or modified
+    raw: Option<String>,
+    pub(crate) drawing: Option<Drawing>,
+}
+
+impl Anchor {
+    fn new(drawing: Drawing) -> Anchor {
+        Anchor {
+            tag: drawing.tag().to_string(),
+            raw: None,
+            drawing: Some(drawing),
+        }
+    }
+
+    fn from_raw(tag: String, raw: String) -> Anchor {
+        let drawing = de::from_str(&raw).ok();
+        Anchor { tag, raw: Some(raw), drawing }
+    }
+
+    ///
+    /// The relationship ids referred by the anchor, e.g. the embedded image or the hyperlink.
+    ///
+    fn r_ids(&self) -> HashSet<u32> {
+        let xml = self.to_xml();
+        xml.match_indices("\"rId")
+            .filter_map(|(start, _)| {
+                let id = &xml[start + 4..];
+                id[..id.find('"')?].parse().ok()
+            })
+            .collect()
+    }
+
+    fn replace_embed(&mut self, r_id: u32) -> Option<u32> {
+        let blip = &mut self.drawing.as_mut()?.pic.as_mut()?.blip_fill.blip;
+        let old_r_id = blip.r_embed.get_id();
+        blip.r_embed = Rel::from_id(r_id);
+        // Only the embedded image is changed, so the rest of the original xml is kept
+        if let Some(raw) = &mut self.raw {
+            *raw = raw.replace(&format!("embed=\"rId{old_r_id}\""), &format!("embed=\"rId{r_id}\""));
+        }
+        Some(old_r_id)
+    }
+
+    fn to_xml(&self) -> String {
+        match (&self.raw, &self.drawing) {
+            (Some(raw), _) => raw.clone(),
+            (None, Some(drawing)) => se::to_string_with_root(&format!("xdr:{}", self.tag), drawing).unwrap_or_default(),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+// The xml before the children, the children with their local names and the xml after them
+type SplitXml = (String, Vec<(String, String)>, String);
+
+///
+/// Split the top level children of the root element.
+///
+fn split_children(xml: &str) -> Option<SplitXml> {
+    let mut reader = Reader::from_str(xml);
+    let mut depth = 0;
+    let mut head_end = None;
+    let mut child_start = 0;
+    let mut child_tag = String::new();
+    let mut children = vec![];
+    loop {
+        let position = reader.buffer_position();
+        match reader.read_event().ok()? {
+            Event::Start(e) => {
+                if depth == 1 {
+                    child_start = position;
+                    child_tag = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                }
+                depth += 1;
+                if depth == 1 {
+                    head_end = Some(reader.buffer_position());
+                }
+            }
+            Event::Empty(e) if depth == 1 => {
+                let tag = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                children.push((tag, xml[position..reader.buffer_position()].to_string()));
+            }
+            Event::End(_) => {
+                depth -= 1;
+                if depth == 1 {
+                    children.push((child_tag.clone(), xml[child_start..reader.buffer_position()].to_string()));
+                } else if depth == 0 {
+                    let head_end = head_end?;
+                    return Some((xml[..head_end].to_string(), children, xml[position..].to_string()));
+                }
+            }
+            Event::Eof => return None,
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub(crate) struct Drawing {
     #[serde(rename = "@editAs", default, skip_serializing_if = "String::is_empty")]
     edit_as: String,
-    #[serde(rename(serialize = "xdr:from", deserialize = "from"))]
-    from: DrawingLocation,
-    #[serde(rename(serialize = "xdr:to", deserialize = "to"))]
-    to: DrawingLocation,
-    #[serde(rename(serialize = "xdr:pic", deserialize = "pic"))]
-    pic: Picture,
-    #[serde(rename(serialize = "xdr:clientData", deserialize = "clientData"))]
+    #[serde(rename(serialize = "xdr:from", deserialize = "from"), default, skip_serializing_if = "Option::is_none")]
+    from: Option<DrawingLocation>,
+    #[serde(rename(serialize = "xdr:to", deserialize = "to"), default, skip_serializing_if = "Option::is_none")]
+    to: Option<DrawingLocation>,
+    #[serde(rename(serialize = "xdr:pos", deserialize = "pos"), default, skip_serializing_if = "Option::is_none")]
+    pos: Option<Position>,
+    #[serde(rename(serialize = "xdr:ext", deserialize = "ext"), default, skip_serializing_if = "Option::is_none")]
+    ext: Option<Extent>,
+    #[serde(rename(serialize = "xdr:pic", deserialize = "pic"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) pic: Option<Picture>,
+    #[serde(rename(serialize = "xdr:sp", deserialize = "sp"), default, skip_serializing_if = "Option::is_none")]
+    sp: Option<Shape>,
+    #[serde(rename(serialize = "xdr:cxnSp", deserialize = "cxnSp"), default, skip_serializing_if = "Option::is_none")]
+    cxn_sp: Option<Connector>,
+    #[serde(rename(serialize = "xdr:clientData", deserialize = "clientData"), default)]
     client_data: ClientData,
 }
 
 impl Drawing {
-    fn new<L: LocationRange>(from_to: L, r_id: u32) -> Drawing {
+    fn new<L: LocationRange>(from_to: L, pic: Picture) -> Drawing {
         let (from_row, from_col, to_row, to_col) = from_to.to_range();
         Drawing {
             edit_as: String::from("oneCell"),
-            from: DrawingLocation::from_location((from_row, from_col)),
-            to: DrawingLocation::from_location((to_row, to_col)),
-            pic: Picture::from_id(r_id),
-            client_data: ClientData::default(),
+            from: Some(DrawingLocation::from_location((from_row, from_col))),
+            to: Some(DrawingLocation::from_location((to_row, to_col))),
+            pic: Some(pic),
+            ..Default::default()
+        }
+    }
+
+    fn shape<L: LocationRange>(from_to: L, shape: &ApiShape, id: u32) -> Drawing {
+        let (from_row, from_col, to_row, to_col) = from_to.to_range();
+        let (sp, cxn_sp) = match shape.shape_type.is_connector() {
+            true => (None, Some(Connector::from_shape(shape, id))),
+            false => (Some(Shape::from_shape(shape, id)), None),
+        };
+        Drawing {
+            from: Some(DrawingLocation::from_location((from_row, from_col))),
+            to: Some(DrawingLocation::from_location((to_row, to_col))),
+            sp,
+            cxn_sp,
+            ..Default::default()
+        }
+    }
+
+    fn one_cell<L: Location>(loc: L, (col_off, row_off): (u32, u32), (cx, cy): (u32, u32), pic: Picture) -> Drawing {
+        let mut from = DrawingLocation::from_location(loc);
+        from.col_off = col_off;
+        from.row_off = row_off;
+        Drawing {
+            from: Some(from),
+            ext: Some(Extent { cx, cy }),
+            pic: Some(pic.with_extent(cx, cy)),
+            ..Default::default()
+        }
+    }
+
+    fn absolute((x, y): (u32, u32), (cx, cy): (u32, u32), pic: Picture) -> Drawing {
+        Drawing {
+            pos: Some(Position { x, y }),
+            ext: Some(Extent { cx, cy }),
+            pic: Some(pic.with_extent(cx, cy)),
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Apply the alt text, the placement and the lock and print properties of the options to a new picture,
+    /// the hyperlink relationship is added by the caller.
+    ///
+    pub(crate) fn set_options(&mut self, options: &ImageOptions, hyperlink_r_id: Option<u32>) {
+        if self.to.is_some() {
+            self.edit_as = options.edit_as.to_str().to_string();
+        }
+        self.client_data.locks_with_sheet = (!options.locks_with_sheet).then_some(0);
+        self.client_data.prints_with_sheet = (!options.prints_with_sheet).then_some(0);
+        if let Some(pic) = &mut self.pic {
+            let c_nv_pr = &mut pic.pic_pr.c_nv_pr;
+            c_nv_pr.descr = options.description.clone();
+            c_nv_pr.title = options.title.clone();
+            c_nv_pr.hlink_click = hyperlink_r_id.map(HlinkClick::from_id);
+        }
+    }
+
+    pub(crate) fn picture_r_id(&self) -> Option<u32> {
+        Some(self.pic.as_ref()?.blip_fill.blip.r_embed.get_id())
+    }
+
+    pub(crate) fn name(&self) -> Option<&str> {
+        Some(&self.pic.as_ref()?.pic_pr.c_nv_pr.name)
+    }
+
+    pub(crate) fn description(&self) -> Option<&str> {
+        self.pic.as_ref()?.pic_pr.c_nv_pr.descr.as_deref()
+    }
+
+    ///
+    /// The top left cell of the anchor as (row, col), `None` for an absolute anchor.
+    ///
+    pub(crate) fn top_left_cell(&self) -> Option<(u32, u32)> {
+        self.from.as_ref().map(DrawingLocation::to_location)
+    }
+
+    ///
+    /// The bottom right cell of a two cell anchor as (row, col).
+    ///
+    pub(crate) fn bottom_right_cell(&self) -> Option<(u32, u32)> {
+        self.to.as_ref().map(DrawingLocation::to_location)
+    }
+
+    fn tag(&self) -> &str {
+        match (&self.from, &self.to) {
+            (Some(_), Some(_)) => "twoCellAnchor",
+            (Some(_), None) => "oneCellAnchor",
+            _ => "absoluteAnchor",
         }
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct Position {
+    #[serde(rename = "@x")]
+    x: u32,
+    #[serde(rename = "@y")]
+    y: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct Extent {
+    #[serde(rename = "@cx")]
+    cx: u32,
+    #[serde(rename = "@cy")]
+    cy: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 struct DrawingLocation {
     #[serde(rename(serialize = "xdr:col", deserialize = "col"))]
     col: u32,
@@ -90,33 +422,42 @@ impl DrawingLocation {
             row_off: 0,
         }
     }
+
+    fn to_location(&self) -> (u32, u32) {
+        (self.row + 1, self.col + 1)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
-struct Picture {
+pub(crate) struct Picture {
     #[serde(rename(serialize = "xdr:nvPicPr", deserialize = "nvPicPr"))]
     pic_pr: PicPr,
     #[serde(rename(serialize = "xdr:blipFill", deserialize = "blipFill"))]
     blip_fill: BlipFill,
-    #[serde(rename(serialize = "xdr:spPr", deserialize = "spPr"))]
+    #[serde(rename(serialize = "xdr:spPr", deserialize = "spPr"), default)]
     sp_pr: SpPr,
 }
 
 impl Picture {
-    fn from_id(r_id: u32) -> Self {
+    fn new(id: u32, r_id: u32) -> Self {
         Self {
-            pic_pr: PicPr::from_id(r_id),
+            pic_pr: PicPr::from_id(id),
             blip_fill: BlipFill::from_id(r_id),
             sp_pr: SpPr::default(),
         }
     }
+
+    fn with_extent(mut self, cx: u32, cy: u32) -> Self {
+        self.sp_pr.xfrm = Some(Xfrm { off: Offset::default(), ext: Extent { cx, cy } });
+        self
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 struct PicPr {
     #[serde(rename(serialize = "xdr:cNvPr", deserialize = "cNvPr"))]
     c_nv_pr: CNvPr,
-    #[serde(rename(serialize = "xdr:cNvPicPr", deserialize = "cNvPicPr"))]
+    #[serde(rename(serialize = "xdr:cNvPicPr", deserialize = "cNvPicPr"), default)]
     c_nv_pic_pr: CNvPicPr,
 }
 
@@ -135,6 +476,29 @@ struct CNvPr {
     id: String,
     #[serde(rename = "@name")]
     name: String,
+    #[serde(rename = "@descr", default, skip_serializing_if = "Option::is_none")]
+    descr: Option<String>,
+    #[serde(rename = "@title", default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(rename(serialize = "a:hlinkClick", deserialize = "hlinkClick"), default, skip_serializing_if = "Option::is_none")]
+    hlink_click: Option<HlinkClick>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct HlinkClick {
+    #[serde(rename(serialize = "@xmlns:r", deserialize = "@xmlns:r"), default, skip_serializing_if = "String::is_empty")]
+    xmlns_r: String,
+    #[serde(rename(serialize = "@r:id", deserialize = "@id"))]
+    r_id: Rel,
+}
+
+impl HlinkClick {
+    fn from_id(r_id: u32) -> HlinkClick {
+        HlinkClick {
+            xmlns_r: "http://schemas.openxmlformats.org/officeDocument/2006/relationships".to_string(),
+            r_id: Rel::from_id(r_id),
+        }
+    }
 }
 
 impl CNvPr {
@@ -142,19 +506,22 @@ impl CNvPr {
         Self {
             id: id.to_string(),
             name: format!("Picture {id}"),
+            descr: None,
+            title: None,
+            hlink_click: None,
         }
     }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 struct CNvPicPr {
-    #[serde(rename(serialize = "a:picLocks", deserialize = "picLocks"))]
+    #[serde(rename(serialize = "a:picLocks", deserialize = "picLocks"), default)]
     pic_locks: PicLocks,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 struct PicLocks {
-    #[serde(rename = "@noChangeAspect")]
+    #[serde(rename = "@noChangeAspect", default)]
     no_change_aspect: u8,
 }
 
@@ -170,7 +537,7 @@ impl Default for PicLocks {
 struct BlipFill {
     #[serde(rename(serialize = "a:blip", deserialize = "blip"))]
     blip: Blip,
-    #[serde(rename(serialize = "a:stretch", deserialize = "stretch"))]
+    #[serde(rename(serialize = "a:stretch", deserialize = "stretch"), default)]
     stretch: Stretch,
 }
 
@@ -211,7 +578,7 @@ impl Blip {
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 struct Stretch {
-    #[serde(rename(serialize = "a:fillRect", deserialize = "fillRect"))]
+    #[serde(rename(serialize = "a:fillRect", deserialize = "fillRect"), default)]
     fill_rect: FillRect,
 }
 
@@ -220,13 +587,31 @@ struct FillRect {}
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 struct SpPr {
-    #[serde(rename(serialize = "a:prstGeom", deserialize = "prstGeom"))]
+    #[serde(rename(serialize = "a:xfrm", deserialize = "xfrm"), default, skip_serializing_if = "Option::is_none")]
+    xfrm: Option<Xfrm>,
+    #[serde(rename(serialize = "a:prstGeom", deserialize = "prstGeom"), default)]
     prst_geom: PrstGeom,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct Xfrm {
+    #[serde(rename(serialize = "a:off", deserialize = "off"), default)]
+    off: Offset,
+    #[serde(rename(serialize = "a:ext", deserialize = "ext"), default)]
+    ext: Extent,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct Offset {
+    #[serde(rename = "@x")]
+    x: u32,
+    #[serde(rename = "@y")]
+    y: u32,
+}
+
 #[derive(Debug, Clone ,Deserialize, Serialize)]
 struct PrstGeom {
-    #[serde(rename(serialize = "a:avLst", deserialize = "avLst"))]
+    #[serde(rename(serialize = "a:avLst", deserialize = "avLst"), default)]
     av_lst: AvLst,
     #[serde(rename(serialize = "@prst", deserialize = "@prst"))]
     prst: String,
@@ -245,20 +630,16 @@ impl Default for PrstGeom {
 struct AvLst {}
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
-struct ClientData {}
+struct ClientData {
+    #[serde(rename = "@fLocksWithSheet", default, skip_serializing_if = "Option::is_none")]
+    locks_with_sheet: Option<u8>,
+    #[serde(rename = "@fPrintsWithSheet", default, skip_serializing_if = "Option::is_none")]
+    prints_with_sheet: Option<u8>,
+}
 
 impl Drawings {
     pub(crate) fn save<P: AsRef<Path>>(&self, file_path: P, drawing_id: u32) {
-        let xml = se::to_string_with_root("xdr:wsDr", &self).unwrap();
-        let mut xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n{}", xml);
-        let mut content = String::new();
-        if let Ok(_) = XlsxFileWriter::read_from(&file_path, XlsxFileType::Drawings(drawing_id), &mut content) {
-            if content.len() > 0 {
-                self.insert(&mut content);
-                xml = content.clone();
-            }
-        }
         let mut file = XlsxFileWriter::from_path(file_path, XlsxFileType::Drawings(drawing_id)).unwrap();
-        file.write_all(xml.as_ref()).unwrap();
+        file.write_all(self.to_xml().as_ref()).unwrap();
     }
-}
\ No newline at end of file
+}
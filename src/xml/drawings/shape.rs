@@ -0,0 +1,398 @@
+use serde::{Deserialize, Serialize};
+use crate::{FormatAlignHorizontal, FormatAlignVertical, FormatColor, FormatFont, FormatUnderlineType, Shape as ApiShape, ShapeType, Theme};
+use crate::xml::drawings::{CNvPr, PrstGeom};
+
+// A point is 12700 EMUs
+const EMU_PER_POINT: f64 = 12700.0;
+const THEME_COLORS: [&str; 12] = ["lt1", "dk1", "lt2", "dk2", "accent1", "accent2", "accent3", "accent4", "accent5", "accent6", "hlink", "folHlink"];
+
+///
+/// The `xdr:sp` element, a shape with an optional text body.
+///
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub(crate) struct Shape {
+    #[serde(rename = "@macro", default)]
+    macro_name: String,
+    #[serde(rename = "@textlink", default)]
+    text_link: String,
+    #[serde(rename(serialize = "xdr:nvSpPr", deserialize = "nvSpPr"))]
+    nv_sp_pr: NvSpPr,
+    #[serde(rename(serialize = "xdr:spPr", deserialize = "spPr"), default)]
+    sp_pr: ShapeProperties,
+    #[serde(rename(serialize = "xdr:txBody", deserialize = "txBody"), default, skip_serializing_if = "Option::is_none")]
+    tx_body: Option<TextBody>,
+}
+
+impl Shape {
+    pub(crate) fn from_shape(shape: &ApiShape, id: u32) -> Shape {
+        let text_box = shape.shape_type == ShapeType::TextBox;
+        Shape {
+            macro_name: String::new(),
+            text_link: String::new(),
+            nv_sp_pr: NvSpPr {
+                c_nv_pr: shape_c_nv_pr(shape, id, if text_box { "TextBox" } else { "Shape" }),
+                c_nv_sp_pr: CNvSpPr { tx_box: text_box.then_some(1) },
+            },
+            sp_pr: ShapeProperties::from_shape(shape),
+            tx_body: TextBody::from_shape(shape),
+        }
+    }
+}
+
+///
+/// The `xdr:cxnSp` element, a line connecting two points.
+///
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub(crate) struct Connector {
+    #[serde(rename = "@macro", default)]
+    macro_name: String,
+    #[serde(rename(serialize = "xdr:nvCxnSpPr", deserialize = "nvCxnSpPr"))]
+    nv_cxn_sp_pr: NvCxnSpPr,
+    #[serde(rename(serialize = "xdr:spPr", deserialize = "spPr"), default)]
+    sp_pr: ShapeProperties,
+}
+
+impl Connector {
+    pub(crate) fn from_shape(shape: &ApiShape, id: u32) -> Connector {
+        Connector {
+            macro_name: String::new(),
+            nv_cxn_sp_pr: NvCxnSpPr {
+                c_nv_pr: shape_c_nv_pr(shape, id, "Connector"),
+                c_nv_cxn_sp_pr: Empty {},
+            },
+            sp_pr: ShapeProperties::from_shape(shape),
+        }
+    }
+}
+
+fn shape_c_nv_pr(shape: &ApiShape, id: u32, kind: &str) -> CNvPr {
+    let mut c_nv_pr = CNvPr::from_id(id);
+    c_nv_pr.name = shape.name.clone().unwrap_or(format!("{kind} {id}"));
+    c_nv_pr
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct NvSpPr {
+    #[serde(rename(serialize = "xdr:cNvPr", deserialize = "cNvPr"))]
+    c_nv_pr: CNvPr,
+    #[serde(rename(serialize = "xdr:cNvSpPr", deserialize = "cNvSpPr"), default)]
+    c_nv_sp_pr: CNvSpPr,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct CNvSpPr {
+    #[serde(rename = "@txBox", default, skip_serializing_if = "Option::is_none")]
+    tx_box: Option<u8>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct NvCxnSpPr {
+    #[serde(rename(serialize = "xdr:cNvPr", deserialize = "cNvPr"))]
+    c_nv_pr: CNvPr,
+    #[serde(rename(serialize = "xdr:cNvCxnSpPr", deserialize = "cNvCxnSpPr"), default)]
+    c_nv_cxn_sp_pr: Empty,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct Empty {}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct ShapeProperties {
+    #[serde(rename(serialize = "a:prstGeom", deserialize = "prstGeom"), default)]
+    prst_geom: PrstGeom,
+    #[serde(rename(serialize = "a:noFill", deserialize = "noFill"), default, skip_serializing_if = "Option::is_none")]
+    no_fill: Option<Empty>,
+    #[serde(rename(serialize = "a:solidFill", deserialize = "solidFill"), default, skip_serializing_if = "Option::is_none")]
+    solid_fill: Option<SolidFill>,
+    #[serde(rename(serialize = "a:ln", deserialize = "ln"), default, skip_serializing_if = "Option::is_none")]
+    ln: Option<Line>,
+}
+
+impl ShapeProperties {
+    fn from_shape(shape: &ApiShape) -> ShapeProperties {
+        let (default_fill, default_line) = match shape.shape_type {
+            ShapeType::TextBox => (SolidFill::scheme("lt1", None, None), SolidFill::rgb(0xBF, 0xBF, 0xBF)),
+            // The line of the default shape style is the accent color, darker 50%
+            _ => (SolidFill::scheme("accent1", None, None), SolidFill::scheme("accent1", Some(50000), None)),
+        };
+        let fill = match shape.fill_color {
+            Some(color) => SolidFill::from_color(&color),
+            None => Some(default_fill),
+        };
+        let line = match shape.line_color {
+            Some(color) => SolidFill::from_color(&color),
+            None => Some(default_line),
+        };
+        let is_connector = shape.shape_type.is_connector();
+        ShapeProperties {
+            prst_geom: PrstGeom { prst: shape.shape_type.to_str().to_string(), ..Default::default() },
+            // A connector is never filled
+            no_fill: (fill.is_none() && !is_connector).then_some(Empty {}),
+            solid_fill: fill.filter(|_| !is_connector),
+            ln: Some(Line {
+                w: Some((shape.line_width.unwrap_or(1.0) * EMU_PER_POINT).round() as u32),
+                no_fill: line.is_none().then_some(Empty {}),
+                solid_fill: line,
+                tail_end: shape.end_arrow.then(|| LineEnd { end_type: "triangle".to_string() }),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct Line {
+    #[serde(rename = "@w", default, skip_serializing_if = "Option::is_none")]
+    w: Option<u32>,
+    #[serde(rename(serialize = "a:noFill", deserialize = "noFill"), default, skip_serializing_if = "Option::is_none")]
+    no_fill: Option<Empty>,
+    #[serde(rename(serialize = "a:solidFill", deserialize = "solidFill"), default, skip_serializing_if = "Option::is_none")]
+    solid_fill: Option<SolidFill>,
+    #[serde(rename(serialize = "a:tailEnd", deserialize = "tailEnd"), default, skip_serializing_if = "Option::is_none")]
+    tail_end: Option<LineEnd>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct LineEnd {
+    #[serde(rename = "@type")]
+    end_type: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct SolidFill {
+    #[serde(rename(serialize = "a:srgbClr", deserialize = "srgbClr"), default, skip_serializing_if = "Option::is_none")]
+    srgb_clr: Option<Val<String>>,
+    #[serde(rename(serialize = "a:schemeClr", deserialize = "schemeClr"), default, skip_serializing_if = "Option::is_none")]
+    scheme_clr: Option<SchemeClr>,
+}
+
+impl SolidFill {
+    ///
+    /// Returns `None` for [`FormatColor::Default`], which means no fill.
+    ///
+    fn from_color(color: &FormatColor) -> Option<SolidFill> {
+        match *color {
+            FormatColor::Default => None,
+            FormatColor::Theme(theme_id, tint) => {
+                let name = THEME_COLORS.get(theme_id as usize).copied().unwrap_or("accent1");
+                // The tint of a spreadsheet color is a luminance modulation and offset in DrawingML
+                let (lum_mod, lum_off) = match tint {
+                    tint if tint > 0.0 => (Some(((1.0 - tint) * 100000.0).round() as u32), Some((tint * 100000.0).round() as u32)),
+                    tint if tint < 0.0 => (Some(((1.0 + tint) * 100000.0).round() as u32), None),
+                    _ => (None, None),
+                };
+                Some(SolidFill::scheme(name, lum_mod, lum_off))
+            }
+            color => match Theme::default().to_rgb(&color) {
+                FormatColor::RGB(r, g, b) => Some(SolidFill::rgb(r, g, b)),
+                _ => None,
+            },
+        }
+    }
+
+    fn rgb(r: u8, g: u8, b: u8) -> SolidFill {
+        SolidFill {
+            srgb_clr: Some(Val { val: format!("{r:02X}{g:02X}{b:02X}") }),
+            scheme_clr: None,
+        }
+    }
+
+    fn scheme(name: &str, lum_mod: Option<u32>, lum_off: Option<u32>) -> SolidFill {
+        SolidFill {
+            srgb_clr: None,
+            scheme_clr: Some(SchemeClr {
+                val: name.to_string(),
+                lum_mod: lum_mod.map(|val| Val { val }),
+                lum_off: lum_off.map(|val| Val { val }),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct SchemeClr {
+    #[serde(rename = "@val")]
+    val: String,
+    #[serde(rename(serialize = "a:lumMod", deserialize = "lumMod"), default, skip_serializing_if = "Option::is_none")]
+    lum_mod: Option<Val<u32>>,
+    #[serde(rename(serialize = "a:lumOff", deserialize = "lumOff"), default, skip_serializing_if = "Option::is_none")]
+    lum_off: Option<Val<u32>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct Val<T> {
+    #[serde(rename = "@val")]
+    val: T,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct TextBody {
+    #[serde(rename(serialize = "a:bodyPr", deserialize = "bodyPr"), default)]
+    body_pr: BodyPr,
+    #[serde(rename(serialize = "a:lstStyle", deserialize = "lstStyle"), default)]
+    lst_style: Empty,
+    #[serde(rename(serialize = "a:p", deserialize = "p"), default)]
+    p: Vec<Paragraph>,
+}
+
+impl TextBody {
+    fn from_shape(shape: &ApiShape) -> Option<TextBody> {
+        if shape.shape_type.is_connector() {
+            return None;
+        }
+        let algn = shape.align_horizontal.map(|align| match align {
+            FormatAlignHorizontal::Center | FormatAlignHorizontal::CenterContinuous => "ctr",
+            FormatAlignHorizontal::Right => "r",
+            FormatAlignHorizontal::Justify => "just",
+            FormatAlignHorizontal::Distributed => "dist",
+            _ => "l",
+        }.to_string());
+        let anchor = shape.align_vertical.map(|align| match align {
+            FormatAlignVertical::Top => "t",
+            FormatAlignVertical::Center => "ctr",
+            FormatAlignVertical::Justify => "just",
+            FormatAlignVertical::Distributed => "dist",
+            FormatAlignVertical::Bottom => "b",
+        }.to_string());
+        // Every line break in the words starts a new paragraph
+        let mut paragraphs = vec![Paragraph::new(&algn)];
+        for word in &shape.text.words {
+            for (i, text) in word.text.split('\n').enumerate() {
+                if i > 0 {
+                    paragraphs.push(Paragraph::new(&algn));
+                }
+                if !text.is_empty() {
+                    paragraphs.last_mut().unwrap().r.push(Run::new(text, word.font.as_ref()));
+                }
+            }
+        }
+        Some(TextBody {
+            body_pr: BodyPr { anchor, ..Default::default() },
+            lst_style: Empty {},
+            p: paragraphs,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct BodyPr {
+    #[serde(rename = "@vertOverflow", default, skip_serializing_if = "Option::is_none")]
+    vert_overflow: Option<String>,
+    #[serde(rename = "@horzOverflow", default, skip_serializing_if = "Option::is_none")]
+    horz_overflow: Option<String>,
+    #[serde(rename = "@rtlCol", default, skip_serializing_if = "Option::is_none")]
+    rtl_col: Option<u8>,
+    #[serde(rename = "@anchor", default, skip_serializing_if = "Option::is_none")]
+    anchor: Option<String>,
+}
+
+impl Default for BodyPr {
+    fn default() -> Self {
+        Self {
+            vert_overflow: Some("clip".to_string()),
+            horz_overflow: Some("clip".to_string()),
+            rtl_col: Some(0),
+            anchor: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct Paragraph {
+    #[serde(rename(serialize = "a:pPr", deserialize = "pPr"), default, skip_serializing_if = "Option::is_none")]
+    p_pr: Option<ParagraphProperties>,
+    #[serde(rename(serialize = "a:r", deserialize = "r"), default)]
+    r: Vec<Run>,
+}
+
+impl Paragraph {
+    fn new(algn: &Option<String>) -> Paragraph {
+        Paragraph {
+            p_pr: algn.clone().map(|algn| ParagraphProperties { algn }),
+            r: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct ParagraphProperties {
+    #[serde(rename = "@algn")]
+    algn: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct Run {
+    #[serde(rename(serialize = "a:rPr", deserialize = "rPr"), default)]
+    r_pr: RunProperties,
+    #[serde(rename(serialize = "a:t", deserialize = "t"), default)]
+    t: String,
+}
+
+impl Run {
+    fn new(text: &str, font: Option<&FormatFont>) -> Run {
+        Run {
+            r_pr: font.map(RunProperties::from_font).unwrap_or_default(),
+            t: text.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct RunProperties {
+    #[serde(rename = "@lang", default)]
+    lang: String,
+    #[serde(rename = "@sz", default, skip_serializing_if = "Option::is_none")]
+    sz: Option<u32>,
+    #[serde(rename = "@b", default, skip_serializing_if = "Option::is_none")]
+    b: Option<u8>,
+    #[serde(rename = "@i", default, skip_serializing_if = "Option::is_none")]
+    i: Option<u8>,
+    #[serde(rename = "@u", default, skip_serializing_if = "Option::is_none")]
+    u: Option<String>,
+    #[serde(rename = "@strike", default, skip_serializing_if = "Option::is_none")]
+    strike: Option<String>,
+    #[serde(rename(serialize = "a:solidFill", deserialize = "solidFill"), default, skip_serializing_if = "Option::is_none")]
+    solid_fill: Option<SolidFill>,
+    #[serde(rename(serialize = "a:latin", deserialize = "latin"), default, skip_serializing_if = "Option::is_none")]
+    latin: Option<Latin>,
+}
+
+impl Default for RunProperties {
+    fn default() -> Self {
+        Self {
+            lang: "en-US".to_string(),
+            sz: None,
+            b: None,
+            i: None,
+            u: None,
+            strike: None,
+            solid_fill: None,
+            latin: None,
+        }
+    }
+}
+
+impl RunProperties {
+    fn from_font(font: &FormatFont) -> RunProperties {
+        RunProperties {
+            // The font size is in hundredths of a point
+            sz: Some((font.size * 100.0).round() as u32),
+            b: font.bold.then_some(1),
+            i: font.italic.then_some(1),
+            u: match font.underline {
+                FormatUnderlineType::None => None,
+                FormatUnderlineType::Double | FormatUnderlineType::DoubleAccounting => Some("dbl".to_string()),
+                _ => Some("sng".to_string()),
+            },
+            strike: font.strike.then(|| "sngStrike".to_string()),
+            solid_fill: SolidFill::from_color(&font.color),
+            latin: Some(Latin { typeface: font.name.clone() }),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct Latin {
+    #[serde(rename = "@typeface")]
+    typeface: String,
+}
@@ -1,40 +1,294 @@
-use std::io;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
 use std::path::Path;
-use quick_xml::{de, se};
-use serde::{Deserialize, Serialize};
+use quick_xml::se;
+use zip::ZipArchive;
 use crate::file::{XlsxFileType, XlsxFileWriter};
+use crate::xml::namespaces::excel as x;
 use crate::xml::namespaces::office as o;
+use crate::utils::range_util;
 use crate::xml::namespaces::vml as v;
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+///
+/// A vml drawing part, which holds the shapes of the comments and the form controls.
+///
+/// The shapes of a loaded part are kept as they were read, the new shapes are added before its end.
+///
+#[derive(Debug, Clone)]
 pub(crate) struct VmlDrawing {
-    #[serde(rename(serialize = "@xmlns:v", deserialize = "@xmlns:v"))]
-    xmlns_v: String,
-    #[serde(rename(serialize = "@xmlns:o", deserialize = "@xmlns:o"))]
-    xmlns_o: String,
-    #[serde(rename(serialize = "@xmlns:x", deserialize = "@xmlns:x"))]
-    xmlns_x: String,
-    #[serde(rename(serialize = "o:shapelayout", deserialize = "shapelayout"))]
-    shapelayout: o::ShapeLayout,
-    #[serde(rename(serialize = "v:shapetype", deserialize = "shapetype"))]
-    shapetype: v::ShapeType,
-    #[serde(rename(serialize = "v:shape", deserialize = "shape"))]
-    shape: v::Shape,
+    raw: Option<String>,
+    shape_layout: o::ShapeLayout,
+    shape_types: Vec<v::ShapeType>,
+    shapes: Vec<v::Shape>,
+    // The shapes and the shape types copied from other parts, as they were read
+    copied_shapes: Vec<String>,
+    next_shape_id: u32,
 }
 
 impl VmlDrawing {
-    // pub(crate) fn from_path<P: AsRef<Path>>(file_path: P, drawing_id: u32) -> io::Result<VmlDrawing> {
-    //     let mut file = XlsxFileReader::from_path(file_path, XlsxFileType::VmlDrawings(drawing_id))?;
-    //     let mut xml = String::new();
-    //     file.read_to_string(&mut xml).unwrap();
-    //     let drawing: VmlDrawing = de::from_str(&xml).unwrap();
-    //     Ok(drawing)
-    // }
+    ///
+    /// A new part whose shape ids start from `1024 * id + 1`, like the parts saved by Excel.
+    ///
+    pub(crate) fn new(id: u32) -> VmlDrawing {
+        VmlDrawing {
+            raw: None,
+            shape_layout: o::ShapeLayout::new(id),
+            shape_types: vec![],
+            shapes: vec![],
+            copied_shapes: vec![],
+            next_shape_id: 1024 * id + 1,
+        }
+    }
+
+    pub(crate) fn from_zip_file(archive: &mut ZipArchive<File>, path: &str, id: u32) -> Option<VmlDrawing> {
+        let mut file = archive.by_name(path).ok()?;
+        let mut raw = String::new();
+        file.read_to_string(&mut raw).ok()?;
+        let mut drawing = VmlDrawing::new(id);
+        if let Some(max_shape_id) = max_shape_id(&raw) {
+            drawing.next_shape_id = drawing.next_shape_id.max(max_shape_id + 1);
+        }
+        drawing.raw = Some(raw);
+        Some(drawing)
+    }
+
+    ///
+    /// Add a form control shape and return its shape id.
+    ///
+    pub(crate) fn add_form_control(&mut self, style: &str, text: Option<&str>, button: bool, client_data: x::ClientData) -> u32 {
+        let shape_type = v::ShapeType::form_control();
+        if !self.has_shape_type(shape_type.id()) {
+            self.shape_types.push(shape_type);
+        }
+        let shape_id = self.next_shape_id;
+        self.next_shape_id += 1;
+        self.shapes.push(v::Shape::form_control(shape_id, style, text, button, client_data));
+        shape_id
+    }
+
+    ///
+    /// Move the shapes to the ids starting from `1024 * id + 1`, so that a copy of the part no longer
+    /// shares its shape ids with the original, and return the new shape ids by the old ones.
+    ///
+    pub(crate) fn renumber(&mut self, id: u32) -> HashMap<u32, u32> {
+        let mut old_ids: Vec<u32> = self.raw.as_deref().map(shape_ids).unwrap_or_default();
+        old_ids.extend(self.shapes.iter().filter_map(v::Shape::get_shape_id));
+        old_ids.extend(self.copied_shapes.iter().flat_map(|shape| shape_ids(shape)));
+        let mut shape_ids = HashMap::new();
+        for old_id in old_ids {
+            let new_id = 1024 * id + 1 + shape_ids.len() as u32;
+            shape_ids.entry(old_id).or_insert(new_id);
+        }
+        if let Some(raw) = &self.raw {
+            self.raw = Some(set_id_map_data(&set_shape_ids(raw, &shape_ids), id));
+        }
+        for shape in &mut self.copied_shapes {
+            *shape = set_shape_ids(shape, &shape_ids);
+        }
+        for shape in &mut self.shapes {
+            if let Some(new_id) = shape.get_shape_id().and_then(|old_id| shape_ids.get(&old_id)) {
+                shape.set_shape_id(*new_id);
+            }
+        }
+        self.shape_layout = o::ShapeLayout::new(id);
+        self.next_shape_id = 1024 * id + 1 + shape_ids.len() as u32;
+        shape_ids
+    }
+
+    fn has_shape_type(&self, id: &str) -> bool {
+        self.shape_types.iter().any(|shape_type| shape_type.id() == id)
+            || self.raw.as_ref().is_some_and(|raw| raw.contains(&format!("id=\"{id}\"")))
+            || self.copied_shapes.iter().any(|shape| shape.contains(&format!("id=\"{id}\"")))
+    }
+
+    ///
+    /// The shapes of the comments of the cells of the range, each with the shape type it refers to.
+    ///
+    pub(crate) fn get_notes(&self, range: (u32, u32, u32, u32)) -> Vec<((u32, u32), String, Option<String>)> {
+        let raw_shapes = self.raw.iter().flat_map(|raw| elements(raw, "v:shape"));
+        raw_shapes.chain(self.copied_shapes.iter().map(String::as_str))
+            .filter_map(|shape| {
+                let loc = note_loc(shape).filter(|&(row, col)| range_util::contains(range, (row, col, row, col)))?;
+                let shape_type = get_attribute(shape, "type").map(|shape_type| shape_type.trim_start_matches('#'))
+                    .and_then(|shape_type| self.get_shape_type(shape_type));
+                Some((loc, shape.to_string(), shape_type))
+            })
+            .collect()
+    }
+
+    fn get_shape_type(&self, id: &str) -> Option<String> {
+        self.raw.iter().flat_map(|raw| elements(raw, "v:shapetype"))
+            .chain(self.copied_shapes.iter().map(String::as_str))
+            .find(|shape_type| shape_type.starts_with("<v:shapetype") && get_attribute(shape_type, "id") == Some(id))
+            .map(str::to_string)
+    }
+
+    ///
+    /// Remove the shapes of the comments of the cells of the range.
+    ///
+    pub(crate) fn remove_notes(&mut self, range: (u32, u32, u32, u32)) {
+        let in_range = |shape: &str| note_loc(shape).is_some_and(|(row, col)| range_util::contains(range, (row, col, row, col)));
+        if let Some(raw) = &self.raw {
+            let mut kept = raw.clone();
+            for shape in elements(raw, "v:shape").filter(|shape| in_range(shape)) {
+                kept = kept.replacen(shape, "", 1);
+            }
+            self.raw = Some(kept);
+        }
+        self.copied_shapes.retain(|shape| !in_range(shape));
+    }
+
+    ///
+    /// Add a copy of the shape of a comment, moved by the offsets, and the shape type it refers to if this part has none.
+    ///
+    pub(crate) fn add_note(&mut self, shape: &str, shape_type: Option<&str>, row_offset: i64, col_offset: i64) {
+        if let Some(shape_type) = shape_type {
+            if !get_attribute(shape_type, "id").is_some_and(|id| self.has_shape_type(id)) {
+                self.copied_shapes.insert(0, shape_type.to_string());
+            }
+        }
+        let shape_id = self.next_shape_id;
+        self.next_shape_id += 1;
+        let shape = set_shape_ids(shape, &shape_ids(shape).into_iter().map(|old_id| (old_id, shape_id)).collect());
+        let shape = replace_element(&shape, "x:Anchor", |anchor| {
+            anchor.split(',')
+                .enumerate()
+                .map(|(i, value)| match (i, value.trim().parse::<i64>()) {
+                    (0 | 4, Ok(value)) => (value + col_offset).max(0).to_string(),
+                    (2 | 6, Ok(value)) => (value + row_offset).max(0).to_string(),
+                    _ => value.trim().to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        });
+        let offset = |offset: i64| move |value: &str| value.trim().parse::<i64>().map_or(value.to_string(), |value| (value + offset).to_string());
+        let shape = replace_element(&shape, "x:Row", offset(row_offset));
+        let shape = replace_element(&shape, "x:Column", offset(col_offset));
+        self.copied_shapes.push(shape);
+    }
+
+    fn to_xml(&self) -> String {
+        let mut added = String::new();
+        self.shape_types.iter()
+            .for_each(|shape_type| added.push_str(&se::to_string_with_root("v:shapetype", shape_type).unwrap()));
+        self.shapes.iter()
+            .for_each(|shape| added.push_str(&se::to_string_with_root("v:shape", shape).unwrap()));
+        self.copied_shapes.iter().for_each(|shape| added.push_str(shape));
+        match &self.raw {
+            Some(raw) => match raw.rfind("</xml>") {
+                Some(end) => format!("{}{added}{}", &raw[..end], &raw[end..]),
+                None => raw.clone(),
+            },
+            None => format!(
+                "<xml xmlns:v=\"urn:schemas-microsoft-com:vml\" xmlns:o=\"urn:schemas-microsoft-com:office:office\" xmlns:x=\"urn:schemas-microsoft-com:office:excel\">{}{added}</xml>",
+                se::to_string_with_root("o:shapelayout", &self.shape_layout).unwrap()
+            ),
+        }
+    }
 
     pub(crate) fn save<P: AsRef<Path>>(&self, file_path: P, drawing_id: u32) {
-        let xml = se::to_string_with_root("xml", &self).unwrap();
-        // let xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n{}", xml);
         let mut file = XlsxFileWriter::from_path(file_path, XlsxFileType::VmlDrawings(drawing_id)).unwrap();
-        file.write_all(xml.as_ref()).unwrap();
+        file.write_all(self.to_xml().as_ref()).unwrap();
     }
 }
+
+///
+/// The N of the shape ids `_x0000_sN` in a vml drawing, in the order they appear.
+///
+fn shape_ids(raw: &str) -> Vec<u32> {
+    raw.split("_x0000_s")
+        .skip(1)
+        .filter_map(|s| s.chars().take_while(|c| c.is_ascii_digit()).collect::<String>().parse().ok())
+        .collect()
+}
+
+///
+/// Replace the shape ids `_x0000_sN` in the xml by their new ids.
+///
+fn set_shape_ids(xml: &str, shape_ids: &HashMap<u32, u32>) -> String {
+    let mut parts = xml.split("_x0000_s");
+    let mut result = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        let digits = part.chars().take_while(|c| c.is_ascii_digit()).count();
+        let new_id = part[..digits].parse().ok().and_then(|old_id| shape_ids.get(&old_id));
+        result.push_str("_x0000_s");
+        match new_id {
+            Some(new_id) => result.push_str(&format!("{new_id}{}", &part[digits..])),
+            None => result.push_str(part),
+        }
+    }
+    result
+}
+
+///
+/// The elements `name` in the xml, like `<v:shape ...>...</v:shape>`.
+///
+fn elements<'a>(xml: &'a str, name: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut rest = xml;
+    std::iter::from_fn(move || {
+        let start = rest.match_indices(&format!("<{name}"))
+            .map(|(i, _)| i)
+            .find(|&i| matches!(rest[i + 1 + name.len()..].chars().next(), Some('>' | '/' | ' ')))?;
+        let tag_end = start + rest[start..].find('>')? + 1;
+        let end = match rest[..tag_end].ends_with("/>") {
+            true => tag_end,
+            false => {
+                let close = format!("</{name}>");
+                tag_end + rest[tag_end..].find(&close)? + close.len()
+            }
+        };
+        let element = &rest[start..end];
+        rest = &rest[end..];
+        Some(element)
+    })
+}
+
+fn get_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!(" {name}=\""))? + name.len() + 3;
+    let end = start + tag[start..].find('"')?;
+    Some(&tag[start..end])
+}
+
+///
+/// Replace the text of the element `name` in the xml by the function of it.
+///
+fn replace_element<F: Fn(&str) -> String>(xml: &str, name: &str, f: F) -> String {
+    let (start_tag, end_tag) = (format!("<{name}>"), format!("</{name}>"));
+    let Some(start) = xml.find(&start_tag).map(|start| start + start_tag.len()) else { return xml.to_string() };
+    let Some(end) = xml[start..].find(&end_tag).map(|end| start + end) else { return xml.to_string() };
+    format!("{}{}{}", &xml[..start], f(&xml[start..end]), &xml[end..])
+}
+
+///
+/// The cell of the shape of a comment, by its `x:Row` and `x:Column` counted from 0.
+///
+fn note_loc(shape: &str) -> Option<(u32, u32)> {
+    if !shape.contains("ObjectType=\"Note\"") {
+        return None;
+    }
+    let text = |name: &str| {
+        let (start_tag, end_tag) = (format!("<{name}>"), format!("</{name}>"));
+        let start = shape.find(&start_tag)? + start_tag.len();
+        let end = start + shape[start..].find(&end_tag)?;
+        shape[start..end].trim().parse::<u32>().ok()
+    };
+    Some((text("x:Row")? + 1, text("x:Column")? + 1))
+}
+
+///
+/// Point the `o:idmap` of a vml drawing to the block of the shape ids of the part `id`.
+///
+fn set_id_map_data(xml: &str, id: u32) -> String {
+    let Some(id_map) = xml.find("<o:idmap") else { return xml.to_string() };
+    let Some(start) = xml[id_map..].find("data=\"").map(|i| id_map + i + "data=\"".len()) else { return xml.to_string() };
+    let end = start + xml[start..].find('"').unwrap_or(0);
+    format!("{}{id}{}", &xml[..start], &xml[end..])
+}
+
+///
+/// The largest N of the shape ids `_x0000_sN` in a vml drawing.
+///
+fn max_shape_id(raw: &str) -> Option<u32> {
+    shape_ids(raw).into_iter().max()
+}
@@ -2,6 +2,7 @@ mod xda_dynamic_array_properties;
 mod x15_workbook_pr;
 mod x14_slicer_styles;
 mod x15_timeline_styles;
+mod x14_sparkline_groups;
 
 use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
@@ -10,6 +11,7 @@ use crate::xml::common::XmlnsAttrs;
 use crate::xml::extension::x14_slicer_styles::X14SlicerStyles;
 use crate::xml::extension::x15_timeline_styles::X15TimelineStyles;
 use crate::xml::extension::x15_workbook_pr::X15WorkbookPr;
+pub(crate) use crate::xml::extension::x14_sparkline_groups::X14SparklineGroups;
 use crate::xml::extension::xda_dynamic_array_properties::XdaDynamicArrayProperties;
 
 pub(crate) enum ExtensionType {
@@ -17,6 +19,7 @@ pub(crate) enum ExtensionType {
     X14SlicerStyles,
     X15TimelineStyles,
     XdaDynamicArrayProperties,
+    X14SparklineGroups,
 }
 
 pub(crate) trait AddExtension {
@@ -33,6 +36,27 @@ impl ExtensionList {
     pub(crate) fn is_empty(&self) -> bool {
         self.ext.is_empty()
     }
+
+    ///
+    /// Drop the extensions whose content is not supported, they would be saved empty otherwise.
+    ///
+    pub(crate) fn retain_supported(&mut self) {
+        self.ext.retain(|e| !e.is_empty());
+    }
+
+    ///
+    /// Update the sparkline groups, the extension is added if it does not exist.
+    ///
+    pub(crate) fn update_sparkline_groups<F: FnOnce(&mut X14SparklineGroups)>(&mut self, f: F) {
+        let mut extension = self.ext
+            .take(&Extension::from_extension_type(ExtensionType::X14SparklineGroups))
+            .filter(|e| e.x14_sparkline_groups.is_some())
+            .unwrap_or_else(|| Extension::from_extension_type(ExtensionType::X14SparklineGroups));
+        if let Some(sparkline_groups) = &mut extension.x14_sparkline_groups {
+            f(sparkline_groups);
+        }
+        self.ext.insert(extension);
+    }
 }
 
 impl AddExtension for ExtensionList {
@@ -57,6 +81,8 @@ struct Extension {
     x15_timeline_styles: Option<X15TimelineStyles>,
     #[serde(rename(serialize = "xda:dynamicArrayProperties", deserialize = "dynamicArrayProperties"), skip_serializing_if = "Option::is_none")]
     xda_dynamic_array_properties: Option<XdaDynamicArrayProperties>,
+    #[serde(rename(serialize = "x14:sparklineGroups", deserialize = "sparklineGroups"), skip_serializing_if = "Option::is_none")]
+    x14_sparkline_groups: Option<X14SparklineGroups>,
 }
 
 impl PartialEq for Extension {
@@ -80,9 +106,18 @@ impl Extension {
             ExtensionType::X14SlicerStyles => Self::new_x14_slicer_styles(),
             ExtensionType::X15TimelineStyles => Self::new_x15_timeline_styles(),
             ExtensionType::XdaDynamicArrayProperties => Self::new_xda_dynamic_array_properties(),
+            ExtensionType::X14SparklineGroups => Self::new_x14_sparkline_groups(),
         }
     }
 
+    fn is_empty(&self) -> bool {
+        self.x15_workbook_pr.is_none()
+            && self.x14_slicer_styles.is_none()
+            && self.x15_timeline_styles.is_none()
+            && self.xda_dynamic_array_properties.is_none()
+            && self.x14_sparkline_groups.is_none()
+    }
+
     fn new_x15_workbook_pr() -> Self {
         Self {
             uri: "".to_string(),
@@ -92,6 +127,7 @@ impl Extension {
             x14_slicer_styles: None,
             x15_timeline_styles: None,
             xda_dynamic_array_properties: None,
+            x14_sparkline_groups: None,
         }
     }
 
@@ -104,6 +140,7 @@ impl Extension {
             x14_slicer_styles: Some(Default::default()),
             x15_timeline_styles: None,
             xda_dynamic_array_properties: None,
+            x14_sparkline_groups: None,
         }
     }
 
@@ -116,6 +153,7 @@ impl Extension {
             x14_slicer_styles: None,
             x15_timeline_styles: Some(Default::default()),
             xda_dynamic_array_properties: None,
+            x14_sparkline_groups: None,
         }
     }
 
@@ -128,6 +166,20 @@ impl Extension {
             x14_slicer_styles: None,
             x15_timeline_styles: None,
             xda_dynamic_array_properties: Some(Default::default()),
+            x14_sparkline_groups: None,
+        }
+    }
+
+    fn new_x14_sparkline_groups() -> Self {
+        Self {
+            uri: "{05C60535-1F16-4fd2-B633-F4F36F0B64E0}".to_string(),
+            xmlns_x14: Some("http://schemas.microsoft.com/office/spreadsheetml/2009/9/main".to_string()),
+            xmlns_x15: None,
+            x15_workbook_pr: None,
+            x14_slicer_styles: None,
+            x15_timeline_styles: None,
+            xda_dynamic_array_properties: None,
+            x14_sparkline_groups: Some(Default::default()),
         }
     }
 }
\ No newline at end of file
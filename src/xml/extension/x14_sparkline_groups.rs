@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use crate::{FormatColor, SparklineAxis, SparklineOptions, SparklineType};
+use crate::xml::common::FromFormat;
+use crate::xml::style::color::Color;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub(crate) struct X14SparklineGroups {
+    #[serde(rename = "@xmlns:xm", default)]
+    xmlns_xm: String,
+    #[serde(rename(serialize = "x14:sparklineGroup", deserialize = "sparklineGroup"), default)]
+    sparkline_group: Vec<SparklineGroup>,
+}
+
+impl Default for X14SparklineGroups {
+    fn default() -> Self {
+        Self {
+            xmlns_xm: "http://schemas.microsoft.com/office/excel/2006/main".to_string(),
+            sparkline_group: vec![],
+        }
+    }
+}
+
+impl X14SparklineGroups {
+    ///
+    /// Add a sparkline to the group with the same options, a new group is added if there is none.
+    ///
+    pub(crate) fn add_sparkline(&mut self, options: &SparklineOptions, formula: &str, sqref: &str) {
+        let group = SparklineGroup::from_options(options);
+        let sparkline = Sparkline { f: formula.to_string(), sqref: sqref.to_string() };
+        match self.sparkline_group.iter_mut().find(|g| g.same_options(&group)) {
+            Some(existed) => existed.sparklines.sparkline.push(sparkline),
+            None => {
+                let mut group = group;
+                group.sparklines.sparkline.push(sparkline);
+                self.sparkline_group.push(group);
+            }
+        }
+    }
+
+    ///
+    /// Point the data ranges on the sheet `old_ref` to `new_ref`, both are quoted sheet names with the `!`.
+    ///
+    pub(crate) fn rename_sheet(&mut self, old_ref: &str, new_ref: &str) {
+        self.sparkline_group.iter_mut()
+            .flat_map(|group| group.sparklines.sparkline.iter_mut())
+            .filter(|sparkline| sparkline.f.starts_with(old_ref))
+            .for_each(|sparkline| sparkline.f = sparkline.f.replacen(old_ref, new_ref, 1));
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+struct SparklineGroup {
+    #[serde(rename = "@manualMax", default, skip_serializing_if = "Option::is_none")]
+    manual_max: Option<f64>,
+    #[serde(rename = "@manualMin", default, skip_serializing_if = "Option::is_none")]
+    manual_min: Option<f64>,
+    #[serde(rename = "@lineWeight", default, skip_serializing_if = "Option::is_none")]
+    line_weight: Option<f64>,
+    #[serde(rename = "@type", default, skip_serializing_if = "Option::is_none")]
+    tp: Option<String>,
+    #[serde(rename = "@dateAxis", default, skip_serializing_if = "Option::is_none")]
+    date_axis: Option<u8>,
+    #[serde(rename = "@displayEmptyCellsAs", default, skip_serializing_if = "Option::is_none")]
+    display_empty_cells_as: Option<String>,
+    #[serde(rename = "@markers", default, skip_serializing_if = "Option::is_none")]
+    markers: Option<u8>,
+    #[serde(rename = "@high", default, skip_serializing_if = "Option::is_none")]
+    high: Option<u8>,
+    #[serde(rename = "@low", default, skip_serializing_if = "Option::is_none")]
+    low: Option<u8>,
+    #[serde(rename = "@first", default, skip_serializing_if = "Option::is_none")]
+    first: Option<u8>,
+    #[serde(rename = "@last", default, skip_serializing_if = "Option::is_none")]
+    last: Option<u8>,
+    #[serde(rename = "@negative", default, skip_serializing_if = "Option::is_none")]
+    negative: Option<u8>,
+    #[serde(rename = "@displayXAxis", default, skip_serializing_if = "Option::is_none")]
+    display_x_axis: Option<u8>,
+    #[serde(rename = "@displayHidden", default, skip_serializing_if = "Option::is_none")]
+    display_hidden: Option<u8>,
+    #[serde(rename = "@minAxisType", default, skip_serializing_if = "Option::is_none")]
+    min_axis_type: Option<String>,
+    #[serde(rename = "@maxAxisType", default, skip_serializing_if = "Option::is_none")]
+    max_axis_type: Option<String>,
+    #[serde(rename = "@rightToLeft", default, skip_serializing_if = "Option::is_none")]
+    right_to_left: Option<u8>,
+    #[serde(rename(serialize = "x14:colorSeries", deserialize = "colorSeries"), default, skip_serializing_if = "Option::is_none")]
+    color_series: Option<Color>,
+    #[serde(rename(serialize = "x14:colorNegative", deserialize = "colorNegative"), default, skip_serializing_if = "Option::is_none")]
+    color_negative: Option<Color>,
+    #[serde(rename(serialize = "x14:colorAxis", deserialize = "colorAxis"), default, skip_serializing_if = "Option::is_none")]
+    color_axis: Option<Color>,
+    #[serde(rename(serialize = "x14:colorMarkers", deserialize = "colorMarkers"), default, skip_serializing_if = "Option::is_none")]
+    color_markers: Option<Color>,
+    #[serde(rename(serialize = "x14:colorFirst", deserialize = "colorFirst"), default, skip_serializing_if = "Option::is_none")]
+    color_first: Option<Color>,
+    #[serde(rename(serialize = "x14:colorLast", deserialize = "colorLast"), default, skip_serializing_if = "Option::is_none")]
+    color_last: Option<Color>,
+    #[serde(rename(serialize = "x14:colorHigh", deserialize = "colorHigh"), default, skip_serializing_if = "Option::is_none")]
+    color_high: Option<Color>,
+    #[serde(rename(serialize = "x14:colorLow", deserialize = "colorLow"), default, skip_serializing_if = "Option::is_none")]
+    color_low: Option<Color>,
+    // The date axis range
+    #[serde(rename(serialize = "xm:f", deserialize = "f"), default, skip_serializing_if = "Option::is_none")]
+    f: Option<String>,
+    #[serde(rename(serialize = "x14:sparklines", deserialize = "sparklines"), default)]
+    sparklines: Sparklines,
+}
+
+impl SparklineGroup {
+    fn from_options(options: &SparklineOptions) -> SparklineGroup {
+        // The colors of the default sparkline style of Excel
+        let dark_accent = FormatColor::Theme(4, -0.499984740745262);
+        let light_accent = FormatColor::Theme(4, 0.3999755851924192);
+        let color = |color: Option<FormatColor>, default: FormatColor| Some(Color::from_format(&color.unwrap_or(default)));
+        let flag = |flag: bool| flag.then_some(1);
+        let manual = |axis: SparklineAxis| match axis {
+            SparklineAxis::Custom(value) => Some(value),
+            _ => None,
+        };
+        let axis_type = |axis: SparklineAxis| (axis != SparklineAxis::Individual).then(|| axis.to_str().to_string());
+        SparklineGroup {
+            manual_max: manual(options.max_axis),
+            manual_min: manual(options.min_axis),
+            line_weight: options.line_weight,
+            tp: (options.sparkline_type != SparklineType::Line).then(|| options.sparkline_type.to_str().to_string()),
+            date_axis: None,
+            display_empty_cells_as: Some(options.empty_cells.to_str().to_string()),
+            markers: flag(options.markers),
+            high: flag(options.high_point),
+            low: flag(options.low_point),
+            first: flag(options.first_point),
+            last: flag(options.last_point),
+            negative: flag(options.negative_points),
+            display_x_axis: flag(options.show_axis),
+            display_hidden: flag(options.show_hidden),
+            min_axis_type: axis_type(options.min_axis),
+            max_axis_type: axis_type(options.max_axis),
+            right_to_left: flag(options.right_to_left),
+            color_series: color(options.series_color, dark_accent),
+            color_negative: color(options.negative_color, FormatColor::Theme(5, 0.0)),
+            color_axis: color(options.axis_color, FormatColor::RGB(0, 0, 0)),
+            color_markers: color(options.markers_color, dark_accent),
+            color_first: color(options.first_color, light_accent),
+            color_last: color(options.last_color, light_accent),
+            color_high: color(options.high_color, FormatColor::Theme(4, 0.0)),
+            color_low: color(options.low_color, FormatColor::Theme(4, 0.0)),
+            f: None,
+            sparklines: Sparklines::default(),
+        }
+    }
+
+    fn same_options(&self, other: &SparklineGroup) -> bool {
+        SparklineGroup { sparklines: Sparklines::default(), ..self.clone() }
+            == SparklineGroup { sparklines: Sparklines::default(), ..other.clone() }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq)]
+struct Sparklines {
+    #[serde(rename(serialize = "x14:sparkline", deserialize = "sparkline"), default)]
+    sparkline: Vec<Sparkline>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+struct Sparkline {
+    #[serde(rename(serialize = "xm:f", deserialize = "f"))]
+    f: String,
+    #[serde(rename(serialize = "xm:sqref", deserialize = "sqref"))]
+    sqref: String,
+}
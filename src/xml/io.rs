@@ -1,4 +1,3 @@
-pub(crate) mod insert;
 
 use std::fs::File;
 use std::io;
@@ -8,13 +7,9 @@ use quick_xml::de;
 use serde::Deserialize;
 use zip::ZipArchive;
 use crate::xml::content_types::ContentTypes;
-use crate::xml::drawings::Drawings;
-use crate::xml::drawings::vml_drawing::VmlDrawing;
 use crate::xml::metadata::Metadata;
 use crate::xml::relationships::Relationships;
-use crate::xml::shared_string::SharedString;
 use crate::xml::style::StyleSheet;
-use crate::xml::theme::Theme;
 use crate::xml::workbook::Workbook;
 use crate::xml::worksheet::WorkSheet;
 
@@ -44,7 +39,3 @@ impl IoV2<StyleSheet> for StyleSheet {}
 impl IoV2<ContentTypes> for ContentTypes{}
 impl IoV2<Relationships> for Relationships{}
 impl IoV2<Metadata> for Metadata{}
-impl IoV2<SharedString> for SharedString{}
-impl IoV2<Drawings> for Drawings{}
-impl IoV2<VmlDrawing> for VmlDrawing{}
-impl IoV2<Theme> for Theme {}
\ No newline at end of file
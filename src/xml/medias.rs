@@ -1,13 +1,27 @@
+use std::collections::HashSet;
+use std::fs;
+use std::fs::File;
+use std::io::Read;
 use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
 use crate::file::{XlsxFileType, XlsxFileWriter};
 use crate::xml::io::Io;
 
 #[derive(Debug, Default)]
 pub(crate) struct Medias {
-    medias: Vec<Media>
+    medias: Vec<Media>,
+    // The xlsx file which the existed medias are read from
+    source: Option<PathBuf>,
 }
 
 impl Medias {
+    pub(crate) fn from_path<P: AsRef<Path>>(file_path: P) -> Medias {
+        Medias {
+            medias: vec![],
+            source: Some(file_path.as_ref().to_path_buf()),
+        }
+    }
+
     pub(crate) fn add_media<P: AsRef<Path>>(&mut self, file_path: P) -> u32 {
         // let id = 1 + self.medias.len() as u32;
         let id = 1 + self.max_id(); // self.medias.iter().map(|m| m.id).max().unwrap();
@@ -17,6 +31,12 @@ impl Medias {
         id
     }
 
+    pub(crate) fn add_media_buffer(&mut self, buffer: &[u8], extension: &str) -> u32 {
+        let id = 1 + self.max_id();
+        self.medias.push(Media::from_buffer(id, buffer, extension));
+        id
+    }
+
     fn max_id(&self) -> u32 {
         self.medias.iter().map(|m| m.id).max().unwrap_or_default()
     }
@@ -28,9 +48,69 @@ impl Medias {
             .collect::<String>()
             .parse()
             .unwrap_or(1 + self.max_id());
-        self.medias.push(Media::by_id(id));
+        let name = file_name.trim_start_matches("xl/media/");
+        self.medias.push(Media::by_id(id, name));
         id
     }
+
+    ///
+    /// Read the content of the media by its file name in `xl/media`.
+    ///
+    pub(crate) fn read_media(&self, name: &str) -> Option<Vec<u8>> {
+        let media = self.medias.iter().find(|m| m.name() == name)?;
+        if let Some(buffer) = &media.buffer {
+            return Some(buffer.clone());
+        }
+        if let Some(path) = &media.file_path {
+            return fs::read(path).ok();
+        }
+        let mut archive = zip::ZipArchive::new(File::open(self.source.as_ref()?).ok()?).ok()?;
+        let mut buffer = vec![];
+        archive.by_name(&format!("xl/media/{name}")).ok()?.read_to_end(&mut buffer).ok()?;
+        Some(buffer)
+    }
+
+    ///
+    /// Mark the media as possibly orphaned, it is deleted on saving if no relationship refers to it any more.
+    /// The media is kept in the list so that its id is not reused.
+    ///
+    pub(crate) fn remove_media(&mut self, name: &str) {
+        if let Some(media) = self.medias.iter_mut().find(|m| m.name() == name) {
+            media.removed = true;
+        }
+    }
+
+    ///
+    /// Delete the removed medias which are not referred by any relationship in the extracted xlsx at `base_path`,
+    /// returns the extensions which no file in the xlsx uses any more.
+    ///
+    pub(crate) fn clean_orphans<P: AsRef<Path>>(&mut self, base_path: P) -> Vec<String> {
+        if !self.medias.iter().any(|m| m.removed) {
+            return vec![];
+        }
+        let files: Vec<PathBuf> = WalkDir::new(&base_path).into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .map(|entry| entry.into_path())
+            .collect();
+        let rels: Vec<String> = files.iter()
+            .filter(|path| path.extension().is_some_and(|extension| extension == "rels"))
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .collect();
+        let mut extensions = HashSet::new();
+        for media in self.medias.iter_mut().filter(|m| m.removed) {
+            let target = format!("media/{}\"", media.name());
+            media.orphaned = !rels.iter().any(|rel| rel.contains(&target));
+            if media.orphaned {
+                let path = XlsxFileType::Medias(media.name()).get_path(&base_path);
+                fs::remove_file(path).unwrap_or_default();
+                extensions.insert(media.extension().to_string());
+            }
+        }
+        extensions.into_iter()
+            .filter(|extension| !files.iter().any(|path| path.exists() && path.extension().is_some_and(|e| e == extension.as_str())))
+            .collect()
+    }
 }
 
 impl Io<Medias> for Medias {
@@ -43,30 +123,67 @@ impl Io<Medias> for Medias {
 struct Media {
     id: u32,
     file_path: Option<PathBuf>,
+    buffer: Option<Vec<u8>>,
+    // The file name in `xl/media`
+    name: Option<String>,
+    extension: String,
+    removed: bool,
+    orphaned: bool,
 }
 
 impl Media {
     fn new<P: AsRef<Path>>(id: u32, file_path: P) -> Media {
+        let extension = file_path.as_ref().extension().unwrap_or("png".as_ref()).to_string_lossy();
         Media {
             id,
+            extension: extension.to_string(),
             file_path: Some(file_path.as_ref().to_path_buf()),
+            ..Default::default()
+        }
+    }
+
+    fn from_buffer(id: u32, buffer: &[u8], extension: &str) -> Media {
+        Media {
+            id,
+            extension: extension.to_string(),
+            buffer: Some(buffer.to_vec()),
+            ..Default::default()
         }
     }
 
-    fn by_id(id: u32) -> Media {
+    fn by_id(id: u32, name: &str) -> Media {
+        let extension = Path::new(name).extension().unwrap_or_default().to_string_lossy();
         Media {
             id,
-            file_path: None,
+            extension: extension.to_string(),
+            name: Some(name.to_string()),
+            ..Default::default()
         }
     }
+
+    fn name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("image{}.{}", self.id, self.extension),
+        }
+    }
+
+    fn extension(&self) -> &str {
+        &self.extension
+    }
 }
 
 impl Io<Media> for Media {
     fn save<P: AsRef<Path>>(&self, file_path: P) {
+        if self.orphaned {
+            return;
+        }
+        let file_type = XlsxFileType::Medias(self.name());
         if let Some(path) = &self.file_path {
-            let extension = path.extension().unwrap_or("png".as_ref()).to_string_lossy();
-            let file_name = format!("image{}.{}", self.id, extension);
-            XlsxFileWriter::copy_from(&file_path, XlsxFileType::Medias(file_name), &path).unwrap();
+            XlsxFileWriter::copy_from(&file_path, file_type, path).unwrap();
+        } else if let Some(buffer) = &self.buffer {
+            let mut file = XlsxFileWriter::from_path(&file_path, file_type).unwrap();
+            file.write_all(buffer).unwrap();
         }
     }
-}
\ No newline at end of file
+}
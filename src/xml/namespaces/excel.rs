@@ -4,31 +4,96 @@ use serde::{Deserialize, Serialize};
 /// xmlns:x="urn:schemas-microsoft-com:office:excel"
 ///
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename(serialize = "x:ClientData", deserialize = "ClientData"))]
 pub(crate) struct ClientData {
     #[serde(rename(serialize = "@ObjectType", deserialize = "@ObjectType"))]
     object_type: String,
-    #[serde(rename(serialize = "x:MoveWithCells", deserialize = "MoveWithCells"))]
-    move_with_cells: MoveWithCells,
-    #[serde(rename(serialize = "x:SizeWithCells", deserialize = "SizeWithCells"))]
-    size_with_cells: SizeWithCells,
+    #[serde(rename(serialize = "x:MoveWithCells", deserialize = "MoveWithCells"), default, skip_serializing_if = "Option::is_none")]
+    move_with_cells: Option<MoveWithCells>,
+    #[serde(rename(serialize = "x:SizeWithCells", deserialize = "SizeWithCells"), default, skip_serializing_if = "Option::is_none")]
+    size_with_cells: Option<SizeWithCells>,
     #[serde(rename(serialize = "x:Anchor", deserialize = "Anchor"))]
     anchor: Anchor,
-    #[serde(rename(serialize = "x:Row", deserialize = "Row"))]
-    row: Row,
-    #[serde(rename(serialize = "x:Column", deserialize = "Column"))]
-    column: Column,
+    #[serde(rename(serialize = "x:PrintObject", deserialize = "PrintObject"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) print_object: Option<String>,
+    #[serde(rename(serialize = "x:AutoFill", deserialize = "AutoFill"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) auto_fill: Option<String>,
+    #[serde(rename(serialize = "x:AutoLine", deserialize = "AutoLine"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) auto_line: Option<String>,
+    #[serde(rename(serialize = "x:TextHAlign", deserialize = "TextHAlign"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) text_h_align: Option<String>,
+    #[serde(rename(serialize = "x:TextVAlign", deserialize = "TextVAlign"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) text_v_align: Option<String>,
+    #[serde(rename(serialize = "x:Row", deserialize = "Row"), default, skip_serializing_if = "Option::is_none")]
+    row: Option<Row>,
+    #[serde(rename(serialize = "x:Column", deserialize = "Column"), default, skip_serializing_if = "Option::is_none")]
+    column: Option<Column>,
+    #[serde(rename(serialize = "x:FmlaMacro", deserialize = "FmlaMacro"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) fmla_macro: Option<String>,
+    #[serde(rename(serialize = "x:FmlaLink", deserialize = "FmlaLink"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) fmla_link: Option<String>,
+    #[serde(rename(serialize = "x:FmlaRange", deserialize = "FmlaRange"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) fmla_range: Option<String>,
+    #[serde(rename(serialize = "x:Sel", deserialize = "Sel"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) sel: Option<u32>,
+    #[serde(rename(serialize = "x:NoThreeD2", deserialize = "NoThreeD2"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) no_three_d2: Option<Flag>,
+    #[serde(rename(serialize = "x:SelType", deserialize = "SelType"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) sel_type: Option<String>,
+    #[serde(rename(serialize = "x:LCT", deserialize = "LCT"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) lct: Option<String>,
+    #[serde(rename(serialize = "x:Val", deserialize = "Val"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) val: Option<u32>,
+    #[serde(rename(serialize = "x:Min", deserialize = "Min"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) min: Option<u32>,
+    #[serde(rename(serialize = "x:Max", deserialize = "Max"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) max: Option<u32>,
+    #[serde(rename(serialize = "x:Inc", deserialize = "Inc"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) inc: Option<u32>,
+    #[serde(rename(serialize = "x:Page", deserialize = "Page"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) page: Option<u32>,
+    #[serde(rename(serialize = "x:DropStyle", deserialize = "DropStyle"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) drop_style: Option<String>,
+    #[serde(rename(serialize = "x:DropLines", deserialize = "DropLines"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) drop_lines: Option<u32>,
+    #[serde(rename(serialize = "x:Checked", deserialize = "Checked"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) checked: Option<u32>,
+    #[serde(rename(serialize = "x:FirstButton", deserialize = "FirstButton"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) first_button: Option<Flag>,
+    #[serde(rename(serialize = "x:NoThreeD", deserialize = "NoThreeD"), default, skip_serializing_if = "Option::is_none")]
+    pub(crate) no_three_d: Option<Flag>,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+impl ClientData {
+    ///
+    /// `anchor` is the left column, the left offset, the top row, the top offset, the right column,
+    /// the right offset, the bottom row and the bottom offset, the cells start from 0 and the offsets are in pixels.
+    ///
+    pub(crate) fn new(object_type: &str, anchor: [u32; 8]) -> ClientData {
+        let anchor = anchor.map(|v| v.to_string()).join(", ");
+        ClientData {
+            object_type: object_type.to_string(),
+            anchor: Anchor { value: anchor },
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 struct MoveWithCells {}
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 struct SizeWithCells {
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+///
+/// An element which is set by being present, such as `<x:NoThreeD/>`.
+///
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub(crate) struct Flag {}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 struct Anchor {
     #[serde(rename = "$value", default, skip_serializing_if = "String::is_empty")]
     value: String,// Vec<u32>,
@@ -39,12 +104,12 @@ struct AutoFill {
     #[serde(rename = "$value", default)]
     value: bool,
 }
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 struct Row {
     #[serde(rename = "$value", default)]
     value: u32,
 }
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 struct Column {
     #[serde(rename = "$value", default)]
     value: u32,
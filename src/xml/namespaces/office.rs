@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 // #[serde(rename(serialize = "o:shapelayout", deserialize = "shapelayout"))]
 pub(crate) struct ShapeLayout {
     #[serde(rename(serialize = "@v:ext", deserialize = "@ext"))]
@@ -9,11 +9,40 @@ pub(crate) struct ShapeLayout {
     id_map: IdMap,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+impl ShapeLayout {
+    pub(crate) fn new(data: u32) -> ShapeLayout {
+        ShapeLayout {
+            v_ext: "edit".to_string(),
+            id_map: IdMap { v_ext: "edit".to_string(), data },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 // #[serde(rename(serialize = "o:idmap", deserialize = "idmap"))]
 struct IdMap {
     #[serde(rename(serialize = "@v:ext", deserialize = "@ext"))]
     v_ext: String,
     #[serde(rename = "@data")]
     data: u32,
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub(crate) struct Lock {
+    #[serde(rename(serialize = "@v:ext", deserialize = "@ext"))]
+    v_ext: String,
+    #[serde(rename = "@shapetype", skip_serializing_if = "Option::is_none")]
+    shape_type: Option<String>,
+    #[serde(rename = "@rotation", skip_serializing_if = "Option::is_none")]
+    rotation: Option<String>,
+}
+
+impl Lock {
+    pub(crate) fn shape_type() -> Lock {
+        Lock { v_ext: "edit".to_string(), shape_type: Some("t".to_string()), rotation: None }
+    }
+
+    pub(crate) fn rotation() -> Lock {
+        Lock { v_ext: "edit".to_string(), shape_type: None, rotation: Some("t".to_string()) }
+    }
+}
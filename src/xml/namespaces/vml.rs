@@ -1,11 +1,12 @@
-use serde::{Deserialize, Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 use crate::xml::namespaces::excel as x;
+use crate::xml::namespaces::office as o;
 
 ///
 /// xmlns:v="urn:schemas-microsoft-com:vml"
 ///
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename(serialize = "v:shapetype", deserialize = "shapetype"))]
 pub(crate) struct ShapeType {
     #[serde(rename = "@id")]
@@ -20,9 +21,39 @@ pub(crate) struct ShapeType {
     stroke: Stroke,
     #[serde(rename(serialize = "v:path", deserialize = "path"))]
     path: Path,
+    #[serde(rename(serialize = "o:lock", deserialize = "lock"), default, skip_serializing_if = "Option::is_none")]
+    lock: Option<o::Lock>,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+impl ShapeType {
+    ///
+    /// The shape type of the form controls.
+    ///
+    pub(crate) fn form_control() -> ShapeType {
+        ShapeType {
+            id: "_x0000_t201".to_string(),
+            coord_size: "21600,21600".to_string(),
+            o_spt: "201".to_string(),
+            param_path: "m,l,21600r21600,l21600,xe".to_string(),
+            stroke: Stroke { join_style: "miter".to_string() },
+            path: Path {
+                shadow_ok: Some("f".to_string()),
+                o_extrusion_ok: Some("f".to_string()),
+                stroke_ok: Some("f".to_string()),
+                fill_ok: Some("f".to_string()),
+                o_connect_type: Some("rect".to_string()),
+                ..Default::default()
+            },
+            lock: Some(o::Lock::shape_type()),
+        }
+    }
+
+    pub(crate) fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub(crate) struct Shape {
     #[serde(rename = "@id")]
     id: String,
@@ -30,44 +61,122 @@ pub(crate) struct Shape {
     param_type: String,
     #[serde(rename = "@style")]
     style: String,
+    #[serde(rename(serialize = "@o:button", deserialize = "@button"), default, skip_serializing_if = "Option::is_none")]
+    o_button: Option<String>,
+    #[serde(rename = "@filled", default, skip_serializing_if = "Option::is_none")]
+    filled: Option<String>,
     #[serde(rename = "@fillcolor")]
     fillcolor: String,
+    #[serde(rename = "@stroked", default, skip_serializing_if = "Option::is_none")]
+    stroked: Option<String>,
+    #[serde(rename = "@strokecolor", default, skip_serializing_if = "Option::is_none")]
+    strokecolor: Option<String>,
     #[serde(rename(serialize = "@o:insetmode", deserialize = "@insetmode"))]
     o_insetmode: String,
-    #[serde(rename(serialize = "v:fill", deserialize = "fill"))]
-    fill: Fill,
-    #[serde(rename(serialize = "v:shadow", deserialize = "shadow"))]
-    shadow: Shadow,
+    #[serde(rename(serialize = "v:fill", deserialize = "fill"), default, skip_serializing_if = "Option::is_none")]
+    fill: Option<Fill>,
+    #[serde(rename(serialize = "v:shadow", deserialize = "shadow"), default, skip_serializing_if = "Option::is_none")]
+    shadow: Option<Shadow>,
     #[serde(rename(serialize = "v:path", deserialize = "path"))]
     path: Path,
-    #[serde(rename(serialize = "v:textbox", deserialize = "textbox"))]
-    textbox: TextBox,
+    #[serde(rename(serialize = "o:lock", deserialize = "lock"), default, skip_serializing_if = "Option::is_none")]
+    lock: Option<o::Lock>,
+    #[serde(rename(serialize = "v:textbox", deserialize = "textbox"), default, skip_serializing_if = "Option::is_none")]
+    textbox: Option<TextBox>,
     #[serde(rename(serialize = "x:ClientData", deserialize = "ClientData"))]
     clientdata: x::ClientData,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+impl Shape {
+    ///
+    /// A form control, the buttons are filled with the face color of the system,
+    /// other controls are transparent and only show their text.
+    ///
+    pub(crate) fn form_control(shape_id: u32, style: &str, text: Option<&str>, button: bool, client_data: x::ClientData) -> Shape {
+        let (text_align, font_color) = match button {
+            true => ("center", "#000000"),
+            false => ("left", "auto"),
+        };
+        Shape {
+            id: format!("_x0000_s{shape_id}"),
+            param_type: format!("#{}", ShapeType::form_control().id()),
+            style: style.to_string(),
+            o_button: button.then(|| "t".to_string()),
+            filled: (!button).then(|| "f".to_string()),
+            fillcolor: match button {
+                true => "buttonFace [67]".to_string(),
+                false => "window [65]".to_string(),
+            },
+            stroked: (!button).then(|| "f".to_string()),
+            strokecolor: Some("windowText [64]".to_string()),
+            o_insetmode: "auto".to_string(),
+            fill: button.then(|| Fill { color2: "buttonFace [67]".to_string(), o_detectmouseclick: Some("t".to_string()) }),
+            shadow: None,
+            path: Path {
+                shadow_ok: Some("t".to_string()),
+                stroke_ok: Some("t".to_string()),
+                fill_ok: Some("t".to_string()),
+                ..Default::default()
+            },
+            lock: Some(o::Lock::rotation()),
+            textbox: text.map(|text| TextBox {
+                style: "mso-direction-alt:auto".to_string(),
+                o_singleclick: Some("f".to_string()),
+                div: Div {
+                    style: format!("text-align:{text_align}"),
+                    font: Some(Font {
+                        face: "Segoe UI".to_string(),
+                        size: 160,
+                        color: font_color.to_string(),
+                        text: text.to_string(),
+                    }),
+                },
+            }),
+            clientdata: client_data,
+        }
+    }
+
+    pub(crate) fn get_shape_id(&self) -> Option<u32> {
+        self.id.strip_prefix("_x0000_s")?.parse().ok()
+    }
+
+    pub(crate) fn set_shape_id(&mut self, shape_id: u32) {
+        self.id = format!("_x0000_s{shape_id}");
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 struct Stroke {
     #[serde(rename = "@joinstyle")]
     join_style :String
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 struct Path {
     #[serde(rename = "@gradientshapeok", skip_serializing_if = "Option::is_none")]
     gradient_shape_ok: Option<String>,
-    #[serde(rename(serialize = "@o:connecttype", deserialize = "@connecttype"))]
-    o_connect_type: String,
+    #[serde(rename = "@shadowok", skip_serializing_if = "Option::is_none")]
+    shadow_ok: Option<String>,
+    #[serde(rename(serialize = "@o:extrusionok", deserialize = "@extrusionok"), skip_serializing_if = "Option::is_none")]
+    o_extrusion_ok: Option<String>,
+    #[serde(rename = "@strokeok", skip_serializing_if = "Option::is_none")]
+    stroke_ok: Option<String>,
+    #[serde(rename = "@fillok", skip_serializing_if = "Option::is_none")]
+    fill_ok: Option<String>,
+    #[serde(rename(serialize = "@o:connecttype", deserialize = "@connecttype"), skip_serializing_if = "Option::is_none")]
+    o_connect_type: Option<String>,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename(serialize = "v:fill", deserialize = "fill"))]
 struct Fill {
     #[serde(rename = "@color2")]
     color2: String,
+    #[serde(rename(serialize = "@o:detectmouseclick", deserialize = "@detectmouseclick"), skip_serializing_if = "Option::is_none")]
+    o_detectmouseclick: Option<String>,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename(serialize = "v:shadow", deserialize = "shadow"))]
 struct Shadow {
     #[serde(rename = "@on")]
@@ -78,17 +187,33 @@ struct Shadow {
     obscured: String,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename(serialize = "v:textbox", deserialize = "textbox"))]
 struct TextBox {
     #[serde(rename = "@style")]
     style: String,
+    #[serde(rename(serialize = "@o:singleclick", deserialize = "@singleclick"), skip_serializing_if = "Option::is_none")]
+    o_singleclick: Option<String>,
     div: Div
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename = "div")]
 struct Div {
     #[serde(rename = "@style")]
     style: String,
-}
\ No newline at end of file
+    #[serde(rename = "font", default, skip_serializing_if = "Option::is_none")]
+    font: Option<Font>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+struct Font {
+    #[serde(rename = "@face")]
+    face: String,
+    #[serde(rename = "@size")]
+    size: u32,
+    #[serde(rename = "@color")]
+    color: String,
+    #[serde(rename = "$text")]
+    text: String,
+}
@@ -69,7 +69,8 @@ impl Targets {
             RelType::Chart => format!("../charts/chart{id}.xml"),
             RelType::SharedStrings => { "".to_string() }
             RelType::PrinterSettings => { "".to_string() }
-            RelType::VmlDrawing => { "".to_string() }
+            RelType::VmlDrawing => format!("../drawings/vmlDrawing{id}.vml"),
+            RelType::CtrlProp => format!("../ctrlProps/ctrlProp{id}.xml"),
             RelType::Comments => { "".to_string() }
             RelType::Unknown => { "".to_string() }
         }
@@ -92,7 +93,41 @@ impl Default for Relationships {
 
 impl Relationships {
     pub(crate) fn next_id(&self) -> u32 {
-        1 + self.relationship.len() as u32
+        // Relationships may have been removed, so the ids are not always continuous
+        1 + self.relationship.iter()
+            .map(|r| r.id.get_id())
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn get_target_by_id(&self, r_id: u32) -> Option<&str> {
+        self.relationship.iter()
+            .find(|r| r.id.get_id() == r_id)
+            .map(|r| r.target.as_str())
+    }
+
+    ///
+    /// The relationship ids and the targets of the relationships to the parts inside the package.
+    ///
+    pub(crate) fn get_internal_targets(&self) -> Vec<(u32, String)> {
+        self.relationship.iter()
+            .filter(|r| !r.is_external())
+            .map(|r| (r.id.get_id(), r.target.clone()))
+            .collect()
+    }
+
+    pub(crate) fn set_target(&mut self, r_id: u32, target: &str) {
+        if let Some(r) = self.relationship.iter_mut().find(|r| r.id.get_id() == r_id) {
+            r.target = target.to_string();
+        }
+    }
+
+    ///
+    /// Remove the relationship and return its target.
+    ///
+    pub(crate) fn remove_by_id(&mut self, r_id: u32) -> Option<String> {
+        let index = self.relationship.iter().position(|r| r.id.get_id() == r_id)?;
+        Some(self.relationship.remove(index).target)
     }
 
     ///
@@ -119,16 +154,56 @@ impl Relationships {
             .unwrap_or(0)
     }
 
-    pub(crate) fn get_drawings_rids(&self) -> Vec<u32> {
-        let binding = self.get_target_by_type(RelType::Drawings);
-        let rids: Vec<u32> = binding.iter()
-            .map(|s|s.chars().filter(|&c| c >= '0' && c <= '9').collect::<String>().parse().unwrap())
-            .collect();
-        rids
+    pub(crate) fn get_drawings_r_ids(&self) -> Vec<u32> {
+        self.get_rid_by_type(RelType::Drawings)
+    }
+
+    ///
+    /// Get N of the `drawingN.xml` the relationship points to.
+    ///
+    pub(crate) fn get_drawing_id(&self, r_id: u32) -> Option<u32> {
+        let target = self.relationship.iter()
+            .find(|r| r.id.get_id() == r_id && r.rel_type == RelType::Drawings)
+            .map(|r| &r.target)?;
+        Some(self.get_target_id(target))
+    }
+
+    pub(crate) fn set_drawing_id(&mut self, r_id: u32, id: u32) {
+        if let Some(r) = self.relationship.iter_mut().find(|r| r.id.get_id() == r_id) {
+            r.target = format!("../drawings/drawing{id}.xml");
+        }
+    }
+
+    ///
+    /// Get N of the `vmlDrawingN.vml` the relationship points to.
+    ///
+    pub(crate) fn get_vml_drawing_id(&self, r_id: u32) -> Option<u32> {
+        let target = self.relationship.iter()
+            .find(|r| r.id.get_id() == r_id && r.rel_type == RelType::VmlDrawing)
+            .map(|r| &r.target)?;
+        Some(self.get_target_id(target))
     }
-    
-    pub(crate) fn get_vml_drawing_rid(&self) -> Option<u32> {
-        self.get_rid_by_type(RelType::VmlDrawing).first().copied()
+
+    pub(crate) fn set_vml_drawing_id(&mut self, r_id: u32, id: u32) {
+        if let Some(r) = self.relationship.iter_mut().find(|r| r.id.get_id() == r_id) {
+            r.target = format!("../drawings/vmlDrawing{id}.vml");
+        }
+    }
+
+    ///
+    /// Get the relationship ids of the control properties with N of their `ctrlPropN.xml`.
+    ///
+    pub(crate) fn get_ctrl_props(&self) -> Vec<(u32, u32)> {
+        self.relationship.iter()
+            .filter(|r| r.rel_type == RelType::CtrlProp)
+            .map(|r| (r.id.get_id(), self.get_target_id(&r.target)))
+            .collect()
+    }
+
+    pub(crate) fn set_ctrl_prop_id(&mut self, r_id: u32, id: u32) {
+        if let Some(r) = self.relationship.iter_mut().find(|r| r.id.get_id() == r_id) {
+            r.target = format!("../ctrlProps/ctrlProp{id}.xml");
+        }
     }
 
     pub(crate) fn get_target(&self, r_id: &Rel) -> (&String, u32) {
@@ -213,6 +288,41 @@ impl Relationships {
         r_id
     }
 
+    pub(crate) fn add_vml_drawing(&mut self, id: u32) -> u32 {
+        let r_id = self.next_id();
+        let target = self.targets.next_target(RelType::VmlDrawing, id);
+        self.relationship.push(RelationShip::new(r_id, RelType::VmlDrawing, &target, None));
+        r_id
+    }
+
+    pub(crate) fn add_ctrl_prop(&mut self, id: u32) -> u32 {
+        let r_id = self.next_id();
+        let target = self.targets.next_target(RelType::CtrlProp, id);
+        self.relationship.push(RelationShip::new(r_id, RelType::CtrlProp, &target, None));
+        r_id
+    }
+
+    ///
+    /// The relationship id and the target of the comments part.
+    ///
+    pub(crate) fn get_comments(&self) -> Option<(u32, String)> {
+        self.relationship.iter()
+            .find(|r| r.rel_type == RelType::Comments)
+            .map(|r| (r.id.get_id(), r.target.clone()))
+    }
+
+    pub(crate) fn add_comments(&mut self, id: u32) -> u32 {
+        let r_id = self.next_id();
+        self.relationship.push(RelationShip::new(r_id, RelType::Comments, &format!("../comments{id}.xml"), None));
+        r_id
+    }
+
+    pub(crate) fn set_comments_id(&mut self, r_id: u32, id: u32) {
+        if let Some(r) = self.relationship.iter_mut().find(|r| r.id.get_id() == r_id) {
+            r.target = format!("../comments{id}.xml");
+        }
+    }
+
     pub(crate) fn get_or_add_metadata(&mut self) -> u32 {
         let r_id = self.get_rid_by_type(RelType::MetaData);
         if r_id.is_empty() {
@@ -222,6 +332,26 @@ impl Relationships {
         }
         return r_id[0]
     }
+
+    pub(crate) fn get_or_add_shared_strings(&mut self) -> u32 {
+        let r_id = self.get_rid_by_type(RelType::SharedStrings);
+        if r_id.is_empty() {
+            let r_id = self.next_id();
+            self.relationship.push(RelationShip::new(r_id, RelType::SharedStrings, "sharedStrings.xml", None));
+            return r_id;
+        }
+        r_id[0]
+    }
+
+    pub(crate) fn get_or_add_theme(&mut self) -> u32 {
+        let r_id = self.get_rid_by_type(RelType::Theme);
+        if r_id.is_empty() {
+            let r_id = self.next_id();
+            self.relationship.push(RelationShip::new(r_id, RelType::Theme, "theme/theme1.xml", None));
+            return r_id;
+        }
+        r_id[0]
+    }
 }
 
 impl Relationships {
@@ -26,6 +26,10 @@ impl RelationShip {
     }
 
 
+    pub(crate) fn is_external(&self) -> bool {
+        self.target_mode.as_deref() == Some("External")
+    }
+
     pub(crate) fn new_sheet(r_id: u32, target: &str) -> RelationShip {
         RelationShip {
             id: Rel::from_id(r_id),
@@ -18,7 +18,8 @@ pub(crate) enum RelType {
     PrinterSettings,
     CalcChain,
     Table,
-    Chart
+    Chart,
+    CtrlProp,
 }
 
 impl Serialize for RelType {
@@ -61,6 +62,7 @@ impl RelType {
             RelType::CalcChain => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/calcChain",
             RelType::Table => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/table",
             RelType::Chart => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart",
+            RelType::CtrlProp => "http://schemas.microsoft.com/office/2007/relationships/ctrlProp",
             RelType::Unknown => "",
         }
     }
@@ -81,6 +83,7 @@ impl RelType {
             "http://schemas.openxmlformats.org/officeDocument/2006/relationships/calcChain" => RelType::CalcChain,
             "http://schemas.openxmlformats.org/officeDocument/2006/relationships/table" => RelType::Table,
             "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart" => RelType::Chart,
+            "http://schemas.microsoft.com/office/2007/relationships/ctrlProp" => RelType::CtrlProp,
             &_ => RelType::Unknown
         }
     }
@@ -4,8 +4,10 @@ use std::io::Read;
 use std::path::Path;
 use quick_xml::{de, se};
 use serde::{Deserialize, Serialize};
+use zip::ZipArchive;
+use crate::file::{XlsxFileType, XlsxFileWriter};
 use crate::xml::common::FromFormat;
-use crate::xml::worksheet::sheet_data::cell::inline_string::RichText;
+use crate::xml::worksheet::sheet_data::cell::inline_string::{InlineString, RichText};
 use crate::xml::io::Io;
 use crate::api::cell::rich_text::RichText as ApiRichText;
 
@@ -20,6 +22,16 @@ pub(crate) struct SharedString {
     // unique_count: u32,
     #[serde(rename = "si", default = "Vec::new")]
     string_item: Vec<StringItem>,
+    // The text of the strings is trimmed when they are read,
+    // so the original xml is kept and the added strings are written into it.
+    #[serde(skip)]
+    raw: String,
+    // The places of the `<si>` elements in the original xml
+    #[serde(skip)]
+    raw_items: Vec<(usize, usize)>,
+    // The xml of the strings added to the table
+    #[serde(skip)]
+    added: Vec<String>,
 }
 
 impl Default for SharedString {
@@ -29,6 +41,9 @@ impl Default for SharedString {
             // count: 0,
             // unique_count: 0,
             string_item: vec![],
+            raw: String::new(),
+            raw_items: vec![],
+            added: vec![],
         }
     }
 }
@@ -60,6 +75,111 @@ impl SharedString {
             None => None
         }
     }
+
+    ///
+    /// The xml of the string `id`, like `<si><t>text</t></si>`.
+    ///
+    fn get_item_xml(&self, id: usize) -> Option<&str> {
+        match self.raw_items.get(id) {
+            Some(&(start, end)) => Some(&self.raw[start..end]),
+            None => self.added.get(id.checked_sub(self.raw_items.len())?).map(String::as_str),
+        }
+    }
+
+    fn is_consistent(&self) -> bool {
+        self.raw_items.len() + self.added.len() == self.string_item.len()
+    }
+
+    ///
+    /// Add a copy of the string `id` of another table and return its index in this one,
+    /// a string already in this table is reused.
+    ///
+    pub(crate) fn import_string(&mut self, shared_string: &SharedString, id: usize) -> Option<usize> {
+        // The strings are found in the xml by their places, which have to match the strings read
+        if !self.is_consistent() || !shared_string.is_consistent() {
+            return None;
+        }
+        let item_xml = shared_string.get_item_xml(id)?;
+        self.add_item(shared_string.string_item.get(id)?.clone(), item_xml)
+    }
+
+    ///
+    /// Add the string of an inline string and return its index, a string already in the table is reused.
+    ///
+    pub(crate) fn add_string(&mut self, inline_string: &InlineString) -> Option<usize> {
+        if !self.is_consistent() {
+            return None;
+        }
+        let item_xml = se::to_string_with_root("si", inline_string).ok()?;
+        self.add_item(de::from_str(&item_xml).ok()?, &item_xml)
+    }
+
+    fn add_item(&mut self, string_item: StringItem, item_xml: &str) -> Option<usize> {
+        let found = (0..self.string_item.len()).find(|&i| self.get_item_xml(i) == Some(item_xml));
+        if found.is_some() {
+            return found;
+        }
+        self.string_item.push(string_item);
+        self.added.push(item_xml.to_string());
+        Some(self.string_item.len() - 1)
+    }
+
+    pub(crate) fn from_zip_file(archive: &mut ZipArchive<File>, path: &str) -> Option<SharedString> {
+        let mut file = archive.by_name(path).ok()?;
+        let mut xml = String::new();
+        file.read_to_string(&mut xml).ok()?;
+        let mut shared_string: SharedString = de::from_str(&xml).ok()?;
+        let mut rest = 0;
+        while let Some(start) = find_item(&xml[rest..]).map(|start| rest + start) {
+            let end = match xml[start..].find('>') {
+                Some(i) if xml[..start + i].ends_with('/') => start + i + 1,
+                _ => xml[start..].find("</si>").map_or(xml.len(), |i| start + i + "</si>".len()),
+            };
+            shared_string.raw_items.push((start, end));
+            rest = end;
+        }
+        shared_string.raw = xml;
+        Some(shared_string)
+    }
+
+    ///
+    /// The xml of the table with the added strings at its end, and `uniqueCount` updated.
+    ///
+    fn to_xml(&self) -> String {
+        let added = self.added.concat();
+        let unique_count = format!("uniqueCount=\"{}\"", self.string_item.len());
+        if self.raw.is_empty() {
+            return format!("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+                <sst xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\" {unique_count}>{added}</sst>");
+        }
+        let Some(sst) = self.raw.find("<sst") else { return self.raw.clone() };
+        let tag_end = sst + self.raw[sst..].find('>').unwrap_or(0);
+        // The count of the references to the strings is left out, as the added references are not counted
+        let mut tag: String = self.raw[sst..tag_end].split_whitespace()
+            .filter(|attr| !attr.starts_with("count=") && !attr.starts_with("uniqueCount="))
+            .collect::<Vec<&str>>()
+            .join(" ");
+        let empty = tag.ends_with('/');
+        if empty {
+            tag.pop();
+        }
+        let tag = format!("{} {unique_count}>", tag.trim_end());
+        match empty {
+            true => format!("{}{tag}{added}</sst>{}", &self.raw[..sst], &self.raw[tag_end + 1..]),
+            false => {
+                let close = self.raw.rfind("</sst>").unwrap_or(self.raw.len());
+                format!("{}{tag}{}{added}{}", &self.raw[..sst], &self.raw[tag_end + 1..close], &self.raw[close..])
+            }
+        }
+    }
+}
+
+///
+/// The offset of the next `<si>` element.
+///
+fn find_item(xml: &str) -> Option<usize> {
+    xml.match_indices("<si").map(|(i, _)| i)
+        .find(|&i| matches!(xml[i + "<si".len()..].chars().next(), Some('>' | '/' | ' ')))
 }
 
 impl FromFormat<ApiRichText> for StringItem {
@@ -99,10 +219,11 @@ impl SharedString {
 
 impl Io<SharedString> for SharedString {
     fn save<P: AsRef<Path>>(&self, file_path: P) {
-        return;
-        // let xml = se::to_string_with_root("sst", &self).unwrap();
-        // let xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n{}", xml);
-        // let mut file = XlsxFileWriter::from_path(file_path, XlsxFileType::SharedStringFile).unwrap();
-        // file.write_all(xml.as_ref()).unwrap();
+        // The original part is kept unless strings were added
+        if self.added.is_empty() {
+            return;
+        }
+        let mut file = XlsxFileWriter::from_path(file_path, XlsxFileType::SharedStringFile).unwrap();
+        file.write_all(self.to_xml().as_ref()).unwrap();
     }
 }
\ No newline at end of file
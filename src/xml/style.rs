@@ -4,8 +4,11 @@ pub(crate) mod fill;
 pub(crate) mod alignment;
 pub(crate) mod xf;
 pub(crate) mod color;
+pub(crate) mod protection;
 mod num_fmt;
+mod index;
 
+use std::collections::HashSet;
 use std::fs::File;
 use std::hash::Hash;
 use std::io;
@@ -14,7 +17,7 @@ use std::path::Path;
 use quick_xml::{de, se};
 use serde::{Deserialize, Serialize};
 use zip::read::ZipFile;
-use crate::api::format::Format;
+use crate::api::format::{Format, FormatBorder, FormatColor, NumFormat};
 use crate::file::{XlsxFileType, XlsxFileWriter};
 use crate::xml::common;
 use crate::xml::common::{FromFormat, XmlnsAttrs};
@@ -23,9 +26,11 @@ use crate::xml::io::Io;
 use crate::xml::style::alignment::Alignment;
 use crate::xml::style::border::{Border, Borders};
 use crate::xml::style::color::Color;
-use crate::xml::style::fill::{Fill, Fills};
+use crate::xml::style::fill::{Fill, Fills, PatternFill};
+use crate::xml::style::index::StyleIndex;
 use crate::xml::style::font::{Font, Fonts};
 use crate::xml::style::num_fmt::{NumFmt, NumFmts};
+use crate::xml::style::protection::Protection;
 use crate::xml::style::xf::Xf;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -65,9 +70,11 @@ struct CellStyleXfs {
 
 impl Default for CellStyleXfs {
     fn default() -> Self {
+        let mut xf = Xf::default();
+        xf.xf_id = None;
         CellStyleXfs {
             count: 1,
-            xf: vec![Xf::default()],
+            xf: vec![xf],
         }
     }
 }
@@ -76,7 +83,9 @@ impl Default for CellStyleXfs {
 struct CellXfs {
     #[serde(rename = "@count", default)]
     count: u32,
-    xf: Vec<Xf>
+    xf: Vec<Xf>,
+    #[serde(skip)]
+    index: StyleIndex,
 }
 
 impl Default for CellXfs {
@@ -84,20 +93,16 @@ impl Default for CellXfs {
         CellXfs {
             count: 1,
             xf: vec![Xf::default()],
+            index: StyleIndex::default(),
         }
     }
 }
 
 impl CellXfs {
     pub(crate) fn add_xf(&mut self, xf: &Xf) -> u32 {
-        for i in 0..self.xf.len() {
-            if self.xf[i] == *xf {
-                return i as u32;
-            }
-        }
-        self.count += 1;
-        self.xf.push(xf.clone());
-        self.xf.len() as u32 - 1
+        let id = self.index.add(&mut self.xf, xf);
+        self.count = self.xf.len() as u32;
+        id
     }
 
     pub(crate) fn get_xf(&self, id: u32) -> Option<&Xf> {
@@ -128,8 +133,8 @@ struct CellStyle {
     name: String,
     #[serde(rename = "@xfId", default, skip_serializing_if = "Option::is_none")]
     xf_id: Option<u32>,
-    #[serde(rename = "@builtinId", default)]
-    builtin_id: u32,
+    #[serde(rename = "@builtinId", default, skip_serializing_if = "Option::is_none")]
+    builtin_id: Option<u32>,
     #[serde(rename = "@customBuiltin", default, skip_serializing_if = "Option::is_none")]
     custom_builtin: Option<u32>,
 }
@@ -138,8 +143,8 @@ impl Default for CellStyle {
     fn default() -> Self {
         CellStyle {
             name: "Normal".to_string(),
-            xf_id: None,
-            builtin_id: 0,
+            xf_id: Some(0),
+            builtin_id: Some(0),
             custom_builtin: None,
         }
     }
@@ -153,7 +158,7 @@ struct Dxfs {
     dxf: Vec<Dxf>
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq)]
 struct Dxf {
     #[serde(rename = "font", skip_serializing_if = "Option::is_none")]
     font: Option<Font>,
@@ -240,7 +245,7 @@ impl Default for StyleSheet {
             fonts: Default::default(),
             fills: Default::default(),
             borders: Default::default(),
-            cell_style_xfs: None, //CellStyleXfs::default(),
+            cell_style_xfs: Some(CellStyleXfs::default()),
             cell_xfs: Default::default(),
             cell_styles: Default::default(),
             dxfs: None,//Dxfs::default(),
@@ -252,7 +257,7 @@ impl Default for StyleSheet {
 }
 
 impl StyleSheet {
-    pub(crate) fn add_format(&mut self, format: &Format) -> u32 {
+    fn xf_from_format(&mut self, format: &Format) -> Xf {
         let fonts = self.fonts.get_or_insert(Fonts::default());
         let font = Font::from_format(&format.font);
         let font_id = fonts.add_font(&font);
@@ -265,27 +270,293 @@ impl StyleSheet {
         let mut xf = Xf::default();
         let align = Alignment::from_format(&format.align);
         xf.alignment = Some(align);
+        let protection = Protection::from_format(&format.protection);
+        if protection != Protection::default() {
+            xf.apply_protection = 1;
+            xf.protection = Some(protection);
+        }
         xf.font_id = font_id;
         xf.border_id = border_id;
         xf.fill_id = fill_id;
+        xf.num_fmt_id = match &format.num_format {
+            Some(NumFormat::Builtin(num_fmt_id)) => *num_fmt_id,
+            Some(NumFormat::Custom(format_code)) => self.num_fmts.get_or_insert(NumFmts::default()).add_num_fmt(format_code),
+            None => 0,
+        };
+        xf.apply_number_format = (xf.num_fmt_id != 0) as u32;
+        xf
+    }
+
+    pub(crate) fn add_format(&mut self, format: &Format) -> u32 {
+        let mut xf = self.xf_from_format(format);
+        let style_xf = format.style_name.as_ref()
+            .and_then(|name| self.get_named_style_xf_id(name))
+            .and_then(|xf_id| Some((xf_id, self.cell_style_xfs.as_ref()?.xf.get(xf_id as usize)?.clone())));
+        if let Some((xf_id, style_xf)) = style_xf {
+            // The cell inherits the named style, only the differences need to be applied.
+            xf.xf_id = Some(xf_id);
+            // The number format of the format wins over the one of the style
+            if format.num_format.is_none() {
+                xf.num_fmt_id = style_xf.num_fmt_id;
+            }
+            xf.apply_number_format = (xf.num_fmt_id != style_xf.num_fmt_id) as u32;
+            xf.apply_font = (xf.font_id != style_xf.font_id) as u32;
+            xf.apply_fill = (xf.fill_id != style_xf.fill_id) as u32;
+            xf.apply_border = (xf.border_id != style_xf.border_id) as u32;
+            xf.apply_alignment = (xf.alignment.as_ref().unwrap_or(&Alignment::default())
+                != style_xf.alignment.as_ref().unwrap_or(&Alignment::default())) as u32;
+        }
         let cell_xfs = self.cell_xfs.get_or_insert(CellXfs::default());
         cell_xfs.add_xf(&xf)
     }
 
+    ///
+    /// Returns the id of a cell xf like `style_id` whose border is `border`,
+    /// the other records of the xf, such as its number format, are kept.
+    ///
+    pub(crate) fn set_xf_border(&mut self, style_id: u32, border: &FormatBorder) -> u32 {
+        let mut xf = self.cell_xfs.as_ref()
+            .and_then(|cell_xfs| cell_xfs.get_xf(style_id))
+            .cloned()
+            .unwrap_or_else(Xf::default);
+        let borders = self.borders.get_or_insert(Borders::default());
+        xf.border_id = borders.add_border(&Border::from_format(border));
+        let style_xf = xf.xf_id.and_then(|xf_id| self.cell_style_xfs.as_ref()?.xf.get(xf_id as usize));
+        if let Some(style_xf) = style_xf {
+            xf.apply_border = (xf.border_id != style_xf.border_id) as u32;
+        }
+        let cell_xfs = self.cell_xfs.get_or_insert(CellXfs::default());
+        cell_xfs.add_xf(&xf)
+    }
+
+    ///
+    /// Add a named cell style, replace the style's format if the name already exists.
+    /// Returns the id of the style's xf in cellStyleXfs.
+    ///
+    pub(crate) fn add_named_style(&mut self, name: &str, format: &Format, builtin_id: Option<u32>, num_fmt_id: u32) -> u32 {
+        let mut xf = self.xf_from_format(format);
+        xf.xf_id = None;
+        if format.num_format.is_none() {
+            xf.num_fmt_id = num_fmt_id;
+        }
+        let cell_style_xfs = self.cell_style_xfs.get_or_insert(CellStyleXfs::default());
+        let cell_styles = self.cell_styles.get_or_insert(CellStyles::default());
+        match cell_styles.cell_styles.iter().find(|s| s.name == name) {
+            Some(cell_style) => {
+                let xf_id = cell_style.xf_id.unwrap_or_default();
+                if let Some(style_xf) = cell_style_xfs.xf.get_mut(xf_id as usize) {
+                    *style_xf = xf;
+                }
+                xf_id
+            }
+            None => {
+                cell_style_xfs.xf.push(xf);
+                cell_style_xfs.count = cell_style_xfs.xf.len() as u32;
+                let xf_id = cell_style_xfs.count - 1;
+                cell_styles.cell_styles.push(CellStyle {
+                    name: name.to_string(),
+                    xf_id: Some(xf_id),
+                    builtin_id,
+                    custom_builtin: None,
+                });
+                cell_styles.count = cell_styles.cell_styles.len() as u32;
+                xf_id
+            }
+        }
+    }
+
+    pub(crate) fn get_named_style_xf_id(&self, name: &str) -> Option<u32> {
+        let cell_style = self.cell_styles.as_ref()?.cell_styles.iter().find(|s| s.name == name)?;
+        Some(cell_style.xf_id.unwrap_or_default())
+    }
+
+    pub(crate) fn get_named_style_format(&self, name: &str) -> Option<Format> {
+        let xf_id = self.get_named_style_xf_id(name)?;
+        let mut format = Format::default();
+        if let Some(xf) = self.cell_style_xfs.as_ref().and_then(|xfs| xfs.xf.get(xf_id as usize)) {
+            self.update_format_by_xf(&mut format, xf);
+        }
+        format.style_name = Some(name.to_string());
+        Some(format)
+    }
+
+    pub(crate) fn named_styles(&self) -> Vec<String> {
+        match &self.cell_styles {
+            Some(cell_styles) => cell_styles.cell_styles.iter().map(|s| s.name.clone()).collect(),
+            None => vec![],
+        }
+    }
+
+    fn update_format_by_xf(&self, format: &mut Format, xf: &Xf) {
+        let font = &self.fonts.as_ref().unwrap().get_font(xf.font_id);
+        format.font = font.get_format();
+        let border = &self.borders.as_ref().unwrap().get_border(xf.border_id);
+        format.border = border.get_format();
+        let fill = &self.fills.as_ref().unwrap().get_fill(xf.fill_id);
+        format.fill = fill.get_format();
+        if let Some(alignment) = &xf.alignment {
+            format.align = alignment.get_format();
+        }
+        if let Some(protection) = &xf.protection {
+            format.protection = protection.get_format();
+        }
+        format.num_format = match xf.num_fmt_id {
+            0 => None,
+            num_fmt_id if num_fmt_id < 164 => Some(NumFormat::Builtin(num_fmt_id)),
+            num_fmt_id => self.num_fmts.as_ref()
+                .and_then(|num_fmts| num_fmts.get_format_code(num_fmt_id))
+                .map(|format_code| NumFormat::Custom(format_code.to_string())),
+        };
+    }
+
+    ///
+    /// Remove the cell xfs whose ids are not in `used_xfs`, and the fonts, fills, borders and numFmts
+    /// no longer referenced, duplicated records are merged.
+    /// Returns the new id of every old cell xf, which should be used to remap the styles of the worksheets.
+    ///
+    pub(crate) fn compact(&mut self, used_xfs: &HashSet<u32>) -> Vec<u32> {
+        let cell_xfs = match &self.cell_xfs {
+            Some(cell_xfs) => cell_xfs,
+            None => return vec![],
+        };
+        // The first xf is the default style of cells
+        let kept = |id: usize| id == 0 || used_xfs.contains(&(id as u32));
+        let cell_style_xfs: &[Xf] = match &self.cell_style_xfs {
+            Some(cell_style_xfs) => &cell_style_xfs.xf,
+            None => &[],
+        };
+        // The first two fills are reserved by Excel
+        let (mut used_fonts, mut used_fills, mut used_borders, mut used_num_fmts) =
+            (HashSet::from([0]), HashSet::from([0, 1]), HashSet::from([0]), HashSet::new());
+        cell_xfs.xf.iter().enumerate()
+            .filter(|(id, _)| kept(*id))
+            .map(|(_, xf)| xf)
+            .chain(cell_style_xfs.iter())
+            .for_each(|xf| {
+                used_fonts.insert(xf.font_id);
+                used_fills.insert(xf.fill_id);
+                used_borders.insert(xf.border_id);
+                used_num_fmts.insert(xf.num_fmt_id);
+            });
+        let font_map = self.fonts.as_mut().map(|fonts| fonts.compact(&used_fonts)).unwrap_or_default();
+        let fill_map = self.fills.as_mut().map(|fills| fills.compact(&used_fills)).unwrap_or_default();
+        let border_map = self.borders.as_mut().map(|borders| borders.compact(&used_borders)).unwrap_or_default();
+        if let Some(num_fmts) = &mut self.num_fmts {
+            num_fmts.compact(&used_num_fmts);
+            if num_fmts.is_empty() {
+                self.num_fmts = None;
+            }
+        }
+        let remap = |xf: &mut Xf| {
+            xf.font_id = font_map.get(xf.font_id as usize).copied().unwrap_or_default();
+            xf.fill_id = fill_map.get(xf.fill_id as usize).copied().unwrap_or_default();
+            xf.border_id = border_map.get(xf.border_id as usize).copied().unwrap_or_default();
+        };
+        if let Some(cell_style_xfs) = &mut self.cell_style_xfs {
+            cell_style_xfs.xf.iter_mut().for_each(remap);
+        }
+        let cell_xfs = self.cell_xfs.as_mut().unwrap();
+        let old_xfs = std::mem::take(&mut cell_xfs.xf);
+        cell_xfs.index.clear();
+        old_xfs.into_iter()
+            .enumerate()
+            .map(|(id, mut xf)| match kept(id) {
+                true => {
+                    remap(&mut xf);
+                    cell_xfs.add_xf(&xf)
+                }
+                false => 0,
+            })
+            .collect()
+    }
+
     pub(crate) fn update_format(&self, format: &mut Format, style_id: u32) {
         if let Some(cell_xfs) = &self.cell_xfs {
             if let Some(xf) = cell_xfs.get_xf(style_id) {
-                let font = &self.fonts.as_ref().unwrap().get_font(xf.font_id);
-                format.font = font.get_format();
-                let border = &self.borders.as_ref().unwrap().get_border(xf.border_id);
-                format.border = border.get_format();
-                let fill = &self.fills.as_ref().unwrap().get_fill(xf.fill_id);
-                format.fill = fill.get_format();
-                if let Some(alignment) = &xf.alignment {
-                    format.align = alignment.get_format();
-                }
+                self.update_format_by_xf(format, xf);
+                // The xf 0 of cellStyleXfs is always the Normal style
+                format.style_name = match (xf.xf_id, &self.cell_styles) {
+                    (Some(xf_id), Some(cell_styles)) if xf_id != 0 => cell_styles.cell_styles.iter()
+                        .find(|s| s.xf_id == Some(xf_id))
+                        .map(|s| s.name.clone()),
+                    _ => None,
+                };
+            }
+        }
+    }
+
+    ///
+    /// Add the named cell style of another style sheet if this one has no style of that name.
+    ///
+    pub(crate) fn import_named_style(&mut self, style_sheet: &StyleSheet, name: &str) {
+        if self.get_named_style_xf_id(name).is_some() {
+            return;
+        }
+        let Some(format) = style_sheet.get_named_style_format(name) else { return };
+        let cell_style = style_sheet.cell_styles.as_ref()
+            .and_then(|cell_styles| cell_styles.cell_styles.iter().find(|s| s.name == name));
+        let builtin_id = cell_style.and_then(|cell_style| cell_style.builtin_id);
+        // The number format of the style is carried by its format
+        self.add_named_style(name, &format, builtin_id, 0);
+    }
+
+    ///
+    /// Add a copy of the dxf `dxf_id` of another style sheet and return its id in this one.
+    ///
+    pub(crate) fn import_dxf(&mut self, style_sheet: &StyleSheet, dxf_id: u32) -> Option<u32> {
+        let dxf = style_sheet.dxfs.as_ref()?.dxf.get(dxf_id as usize)?.clone();
+        Some(self.add_dxf(dxf))
+    }
+
+    ///
+    /// The color of the fill, or of the font, of the dxf.
+    ///
+    pub(crate) fn get_dxf_color(&self, dxf_id: u32, cell_color: bool) -> Option<FormatColor> {
+        let dxf = self.dxfs.as_ref()?.dxf.get(dxf_id as usize)?;
+        let color = match cell_color {
+            // The solid fills of the dxfs have their colors in bgColor
+            true => dxf.fill.as_ref()?.pattern_fill.as_ref()
+                .map(|pattern_fill| if pattern_fill.bg_color.is_empty() { &pattern_fill.fg_color } else { &pattern_fill.bg_color })?,
+            false => dxf.font.as_ref()?.color.as_ref()?,
+        };
+        let mut format_color = FormatColor::default();
+        color.set_format(&mut format_color);
+        Some(format_color)
+    }
+
+    ///
+    /// Add a dxf holding only a solid fill, or only a font, of the color and return its id.
+    ///
+    pub(crate) fn add_color_dxf(&mut self, color: &FormatColor, font_color: bool) -> u32 {
+        let color = Color::from_format(color);
+        let mut dxf = Dxf::default();
+        match font_color {
+            true => {
+                let mut font = Font::default();
+                font.sz = None;
+                font.name = None;
+                font.color = Some(color);
+                dxf.font = Some(font);
             }
+            false => dxf.fill = Some(Fill {
+                pattern_fill: Some(PatternFill { pattern_type: "solid".to_string(), fg_color: color.clone(), bg_color: color }),
+                gradient_fill: None,
+            }),
+        }
+        self.add_dxf(dxf)
+    }
+
+    ///
+    /// Return the id of the dxf, which is added if the style sheet has no identical one.
+    ///
+    fn add_dxf(&mut self, dxf: Dxf) -> u32 {
+        let dxfs = self.dxfs.get_or_insert(Dxfs::default());
+        if let Some(id) = dxfs.dxf.iter().position(|d| *d == dxf) {
+            return id as u32;
         }
+        dxfs.dxf.push(dxf);
+        dxfs.count = dxfs.dxf.len() as u32;
+        dxfs.count - 1
     }
 }
 
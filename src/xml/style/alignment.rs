@@ -1,9 +1,8 @@
 use serde::{Deserialize, Serialize};
-use crate::api::format::FormatAlign;
-use crate::FormatAlignType;
+use crate::api::format::{FormatAlign, FormatAlignHorizontal, FormatAlignVertical};
 use crate::xml::common::FromFormat;
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Default, Hash)]
 pub(crate) struct Alignment {
     #[serde(rename = "@horizontal", skip_serializing_if = "Option::is_none")]
     pub(crate) horizontal: Option<String>,
@@ -15,6 +14,8 @@ pub(crate) struct Alignment {
     wrap_text: Option<u8>,
     #[serde(rename = "@indent", skip_serializing_if = "Option::is_none")]
     indent: Option<u8>,
+    #[serde(rename = "@relativeIndent", skip_serializing_if = "Option::is_none")]
+    relative_indent: Option<i32>,
     #[serde(rename = "@justifyLastLine", skip_serializing_if = "Option::is_none")]
     justify_last_line: Option<u8>,
     #[serde(rename = "@shrinkToFit", skip_serializing_if = "Option::is_none")]
@@ -50,13 +51,34 @@ impl FromFormat<FormatAlign> for Alignment {
         if format.indent != 0 {
             self.indent = Some(format.indent);
         }
+        if format.relative_indent != 0 {
+            self.relative_indent = Some(format.relative_indent);
+        }
+        if format.wrap_text {
+            self.wrap_text = Some(1);
+        }
+        if format.shrink_to_fit {
+            self.shrink_to_fit = Some(1);
+        }
+        if format.justify_last_line {
+            self.justify_last_line = Some(1);
+        }
+        // only 0-180 and 255 are valid text rotations
+        if let 1..=180 | 255 = format.text_rotation {
+            self.text_rotation = Some(format.text_rotation);
+        }
     }
 
     fn set_format(&self, format: &mut FormatAlign) {
         format.indent = self.indent.unwrap_or(0);
+        format.relative_indent = self.relative_indent.unwrap_or(0);
         format.reading_order = self.reading_order.unwrap_or(1);
-        format.horizontal = FormatAlignType::from_str(self.horizontal.as_ref(), true);
-        format.vertical = FormatAlignType::from_str(self.vertical.as_ref(), false);
+        format.horizontal = self.horizontal.as_deref().map(FormatAlignHorizontal::from_str);
+        format.vertical = self.vertical.as_deref().map(FormatAlignVertical::from_str);
+        format.wrap_text = self.wrap_text.unwrap_or(0) != 0;
+        format.shrink_to_fit = self.shrink_to_fit.unwrap_or(0) != 0;
+        format.justify_last_line = self.justify_last_line.unwrap_or(0) != 0;
+        format.text_rotation = self.text_rotation.unwrap_or(0);
     }
 }
 
@@ -1,24 +1,29 @@
+use std::collections::HashSet;
 use serde::{Deserialize, Serialize};
 use crate::xml::common;
 use crate::xml::style::color::Color;
+use crate::xml::style::index::StyleIndex;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub(crate) struct Borders {
     #[serde(rename = "@count", default, skip_serializing_if = "common::is_zero")]
     count: u32,
     border: Vec<Border>,
+    #[serde(skip)]
+    index: StyleIndex,
 }
 
 impl Borders {
     pub(crate) fn add_border(&mut self, border: &Border) -> u32 {
-        for i in 0..self.border.len() {
-            if self.border[i] == *border {
-                return i as u32;
-            }
-        }
-        self.count += 1;
-        self.border.push(border.clone());
-        self.border.len() as u32 - 1
+        let id = self.index.add(&mut self.border, border);
+        self.count = self.border.len() as u32;
+        id
+    }
+
+    pub(crate) fn compact(&mut self, used: &HashSet<u32>) -> Vec<u32> {
+        let border_map = self.index.compact(&mut self.border, used);
+        self.count = self.border.len() as u32;
+        border_map
     }
 
     pub(crate) fn get_border(&self, id: u32) -> Option<&Border> {
@@ -26,7 +31,7 @@ impl Borders {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default, Hash)]
 pub(crate) struct Border {
     #[serde(rename = "@diagonalUp", skip_serializing_if = "Option::is_none")]
     pub(crate) diagonal_up: Option<u8>,
@@ -44,9 +49,13 @@ pub(crate) struct Border {
     pub(crate) bottom: Option<BorderElement>,
     #[serde(rename = "diagonal", skip_serializing_if = "Option::is_none")]
     pub(crate) diagonal: Option<BorderElement>,
+    #[serde(rename = "vertical", skip_serializing_if = "Option::is_none")]
+    pub(crate) vertical: Option<BorderElement>,
+    #[serde(rename = "horizontal", skip_serializing_if = "Option::is_none")]
+    pub(crate) horizontal: Option<BorderElement>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Hash)]
 pub(crate) struct BorderElement {
     #[serde(rename = "@style", skip_serializing_if = "Option::is_none")]
     pub(crate) style: Option<String>,
@@ -68,6 +77,7 @@ impl Default for Borders {
         Borders {
             count: 0,
             border: vec![],
+            index: StyleIndex::default(),
         }
     }
 }
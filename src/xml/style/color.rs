@@ -1,3 +1,4 @@
+use std::hash::{Hash, Hasher};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
@@ -14,6 +15,16 @@ pub(crate) struct Color {
     auto: Option<u32>
 }
 
+impl Hash for Color {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.indexed.hash(state);
+        self.rgb.hash(state);
+        self.theme.hash(state);
+        self.tint.map(f64::to_bits).hash(state);
+        self.auto.hash(state);
+    }
+}
+
 impl Color {
     pub(crate) fn from_rgb(r: u8, g: u8, b: u8) -> Color {
         let rgb = format!("FF{:02X}{:02X}{:02X}", r, g, b);
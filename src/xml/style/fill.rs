@@ -1,7 +1,8 @@
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 use serde::{Deserialize, Serialize};
-use crate::api::format::FormatFill;
-use crate::xml::common::FromFormat;
 use crate::xml::style::color::Color;
+use crate::xml::style::index::StyleIndex;
 
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
@@ -9,19 +10,22 @@ pub(crate) struct Fills {
     #[serde(rename = "@count", default)]
     count: u32,
     #[serde(rename = "fill", default)]
-    fills: Vec<Fill>
+    fills: Vec<Fill>,
+    #[serde(skip)]
+    index: StyleIndex,
 }
 
 impl Fills {
     pub(crate) fn add_fill(&mut self, fill: &Fill) -> u32 {
-        for i in 0..self.fills.len() {
-            if self.fills[i] == *fill {
-                return i as u32;
-            }
-        }
-        self.count += 1;
-        self.fills.push(fill.clone());
-        self.fills.len() as u32 - 1
+        let id = self.index.add(&mut self.fills, fill);
+        self.count = self.fills.len() as u32;
+        id
+    }
+
+    pub(crate) fn compact(&mut self, used: &HashSet<u32>) -> Vec<u32> {
+        let fill_map = self.index.compact(&mut self.fills, used);
+        self.count = self.fills.len() as u32;
+        fill_map
     }
     
     pub(crate) fn get_fill(&self, id: u32) -> Option<&Fill> {
@@ -29,13 +33,24 @@ impl Fills {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Hash)]
 pub(crate) struct Fill {
-    #[serde(rename = "patternFill")]
-    pub(crate) pattern_fill: PatternFill
+    #[serde(rename = "patternFill", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) pattern_fill: Option<PatternFill>,
+    #[serde(rename = "gradientFill", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) gradient_fill: Option<GradientFill>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+impl Default for Fill {
+    fn default() -> Fill {
+        Fill {
+            pattern_fill: Some(PatternFill::default()),
+            gradient_fill: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Hash)]
 pub(crate) struct PatternFill {
     #[serde(rename = "@patternType", default, skip_serializing_if = "String::is_empty")]
     pub(crate) pattern_type: String,
@@ -53,4 +68,49 @@ impl Default for PatternFill {
             bg_color: Color::default(),
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub(crate) struct GradientFill {
+    #[serde(rename = "@type", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) gradient_type: Option<String>,
+    #[serde(rename = "@degree", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) degree: Option<f64>,
+    #[serde(rename = "@left", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) left: Option<f64>,
+    #[serde(rename = "@right", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) right: Option<f64>,
+    #[serde(rename = "@top", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) top: Option<f64>,
+    #[serde(rename = "@bottom", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) bottom: Option<f64>,
+    #[serde(rename = "stop", default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) stops: Vec<Stop>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub(crate) struct Stop {
+    #[serde(rename = "@position", default)]
+    pub(crate) position: f64,
+    #[serde(rename = "color", default)]
+    pub(crate) color: Color,
+}
+
+impl Hash for GradientFill {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.gradient_type.hash(state);
+        self.degree.map(f64::to_bits).hash(state);
+        self.left.map(f64::to_bits).hash(state);
+        self.right.map(f64::to_bits).hash(state);
+        self.top.map(f64::to_bits).hash(state);
+        self.bottom.map(f64::to_bits).hash(state);
+        self.stops.hash(state);
+    }
+}
+
+impl Hash for Stop {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.position.to_bits().hash(state);
+        self.color.hash(state);
+    }
+}
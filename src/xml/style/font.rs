@@ -1,8 +1,10 @@
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 use serde::{Deserialize, Serialize};
 use crate::xml::common::{Element, FromFormat};
 use crate::xml::style::color::Color;
-use crate::api::format::FormatFont;
-use crate::RichText;
+use crate::xml::style::index::StyleIndex;
+use crate::api::format::{FormatFont, FormatFontScheme, FormatUnderlineType, FormatVertAlignType};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub(crate) struct Fonts {
@@ -12,6 +14,8 @@ pub(crate) struct Fonts {
     x14ac_known_fonts: Option<u32>,
     #[serde(rename = "font", default)]
     fonts: Vec<Font>,
+    #[serde(skip)]
+    index: StyleIndex,
 }
 
 impl Default for Fonts {
@@ -20,20 +24,22 @@ impl Default for Fonts {
             count: 1,
             x14ac_known_fonts: Some(1),
             fonts: vec![Default::default()],
+            index: StyleIndex::default(),
         }
     }
 }
 
 impl Fonts {
     pub(crate) fn add_font(&mut self, font: &Font) -> u32 {
-        for i in 0..self.fonts.len() {
-            if self.fonts[i] == *font {
-                return i as u32;
-            }
-        }
-        self.count += 1;
-        self.fonts.push(font.clone());
-        self.fonts.len() as u32 - 1
+        let id = self.index.add(&mut self.fonts, font);
+        self.count = self.fonts.len() as u32;
+        id
+    }
+
+    pub(crate) fn compact(&mut self, used: &HashSet<u32>) -> Vec<u32> {
+        let font_map = self.index.compact(&mut self.fonts, used);
+        self.count = self.fonts.len() as u32;
+        font_map
     }
     
     pub(crate) fn get_font(&self, id: u32) -> Option<&Font> {
@@ -48,15 +54,15 @@ pub(crate) struct Font {
     #[serde(rename = "i", skip_serializing_if = "Option::is_none")]
     pub(crate) italic: Option<Italic>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    strike: Option<Element<u8>>,
+    strike: Option<BoolElement>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    condense: Option<Element<u8>>,
+    condense: Option<BoolElement>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    extend: Option<Element<u8>>,
+    extend: Option<BoolElement>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    outline: Option<Element<u8>>,
+    outline: Option<BoolElement>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    shadow: Option<Element<u8>>,
+    shadow: Option<BoolElement>,
     #[serde(rename = "u", skip_serializing_if = "Option::is_none")]
     pub(crate) underline: Option<Underline>,
     #[serde(rename = "vertAlign", skip_serializing_if = "Option::is_none")]
@@ -77,6 +83,27 @@ pub(crate) struct Font {
     scheme: Option<Element<String>>,
 }
 
+impl Hash for Font {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bold.hash(state);
+        self.italic.hash(state);
+        self.strike.hash(state);
+        self.condense.hash(state);
+        self.extend.hash(state);
+        self.outline.hash(state);
+        self.shadow.hash(state);
+        self.underline.hash(state);
+        self.vert_align.hash(state);
+        self.sz.as_ref().map(|sz| sz.val.to_bits()).hash(state);
+        self.color.hash(state);
+        self.name.hash(state);
+        self.rich_font_name.hash(state);
+        self.family.hash(state);
+        self.charset.hash(state);
+        self.scheme.hash(state);
+    }
+}
+
 impl Default for Font {
     fn default() -> Font {
         Font {
@@ -100,7 +127,7 @@ impl Default for Font {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Hash)]
 pub(crate) struct Bold {
 
 }
@@ -111,7 +138,7 @@ impl Bold {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Hash)]
 pub(crate) struct Italic {
 
 }
@@ -122,14 +149,54 @@ impl Italic {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Hash)]
 pub(crate) struct Underline {
-
+    #[serde(rename = "@val", skip_serializing_if = "Option::is_none")]
+    val: Option<String>,
 }
 
 impl Underline {
     pub(crate) fn default() -> Underline {
-        Underline {}
+        Underline {
+            val: None,
+        }
+    }
+
+    fn from_underline_type(underline_type: &FormatUnderlineType) -> Option<Underline> {
+        match underline_type {
+            FormatUnderlineType::None => None,
+            FormatUnderlineType::Single => Some(Underline::default()),
+            _ => Some(Underline { val: Some(underline_type.to_str().to_string()) }),
+        }
+    }
+
+    fn get_underline_type(&self) -> FormatUnderlineType {
+        match &self.val {
+            None => FormatUnderlineType::Single,
+            Some(val) => FormatUnderlineType::from_str(val),
+        }
+    }
+}
+
+///
+/// The boolean property of font, such as `<strike/>`, whose `val` is optional and defaults to true.
+///
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default, Hash)]
+pub(crate) struct BoolElement {
+    #[serde(rename = "@val", skip_serializing_if = "Option::is_none")]
+    val: Option<String>,
+}
+
+impl BoolElement {
+    fn from_bool(val: bool) -> Option<BoolElement> {
+        if val { Some(BoolElement::default()) } else { None }
+    }
+
+    fn is_true(element: &Option<BoolElement>) -> bool {
+        match element {
+            Some(element) => !matches!(element.val.as_deref(), Some("0") | Some("false")),
+            None => false,
+        }
     }
 }
 
@@ -139,14 +206,41 @@ impl FromFormat<FormatFont> for Font {
         self.name = Some(Element::from_val(format.name.to_string()));
         self.sz = Some(Element::from_val(format.size));
         self.bold = if format.bold { Some(Bold::default()) } else { None };
-        self.underline = if format.underline { Some(Underline::default()) } else { None };
+        self.underline = Underline::from_underline_type(&format.underline);
         self.italic = if format.italic { Some(Italic::default()) } else { None };
+        self.strike = BoolElement::from_bool(format.strike);
+        self.outline = BoolElement::from_bool(format.outline);
+        self.shadow = BoolElement::from_bool(format.shadow);
+        self.vert_align = match format.vert_align {
+            FormatVertAlignType::Baseline => None,
+            vert_align => Some(Element::from_val(vert_align.to_str().to_string())),
+        };
+        self.family = format.family.map(Element::from_val);
+        self.charset = format.charset.map(Element::from_val);
+        self.scheme = match format.scheme {
+            FormatFontScheme::None => None,
+            scheme => Some(Element::from_val(scheme.to_str().to_string())),
+        };
     }
 
     fn set_format(&self, format: &mut FormatFont) {
         format.bold = self.bold.is_some();
         format.italic = self.italic.is_some();
-        format.underline = self.underline.is_some();
+        format.underline = match &self.underline {
+            Some(underline) => underline.get_underline_type(),
+            None => FormatUnderlineType::None,
+        };
+        format.strike = BoolElement::is_true(&self.strike);
+        format.outline = BoolElement::is_true(&self.outline);
+        format.shadow = BoolElement::is_true(&self.shadow);
+        if let Some(vert_align) = &self.vert_align {
+            format.vert_align = FormatVertAlignType::from_str(&vert_align.val);
+        }
+        format.family = self.family.as_ref().map(|family| family.val);
+        format.charset = self.charset.as_ref().map(|charset| charset.val);
+        if let Some(scheme) = &self.scheme {
+            format.scheme = FormatFontScheme::from_str(&scheme.val);
+        }
         if let Some(size) = &self.sz {
             format.size = size.get_format();
         }
@@ -0,0 +1,101 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+///
+/// A hash index over a list of style records (fonts, fills, borders, xfs, numFmts),
+/// so that adding a record does not need to scan the whole list.
+/// Records are compared by `PartialEq` after the hashes matched,
+/// the first equal record wins as the linear scan did.
+///
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StyleIndex {
+    ids: HashMap<u64, Vec<u32>>,
+    // The number of records the index was built from,
+    // the index is rebuilt lazily when the list was deserialized or changed elsewhere.
+    len: usize,
+}
+
+impl StyleIndex {
+    ///
+    /// Returns the id of the record, the record is pushed to `records` if it is not found.
+    ///
+    pub(crate) fn add<T: Hash + PartialEq + Clone>(&mut self, records: &mut Vec<T>, record: &T) -> u32 {
+        self.sync(records, hash);
+        let key = hash(record);
+        if let Some(ids) = self.ids.get(&key) {
+            if let Some(id) = ids.iter().find(|&&id| records[id as usize] == *record) {
+                return *id;
+            }
+        }
+        records.push(record.clone());
+        let id = records.len() as u32 - 1;
+        self.ids.entry(key).or_default().push(id);
+        self.len = records.len();
+        id
+    }
+
+    ///
+    /// The id of the first record whose key is `key`, the records are indexed by their keys,
+    /// such as the format code of a number format. A list is indexed either by its records or by their keys.
+    ///
+    pub(crate) fn find_by_key<T, K: Hash + PartialEq + ?Sized>(&mut self, records: &[T], key: &K, key_of: impl Fn(&T) -> &K) -> Option<u32> {
+        self.sync(records, |record| hash(key_of(record)));
+        self.ids.get(&hash(key))?
+            .iter()
+            .find(|&&id| key_of(&records[id as usize]) == key)
+            .copied()
+    }
+
+    ///
+    /// Returns the id of the record whose key is the one of `record`, the record is pushed if it is not found.
+    ///
+    pub(crate) fn add_by_key<T: Clone, K: Hash + PartialEq + ?Sized>(&mut self, records: &mut Vec<T>, record: &T, key_of: impl Fn(&T) -> &K) -> u32 {
+        if let Some(id) = self.find_by_key(records, key_of(record), &key_of) {
+            return id;
+        }
+        records.push(record.clone());
+        let id = records.len() as u32 - 1;
+        self.ids.entry(hash(key_of(record))).or_default().push(id);
+        self.len = records.len();
+        id
+    }
+
+    ///
+    /// Drop the records whose ids are not in `used` and merge the duplicated ones.
+    /// Returns the new id of every old record, unused records are mapped to 0.
+    ///
+    pub(crate) fn compact<T: Hash + PartialEq + Clone>(&mut self, records: &mut Vec<T>, used: &HashSet<u32>) -> Vec<u32> {
+        let old_records = std::mem::take(records);
+        self.clear();
+        old_records.iter()
+            .enumerate()
+            .map(|(id, record)| match used.contains(&(id as u32)) {
+                true => self.add(records, record),
+                false => 0,
+            })
+            .collect()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.ids.clear();
+        self.len = 0;
+    }
+
+    fn sync<T>(&mut self, records: &[T], hash_of: impl Fn(&T) -> u64) {
+        if self.len == records.len() {
+            return;
+        }
+        self.clear();
+        for (id, record) in records.iter().enumerate() {
+            self.ids.entry(hash_of(record)).or_default().push(id as u32);
+        }
+        self.len = records.len();
+    }
+}
+
+fn hash<T: Hash + ?Sized>(record: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    record.hash(&mut hasher);
+    hasher.finish()
+}
@@ -1,17 +1,63 @@
+use std::collections::HashSet;
 use serde::{Deserialize, Serialize};
+use crate::xml::style::index::StyleIndex;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+/// The ids below are the built-in number formats, which are not written in the style sheet.
+const FIRST_CUSTOM_ID: u32 = 164;
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub(crate) struct NumFmts {
     #[serde(rename = "@count", default)]
     count: u32,
     #[serde(rename = "numFmt", default)]
     num_fmt: Vec<NumFmt>,
+    #[serde(skip)]
+    index: StyleIndex,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq)]
 pub(crate) struct NumFmt {
     #[serde(rename = "@numFmtId", default)]
     num_fmt_id: u32,
     #[serde(rename = "@formatCode", default)]
     format_code: String
-}
\ No newline at end of file
+}
+
+impl NumFmts {
+    ///
+    /// Returns the id of the custom number format whose code is `format_code`,
+    /// the format is added with a free id if it does not exist.
+    ///
+    pub(crate) fn add_num_fmt(&mut self, format_code: &str) -> u32 {
+        let num_fmt = NumFmt {
+            num_fmt_id: self.num_fmt.iter()
+                .map(|num_fmt| num_fmt.num_fmt_id + 1)
+                .max()
+                .unwrap_or_default()
+                .max(FIRST_CUSTOM_ID),
+            format_code: format_code.to_string(),
+        };
+        let id = self.index.add_by_key(&mut self.num_fmt, &num_fmt, |num_fmt| num_fmt.format_code.as_str());
+        self.count = self.num_fmt.len() as u32;
+        self.num_fmt[id as usize].num_fmt_id
+    }
+
+    pub(crate) fn get_format_code(&self, num_fmt_id: u32) -> Option<&str> {
+        self.num_fmt.iter()
+            .find(|num_fmt| num_fmt.num_fmt_id == num_fmt_id)
+            .map(|num_fmt| num_fmt.format_code.as_str())
+    }
+
+    ///
+    /// Drop the custom number formats whose ids are not in `used`, the ids of the others are kept.
+    ///
+    pub(crate) fn compact(&mut self, used: &HashSet<u32>) {
+        self.num_fmt.retain(|num_fmt| used.contains(&num_fmt.num_fmt_id));
+        self.index.clear();
+        self.count = self.num_fmt.len() as u32;
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.num_fmt.is_empty()
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Default, Hash)]
+pub(crate) struct Protection {
+    #[serde(rename = "@locked", skip_serializing_if = "Option::is_none")]
+    pub(crate) locked: Option<u8>,
+    #[serde(rename = "@hidden", skip_serializing_if = "Option::is_none")]
+    pub(crate) hidden: Option<u8>,
+}
@@ -1,20 +1,22 @@
 use serde::{Deserialize, Serialize};
 use crate::api::format::FormatAlign;
 use crate::xml::style::alignment::Alignment;
+use crate::xml::style::protection::Protection;
 use crate::xml::common;
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Hash)]
 pub(crate) struct Xf {
     #[serde(rename = "@numFmtId", default)]
-    num_fmt_id: u32,
+    pub(crate) num_fmt_id: u32,
     #[serde(rename = "@fontId", default)]
     pub(crate) font_id: u32,
     #[serde(rename = "@fillId", default)]
     pub(crate) fill_id: u32,
     #[serde(rename = "@borderId", default)]
     pub(crate) border_id: u32,
-    #[serde(rename = "@xfId", default)] //, skip_serializing_if = "common::is_zero")]
-    xf_id: u32,
+    // The xf of cellStyleXfs has no xfId
+    #[serde(rename = "@xfId", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) xf_id: Option<u32>,
     #[serde(rename = "@applyNumberFormat", default, skip_serializing_if = "common::is_zero")]
     pub(crate) apply_number_format: u32,
     #[serde(rename = "@applyFont", default, skip_serializing_if = "common::is_zero")]
@@ -22,13 +24,15 @@ pub(crate) struct Xf {
     #[serde(rename = "@applyFill", default, skip_serializing_if = "common::is_zero")]
     pub(crate) apply_fill: u32,
     #[serde(rename = "@applyBorder", default, skip_serializing_if = "common::is_zero")]
-    apply_border: u32,
+    pub(crate) apply_border: u32,
     #[serde(rename = "@applyAlignment", default, skip_serializing_if = "common::is_zero")]
     pub(crate) apply_alignment: u32,
     #[serde(rename = "@applyProtection", default, skip_serializing_if = "common::is_zero")]
-    apply_protection: u32,
+    pub(crate) apply_protection: u32,
     #[serde(rename = "alignment", skip_serializing_if = "Option::is_none")]
     pub(crate) alignment: Option<Alignment>,
+    #[serde(rename = "protection", skip_serializing_if = "Option::is_none")]
+    pub(crate) protection: Option<Protection>,
 }
 
 impl Xf {
@@ -38,7 +42,7 @@ impl Xf {
             font_id: 0,
             fill_id: 0,
             border_id: 0,
-            xf_id: 0,
+            xf_id: Some(0),
             apply_font: 0,
             apply_fill: 0,
             apply_border: 0,
@@ -46,6 +50,7 @@ impl Xf {
             apply_number_format: 0,
             alignment: None,
             apply_protection: 0,
+            protection: None,
         }
     }
     
@@ -1,8 +1,17 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use quick_xml::{de, se};
+use quick_xml::events::Event;
+use quick_xml::Reader;
 use serde::{Deserialize, Serialize};
+use zip::ZipArchive;
 use crate::xml::style::color::Color;
 use crate::api::theme::Theme as ApiTheme;
+use crate::file::{XlsxFileType, XlsxFileWriter};
 use crate::FormatColor;
 use crate::xml::common::FromFormat;
+use crate::xml::io::Io;
 
 #[derive(Debug, Default)]
 pub(crate) struct Themes {
@@ -15,29 +24,171 @@ impl Themes {
     }
 }
 
+impl Io<Themes> for Themes {
+    fn save<P: AsRef<Path>>(&self, file_path: P) {
+        self.themes.iter()
+            .filter(|theme| theme.modified)
+            .for_each(|theme| {
+                let mut file = XlsxFileWriter::from_path(&file_path, XlsxFileType::Theme(theme.id)).unwrap();
+                file.write_all(theme.to_xml().as_ref()).unwrap();
+            });
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename(serialize = "a:theme", deserialize = "theme"))]
 pub(crate) struct Theme {
     #[serde(rename(serialize = "a:themeElements", deserialize = "themeElements"), default)]
     theme_elements: ThemeElements,
+    // The theme part is only partially modelled,
+    // so the original xml is kept and the modified schemes are written back into it.
+    #[serde(skip)]
+    raw: String,
+    #[serde(skip)]
+    pub(crate) id: u32,
+    #[serde(skip)]
+    pub(crate) modified: bool,
 }
 
 impl Theme {
+    pub(crate) fn from_xml(id: u32, xml: &str) -> Option<Theme> {
+        let mut theme: Theme = de::from_str(xml).ok()?;
+        theme.raw = xml.to_string();
+        theme.id = id;
+        Some(theme)
+    }
+
+    ///
+    /// Read a theme part such as `xl/theme/theme1.xml` from the archive.
+    ///
+    pub(crate) fn from_zip_file(archive: &mut ZipArchive<File>, path: &str) -> Option<Theme> {
+        let mut file = archive.by_name(path).ok()?;
+        let mut xml = String::new();
+        file.read_to_string(&mut xml).ok()?;
+        let id = path.trim_start_matches("xl/theme/theme")
+            .trim_end_matches(".xml")
+            .parse()
+            .unwrap_or(1);
+        Self::from_xml(id, &xml)
+    }
+
+    ///
+    /// Replace the whole theme by another theme part, such as the one in a `.thmx` file.
+    ///
+    pub(crate) fn replace_by_xml(&mut self, xml: &str) -> Option<()> {
+        let theme = Self::from_xml(self.id, xml)?;
+        self.theme_elements = theme.theme_elements;
+        self.raw = theme.raw;
+        self.modified = true;
+        Some(())
+    }
+
     pub(crate) fn to_api_theme(&self) -> ApiTheme {
-        let clr = &self.theme_elements.clr_theme.clr;
-        let colors_rgb = clr.iter()
+        let mut colors_rgb = self.theme_elements.clr_theme.colors().iter()
             .map(|clr| clr.to_color())
             .map(|c| c.get_format())
             .collect::<Vec<FormatColor>>();
-        ApiTheme::new(colors_rgb)
+        // The scheme lists dk1, lt1, dk2, lt2 first, while the theme index of cells starts with lt1, dk1, lt2, dk2.
+        colors_rgb.swap(0, 1);
+        colors_rgb.swap(2, 3);
+        let font_scheme = &self.theme_elements.font_scheme;
+        ApiTheme::new(
+            colors_rgb,
+            &font_scheme.major_font.latin.typeface,
+            &font_scheme.minor_font.latin.typeface,
+        )
+    }
+
+    ///
+    /// Update the color scheme and the font scheme by the [`ApiTheme`],
+    /// only the colors and fonts which are different from the current ones are replaced.
+    ///
+    pub(crate) fn update_by_api_theme(&mut self, api_theme: &ApiTheme) {
+        let current = self.to_api_theme();
+        for theme_id in 0..12u8 {
+            let color = api_theme.to_rgb(&api_theme.get_color(theme_id));
+            if let FormatColor::RGB(r, g, b) = color {
+                if color != current.get_color(theme_id) {
+                    // swap lt1, dk1, lt2, dk2 back to the order of the scheme
+                    let slot = match theme_id {
+                        0 => 1,
+                        1 => 0,
+                        2 => 3,
+                        3 => 2,
+                        id => id as usize,
+                    };
+                    *self.theme_elements.clr_theme.colors_mut()[slot] = Clr::from_rgb(r, g, b);
+                    self.modified = true;
+                }
+            }
+        }
+        let font_scheme = &mut self.theme_elements.font_scheme;
+        if api_theme.get_major_font() != current.get_major_font() {
+            font_scheme.major_font.latin = TextFont::new(api_theme.get_major_font());
+            self.modified = true;
+        }
+        if api_theme.get_minor_font() != current.get_minor_font() {
+            font_scheme.minor_font.latin = TextFont::new(api_theme.get_minor_font());
+            self.modified = true;
+        }
+    }
+
+    fn to_xml(&self) -> String {
+        let mut xml = self.raw.clone();
+        let clr_scheme = se::to_string_with_root("a:clrScheme", &self.theme_elements.clr_theme).unwrap();
+        replace_element(&mut xml, "clrScheme", &clr_scheme);
+        let font_scheme = se::to_string_with_root("a:fontScheme", &self.theme_elements.font_scheme).unwrap();
+        replace_element(&mut xml, "fontScheme", &font_scheme);
+        xml
     }
+}
+
+///
+/// Replace the first element of the local name `name` with the new element, whatever the prefix of the xml.
+/// The new element uses the `a` prefix, which is declared on it if the xml binds another prefix to the namespace.
+///
+fn replace_element(xml: &mut String, name: &str, element: &str) {
+    let Some((start, end, prefix)) = find_element(xml, name) else { return };
+    let element = match prefix.as_str() {
+        "a" => element.to_string(),
+        _ => element.replacen(&format!("<a:{name}"), &format!("<a:{name} xmlns:a=\"{DRAWING_NAMESPACE}\""), 1),
+    };
+    xml.replace_range(start..end, &element);
+}
 
-    pub(crate) fn get_color_rgb(&self, color_theme: u32) -> Color {
-        let clr = &self.theme_elements.clr_theme.clr;
-        if let Some(clr) = clr.get(color_theme as usize) {
-            clr.to_color()
-        } else {
-            Color::default()
+const DRAWING_NAMESPACE: &str = "http://schemas.openxmlformats.org/drawingml/2006/main";
+
+///
+/// The position of the first element of the local name `name` in the xml, and its prefix.
+///
+fn find_element(xml: &str, name: &str) -> Option<(usize, usize, String)> {
+    let mut reader = Reader::from_str(xml);
+    let mut found: Option<(usize, String)> = None;
+    let mut depth = 0;
+    loop {
+        let position = reader.buffer_position();
+        match reader.read_event().ok()? {
+            Event::Start(e) => match &found {
+                Some(_) => depth += 1,
+                None if e.local_name().as_ref() == name.as_bytes() => {
+                    let prefix = e.name().prefix().map(|prefix| String::from_utf8_lossy(prefix.as_ref()).to_string());
+                    found = Some((position, prefix.unwrap_or_default()));
+                }
+                None => {}
+            },
+            Event::Empty(e) if found.is_none() && e.local_name().as_ref() == name.as_bytes() => {
+                let prefix = e.name().prefix().map(|prefix| String::from_utf8_lossy(prefix.as_ref()).to_string());
+                return Some((position, reader.buffer_position(), prefix.unwrap_or_default()));
+            }
+            Event::End(_) if found.is_some() => match depth {
+                0 => {
+                    let (start, prefix) = found?;
+                    return Some((start, reader.buffer_position(), prefix));
+                }
+                _ => depth -= 1,
+            },
+            Event::Eof => return None,
+            _ => {}
         }
     }
 }
@@ -46,23 +197,74 @@ impl Theme {
 pub(crate) struct ThemeElements {
     #[serde(rename(serialize = "a:clrScheme", deserialize = "clrScheme"), default)]
     clr_theme: ClrTheme,
+    #[serde(rename(serialize = "a:fontScheme", deserialize = "fontScheme"), default)]
+    font_scheme: FontScheme,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub(crate) struct ClrTheme {
-    #[serde(rename = "$value")]
-    clr: Vec<Clr>,
+    #[serde(rename = "@name", default)]
+    name: String,
+    #[serde(rename(serialize = "a:dk1", deserialize = "dk1"), default)]
+    dk1: Clr,
+    #[serde(rename(serialize = "a:lt1", deserialize = "lt1"), default)]
+    lt1: Clr,
+    #[serde(rename(serialize = "a:dk2", deserialize = "dk2"), default)]
+    dk2: Clr,
+    #[serde(rename(serialize = "a:lt2", deserialize = "lt2"), default)]
+    lt2: Clr,
+    #[serde(rename(serialize = "a:accent1", deserialize = "accent1"), default)]
+    accent1: Clr,
+    #[serde(rename(serialize = "a:accent2", deserialize = "accent2"), default)]
+    accent2: Clr,
+    #[serde(rename(serialize = "a:accent3", deserialize = "accent3"), default)]
+    accent3: Clr,
+    #[serde(rename(serialize = "a:accent4", deserialize = "accent4"), default)]
+    accent4: Clr,
+    #[serde(rename(serialize = "a:accent5", deserialize = "accent5"), default)]
+    accent5: Clr,
+    #[serde(rename(serialize = "a:accent6", deserialize = "accent6"), default)]
+    accent6: Clr,
+    #[serde(rename(serialize = "a:hlink", deserialize = "hlink"), default)]
+    hlink: Clr,
+    #[serde(rename(serialize = "a:folHlink", deserialize = "folHlink"), default)]
+    fol_hlink: Clr,
+}
+
+impl ClrTheme {
+    fn colors(&self) -> [&Clr; 12] {
+        [
+            &self.dk1, &self.lt1, &self.dk2, &self.lt2,
+            &self.accent1, &self.accent2, &self.accent3, &self.accent4, &self.accent5, &self.accent6,
+            &self.hlink, &self.fol_hlink,
+        ]
+    }
+
+    fn colors_mut(&mut self) -> [&mut Clr; 12] {
+        [
+            &mut self.dk1, &mut self.lt1, &mut self.dk2, &mut self.lt2,
+            &mut self.accent1, &mut self.accent2, &mut self.accent3, &mut self.accent4, &mut self.accent5, &mut self.accent6,
+            &mut self.hlink, &mut self.fol_hlink,
+        ]
+    }
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub(crate) struct Clr {
-    #[serde(rename(serialize = "a:sysClr", deserialize = "sysClr"), default)]
+    #[serde(rename(serialize = "a:sysClr", deserialize = "sysClr"), default, skip_serializing_if = "Option::is_none")]
     sys_clr: Option<SysClr>,
-    #[serde(rename(serialize = "a:srgbClr", deserialize = "srgbClr"), default)]
+    #[serde(rename(serialize = "a:srgbClr", deserialize = "srgbClr"), default, skip_serializing_if = "Option::is_none")]
     srgb_clr: Option<SrgbClr>,
 }
 
 impl Clr {
+    fn from_rgb(r: u8, g: u8, b: u8) -> Clr {
+        Clr {
+            sys_clr: None,
+            srgb_clr: Some(SrgbClr { val: format!("{:02X}{:02X}{:02X}", r, g, b) }),
+        }
+    }
+
     fn to_color(&self) -> Color {
         match self {
             Clr {
@@ -88,13 +290,24 @@ impl Clr {
 pub(crate) struct SysClr {
     #[serde(rename = "@val", default)]
     val: String,
-    #[serde(rename = "@lastClr", default)]
+    #[serde(rename = "@lastClr", default, skip_serializing_if = "String::is_empty")]
     last_clr: String,
 }
 
 impl SysClr {
     fn to_color_rgb(&self) -> Color {
-        Color::from_rgb_hex(&self.last_clr)
+        if !self.last_clr.is_empty() {
+            return Color::from_rgb_hex(&self.last_clr);
+        }
+        // Without lastClr, fall back to the usual values of the system colors.
+        match self.val.as_str() {
+            "windowText" | "menuText" | "btnText" | "captionText" | "infoText" => Color::from_rgb_hex("000000"),
+            "window" | "menu" | "highlightText" => Color::from_rgb_hex("FFFFFF"),
+            "btnFace" | "3dLight" => Color::from_rgb_hex("F0F0F0"),
+            "btnShadow" | "grayText" => Color::from_rgb_hex("A0A0A0"),
+            "highlight" => Color::from_rgb_hex("0078D7"),
+            _ => Color::default(),
+        }
     }
 }
 
@@ -108,4 +321,66 @@ impl SrgbClr {
     fn to_color_rgb(&self) -> Color {
         Color::from_rgb_hex(&self.val)
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct FontScheme {
+    #[serde(rename = "@name", default)]
+    name: String,
+    #[serde(rename(serialize = "a:majorFont", deserialize = "majorFont"), default)]
+    major_font: ThemeFont,
+    #[serde(rename(serialize = "a:minorFont", deserialize = "minorFont"), default)]
+    minor_font: ThemeFont,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct ThemeFont {
+    #[serde(rename(serialize = "a:latin", deserialize = "latin"), default)]
+    latin: TextFont,
+    #[serde(rename(serialize = "a:ea", deserialize = "ea"), default)]
+    ea: TextFont,
+    #[serde(rename(serialize = "a:cs", deserialize = "cs"), default)]
+    cs: TextFont,
+    #[serde(rename(serialize = "a:font", deserialize = "font"), default, skip_serializing_if = "Vec::is_empty")]
+    font: Vec<ScriptFont>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct TextFont {
+    #[serde(rename = "@typeface", default)]
+    typeface: String,
+    #[serde(rename = "@panose", skip_serializing_if = "Option::is_none")]
+    panose: Option<String>,
+    #[serde(rename = "@pitchFamily", skip_serializing_if = "Option::is_none")]
+    pitch_family: Option<String>,
+    #[serde(rename = "@charset", skip_serializing_if = "Option::is_none")]
+    charset: Option<String>,
+}
+
+impl TextFont {
+    fn new(typeface: &str) -> TextFont {
+        TextFont {
+            typeface: typeface.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct ScriptFont {
+    #[serde(rename = "@script", default)]
+    script: String,
+    #[serde(rename = "@typeface", default)]
+    typeface: String,
+}
+
+#[test]
+fn test_replace_element() {
+    let mut xml = r#"<a:theme xmlns:a="ns"><a:themeElements><a:clrScheme name="Office"><a:dk1/></a:clrScheme></a:themeElements></a:theme>"#.to_string();
+    replace_element(&mut xml, "clrScheme", r#"<a:clrScheme name="New"/>"#);
+    assert_eq!(xml, r#"<a:theme xmlns:a="ns"><a:themeElements><a:clrScheme name="New"/></a:themeElements></a:theme>"#);
+    // Another prefix, or none, is bound to the namespace
+    let mut xml = r#"<theme xmlns="ns"><themeElements><fontScheme name="Office"><majorFont/></fontScheme></themeElements></theme>"#.to_string();
+    replace_element(&mut xml, "fontScheme", r#"<a:fontScheme name="New"/>"#);
+    assert!(xml.contains(r#"<themeElements><a:fontScheme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="New"/></themeElements>"#));
+}
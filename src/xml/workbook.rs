@@ -13,6 +13,7 @@ use zip::ZipArchive;
 use crate::api::relationship::Rel;
 use crate::file::{XlsxFileType, XlsxFileWriter};
 use crate::result::{WorkSheetError, WorkbookError};
+use crate::utils::hash_util;
 use crate::WorkbookResult;
 use crate::xml::common::{XmlnsAttrs};
 use crate::xml::extension::ExtensionList;
@@ -34,6 +35,8 @@ pub(crate) struct Workbook {
     workbook_pr: WorkbookPr,
     #[serde(rename(serialize = "xr:revisionPtr", deserialize = "revisionPtr"), skip_serializing_if = "Option::is_none")]
     xr_revision_ptr: Option<XrRevisionPtr>,
+    #[serde(rename = "workbookProtection", skip_serializing_if = "Option::is_none")]
+    pub(crate) workbook_protection: Option<WorkbookProtection>,
     #[serde(rename = "bookViews")]
     pub(crate) book_views: BookViews,
     #[serde(rename = "sheets")]
@@ -121,6 +124,43 @@ impl Default for FileSharing {
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub(crate) struct WorkbookProtection {
+    #[serde(rename = "@workbookPassword", skip_serializing_if = "Option::is_none")]
+    workbook_password: Option<String>,
+    #[serde(rename = "@workbookAlgorithmName", skip_serializing_if = "Option::is_none")]
+    workbook_algorithm_name: Option<String>,
+    #[serde(rename = "@workbookHashValue", skip_serializing_if = "Option::is_none")]
+    workbook_hash_value: Option<String>,
+    #[serde(rename = "@workbookSaltValue", skip_serializing_if = "Option::is_none")]
+    workbook_salt_value: Option<String>,
+    #[serde(rename = "@workbookSpinCount", skip_serializing_if = "Option::is_none")]
+    workbook_spin_count: Option<u32>,
+    #[serde(rename = "@lockStructure", skip_serializing_if = "Option::is_none")]
+    pub(crate) lock_structure: Option<u8>,
+    #[serde(rename = "@lockWindows", skip_serializing_if = "Option::is_none")]
+    lock_windows: Option<u8>,
+}
+
+impl WorkbookProtection {
+    pub(crate) fn lock_structure(password: &str, legacy_password: bool) -> WorkbookProtection {
+        let mut workbook_protection = WorkbookProtection::default();
+        if !password.is_empty() {
+            if legacy_password {
+                workbook_protection.workbook_password = Some(hash_util::legacy_password_hash(password));
+            } else {
+                let (hash_value, salt_value) = hash_util::sha512_password_hash(password, hash_util::DEFAULT_SPIN_COUNT);
+                workbook_protection.workbook_algorithm_name = Some(hash_util::SHA512_ALGORITHM_NAME.to_string());
+                workbook_protection.workbook_hash_value = Some(hash_value);
+                workbook_protection.workbook_salt_value = Some(salt_value);
+                workbook_protection.workbook_spin_count = Some(hash_util::DEFAULT_SPIN_COUNT);
+            }
+        }
+        workbook_protection.lock_structure = Some(1);
+        workbook_protection
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Default)]
 struct WorkbookPr {
     #[serde(rename = "@filterPrivacy", skip_serializing_if = "Option::is_none")]
@@ -227,6 +267,7 @@ impl Default for Workbook {
             file_sharing: None,
             workbook_pr: Default::default(),
             xr_revision_ptr: None,
+            workbook_protection: None,
             book_views: Default::default(),
             sheets: Default::default(),
             defined_names: Default::default(),
@@ -22,6 +22,13 @@ impl DefinedNames {
                 .then_some(entry.value.as_str())
         })
     }
+    /// The names and the values of the names local to the sheet
+    pub(crate) fn get_local_names(&self, local_sheet_id: u32) -> Vec<(String, String)> {
+        self.defined_names.iter()
+            .filter(|entry| entry.local_sheet_id == Some(local_sheet_id))
+            .map(|entry| (entry.name.clone(), entry.value.clone()))
+            .collect()
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -7,19 +7,26 @@ use zip::read::ZipFile;
 use crate::api::cell::location::{Location, LocationRange};
 use crate::api::relationship::Rel;
 use crate::file::{XlsxFileType, XlsxFileWriter};
-use crate::{Column, Filters, FormatColor};
+use crate::{Column, Filters, FormatColor, ProtectionOptions, SparklineOptions};
 use crate::result::{ColResult, WorkSheetResult};
+use crate::utils::col_helper::to_sheet_ref;
+use crate::utils::formula_util::rename_sheet;
+use crate::utils::range_util;
 use crate::xml::common::{PhoneticPr, XmlnsAttrs};
+use crate::xml::extension::ExtensionList;
 use crate::xml::worksheet::auto_filter::AutoFilter;
 use crate::xml::worksheet::columns::{Col, Cols};
 use crate::xml::worksheet::conditional_formatting::ConditionalFormatting;
-use crate::xml::worksheet::data_validations::DataValidations;
-use crate::xml::worksheet::hyperlinks::Hyperlinks;
+use crate::xml::worksheet::controls::AlternateContent;
+use crate::xml::worksheet::data_validations::{DataValidation, DataValidations};
+use crate::xml::worksheet::hyperlinks::{Hyperlink, Hyperlinks};
 use crate::xml::worksheet::ignore_errors::IgnoredErrors;
 use crate::xml::worksheet::merge_cells::MergeCells;
 use crate::xml::worksheet::page_margins::PageMargins;
 use crate::xml::worksheet::row_breaks::RowBreaks;
 use crate::xml::worksheet::sheet_format::SheetFormatPr;
+use crate::xml::worksheet::sheet_protection::SheetProtection;
+use crate::xml::worksheet::sort_state::SortState;
 use crate::xml::worksheet::table_parts::TableParts;
 use self::sheet_views::SheetViews;
 use self::sheet_data::SheetData;
@@ -34,11 +41,14 @@ mod columns;
 mod ignore_errors;
 mod hyperlinks;
 mod page_margins;
-mod auto_filter;
+pub(crate) mod auto_filter;
 mod row_breaks;
 mod conditional_formatting;
-mod data_validations;
+pub(crate) mod data_validations;
 mod table_parts;
+mod sheet_protection;
+mod controls;
+pub(crate) mod sort_state;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename="worksheet")]
@@ -57,6 +67,12 @@ pub(crate) struct WorkSheet {
     pub(crate) cols: Option<Cols>,
     #[serde(rename = "sheetData", default)]
     pub(crate) sheet_data: SheetData,
+    #[serde(rename = "sheetProtection", default, skip_serializing_if = "Option::is_none")]
+    sheet_protection: Option<SheetProtection>,
+    #[serde(rename = "autoFilter", default, skip_serializing_if = "Option::is_none")]
+    auto_filter: Option<AutoFilter>,
+    #[serde(rename = "sortState", default, skip_serializing_if = "Option::is_none")]
+    sort_state: Option<SortState>,
     #[serde(rename = "mergeCells", default, skip_serializing_if = "Option::is_none")]
     merge_cells: Option<MergeCells>,
     #[serde(rename = "phoneticPr", default, skip_serializing_if = "Option::is_none")]
@@ -67,8 +83,6 @@ pub(crate) struct WorkSheet {
     data_validations: Option<DataValidations>,
     #[serde(rename = "hyperlinks", default, skip_serializing_if = "Option::is_none")]
     hyperlinks: Option<Hyperlinks>,
-    #[serde(rename = "autoFilter", default, skip_serializing_if = "Option::is_none")]
-    auto_filter: Option<AutoFilter>,
     #[serde(rename = "printOptions", default, skip_serializing_if = "Option::is_none")]
     print_options: Option<PrintOptions>,
     #[serde(rename = "pageMargins")]
@@ -85,10 +99,38 @@ pub(crate) struct WorkSheet {
     drawing: Option<Drawing>,
     #[serde(rename = "legacyDrawing", default, skip_serializing_if = "Option::is_none")]
     legacy_drawing: Option<Drawing>,
-    #[serde(rename = "tableParts", default, skip_serializing_if = "Option::is_none")]
-    table_parts: Option<TableParts>,
     #[serde(rename = "picture", default, skip_serializing_if = "Option::is_none")]
     picture: Option<Picture>,
+    #[serde(rename(serialize = "mc:AlternateContent", deserialize = "AlternateContent"), default, skip_serializing_if = "Vec::is_empty")]
+    alternate_content: Vec<AlternateContent>,
+    #[serde(rename = "tableParts", default, skip_serializing_if = "Option::is_none")]
+    table_parts: Option<TableParts>,
+    #[serde(rename = "extLst", default, skip_serializing_if = "Option::is_none")]
+    ext_lst: Option<ExtensionList>,
+}
+
+impl WorkSheet {
+    ///
+    /// Visit the style ids of every cell, row and column.
+    ///
+    pub(crate) fn for_each_style_mut<F: FnMut(&mut u32)>(&mut self, mut f: F) {
+        if let Some(cols) = &mut self.cols {
+            cols.for_each_style_mut(&mut f);
+        }
+        self.sheet_data.for_each_style_mut(&mut f);
+    }
+
+    ///
+    /// Call `f` on the ids of the dxfs the conditional formats refer to.
+    ///
+    pub(crate) fn for_each_dxf_id_mut<F: FnMut(&mut u32)>(&mut self, mut f: F) {
+        self.conditional_formatting.iter_mut().for_each(|conditional_formatting| conditional_formatting.for_each_dxf_id_mut(&mut f));
+        let sort_states = [self.sort_state.as_mut(), self.auto_filter.as_mut().and_then(|auto_filter| auto_filter.sort_state.as_mut())];
+        sort_states.into_iter().flatten().for_each(|sort_state| sort_state.for_each_dxf_id_mut(&mut f));
+        if let Some(auto_filter) = &mut self.auto_filter {
+            auto_filter.filter_column.iter_mut().for_each(|filter_column| filter_column.for_each_dxf_id_mut(&mut f));
+        }
+    }
 }
 
 impl WorkSheet {
@@ -97,9 +139,38 @@ impl WorkSheet {
         auto_filter.sqref = loc_range.to_range_ref();
     }
 
-    pub(crate) fn filter_column<L: Location>(&mut self, col: L, filters: &Filters) {
+    ///
+    /// Set the criteria of the column, `dxf_id` is the dxf holding the color of a color filter.
+    ///
+    pub(crate) fn filter_column<L: Location>(&mut self, col: L, filters: &Filters, dxf_id: Option<u32>) {
         let auto_filter = self.auto_filter.get_or_insert(AutoFilter::default());
-        auto_filter.add_filters(col.to_col(), filters);
+        let first_col = match auto_filter.sqref.is_empty() {
+            true => 1,
+            false => range_util::from_range_ref(&auto_filter.sqref).1,
+        };
+        auto_filter.add_filters(col.to_col().saturating_sub(first_col), filters, dxf_id);
+    }
+
+    pub(crate) fn get_auto_filter(&self) -> Option<&AutoFilter> {
+        self.auto_filter.as_ref()
+    }
+
+    pub(crate) fn get_auto_filter_mut(&mut self) -> Option<&mut AutoFilter> {
+        self.auto_filter.as_mut()
+    }
+
+    ///
+    /// Record the last sort of the range, in the autofilter if it is the range of the autofilter,
+    /// so that Excel shows the sort indicator on its buttons.
+    ///
+    pub(crate) fn set_sort_state(&mut self, range: (u32, u32, u32, u32), sort_state: SortState) {
+        match &mut self.auto_filter {
+            Some(auto_filter) if range_util::from_range_ref(&auto_filter.sqref) == range => {
+                auto_filter.sort_state = Some(sort_state);
+                self.sort_state = None;
+            }
+            _ => self.sort_state = Some(sort_state),
+        }
     }
 }
 
@@ -123,6 +194,19 @@ impl WorkSheet {
         Ok(res)
     }
 
+    ///
+    /// The widths of the columns between `min` and `max`, by the ranges of columns sharing a width.
+    ///
+    pub(crate) fn get_col_widths(&self, min: u32, max: u32) -> Vec<(u32, u32, f64)> {
+        match &self.cols {
+            Some(cols) => cols.index_range_col_tree(min, max).into_iter()
+                .filter_map(|(first, last, col)| Some((first.max(min), last.min(max), col.width?)))
+                .filter(|(first, last, _)| first <= last)
+                .collect(),
+            None => vec![],
+        }
+    }
+
     pub(crate) fn set_col_by_column<R: LocationRange>(&mut self, col_range: R, column: &Column) -> ColResult<()> {
         let (min, max) = col_range.to_col_range();
         let cols = self.cols.get_or_insert(Cols::default()).index_range_col_tree(min, max);
@@ -178,6 +262,38 @@ impl WorkSheet {
         merge_cells.add_merge_cell(first_row, first_col, last_row, last_col);
     }
 
+    pub(crate) fn get_merged_ranges(&self) -> Vec<(u32, u32, u32, u32)> {
+        match &self.merge_cells {
+            Some(merge_cells) => merge_cells.get_ranges(),
+            None => vec![],
+        }
+    }
+
+    ///
+    /// The merged range which covers the cell.
+    ///
+    pub(crate) fn get_merged_range(&self, row: u32, col: u32) -> Option<(u32, u32, u32, u32)> {
+        self.get_merged_ranges().into_iter()
+            .find(|&range| range_util::intersects(range, (row, col, row, col)))
+    }
+
+    pub(crate) fn overlaps_merged_range(&self, range: (u32, u32, u32, u32)) -> bool {
+        self.get_merged_ranges().into_iter()
+            .any(|merged_range| range_util::intersects(merged_range, range))
+    }
+
+    ///
+    /// Remove the merged ranges which intersect the range, the `mergeCells` element is dropped with its last range.
+    ///
+    pub(crate) fn remove_merge_cells(&mut self, range: (u32, u32, u32, u32)) {
+        if let Some(merge_cells) = &mut self.merge_cells {
+            merge_cells.remove_merge_cells(range);
+            if merge_cells.is_empty() {
+                self.merge_cells = None;
+            }
+        }
+    }
+
     pub(crate) fn autofit_cols(&mut self) {
         // self.cols.col.iter_mut().for_each(|c| {
         //     c.custom_width = 0;
@@ -195,6 +311,62 @@ impl WorkSheet {
         self.picture = Some(Picture::from_id(r_id));
     }
 
+    pub(crate) fn add_sparkline(&mut self, options: &SparklineOptions, formula: &str, sqref: &str) {
+        self.ext_lst.get_or_insert(ExtensionList::default())
+            .update_sparkline_groups(|groups| groups.add_sparkline(options, formula, sqref));
+    }
+
+    ///
+    /// Point the formulas, the data validations, the conditional formats and the sparklines
+    /// which refer to the worksheet itself by its name to its new name.
+    ///
+    pub(crate) fn rename_sheet(&mut self, old_name: &str, new_name: &str) {
+        let mut rename = |formula: &mut String| *formula = rename_sheet(formula, old_name, new_name);
+        let range = (1, 1, self.sheet_data.max_row(), self.sheet_data.max_col());
+        self.sheet_data.for_each_cell_mut(range, false, |cell| {
+            if let Some(formula) = &mut cell.formula {
+                rename(&mut formula.formula);
+            }
+        });
+        if let Some(data_validations) = &mut self.data_validations {
+            data_validations.for_each_formula_mut(&mut rename);
+        }
+        self.conditional_formatting.iter_mut()
+            .for_each(|conditional_formatting| conditional_formatting.for_each_formula_mut(&mut rename));
+        self.rename_sparkline_sheet(old_name, new_name);
+    }
+
+    ///
+    /// Point the sparklines which plot the data of the worksheet itself to its new name.
+    ///
+    pub(crate) fn rename_sparkline_sheet(&mut self, old_name: &str, new_name: &str) {
+        if let Some(ext_lst) = &mut self.ext_lst {
+            let (old_ref, new_ref) = (to_sheet_ref(old_name, ""), to_sheet_ref(new_name, ""));
+            ext_lst.update_sparkline_groups(|groups| groups.rename_sheet(&old_ref, &new_ref));
+        }
+    }
+
+    ///
+    /// Drop the worksheet extensions which are not supported.
+    ///
+    pub(crate) fn clean_ext_lst(&mut self) {
+        if let Some(ext_lst) = &mut self.ext_lst {
+            ext_lst.retain_supported();
+        }
+        self.ext_lst = self.ext_lst.take().filter(|ext_lst| !ext_lst.is_empty());
+    }
+
+    pub(crate) fn get_background_r_id(&self) -> Option<u32> {
+        self.picture.as_ref()?.get_id()
+    }
+
+    ///
+    /// Remove the background and return the relationship id of its image.
+    ///
+    pub(crate) fn remove_background(&mut self) -> Option<u32> {
+        self.picture.take()?.get_id()
+    }
+
     pub(crate) fn insert_image(&mut self, r_id: u32) {
         if let None = &self.drawing {
             let drawing = self.drawing.get_or_insert(Drawing::default());
@@ -202,16 +374,113 @@ impl WorkSheet {
         }
     }
 
+    pub(crate) fn get_legacy_drawing_r_id(&self) -> Option<u32> {
+        self.legacy_drawing.as_ref().map(|drawing| drawing.r_id.get_id())
+    }
+
+    pub(crate) fn set_legacy_drawing(&mut self, r_id: u32) {
+        let legacy_drawing = self.legacy_drawing.get_or_insert(Drawing::default());
+        legacy_drawing.r_id = Rel::from_id(r_id);
+    }
+
+    ///
+    /// Add a form control, which is drawn by the shape `shape_id` of the vml drawing
+    /// and has its properties in the relationship `r_id`.
+    ///
+    pub(crate) fn add_control<L: LocationRange>(&mut self, loc_range: &L, shape_id: u32, r_id: u32, name: &str, macro_name: Option<&str>) {
+        self.xmlns_attrs.add_controls();
+        let index = match self.alternate_content.iter().position(|content| content.has_controls()) {
+            Some(index) => index,
+            None => {
+                self.alternate_content.push(AlternateContent::controls());
+                self.alternate_content.len() - 1
+            }
+        };
+        self.alternate_content[index].add_control(shape_id, r_id, name, macro_name, loc_range.to_range());
+    }
+
+    pub(crate) fn renumber_control_shape_ids(&mut self, shape_ids: &HashMap<u32, u32>) {
+        self.alternate_content.iter_mut().for_each(|content| content.renumber_shape_ids(shape_ids));
+    }
+
+    pub(crate) fn get_drawing_r_id(&self) -> Option<u32> {
+        self.drawing.as_ref().map(|drawing| drawing.r_id.get_id())
+    }
+
     pub(crate) fn add_hyperlink<L: Location>(&mut self, loc: &L, r_id: u32) {
         let hyperlinks = self.hyperlinks.get_or_insert(Default::default());
         hyperlinks.add_hyperlink(loc, r_id);
     }
 
-    pub(crate) fn get_hyperlink<L: Location>(&self, loc: &L) -> Option<String> {
-        if let Some(hyperlinks) = &self.hyperlinks {
-            hyperlinks.get_hyperlink(loc)
-        } else {
-            None
+    pub(crate) fn add_hyperlink_location<L: Location>(&mut self, loc: &L, location: &str) {
+        let hyperlinks = self.hyperlinks.get_or_insert(Default::default());
+        hyperlinks.add_location(loc, location);
+    }
+
+    pub(crate) fn get_hyperlink<L: Location>(&self, loc: &L) -> Option<&Hyperlink> {
+        self.hyperlinks.as_ref()?.get_hyperlink(loc)
+    }
+
+    pub(crate) fn get_hyperlink_mut<L: Location>(&mut self, loc: &L) -> Option<&mut Hyperlink> {
+        self.hyperlinks.as_mut()?.get_hyperlink_mut(loc)
+    }
+
+    ///
+    /// Remove the hyperlink of the cell, the `hyperlinks` element is dropped with its last hyperlink.
+    ///
+    pub(crate) fn remove_hyperlink<L: Location>(&mut self, loc: &L) -> Option<Hyperlink> {
+        let hyperlinks = self.hyperlinks.as_mut()?;
+        let hyperlink = hyperlinks.remove_hyperlink(loc);
+        if hyperlinks.is_empty() {
+            self.hyperlinks = None;
+        }
+        hyperlink
+    }
+
+    ///
+    /// Remove the hyperlinks which intersect the range, the `hyperlinks` element is dropped with its last hyperlink.
+    ///
+    pub(crate) fn remove_hyperlinks(&mut self, range: (u32, u32, u32, u32)) -> Vec<Hyperlink> {
+        let Some(hyperlinks) = self.hyperlinks.as_mut() else { return vec![] };
+        let removed = hyperlinks.remove_range(range);
+        if hyperlinks.is_empty() {
+            self.hyperlinks = None;
+        }
+        removed
+    }
+
+    pub(crate) fn hyperlinks(&self) -> &[Hyperlink] {
+        match &self.hyperlinks {
+            Some(hyperlinks) => hyperlinks.hyperlinks(),
+            None => &[],
+        }
+    }
+
+    pub(crate) fn has_hyperlink_r_id(&self, r_id: u32) -> bool {
+        self.hyperlinks.as_ref().is_some_and(|hyperlinks| hyperlinks.has_r_id(r_id))
+    }
+
+    pub(crate) fn get_data_validations(&self, range: (u32, u32, u32, u32)) -> Vec<DataValidation> {
+        match &self.data_validations {
+            Some(data_validations) => data_validations.get_parts(range),
+            None => vec![],
+        }
+    }
+
+    pub(crate) fn add_data_validation(&mut self, data_validation: DataValidation) {
+        let data_validations = self.data_validations.get_or_insert(Default::default());
+        data_validations.add_data_validation(data_validation);
+    }
+
+    ///
+    /// Remove the cells of the range from the data validations, the `dataValidations` element is dropped with its last one.
+    ///
+    pub(crate) fn remove_data_validations(&mut self, range: (u32, u32, u32, u32)) {
+        if let Some(data_validations) = &mut self.data_validations {
+            data_validations.remove_range(range);
+            if data_validations.is_empty() {
+                self.data_validations = None;
+            }
         }
     }
 
@@ -248,6 +517,21 @@ impl WorkSheet {
         let ignore_errors = IgnoredErrors::from_map(error_map);
         self.ignored_errors = Some(ignore_errors);
     }
+
+    pub(crate) fn protect(&mut self, password: &str, options: &ProtectionOptions) {
+        self.sheet_protection = Some(SheetProtection::from_options(password, options));
+    }
+
+    pub(crate) fn unprotect(&mut self) {
+        self.sheet_protection = None;
+    }
+
+    pub(crate) fn get_protection(&self) -> Option<ProtectionOptions> {
+        match &self.sheet_protection {
+            Some(sheet_protection) if sheet_protection.is_protected() => Some(sheet_protection.to_options()),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -276,6 +560,10 @@ impl Picture {
             r_id: format!("rId{r_id}"),
         }
     }
+
+    fn get_id(&self) -> Option<u32> {
+        self.r_id.trim_start_matches("rId").parse().ok()
+    }
 }
 
 impl Default for WorkSheet {
@@ -288,22 +576,26 @@ impl Default for WorkSheet {
             sheet_format_pr: SheetFormatPr::default(),
             cols: None,
             sheet_data: SheetData::default(),
+            sheet_protection: None,
             merge_cells: None,
             conditional_formatting: vec![],
             data_validations: None,
             phonetic_pr: None,
             page_margins: PageMargins::default(),
             page_setup: None,
-            table_parts: None,
             header_footer: None,
             print_options: None,
             row_breakers: None,
             ignored_errors: None,
             picture: None,
+            alternate_content: vec![],
+            table_parts: None,
             hyperlinks: None,
             drawing: None,
             auto_filter: None,
+            sort_state: None,
             legacy_drawing: None,
+            ext_lst: None,
         }
     }
 }
@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 use crate::Filters as ApiFilters;
 use crate::Filter as ApiFilter;
+use crate::api::filter::DynamicFilterType;
+use crate::api::worksheet::sort::SortValue;
+use crate::utils::date_util::{add_months, from_date, to_date};
 use crate::xml::common::XmlnsAttrs;
+use crate::xml::worksheet::sort_state::SortState;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub(crate) struct AutoFilter {
@@ -10,7 +14,9 @@ pub(crate) struct AutoFilter {
     #[serde(rename = "@ref", default, skip_serializing_if = "String::is_empty")]
     pub(crate) sqref: String,
     #[serde(rename = "filterColumn", default, skip_serializing_if = "Vec::is_empty")]
-    filter_column: Vec<FilterColumn>
+    pub(crate) filter_column: Vec<FilterColumn>,
+    #[serde(rename = "sortState", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) sort_state: Option<SortState>,
 }
 
 impl Default for AutoFilter {
@@ -19,38 +25,114 @@ impl Default for AutoFilter {
             xmlns_attrs: XmlnsAttrs::default_none(),
             sqref: "".to_string(),
             filter_column: vec![],
+            sort_state: None,
         }
     }
 }
 
 impl AutoFilter {
-    pub(crate) fn add_filters(&mut self, col: u32, filters: &ApiFilters) {
-        let mut filter_column = FilterColumn::new(col);
-        if filters.is_custom_filters() {
+    ///
+    /// Set the criteria of the column `col_id`, counted from 0 in the range of the autofilter,
+    /// `dxf_id` is the dxf holding the color of a color filter. The criteria it had are replaced.
+    ///
+    pub(crate) fn add_filters(&mut self, col_id: u32, filters: &ApiFilters, dxf_id: Option<u32>) {
+        let mut filter_column = FilterColumn::new(col_id);
+        if let Some((top, percent, val)) = filters.top10 {
+            filter_column.top10 = Some(Top10 { top: (!top).then_some(0), percent: percent.then_some(1), val, filter_val: None });
+        } else if let Some(dynamic_filter_type) = filters.dynamic {
+            filter_column.dynamic_filter = Some(DynamicFilter { tp: dynamic_filter_type.to_str().to_string(), val: None, max_val: None });
+        } else if let (Some((_, cell_color)), Some(dxf_id)) = (filters.color, dxf_id) {
+            filter_column.color_filter = Some(ColorFilter { dxf_id, cell_color: (!cell_color).then_some(0) });
+        } else if filters.is_custom_filters() {
             filter_column.custom_filters.get_or_insert(Default::default()).add_custom_filters(filters);
         } else {
             filter_column.filters.get_or_insert(Default::default()).add_filters(filters);
         }
+        self.filter_column.retain(|column| column.col_id != col_id);
         self.filter_column.push(filter_column);
     }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
-struct FilterColumn {
+pub(crate) struct FilterColumn {
     #[serde(rename = "@colId")]
     col_id: u32,
     #[serde(rename = "filters", skip_serializing_if = "Option::is_none")]
     filters: Option<Filters>,
+    #[serde(rename = "top10", default, skip_serializing_if = "Option::is_none")]
+    top10: Option<Top10>,
     #[serde(rename = "customFilters", skip_serializing_if = "Option::is_none")]
     custom_filters: Option<Filters>,
+    #[serde(rename = "dynamicFilter", default, skip_serializing_if = "Option::is_none")]
+    dynamic_filter: Option<DynamicFilter>,
+    #[serde(rename = "colorFilter", default, skip_serializing_if = "Option::is_none")]
+    color_filter: Option<ColorFilter>,
 }
 
 impl FilterColumn {
-    fn new(col: u32) -> FilterColumn {
+    fn new(col_id: u32) -> FilterColumn {
         FilterColumn {
-            col_id: col - 1,
+            col_id,
             filters: Default::default(),
+            top10: None,
             custom_filters: Default::default(),
+            dynamic_filter: None,
+            color_filter: None,
+        }
+    }
+
+    pub(crate) fn get_col_id(&self) -> u32 {
+        self.col_id
+    }
+
+    ///
+    /// The dxf of the color filter, and whether its color is the one of the fill rather than of the font.
+    ///
+    pub(crate) fn get_color_filter(&self) -> Option<(u32, bool)> {
+        self.color_filter.as_ref().map(|color_filter| (color_filter.dxf_id, color_filter.cell_color != Some(0)))
+    }
+
+    pub(crate) fn for_each_dxf_id_mut<F: FnMut(&mut u32)>(&mut self, f: &mut F) {
+        if let Some(color_filter) = &mut self.color_filter {
+            f(&mut color_filter.dxf_id);
+        }
+    }
+
+    ///
+    /// Whether the cells of the column pass the criteria, given by their values,
+    /// and by whether they have the color of the color filter.
+    /// The values the criteria are resolved to, like the average of the column, are recorded as Excel does.
+    ///
+    pub(crate) fn filter(&mut self, cells: &[(SortValue, bool)], today: f64) -> Vec<bool> {
+        let numbers: Vec<f64> = cells.iter()
+            .filter_map(|(value, _)| match value {
+                SortValue::Number(number) => Some(*number),
+                _ => None,
+            })
+            .collect();
+        if let Some(top10) = &mut self.top10 {
+            let threshold = top10.threshold(numbers);
+            top10.filter_val = threshold;
+            let top = top10.top != Some(0);
+            return cells.iter().map(|(value, _)| match (value, threshold) {
+                (SortValue::Number(number), Some(threshold)) => if top { *number >= threshold } else { *number <= threshold },
+                _ => false,
+            }).collect();
+        }
+        if let Some(dynamic_filter) = &mut self.dynamic_filter {
+            dynamic_filter.resolve(&numbers, today);
+            return cells.iter().map(|(value, _)| match value {
+                SortValue::Number(number) => dynamic_filter.passes(*number),
+                _ => false,
+            }).collect();
+        }
+        if self.color_filter.is_some() {
+            return cells.iter().map(|(_, colored)| *colored).collect();
+        }
+        match (&self.filters, &self.custom_filters) {
+            (Some(filters), _) => cells.iter().map(|(value, _)| filters.matches(value)).collect(),
+            (None, Some(custom_filters)) => cells.iter().map(|(value, _)| custom_filters.matches_custom(value)).collect(),
+            (None, None) => vec![true; cells.len()],
         }
     }
 }
@@ -61,10 +143,12 @@ struct Filters {
     and: Option<u8>,
     #[serde(rename = "@blank", skip_serializing_if = "Option::is_none")]
     blank: Option<u8>,
-    #[serde(rename = "filter")]
+    #[serde(rename = "filter", default)]
     filters: Vec<Filter>,
-    #[serde(rename = "customFilter")]
-    custom_filters: Vec<Filter>
+    #[serde(rename = "customFilter", default)]
+    custom_filters: Vec<Filter>,
+    #[serde(rename = "dateGroupItem", default, skip_serializing_if = "Vec::is_empty")]
+    date_group_items: Vec<DateGroupItem>,
 }
 
 impl Filters {
@@ -81,11 +165,33 @@ impl Filters {
         self.and = api_filters.and;
         self.blank = api_filters.blank;
         api_filters.filters.iter().for_each(|f| {
-            self.filters.push(
-                Filter::from_api_filter(f)
-            );
+            match f.date_group {
+                Some(date_group) => self.date_group_items.push(DateGroupItem::from_date_group(date_group)),
+                None => self.filters.push(Filter::from_api_filter(f)),
+            }
         });
     }
+
+    ///
+    /// Whether the value is one of the values, or in one of the groups of dates, of the list.
+    ///
+    fn matches(&self, value: &SortValue) -> bool {
+        match value {
+            SortValue::Blank => self.blank == Some(1),
+            value => self.filters.iter().any(|filter| filter.equals(value))
+                || self.date_group_items.iter().any(|date_group_item| date_group_item.contains(value)),
+        }
+    }
+
+    ///
+    /// Whether the value passes all the custom filters if they are joined by `and`, or one of them.
+    ///
+    fn matches_custom(&self, value: &SortValue) -> bool {
+        match self.and {
+            Some(1) => self.custom_filters.iter().all(|filter| filter.compare(value)),
+            _ => self.custom_filters.iter().any(|filter| filter.compare(value)),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -110,4 +216,203 @@ impl Filter {
             operator: if let Some(operator) = api_filter.operator { Some(operator.to_string()) } else { None },
         }
     }
-}
\ No newline at end of file
+
+    fn equals(&self, value: &SortValue) -> bool {
+        let val = self.val.as_deref().unwrap_or_default();
+        match (value, val.parse::<f64>()) {
+            (SortValue::Number(number), Ok(val)) => *number == val,
+            (SortValue::Number(number), Err(_)) => number.to_string() == val,
+            (SortValue::Text(text), _) => *text == val.to_lowercase(),
+            (SortValue::Boolean(boolean), _) => val.eq_ignore_ascii_case(if *boolean { "TRUE" } else { "FALSE" }),
+            (SortValue::Error, _) | (SortValue::Blank, _) => false,
+        }
+    }
+
+    ///
+    /// Whether the value passes the operator of the custom filter, texts are compared without case,
+    /// and `*` and `?` match any characters in the texts compared for equality.
+    /// A value of spaces only stands for the blanks, `notEqual` to it is the filter of the non blanks.
+    ///
+    fn compare(&self, value: &SortValue) -> bool {
+        let val = self.val.as_deref().unwrap_or_default();
+        let operator = self.operator.as_deref().unwrap_or("equal");
+        if val.trim().is_empty() {
+            return match operator {
+                "equal" => *value == SortValue::Blank,
+                "notEqual" => *value != SortValue::Blank,
+                _ => false,
+            };
+        }
+        let ordering = match (value, val.parse::<f64>()) {
+            (SortValue::Number(number), Ok(val)) => number.partial_cmp(&val),
+            (SortValue::Text(text), _) if operator == "equal" || operator == "notEqual" => {
+                let equal = wildcard_match(text, &val.to_lowercase());
+                return equal == (operator == "equal");
+            }
+            (SortValue::Text(text), Err(_)) => Some(text.as_str().cmp(val.to_lowercase().as_str())),
+            _ => None,
+        };
+        match (ordering, operator) {
+            (Some(ordering), "lessThan") => ordering.is_lt(),
+            (Some(ordering), "lessThanOrEqual") => ordering.is_le(),
+            (Some(ordering), "equal") => ordering.is_eq(),
+            (Some(ordering), "notEqual") => ordering.is_ne(),
+            (Some(ordering), "greaterThanOrEqual") => ordering.is_ge(),
+            (Some(ordering), "greaterThan") => ordering.is_gt(),
+            (None, "notEqual") => true,
+            _ => false,
+        }
+    }
+}
+
+///
+/// Whether the text matches the pattern, in which `*` matches any characters and `?` any one character.
+///
+fn wildcard_match(text: &str, pattern: &str) -> bool {
+    let text: Vec<char> = text.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    let (mut t, mut p) = (0, 0);
+    // The positions to go back to after the last `*`
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                t += 1;
+                p += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    star = Some((star_p, star_t + 1));
+                    p = star_p + 1;
+                    t = star_t + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct DateGroupItem {
+    #[serde(rename = "@year")]
+    year: u32,
+    #[serde(rename = "@month", default, skip_serializing_if = "Option::is_none")]
+    month: Option<u32>,
+    #[serde(rename = "@day", default, skip_serializing_if = "Option::is_none")]
+    day: Option<u32>,
+    #[serde(rename = "@dateTimeGrouping")]
+    date_time_grouping: String,
+}
+
+impl DateGroupItem {
+    fn from_date_group((year, month, day): (u32, Option<u32>, Option<u32>)) -> DateGroupItem {
+        let date_time_grouping = match (month, day) {
+            (Some(_), Some(_)) => "day",
+            (Some(_), None) => "month",
+            _ => "year",
+        };
+        DateGroupItem { year, month, day: month.and(day), date_time_grouping: date_time_grouping.to_string() }
+    }
+
+    fn contains(&self, value: &SortValue) -> bool {
+        let SortValue::Number(serial) = value else { return false };
+        let (year, month, day) = to_date(*serial);
+        let grouping = self.date_time_grouping.as_str();
+        year == self.year as i64
+            && (grouping == "year" || self.month == Some(month))
+            && (grouping == "year" || grouping == "month" || self.day == Some(day))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct Top10 {
+    #[serde(rename = "@top", default, skip_serializing_if = "Option::is_none")]
+    top: Option<u8>,
+    #[serde(rename = "@percent", default, skip_serializing_if = "Option::is_none")]
+    percent: Option<u8>,
+    #[serde(rename = "@val")]
+    val: f64,
+    #[serde(rename = "@filterVal", default, skip_serializing_if = "Option::is_none")]
+    filter_val: Option<f64>,
+}
+
+impl Top10 {
+    ///
+    /// The last number shown, `None` if the column has no number.
+    ///
+    fn threshold(&self, mut numbers: Vec<f64>) -> Option<f64> {
+        match self.top {
+            Some(0) => numbers.sort_by(|a, b| a.total_cmp(b)),
+            _ => numbers.sort_by(|a, b| b.total_cmp(a)),
+        }
+        let count = match self.percent {
+            Some(1) => (numbers.len() as f64 * self.val / 100.0).floor() as usize,
+            _ => self.val as usize,
+        };
+        numbers.get(count.clamp(1, numbers.len().max(1)) - 1).copied()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct DynamicFilter {
+    #[serde(rename = "@type")]
+    tp: String,
+    #[serde(rename = "@val", default, skip_serializing_if = "Option::is_none")]
+    val: Option<f64>,
+    #[serde(rename = "@maxVal", default, skip_serializing_if = "Option::is_none")]
+    max_val: Option<f64>,
+}
+
+impl DynamicFilter {
+    ///
+    /// Resolve the criteria against the numbers of the column and the current date,
+    /// the average, or the first and the last excluded dates, are kept in `val` and `maxVal`.
+    ///
+    fn resolve(&mut self, numbers: &[f64], today: f64) {
+        let (year, month, _) = to_date(today);
+        let first_of_month = from_date(year, month, 1);
+        let first_of_year = from_date(year, 1, 1);
+        let (val, max_val) = match DynamicFilterType::from_str(&self.tp) {
+            Some(DynamicFilterType::AboveAverage | DynamicFilterType::BelowAverage) => {
+                (numbers.iter().sum::<f64>() / numbers.len().max(1) as f64, None)
+            }
+            Some(DynamicFilterType::Yesterday) => (today - 1.0, Some(today)),
+            Some(DynamicFilterType::Today) => (today, Some(today + 1.0)),
+            Some(DynamicFilterType::Tomorrow) => (today + 1.0, Some(today + 2.0)),
+            Some(DynamicFilterType::LastMonth) => (add_months(first_of_month, -1), Some(first_of_month)),
+            Some(DynamicFilterType::ThisMonth) => (first_of_month, Some(add_months(first_of_month, 1))),
+            Some(DynamicFilterType::NextMonth) => (add_months(first_of_month, 1), Some(add_months(first_of_month, 2))),
+            Some(DynamicFilterType::LastYear) => (add_months(first_of_year, -12), Some(first_of_year)),
+            Some(DynamicFilterType::ThisYear) => (first_of_year, Some(add_months(first_of_year, 12))),
+            Some(DynamicFilterType::NextYear) => (add_months(first_of_year, 12), Some(add_months(first_of_year, 24))),
+            None => return,
+        };
+        self.val = Some(val);
+        self.max_val = max_val;
+    }
+
+    ///
+    /// Whether the number passes the resolved criteria, the types which are not supported pass everything.
+    ///
+    fn passes(&self, number: f64) -> bool {
+        match (DynamicFilterType::from_str(&self.tp), self.val, self.max_val) {
+            (Some(DynamicFilterType::AboveAverage), Some(average), _) => number > average,
+            (Some(DynamicFilterType::BelowAverage), Some(average), _) => number < average,
+            (Some(_), Some(val), Some(max_val)) => val <= number && number < max_val,
+            _ => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct ColorFilter {
+    #[serde(rename = "@dxfId")]
+    dxf_id: u32,
+    #[serde(rename = "@cellColor", default, skip_serializing_if = "Option::is_none")]
+    cell_color: Option<u8>,
+}
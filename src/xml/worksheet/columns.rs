@@ -78,6 +78,20 @@ impl Cols {
     pub(crate) fn is_empty(&self) -> bool {
         self.col.is_empty()
     }
+
+    pub(crate) fn for_each_style_mut<F: FnMut(&mut u32)>(&mut self, f: &mut F) {
+        let cols: Vec<(i32, i32, Col)> = self.col_tree.to_vec()
+            .into_iter()
+            .filter(|(l, r, col)| *l > 0 && *r > *l && col.style.is_some())
+            .collect();
+        for (l, r, mut col) in cols {
+            if let Some(style) = &mut col.style {
+                f(style);
+            }
+            self.col_tree.update(l, r, &col);
+        }
+        self.col.iter_mut().filter_map(|col| col.style.as_mut()).for_each(f);
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Default, Copy, Clone)]
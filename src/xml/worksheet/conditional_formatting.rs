@@ -8,12 +8,22 @@ pub(crate) struct ConditionalFormatting {
     sqref: String,
 }
 
+impl ConditionalFormatting {
+    pub(crate) fn for_each_dxf_id_mut<F: FnMut(&mut u32)>(&mut self, f: &mut F) {
+        self.cf_rule.iter_mut().filter_map(|cf_rule| cf_rule.dxf_id.as_mut()).for_each(f);
+    }
+
+    pub(crate) fn for_each_formula_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        self.cf_rule.iter_mut().filter_map(|cf_rule| cf_rule.formula.as_mut()).for_each(f);
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 struct CfRule {
     #[serde(rename = "@type", default, skip_serializing_if = "Option::is_none")]
     tp: Option<String>,
     #[serde(rename = "@dxfId", default, skip_serializing_if = "Option::is_none")]
-    dxf_id: Option<u8>,
+    dxf_id: Option<u32>,
     #[serde(rename = "@priority", default, skip_serializing_if = "Option::is_none")]
     priority: Option<u8>,
     #[serde(rename = "@operator", default, skip_serializing_if = "Option::is_none")]
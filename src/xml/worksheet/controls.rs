@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::api::relationship::Rel;
+
+const MC_NAMESPACE: &str = "http://schemas.openxmlformats.org/markup-compatibility/2006";
+
+///
+/// The `<controls>` element is wrapped in `mc:AlternateContent`, so that Excel 2007 skips it.
+///
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub(crate) struct AlternateContent {
+    #[serde(rename = "@xmlns:mc", default, skip_serializing_if = "Option::is_none")]
+    xmlns_mc: Option<String>,
+    #[serde(rename(serialize = "mc:Choice", deserialize = "Choice"), default)]
+    choice: Choice,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct Choice {
+    #[serde(rename = "@Requires", default)]
+    requires: String,
+    #[serde(rename = "controls", default, skip_serializing_if = "Option::is_none")]
+    controls: Option<Controls>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct Controls {
+    #[serde(rename(serialize = "mc:AlternateContent", deserialize = "AlternateContent"), default)]
+    control: Vec<ControlContent>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct ControlContent {
+    #[serde(rename = "@xmlns:mc", default, skip_serializing_if = "Option::is_none")]
+    xmlns_mc: Option<String>,
+    #[serde(rename(serialize = "mc:Choice", deserialize = "Choice"), default)]
+    choice: ControlChoice,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct ControlChoice {
+    #[serde(rename = "@Requires", default)]
+    requires: String,
+    #[serde(rename = "control", default)]
+    control: Control,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct Control {
+    #[serde(rename = "@shapeId")]
+    shape_id: u32,
+    #[serde(rename(serialize = "@r:id", deserialize = "@id"))]
+    r_id: Rel,
+    #[serde(rename = "@name", default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(rename = "controlPr", default, skip_serializing_if = "Option::is_none")]
+    control_pr: Option<ControlPr>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct ControlPr {
+    #[serde(rename = "@locked", default, skip_serializing_if = "Option::is_none")]
+    locked: Option<u8>,
+    #[serde(rename = "@defaultSize", default, skip_serializing_if = "Option::is_none")]
+    default_size: Option<u8>,
+    #[serde(rename = "@print", default, skip_serializing_if = "Option::is_none")]
+    print: Option<u8>,
+    #[serde(rename = "@disabled", default, skip_serializing_if = "Option::is_none")]
+    disabled: Option<u8>,
+    #[serde(rename = "@autoFill", default, skip_serializing_if = "Option::is_none")]
+    auto_fill: Option<u8>,
+    #[serde(rename = "@autoLine", default, skip_serializing_if = "Option::is_none")]
+    auto_line: Option<u8>,
+    #[serde(rename = "@autoPict", default, skip_serializing_if = "Option::is_none")]
+    auto_pict: Option<u8>,
+    #[serde(rename = "@macro", default, skip_serializing_if = "Option::is_none")]
+    macro_name: Option<String>,
+    #[serde(rename = "@altText", default, skip_serializing_if = "Option::is_none")]
+    alt_text: Option<String>,
+    #[serde(rename = "anchor", default)]
+    anchor: ControlAnchor,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct ControlAnchor {
+    #[serde(rename = "@moveWithCells", default, skip_serializing_if = "Option::is_none")]
+    move_with_cells: Option<u8>,
+    #[serde(rename = "@sizeWithCells", default, skip_serializing_if = "Option::is_none")]
+    size_with_cells: Option<u8>,
+    #[serde(rename = "from", default)]
+    from: Marker,
+    #[serde(rename = "to", default)]
+    to: Marker,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+struct Marker {
+    #[serde(rename(serialize = "xdr:col", deserialize = "col"))]
+    col: u32,
+    #[serde(rename(serialize = "xdr:colOff", deserialize = "colOff"))]
+    col_off: u32,
+    #[serde(rename(serialize = "xdr:row", deserialize = "row"))]
+    row: u32,
+    #[serde(rename(serialize = "xdr:rowOff", deserialize = "rowOff"))]
+    row_off: u32,
+}
+
+impl AlternateContent {
+    pub(crate) fn controls() -> AlternateContent {
+        AlternateContent {
+            xmlns_mc: Some(MC_NAMESPACE.to_string()),
+            choice: Choice {
+                requires: "x14".to_string(),
+                controls: Some(Controls::default()),
+            },
+        }
+    }
+
+    pub(crate) fn has_controls(&self) -> bool {
+        self.choice.controls.is_some()
+    }
+
+    ///
+    /// Point the controls to the new ids of their shapes in the vml drawing.
+    ///
+    pub(crate) fn renumber_shape_ids(&mut self, shape_ids: &HashMap<u32, u32>) {
+        self.choice.controls.iter_mut()
+            .flat_map(|controls| controls.control.iter_mut())
+            .for_each(|content| {
+                let control = &mut content.choice.control;
+                if let Some(shape_id) = shape_ids.get(&control.shape_id) {
+                    control.shape_id = *shape_id;
+                }
+            });
+    }
+
+    ///
+    /// Add a control to the `<controls>` element, `range` is the first row, the first column,
+    /// the last row and the last column of the cells the control covers.
+    ///
+    pub(crate) fn add_control(&mut self, shape_id: u32, r_id: u32, name: &str, macro_name: Option<&str>, range: (u32, u32, u32, u32)) {
+        let controls = self.choice.controls.get_or_insert(Controls::default());
+        let (first_row, first_col, last_row, last_col) = range;
+        let control = Control {
+            shape_id,
+            r_id: Rel::from_id(r_id),
+            name: Some(name.to_string()),
+            control_pr: Some(ControlPr {
+                default_size: Some(0),
+                auto_fill: Some(0),
+                auto_line: Some(0),
+                auto_pict: Some(0),
+                macro_name: macro_name.map(|macro_name| macro_name.to_string()),
+                anchor: ControlAnchor {
+                    move_with_cells: Some(1),
+                    from: Marker { col: first_col - 1, row: first_row - 1, ..Default::default() },
+                    to: Marker { col: last_col, row: last_row, ..Default::default() },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        };
+        controls.control.push(ControlContent {
+            xmlns_mc: Some(MC_NAMESPACE.to_string()),
+            choice: ControlChoice { requires: "x14".to_string(), control },
+        });
+    }
+}
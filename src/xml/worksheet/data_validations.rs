@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
+use crate::utils::formula_util::offset_formula;
+use crate::utils::range_util;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub(crate) struct DataValidations {
     #[serde(rename = "@count", default)]
     count: u32,
@@ -8,8 +10,52 @@ pub(crate) struct DataValidations {
     data_validation: Vec<DataValidation>
 }
 
+impl DataValidations {
+    ///
+    /// The parts of the data validations inside the range, each keeps only the cells of its sqref in the range.
+    ///
+    pub(crate) fn get_parts(&self, range: (u32, u32, u32, u32)) -> Vec<DataValidation> {
+        self.data_validation.iter()
+            .filter_map(|data_validation| {
+                let ranges = data_validation.get_ranges().into_iter()
+                    .filter_map(|sqref| range_util::intersection(sqref, range))
+                    .collect();
+                data_validation.with_ranges(ranges)
+            })
+            .collect()
+    }
+
+    ///
+    /// Remove the cells of the range from the data validations, a data validation left without cells is dropped.
+    ///
+    pub(crate) fn remove_range(&mut self, range: (u32, u32, u32, u32)) {
+        self.data_validation = self.data_validation.iter()
+            .filter_map(|data_validation| {
+                let ranges = data_validation.get_ranges().into_iter()
+                    .flat_map(|sqref| range_util::subtract(sqref, range))
+                    .collect();
+                data_validation.with_ranges(ranges)
+            })
+            .collect();
+        self.count = self.data_validation.len() as u32;
+    }
+
+    pub(crate) fn add_data_validation(&mut self, data_validation: DataValidation) {
+        self.data_validation.push(data_validation);
+        self.count = self.data_validation.len() as u32;
+    }
+
+    pub(crate) fn for_each_formula_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        self.data_validation.iter_mut().filter_map(|data_validation| data_validation.formula1.as_mut()).for_each(f);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.data_validation.is_empty()
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
-struct DataValidation {
+pub(crate) struct DataValidation {
     #[serde(rename = "@type", default, skip_serializing_if = "Option::is_none")]
     tp: Option<String>,
     #[serde(rename = "@allowBlank", default, skip_serializing_if = "Option::is_none")]
@@ -22,4 +68,39 @@ struct DataValidation {
     sqref: Option<String>,
     #[serde(rename = "formula1", default, skip_serializing_if = "Option::is_none")]
     formula1: Option<String>,
-}
\ No newline at end of file
+}
+
+impl DataValidation {
+    ///
+    /// The data validation moved by the offsets, the relative references of its formula are re-based.
+    ///
+    pub(crate) fn offset(&self, row_offset: i64, col_offset: i64) -> DataValidation {
+        let ranges = self.get_ranges().into_iter()
+            .map(|range| range_util::offset(range, row_offset, col_offset))
+            .collect();
+        let mut data_validation = self.with_ranges(ranges).unwrap_or(self.clone());
+        data_validation.formula1 = self.formula1.as_ref()
+            .map(|formula| offset_formula(formula, row_offset, col_offset));
+        data_validation
+    }
+
+    fn get_ranges(&self) -> Vec<(u32, u32, u32, u32)> {
+        match &self.sqref {
+            Some(sqref) => sqref.split_whitespace().map(range_util::from_range_ref).collect(),
+            None => vec![],
+        }
+    }
+
+    ///
+    /// A copy of the data validation applied to the ranges, `None` if there is no range.
+    ///
+    fn with_ranges(&self, ranges: Vec<(u32, u32, u32, u32)>) -> Option<DataValidation> {
+        if ranges.is_empty() {
+            return None;
+        }
+        let sqref = ranges.into_iter().map(range_util::to_range_ref).collect::<Vec<String>>().join(" ");
+        let mut data_validation = self.clone();
+        data_validation.sqref = Some(sqref);
+        Some(data_validation)
+    }
+}
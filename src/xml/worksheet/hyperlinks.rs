@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 use crate::api::cell::location::Location;
+use crate::utils::col_helper::to_loc;
+use crate::utils::range_util;
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub(crate) struct Hyperlinks {
@@ -13,30 +15,75 @@ impl Hyperlinks {
         self.hyperlink.push(hyperlink)
     }
 
-    pub(crate) fn get_hyperlink<L: Location>(&self, loc: &L) -> Option<String> {
-        let loc = loc.to_ref();
-        let h = self.hyperlink.iter()
-            .filter(|h| loc == h.hyperlink_ref)
-            .last();
-        match h {
-            None => None,
-            Some(h) => h.display.clone()
-        }
+    ///
+    /// Add a link to a place in the workbook, such as `Sheet2!A1` or a defined name.
+    ///
+    pub(crate) fn add_location<L: Location>(&mut self, loc: &L, location: &str) {
+        let hyperlink = Hyperlink::new_location(&loc.to_ref(), location);
+        self.hyperlink.push(hyperlink)
+    }
+
+    ///
+    /// The last hyperlink whose reference covers the cell.
+    ///
+    pub(crate) fn get_hyperlink<L: Location>(&self, loc: &L) -> Option<&Hyperlink> {
+        let index = self.position(loc)?;
+        Some(&self.hyperlink[index])
+    }
+
+    pub(crate) fn get_hyperlink_mut<L: Location>(&mut self, loc: &L) -> Option<&mut Hyperlink> {
+        let index = self.position(loc)?;
+        Some(&mut self.hyperlink[index])
+    }
+
+    pub(crate) fn remove_hyperlink<L: Location>(&mut self, loc: &L) -> Option<Hyperlink> {
+        let index = self.position(loc)?;
+        Some(self.hyperlink.remove(index))
+    }
+
+    ///
+    /// Remove the hyperlinks whose references intersect the range.
+    ///
+    pub(crate) fn remove_range(&mut self, range: (u32, u32, u32, u32)) -> Vec<Hyperlink> {
+        let (removed, kept) = self.hyperlink.drain(..)
+            .partition(|h| range_util::intersects(range_util::from_range_ref(&h.hyperlink_ref), range));
+        self.hyperlink = kept;
+        removed
+    }
+
+    pub(crate) fn hyperlinks(&self) -> &Vec<Hyperlink> {
+        &self.hyperlink
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.hyperlink.is_empty()
+    }
+
+    ///
+    /// Whether any hyperlink still refers to the relationship.
+    ///
+    pub(crate) fn has_r_id(&self, r_id: u32) -> bool {
+        self.hyperlink.iter().any(|h| h.get_r_id() == Some(r_id))
+    }
+
+    fn position<L: Location>(&self, loc: &L) -> Option<usize> {
+        let (row, col) = loc.to_location();
+        self.hyperlink.iter().rposition(|h| h.contains(row, col))
     }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
-struct Hyperlink {
+pub(crate) struct Hyperlink {
     #[serde(rename = "@ref")]
-    hyperlink_ref: String,
+    pub(crate) hyperlink_ref: String,
     #[serde(rename = "@location", skip_serializing_if = "Option::is_none")]
-    location: Option<String>,
+    pub(crate) location: Option<String>,
     #[serde(rename(serialize = "@r:id", deserialize = "@id"), skip_serializing_if = "Option::is_none")]
     r_id: Option<String>,
     #[serde(rename = "@display", skip_serializing_if = "Option::is_none")]
-    display: Option<String>,
+    pub(crate) display: Option<String>,
     #[serde(rename = "@tooltip", skip_serializing_if = "Option::is_none")]
-    tooltip: Option<String>,
+    pub(crate) tooltip: Option<String>,
     #[serde(rename(serialize = "@xr:uid", deserialize = "@uid"), default, skip_serializing_if = "String::is_empty")]
     uid: String,
 }
@@ -52,4 +99,30 @@ impl Hyperlink {
             uid: "".to_string(),
         }
     }
-}
\ No newline at end of file
+
+    fn new_location(hyperlink_ref: &str, location: &str) -> Self {
+        Self {
+            hyperlink_ref: String::from(hyperlink_ref),
+            location: Some(String::from(location)),
+            display: None,
+            tooltip: None,
+            r_id: None,
+            uid: "".to_string(),
+        }
+    }
+
+    pub(crate) fn get_r_id(&self) -> Option<u32> {
+        self.r_id.as_ref()?.trim_start_matches("rId").parse().ok()
+    }
+
+    ///
+    /// Whether the reference of the hyperlink, a cell or a range, covers the cell.
+    ///
+    fn contains(&self, row: u32, col: u32) -> bool {
+        let (first, last) = self.hyperlink_ref.split_once(':')
+            .unwrap_or((&self.hyperlink_ref, &self.hyperlink_ref));
+        let (first_row, first_col) = to_loc(first);
+        let (last_row, last_col) = to_loc(last);
+        (first_row..=last_row).contains(&row) && (first_col..=last_col).contains(&col)
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::utils::col_helper;
+use crate::utils::range_util::intersects;
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub(crate) struct MergeCells {
@@ -15,6 +16,22 @@ impl MergeCells {
         self.merge_cell.push(merge_cell);
         self.count += 1;
     }
+
+    pub(crate) fn get_ranges(&self) -> Vec<(u32, u32, u32, u32)> {
+        self.merge_cell.iter().map(|merge_cell| merge_cell.to_range()).collect()
+    }
+
+    ///
+    /// Remove the merged ranges which intersect the range.
+    ///
+    pub(crate) fn remove_merge_cells(&mut self, range: (u32, u32, u32, u32)) {
+        self.merge_cell.retain(|merge_cell| !intersects(merge_cell.to_range(), range));
+        self.count = self.merge_cell.len() as u32;
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.merge_cell.is_empty()
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -31,4 +48,12 @@ impl MergeCell {
             cell_ref: format!("{first_col}{first_row}:{last_col}{last_row}")
         }
     }
-}
\ No newline at end of file
+
+    fn to_range(&self) -> (u32, u32, u32, u32) {
+        let (first, last) = self.cell_ref.split_once(':').unwrap_or((&self.cell_ref, &self.cell_ref));
+        let (first_row, first_col) = col_helper::to_loc(first);
+        let (last_row, last_col) = col_helper::to_loc(last);
+        (first_row, first_col, last_row, last_col)
+    }
+}
+
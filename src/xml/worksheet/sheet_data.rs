@@ -4,6 +4,7 @@
 pub(crate) mod cell;
 mod row;
 
+use std::collections::{HashMap, HashSet};
 use serde::{Deserialize, Serialize};
 use crate::api::cell::location::Location;
 use crate::api::cell::Cell as ApiCell;
@@ -12,6 +13,8 @@ use crate::api::worksheet::row::Row as ApiRow;
 use crate::result::CellError::CellNotFound;
 use crate::result::RowError::RowNotFound;
 use crate::result::{RowError, RowResult};
+use crate::utils::formula_util::move_references;
+use crate::utils::range_util;
 use crate::xml::worksheet::sheet_data::cell::Cell;
 use crate::xml::worksheet::sheet_data::row::{_OrderCell, Row};
 
@@ -72,6 +75,18 @@ impl SheetData {
         }
     }
 
+    ///
+    /// Hide or show the row, a missing row is only created to be hidden.
+    ///
+    pub(crate) fn set_row_hidden(&mut self, row: u32, hidden: bool) {
+        match hidden {
+            true => self.get_or_new_row(row).hidden = Some(1),
+            false => if let Some(row) = self.get_row_mut(row) {
+                row.hidden = None;
+            },
+        }
+    }
+
     pub(crate) fn get_cell_type<L: Location>(&self, loc: &L) -> Option<&CellType> {
         let row = self.get_row(loc.to_row());
         match row {
@@ -133,6 +148,21 @@ impl SheetData {
         }
     }
 
+    pub(crate) fn set_style<L: Location>(&mut self, loc: &L, style: u32) {
+        let (row, col) = loc.to_location();
+        let row = self.get_or_new_row(row);
+        row.get_or_new_cell(col).style = Some(style);
+    }
+
+    ///
+    /// Make the cell blank, its style is kept if no style is given.
+    ///
+    pub(crate) fn write_blank<L: Location>(&mut self, loc: &L, style: Option<u32>) {
+        let (row, col) = loc.to_location();
+        let row = self.get_or_new_row(row);
+        row.get_or_new_cell(col).clear(style);
+    }
+
     pub(crate) fn write_display<L: Location, T: CellDisplay + CellValue>(&mut self, loc: &L, text: &T, style: Option<u32>) -> RowResult<()> {
         let (row, col) = loc.to_location();
         let row = self.get_or_new_row(row);
@@ -148,6 +178,146 @@ impl SheetData {
     //     Ok(())
     // }
 
+    ///
+    /// Call `f` once on every cell of the range `(first_row, first_col, last_row, last_col)`,
+    /// the missing cells are created if `create` is true, otherwise only the existing ones are visited.
+    ///
+    pub(crate) fn for_each_cell_mut<F: FnMut(&mut Cell)>(&mut self, range: (u32, u32, u32, u32), create: bool, mut f: F) {
+        let (first_row, first_col, last_row, last_col) = range;
+        if create {
+            for row in first_row..=last_row {
+                let row = self.get_or_new_row(row);
+                for col in first_col..=last_col {
+                    f(row.get_or_new_cell(col));
+                }
+            }
+        } else {
+            self.rows.iter_mut()
+                .filter(|row| (first_row..=last_row).contains(&row.row))
+                .flat_map(|row| row.cells.iter_mut())
+                .filter(|cell| (first_col..=last_col).contains(&cell.loc.col))
+                .for_each(f);
+        }
+    }
+
+    ///
+    /// The existing cells of the range, row by row.
+    ///
+    pub(crate) fn get_cells(&self, range: (u32, u32, u32, u32)) -> Vec<Cell> {
+        let (first_row, first_col, last_row, last_col) = range;
+        self.rows.iter()
+            .filter(|row| (first_row..=last_row).contains(&row.row))
+            .flat_map(|row| row.cells.iter())
+            .filter(|cell| (first_col..=last_col).contains(&cell.loc.col))
+            .cloned()
+            .collect()
+    }
+
+    ///
+    /// Remove the cells of the range, the rows and their formats are kept.
+    ///
+    pub(crate) fn remove_cells(&mut self, range: (u32, u32, u32, u32)) {
+        let (first_row, first_col, last_row, last_col) = range;
+        self.rows.iter_mut()
+            .filter(|row| (first_row..=last_row).contains(&row.row))
+            .for_each(|row| row.cells.retain(|cell| !(first_col..=last_col).contains(&cell.loc.col)));
+    }
+
+    ///
+    /// Put the cells at their locations, the cells which were there are replaced.
+    ///
+    pub(crate) fn put_cells(&mut self, cells: Vec<Cell>) {
+        for cell in cells {
+            let (row, col) = (cell.loc.row, cell.loc.col);
+            *self.get_or_new_row(row).get_or_new_cell(col) = cell;
+        }
+    }
+
+    ///
+    /// The formulas defined by the masters of the shared formulas, with the rows and the columns of the masters, by their ids.
+    ///
+    pub(crate) fn get_shared_formulas(&self) -> HashMap<i32, (u32, u32, String)> {
+        self.rows.iter()
+            .flat_map(|row| row.cells.iter())
+            .filter_map(|cell| {
+                let formula = cell.formula.as_ref()?;
+                match formula.is_shared_follower() {
+                    true => None,
+                    false => Some((formula.get_si()?, (cell.loc.row, cell.loc.col, formula.formula.clone()))),
+                }
+            })
+            .collect()
+    }
+
+    ///
+    /// Turn the shared formulas whose masters are in the range into normal formulas in every cell sharing them,
+    /// so that the cells outside the range keep their formulas when the cells of the range are moved.
+    ///
+    pub(crate) fn unshare_formulas(&mut self, range: (u32, u32, u32, u32)) {
+        let (first_row, first_col, last_row, last_col) = range;
+        let shared_formulas: HashMap<i32, (u32, u32, String)> = self.get_shared_formulas().into_iter()
+            .filter(|(_, (row, col, _))| (first_row..=last_row).contains(row) && (first_col..=last_col).contains(col))
+            .collect();
+        self.rows.iter_mut()
+            .flat_map(|row| row.cells.iter_mut())
+            .filter(|cell| cell.formula.as_ref()
+                .and_then(|formula| formula.get_si())
+                .is_some_and(|si| shared_formulas.contains_key(&si)))
+            .for_each(|cell| *cell = cell.copy_to(cell.loc.row, cell.loc.col, &shared_formulas));
+    }
+
+    ///
+    /// Update the references of the formulas when the cells of the range are moved by the offsets,
+    /// see [`move_references`]. The shared formulas which change, or which are shared by a cell of the range,
+    /// are turned into normal formulas in every cell sharing them, so that the cells of the range keep their formulas.
+    ///
+    pub(crate) fn move_references(&mut self, sheet_name: &str, range: (u32, u32, u32, u32), row_offset: i64, col_offset: i64) {
+        let shared_formulas = self.get_shared_formulas();
+        let unshared: HashSet<i32> = self.rows.iter()
+            .flat_map(|row| row.cells.iter())
+            .filter_map(|cell| {
+                let si = cell.formula.as_ref()?.get_si()?;
+                let (row, col) = (cell.loc.row, cell.loc.col);
+                let formula = cell.copy_to(row, col, &shared_formulas).formula?.formula;
+                let in_range = range_util::contains(range, (row, col, row, col));
+                match in_range || move_references(&formula, sheet_name, range, row_offset, col_offset) != formula {
+                    true => Some(si),
+                    false => None,
+                }
+            })
+            .collect();
+        let shared_formulas: HashMap<i32, (u32, u32, String)> = shared_formulas.into_iter()
+            .filter(|(si, _)| unshared.contains(si))
+            .collect();
+        for cell in self.rows.iter_mut().flat_map(|row| row.cells.iter_mut()) {
+            if cell.formula.as_ref().and_then(|formula| formula.get_si()).is_some_and(|si| unshared.contains(&si)) {
+                *cell = cell.copy_to(cell.loc.row, cell.loc.col, &shared_formulas);
+            }
+            if let Some(formula) = cell.formula.as_mut().filter(|formula| !formula.is_shared_follower()) {
+                formula.formula = move_references(&formula.formula, sheet_name, range, row_offset, col_offset);
+            }
+        }
+    }
+
+    ///
+    /// The custom heights of the rows between `first_row` and `last_row`.
+    ///
+    pub(crate) fn get_row_heights(&self, first_row: u32, last_row: u32) -> Vec<(u32, f64)> {
+        self.rows.iter()
+            .filter(|row| (first_row..=last_row).contains(&row.row) && row.custom_height == Some(1))
+            .filter_map(|row| Some((row.row, row.height?)))
+            .collect()
+    }
+
+    pub(crate) fn for_each_style_mut<F: FnMut(&mut u32)>(&mut self, f: &mut F) {
+        for row in &mut self.rows {
+            if let Some(style) = &mut row.style {
+                f(style);
+            }
+            row.cells.iter_mut().filter_map(|cell| cell.style.as_mut()).for_each(&mut *f);
+        }
+    }
+
     pub(crate) fn clean_formula_value(&mut self) {
         // self.rows.iter_mut().for_each(
         //     |row| row.cells.iter_mut().for_each(|cell|
@@ -2,6 +2,7 @@ pub(crate) mod formula;
 pub(crate) mod inline_string;
 mod text;
 
+use std::collections::HashMap;
 use std::fmt::Formatter;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde::de::{Error, Visitor};
@@ -10,7 +11,9 @@ use crate::api::cell::location::Location;
 use crate::xml::worksheet::sheet_data::cell::formula::Formula;
 use crate::api::cell::values::{CellDisplay, CellValue, CellType};
 use crate::result::CellResult;
+use crate::utils::formula_util::offset_formula;
 use crate::xml::common::FromFormat;
+use crate::xml::shared_string::SharedString;
 use crate::xml::worksheet::sheet_data::cell::inline_string::InlineString;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -70,6 +73,38 @@ impl Cell {
 /// Convertor
 ///
 impl Cell {
+    ///
+    /// A copy of the cell placed at another location, whose relative references are re-based.
+    /// A cell sharing a formula gets the formula of its master in `shared_formulas`, as a normal formula.
+    ///
+    pub(crate) fn copy_to(&self, row: u32, col: u32, shared_formulas: &HashMap<i32, (u32, u32, String)>) -> Cell {
+        let mut cell = self.clone();
+        cell.loc = Sqref { row, col };
+        cell.formula = match &self.formula {
+            Some(formula) if formula.is_shared_follower() => formula.get_si()
+                .and_then(|si| shared_formulas.get(&si))
+                .map(|(from_row, from_col, master)| {
+                    let formula = offset_formula(master, row as i64 - *from_row as i64, col as i64 - *from_col as i64);
+                    Formula::from_formula(formula)
+                }),
+            Some(formula) => formula.offset(row as i64 - self.loc.row as i64, col as i64 - self.loc.col as i64),
+            None => None,
+        };
+        cell
+    }
+
+    ///
+    /// The cell placed at another location with its formula kept, the range of an array formula is moved with the cell.
+    ///
+    pub(crate) fn move_to(&self, row: u32, col: u32) -> Cell {
+        let mut cell = self.clone();
+        cell.loc = Sqref { row, col };
+        if let Some(formula) = &mut cell.formula {
+            formula.move_ref(row as i64 - self.loc.row as i64, col as i64 - self.loc.col as i64);
+        }
+        cell
+    }
+
     pub(crate) fn to_api_cell(&self) -> ApiCell<String> {
         let mut api_cell = ApiCell::default();
         api_cell.text = self.text.clone();
@@ -98,6 +133,54 @@ impl Cell {
         self.formula = None;
     }
 
+    pub(crate) fn clear(&mut self, style: Option<u32>) {
+        if let Some(style) = style {
+            self.style = Some(style);
+        }
+        self.cell_type = None;
+        self.cell_meta_index = None;
+        self.formula = None;
+        self.text = None;
+        self.inline_string = None;
+    }
+
+    ///
+    /// Replace the index of a shared string by the text it refers to, so that the cell no longer depends on the table.
+    ///
+    pub(crate) fn resolve_shared_string(&mut self, shared_string: &SharedString) {
+        if let Some(CellType::SharedString) = self.cell_type {
+            let id = self.text.as_ref().and_then(|text| text.parse().ok()).unwrap_or_default();
+            match shared_string.get_rich_text(id) {
+                Some(rich_text) => {
+                    self.cell_type = Some(CellType::InlineString);
+                    self.text = None;
+                    self.inline_string = Some(InlineString::from_format(&rich_text));
+                }
+                None => {
+                    self.cell_type = Some(CellType::String);
+                    self.text = Some(shared_string.get_text(id).unwrap_or_default().to_string());
+                }
+            }
+        }
+    }
+
+    ///
+    /// Move the string of the cell into the table of shared strings, the opposite of [`Cell::resolve_shared_string`].
+    /// The cell is left as it is if the table can not take new strings.
+    ///
+    pub(crate) fn share_string(&mut self, shared_string: &mut SharedString) {
+        let inline_string = match (&self.cell_type, &self.inline_string, &self.text) {
+            (Some(CellType::InlineString), Some(inline_string), _) => inline_string.clone(),
+            (Some(CellType::String), None, Some(text)) => InlineString::new(text),
+            _ => return,
+        };
+        if let Some(id) = shared_string.add_string(&inline_string) {
+            self.cell_type = Some(CellType::SharedString);
+            self.text = Some(id.to_string());
+            self.inline_string = None;
+        }
+    }
+
     pub(crate) fn update_by_api_cell<T: CellDisplay + CellValue>(&mut self, api_cell: &ApiCell<T>) -> CellResult<()> {
         if let Some(text) = &api_cell.text {
             self.text = Some(text.to_display());
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 use crate::api::cell::formula::Formula as ApiFormula;
+use crate::utils::col_helper::{to_loc, to_ref};
+use crate::utils::formula_util::offset_formula;
 
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub(crate) struct Formula {
@@ -41,6 +43,73 @@ impl Formula {
     }
 }
 
+impl Formula {
+    pub(crate) fn from_formula(formula: String) -> Formula {
+        Formula {
+            formula,
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// The id of the shared formula, if the cell defines or shares one.
+    ///
+    pub(crate) fn get_si(&self) -> Option<i32> {
+        self.si
+    }
+
+    ///
+    /// Whether the cell only shares the formula defined by another cell.
+    ///
+    pub(crate) fn is_shared_follower(&self) -> bool {
+        self.si.is_some() && self.formula.is_empty()
+    }
+
+    ///
+    /// The formula of a cell moved by the offsets, `None` for a cell which only shares the formula of another cell.
+    /// The relative references are re-based, a shared formula becomes a normal one,
+    /// the range of an array formula is moved with the cell.
+    ///
+    pub(crate) fn offset(&self, row_offset: i64, col_offset: i64) -> Option<Formula> {
+        let mut formula = self.clone();
+        formula.formula = offset_formula(&self.formula, row_offset, col_offset);
+        if self.si.is_some() {
+            if self.is_shared_follower() {
+                return None;
+            }
+            formula.si = None;
+            formula.formula_ref = None;
+            formula.formula_type = None;
+        }
+        if let Some(formula_ref) = &formula.formula_ref {
+            formula.formula_ref = Some(offset_ref(formula_ref, row_offset, col_offset));
+        }
+        Some(formula)
+    }
+
+    ///
+    /// Move the range of an array formula by the offsets.
+    ///
+    pub(crate) fn move_ref(&mut self, row_offset: i64, col_offset: i64) {
+        if let Some(formula_ref) = &self.formula_ref {
+            self.formula_ref = Some(offset_ref(formula_ref, row_offset, col_offset));
+        }
+    }
+}
+
+///
+/// Move a reference like A1 or A1:B2 by the offsets.
+///
+fn offset_ref(range_ref: &str, row_offset: i64, col_offset: i64) -> String {
+    range_ref.split(':')
+        .map(|cell_ref| {
+            let (row, col) = to_loc(cell_ref);
+            to_ref((row as i64 + row_offset) as u32, (col as i64 + col_offset) as u32)
+        })
+        .collect::<Vec<String>>()
+        .join(":")
+}
+
 impl Formula {
     // pub(crate) fn from_formula_type(formula: &str, formula_type: FormulaType) -> Formula {
     //     // let mut formula = formula.trim_matches(|f| f == '{' || f == '}').to_string();
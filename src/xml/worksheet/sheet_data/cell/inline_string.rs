@@ -6,10 +6,59 @@ use crate::xml::worksheet::sheet_data::cell::text::Text;
 
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub(crate) struct InlineString {
-    #[serde(rename = "r", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "t", default, skip_serializing_if = "Option::is_none")]
+    text: Option<Text>,
+    #[serde(rename = "r", default, skip_serializing_if = "Vec::is_empty")]
     rich_texts: Vec<RichText>
 }
 
+impl InlineString {
+    pub(crate) fn new(text: &str) -> InlineString {
+        InlineString {
+            text: Some(Text::new_with_space(text)),
+            rich_texts: vec![],
+        }
+    }
+
+    ///
+    /// The texts of the runs, or the plain text of a string without runs.
+    ///
+    pub(crate) fn get_texts(&self) -> Vec<String> {
+        match &self.text {
+            Some(text) if self.rich_texts.is_empty() => vec![text.text.clone()],
+            _ => self.rich_texts
+                .iter()
+                .map(|r| r.text.as_ref().map(|t| t.text.clone()).unwrap_or_default())
+                .collect(),
+        }
+    }
+
+    ///
+    /// Replace the texts returned by [`InlineString::get_texts`], the formats of the runs are kept.
+    ///
+    pub(crate) fn set_texts(&mut self, texts: Vec<String>) {
+        match &mut self.text {
+            Some(text) if self.rich_texts.is_empty() => {
+                *text = Text::new_with_space(texts.concat().as_str());
+            }
+            _ => {
+                self.rich_texts
+                    .iter_mut()
+                    .zip(&texts)
+                    .for_each(|(r, text)| r.text = Some(Text::new_with_space(text)));
+                // The runs left without text are removed, one run is kept for an empty string
+                match texts.iter().any(|text| !text.is_empty()) {
+                    true => {
+                        let mut texts = texts.iter();
+                        self.rich_texts.retain(|_| !texts.next().is_some_and(|text| text.is_empty()));
+                    }
+                    false => self.rich_texts.truncate(1),
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub(crate) struct RichText {
     #[serde(rename = "rPr", skip_serializing_if = "Option::is_none")]
@@ -27,6 +76,10 @@ impl FromFormat<ApiRichText> for InlineString {
     }
 
     fn set_format(&self, api_rich_text: &mut ApiRichText) {
+        if let (Some(text), true) = (&self.text, self.rich_texts.is_empty()) {
+            api_rich_text.words = vec![Word { text: text.text.clone(), font: None }];
+            return;
+        }
         api_rich_text.words = self.rich_texts
             .iter()
             .map(|r|r.get_format())
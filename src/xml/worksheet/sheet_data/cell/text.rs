@@ -3,7 +3,7 @@ use serde::de::IntoDeserializer;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct Text {
-    #[serde(rename = "$text", skip_serializing_if = "String::is_empty")]
+    #[serde(rename = "$text", default, skip_serializing_if = "String::is_empty")]
     pub(crate) text: String,
     #[serde(rename = "@xml:space", default, skip_serializing_if = "String::is_empty")]
     pub(crate) xml_space: String,
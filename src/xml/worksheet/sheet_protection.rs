@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use crate::api::protection::ProtectionOptions;
+use crate::utils::hash_util;
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub(crate) struct SheetProtection {
+    #[serde(rename = "@password", skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+    #[serde(rename = "@algorithmName", skip_serializing_if = "Option::is_none")]
+    algorithm_name: Option<String>,
+    #[serde(rename = "@hashValue", skip_serializing_if = "Option::is_none")]
+    hash_value: Option<String>,
+    #[serde(rename = "@saltValue", skip_serializing_if = "Option::is_none")]
+    salt_value: Option<String>,
+    #[serde(rename = "@spinCount", skip_serializing_if = "Option::is_none")]
+    spin_count: Option<u32>,
+    #[serde(rename = "@sheet", skip_serializing_if = "Option::is_none")]
+    sheet: Option<u8>,
+    #[serde(rename = "@objects", skip_serializing_if = "Option::is_none")]
+    objects: Option<u8>,
+    #[serde(rename = "@scenarios", skip_serializing_if = "Option::is_none")]
+    scenarios: Option<u8>,
+    #[serde(rename = "@formatCells", skip_serializing_if = "Option::is_none")]
+    format_cells: Option<u8>,
+    #[serde(rename = "@formatColumns", skip_serializing_if = "Option::is_none")]
+    format_columns: Option<u8>,
+    #[serde(rename = "@formatRows", skip_serializing_if = "Option::is_none")]
+    format_rows: Option<u8>,
+    #[serde(rename = "@insertColumns", skip_serializing_if = "Option::is_none")]
+    insert_columns: Option<u8>,
+    #[serde(rename = "@insertRows", skip_serializing_if = "Option::is_none")]
+    insert_rows: Option<u8>,
+    #[serde(rename = "@insertHyperlinks", skip_serializing_if = "Option::is_none")]
+    insert_hyperlinks: Option<u8>,
+    #[serde(rename = "@deleteColumns", skip_serializing_if = "Option::is_none")]
+    delete_columns: Option<u8>,
+    #[serde(rename = "@deleteRows", skip_serializing_if = "Option::is_none")]
+    delete_rows: Option<u8>,
+    #[serde(rename = "@selectLockedCells", skip_serializing_if = "Option::is_none")]
+    select_locked_cells: Option<u8>,
+    #[serde(rename = "@sort", skip_serializing_if = "Option::is_none")]
+    sort: Option<u8>,
+    #[serde(rename = "@autoFilter", skip_serializing_if = "Option::is_none")]
+    auto_filter: Option<u8>,
+    #[serde(rename = "@pivotTables", skip_serializing_if = "Option::is_none")]
+    pivot_tables: Option<u8>,
+    #[serde(rename = "@selectUnlockedCells", skip_serializing_if = "Option::is_none")]
+    select_unlocked_cells: Option<u8>,
+}
+
+///
+/// In `sheetProtection`, `objects`, `scenarios` and `select*Cells` are off by default,
+/// the other attributes are on by default, and `1` means the action is protected.
+///
+fn protected(allow: bool, protected_by_default: bool) -> Option<u8> {
+    match (allow, protected_by_default) {
+        (true, true) => Some(0),
+        (false, false) => Some(1),
+        _ => None,
+    }
+}
+
+impl SheetProtection {
+    pub(crate) fn from_options(password: &str, options: &ProtectionOptions) -> SheetProtection {
+        let mut sheet_protection = SheetProtection::default();
+        if !password.is_empty() {
+            if options.legacy_password {
+                sheet_protection.password = Some(hash_util::legacy_password_hash(password));
+            } else {
+                let (hash_value, salt_value) = hash_util::sha512_password_hash(password, options.spin_count);
+                sheet_protection.algorithm_name = Some(hash_util::SHA512_ALGORITHM_NAME.to_string());
+                sheet_protection.hash_value = Some(hash_value);
+                sheet_protection.salt_value = Some(salt_value);
+                sheet_protection.spin_count = Some(options.spin_count);
+            }
+        }
+        sheet_protection.sheet = Some(1);
+        sheet_protection.objects = protected(options.objects, false);
+        sheet_protection.scenarios = protected(options.scenarios, false);
+        sheet_protection.format_cells = protected(options.format_cells, true);
+        sheet_protection.format_columns = protected(options.format_columns, true);
+        sheet_protection.format_rows = protected(options.format_rows, true);
+        sheet_protection.insert_columns = protected(options.insert_columns, true);
+        sheet_protection.insert_rows = protected(options.insert_rows, true);
+        sheet_protection.insert_hyperlinks = protected(options.insert_hyperlinks, true);
+        sheet_protection.delete_columns = protected(options.delete_columns, true);
+        sheet_protection.delete_rows = protected(options.delete_rows, true);
+        sheet_protection.select_locked_cells = protected(options.select_locked_cells, false);
+        sheet_protection.sort = protected(options.sort, true);
+        sheet_protection.auto_filter = protected(options.autofilter, true);
+        sheet_protection.pivot_tables = protected(options.pivot_tables, true);
+        sheet_protection.select_unlocked_cells = protected(options.select_unlocked_cells, false);
+        sheet_protection
+    }
+
+    pub(crate) fn is_protected(&self) -> bool {
+        self.sheet == Some(1)
+    }
+
+    pub(crate) fn to_options(&self) -> ProtectionOptions {
+        let allowed = |val: Option<u8>, protected_by_default: bool| {
+            val.map(|v| v == 0).unwrap_or(!protected_by_default)
+        };
+        ProtectionOptions {
+            objects: allowed(self.objects, false),
+            scenarios: allowed(self.scenarios, false),
+            format_cells: allowed(self.format_cells, true),
+            format_columns: allowed(self.format_columns, true),
+            format_rows: allowed(self.format_rows, true),
+            insert_columns: allowed(self.insert_columns, true),
+            insert_rows: allowed(self.insert_rows, true),
+            insert_hyperlinks: allowed(self.insert_hyperlinks, true),
+            delete_columns: allowed(self.delete_columns, true),
+            delete_rows: allowed(self.delete_rows, true),
+            select_locked_cells: allowed(self.select_locked_cells, false),
+            sort: allowed(self.sort, true),
+            autofilter: allowed(self.auto_filter, true),
+            pivot_tables: allowed(self.pivot_tables, true),
+            select_unlocked_cells: allowed(self.select_unlocked_cells, false),
+            legacy_password: self.password.is_some(),
+            spin_count: self.spin_count.unwrap_or(hash_util::DEFAULT_SPIN_COUNT),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use crate::api::sort::{SortBy, SortKey, SortOrder};
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub(crate) struct SortState {
+    #[serde(rename = "@ref")]
+    sqref: String,
+    #[serde(rename = "@caseSensitive", default, skip_serializing_if = "Option::is_none")]
+    case_sensitive: Option<u8>,
+    #[serde(rename = "sortCondition", default, skip_serializing_if = "Vec::is_empty")]
+    sort_condition: Vec<SortCondition>,
+}
+
+impl SortState {
+    pub(crate) fn new(sqref: &str) -> SortState {
+        SortState {
+            sqref: sqref.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn add_sort_condition(&mut self, sqref: &str, key: &SortKey, dxf_id: Option<u32>) {
+        let custom_list = match &key.by {
+            SortBy::CustomList(list) => Some(list.join(",")),
+            _ => None,
+        };
+        self.sort_condition.push(SortCondition {
+            descending: (key.order == SortOrder::Descending).then_some(1),
+            sort_by: (key.by.to_str() != "value").then(|| key.by.to_str().to_string()),
+            sqref: sqref.to_string(),
+            custom_list,
+            dxf_id,
+        });
+    }
+
+    pub(crate) fn for_each_dxf_id_mut<F: FnMut(&mut u32)>(&mut self, f: &mut F) {
+        self.sort_condition.iter_mut().filter_map(|sort_condition| sort_condition.dxf_id.as_mut()).for_each(f);
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct SortCondition {
+    #[serde(rename = "@descending", default, skip_serializing_if = "Option::is_none")]
+    descending: Option<u8>,
+    #[serde(rename = "@sortBy", default, skip_serializing_if = "Option::is_none")]
+    sort_by: Option<String>,
+    #[serde(rename = "@ref")]
+    sqref: String,
+    #[serde(rename = "@customList", default, skip_serializing_if = "Option::is_none")]
+    custom_list: Option<String>,
+    #[serde(rename = "@dxfId", default, skip_serializing_if = "Option::is_none")]
+    dxf_id: Option<u32>,
+}
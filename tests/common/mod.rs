@@ -0,0 +1,24 @@
+//! Helpers shared by the tests, which inspect the parts of the saved files.
+
+use std::fs::File;
+use std::io::Read;
+use edit_xlsx::WorkbookResult;
+
+///
+/// The xml of a part of an xlsx file, an error if the file has no such part.
+///
+pub fn read_part(file_name: &str, part: &str) -> WorkbookResult<String> {
+    let mut archive = zip::ZipArchive::new(File::open(file_name)?)?;
+    let mut xml = String::new();
+    archive.by_name(part)?.read_to_string(&mut xml)?;
+    Ok(xml)
+}
+
+///
+/// The names of the parts of an xlsx file.
+///
+#[allow(dead_code)]
+pub fn part_names(file_name: &str) -> WorkbookResult<Vec<String>> {
+    let archive = zip::ZipArchive::new(File::open(file_name)?)?;
+    Ok(archive.file_names().map(|name| name.to_string()).collect())
+}
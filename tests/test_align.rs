@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{Format, FormatAlignHorizontal, FormatAlignType, FormatAlignVertical, Read, Workbook, WorkbookResult, Write};
+
+    #[test]
+    fn test_new() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let center = Format::default()
+            .set_align(FormatAlignType::Center)
+            .set_align(FormatAlignType::VerticalCenter);
+        let wrap = Format::default().set_text_wrap();
+        let shrink = Format::default().set_shrink();
+        let up = Format::default().set_rotation(90);
+        let down = Format::default().set_rotation(-45);
+        let stacked = Format::default().set_rotation(270);
+        let distributed = Format::default()
+            .set_align_horizontal(FormatAlignHorizontal::Distributed)
+            .set_align_vertical(FormatAlignVertical::Distributed)
+            .set_justify_last_line()
+            .set_indent(2);
+        let fill = Format::default().set_align_horizontal(FormatAlignHorizontal::Fill);
+        let center_continuous = Format::default().set_align_horizontal(FormatAlignHorizontal::CenterContinuous);
+        worksheet.write_with_format("A1", "Center", &center)?;
+        worksheet.write_with_format("A2", "Wrap the long text", &wrap)?;
+        worksheet.write_with_format("A3", "Shrink the long text", &shrink)?;
+        worksheet.write_with_format("A4", "Up", &up)?;
+        worksheet.write_with_format("A5", "Down", &down)?;
+        worksheet.write_with_format("A6", "Stacked", &stacked)?;
+        worksheet.write_with_format("A7", "Distributed text", &distributed)?;
+        worksheet.write_with_format("A8", "*", &fill)?;
+        worksheet.write_with_format("A9", "Center across", &center_continuous)?;
+        workbook.save_as("tests/output/align_test_new.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/align_test_new.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        let align = |loc: &str| worksheet.read_cell(loc).unwrap().format.unwrap().align;
+        assert_eq!(align("A1"), center.align);
+        assert_eq!(align("A1").vertical, Some(FormatAlignVertical::Center));
+        assert!(align("A2").wrap_text);
+        assert!(align("A3").shrink_to_fit);
+        assert_eq!(align("A4").text_rotation, 90);
+        assert_eq!(align("A5").text_rotation, 135);
+        assert_eq!(align("A6").text_rotation, 255);
+        assert_eq!(align("A7"), distributed.align);
+        assert_eq!(align("A8").horizontal, Some(FormatAlignHorizontal::Fill));
+        assert_eq!(align("A9").horizontal, Some(FormatAlignHorizontal::CenterContinuous));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/accounting.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let justify = Format::default()
+            .set_align_horizontal(FormatAlignHorizontal::Justify)
+            .set_align_vertical(FormatAlignVertical::Justify)
+            .set_rotation(200);
+        worksheet.write_with_format("A1", "Justify", &justify)?;
+        workbook.save_as("tests/output/align_test_from.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/align_test_from.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        let align = worksheet.read_cell("A1")?.format.unwrap().align;
+        assert_eq!(align.horizontal, Some(FormatAlignHorizontal::Justify));
+        assert_eq!(align.vertical, Some(FormatAlignVertical::Justify));
+        assert_eq!(align.text_rotation, 0);
+        Ok(())
+    }
+}
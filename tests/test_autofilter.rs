@@ -0,0 +1,185 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{DynamicFilterType, Filter, Filters, Format, FormatColor, WorkSheet, WorkSheetRow, Workbook, WorkbookResult, Write};
+    use crate::common::read_part;
+
+    fn hidden_rows(worksheet: &WorkSheet, last_row: u32) -> Vec<u32> {
+        (1..=last_row).filter(|&row| worksheet.get_row(row).is_ok_and(|row| row.hidden == Some(1))).collect()
+    }
+
+    fn write_data(worksheet: &mut WorkSheet) -> WorkbookResult<()> {
+        worksheet.write_row("B1", &["Region", "Item", "Volume"])?;
+        let data = [("East", "Apple", 100), ("West", "Pear", 300), ("east", "Banana", 200), ("North", "apricot", 400), ("", "Grape", 500)];
+        for (row, (region, item, volume)) in (2..).zip(data) {
+            if !region.is_empty() {
+                worksheet.write((row, 2), region)?;
+            }
+            worksheet.write((row, 3), item)?;
+            worksheet.write((row, 4), volume)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_new() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        write_data(worksheet)?;
+        worksheet.autofilter("B1:D6");
+        worksheet.filter_column("B", &Filters::eq(vec!["East", "North"]));
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![3, 6]);
+
+        // The criteria of the column are replaced
+        let mut filters = Filters::new();
+        filters.or(Filter::eq("West")).or(Filter::eq("East"));
+        worksheet.filter_column("B", &filters);
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![5, 6]);
+        let mut filters = Filters::new();
+        filters.and(Filter::gt("100")).and(Filter::lt("400"));
+        worksheet.filter_column("D", &filters);
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![2, 5, 6]);
+
+        workbook.save_as("tests/output/autofilter_test_new.xlsx")?;
+        let sheet = read_part("tests/output/autofilter_test_new.xlsx", "xl/worksheets/sheet1.xml")?;
+        // The columns are counted from the first column of the autofilter
+        assert!(sheet.contains(r#"<filterColumn colId="0"><filters><filter val="West"/><filter val="East"/></filters></filterColumn>"#));
+        assert!(sheet.contains(r#"<filterColumn colId="2"><customFilters and="1">"#));
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        write_data(worksheet)?;
+        worksheet.autofilter("B1:D6");
+        worksheet.filter_column("B", &Filters::blank());
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![2, 3, 4, 5]);
+        worksheet.filter_column("B", &Filters::not_blank());
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![6]);
+
+        // The wildcards of the texts
+        let mut filters = Filters::new();
+        filters.or(Filter::ne("a*"));
+        worksheet.filter_column("C", &filters);
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![2, 5, 6]);
+
+        // The data changed
+        worksheet.write("C2", "Cherry")?;
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![5, 6]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_top10() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        write_data(worksheet)?;
+        worksheet.autofilter("B1:D6");
+        worksheet.filter_column("D", &Filters::top(2));
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![2, 3, 4]);
+        worksheet.filter_column("D", &Filters::bottom_percent(40.0));
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![3, 5, 6]);
+        worksheet.filter_column("D", &Filters::dynamic(DynamicFilterType::AboveAverage));
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![2, 3, 4]);
+        workbook.save_as("tests/output/autofilter_test_top10.xlsx")?;
+        let sheet = read_part("tests/output/autofilter_test_top10.xlsx", "xl/worksheets/sheet1.xml")?;
+        assert!(sheet.contains(r#"<dynamicFilter type="aboveAverage" val="300"/>"#));
+
+        // The criteria are read back and applied again
+        let mut workbook = Workbook::from_path("tests/output/autofilter_test_top10.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write("D2", 1000)?;
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![3, 4, 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dates() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write("A1", "Date")?;
+        // 2024-01-31, 2024-02-29, 2023-02-01, 2022-06-15 and 2022-06-16
+        for (row, date) in (2..).zip([45322.0, 45351.0, 44958.0, 44727.0, 44728.0]) {
+            worksheet.write((row, 1), date)?;
+        }
+        worksheet.autofilter("A1:A6");
+        let mut filters = Filters::new();
+        filters.or(Filter::month(2024, 2)).or(Filter::day(2024, 1, 31));
+        worksheet.filter_column("A", &filters);
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![4, 5, 6]);
+        worksheet.filter_column("A", &Filters::new().or(Filter::year(2023)));
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![2, 3, 5, 6]);
+        // None of the dates is yesterday, whichever day the test runs
+        worksheet.filter_column("A", &Filters::dynamic(DynamicFilterType::Yesterday));
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![2, 3, 4, 5, 6]);
+        workbook.save_as("tests/output/autofilter_test_dates.xlsx")?;
+        let sheet = read_part("tests/output/autofilter_test_dates.xlsx", "xl/worksheets/sheet1.xml")?;
+        // Yesterday is the day before the one the filter was applied, from val to maxVal
+        let filter = &sheet[sheet.find(r#"<dynamicFilter type="yesterday" val=""#).unwrap()..];
+        let values: Vec<f64> = filter.split('"').skip(3).step_by(2).take(2).map(|value| value.parse().unwrap()).collect();
+        assert_eq!(values[1] - values[0], 1.0);
+        assert!(values[0] > 45351.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_color() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let red = FormatColor::RGB(255, 0, 0);
+        write_data(worksheet)?;
+        worksheet.write_with_format("C3", "Pear", &Format::default().set_background_color(red))?;
+        worksheet.write_with_format("C4", "Banana", &Format::default().set_color(red))?;
+        worksheet.autofilter("B1:D6");
+        worksheet.filter_column("C", &Filters::cell_color(red));
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![2, 4, 5, 6]);
+        worksheet.filter_column("C", &Filters::font_color(red));
+        worksheet.apply_autofilter();
+        assert_eq!(hidden_rows(worksheet, 6), vec![2, 3, 5, 6]);
+        // Filtering again by the same color reuses its dxf
+        worksheet.filter_column("C", &Filters::font_color(red));
+        workbook.save_as("tests/output/autofilter_test_color.xlsx")?;
+        let sheet = read_part("tests/output/autofilter_test_color.xlsx", "xl/worksheets/sheet1.xml")?;
+        assert!(sheet.contains(r#"<colorFilter dxfId="1" cellColor="0"/>"#));
+        let styles = read_part("tests/output/autofilter_test_color.xlsx", "xl/styles.xml")?;
+        assert_eq!(styles.matches("<dxf>").count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_before_data() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        // The criteria are set before the data is written, and only applied on request
+        worksheet.autofilter("B:D");
+        worksheet.filter_column("B", &Filters::eq(vec!["East", "North"]));
+        write_data(worksheet)?;
+        assert_eq!(hidden_rows(worksheet, 6), vec![]);
+        worksheet.apply_autofilter();
+        // The whole columns are filtered up to the last row of the data
+        assert_eq!(hidden_rows(worksheet, 100), vec![3, 6]);
+        assert_eq!(worksheet.max_row(), 6);
+        workbook.save_as("tests/output/autofilter_test_filter_before_data.xlsx")?;
+        let sheet = read_part("tests/output/autofilter_test_filter_before_data.xlsx", "xl/worksheets/sheet1.xml")?;
+        assert!(sheet.contains(r#"<autoFilter ref="B:D">"#));
+        Ok(())
+    }
+}
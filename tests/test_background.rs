@@ -1,6 +1,9 @@
+mod common;
+
 #[cfg(test)]
 mod tests {
-    use edit_xlsx::{Workbook, WorkbookResult};
+    use edit_xlsx::{Workbook, WorkbookResult, WorkSheetError};
+    use crate::common::read_part;
 
     #[test]
     fn test_new() -> WorkbookResult<()> {
@@ -37,4 +40,45 @@ mod tests {
         workbook.save_as("tests/output/background_test_from_overwrite.xlsx")?;
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_bmp() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.set_background("examples/pics/capybara.bmp")?;
+        assert_eq!(worksheet.get_background(), Some(std::fs::read("examples/pics/capybara.bmp")?));
+        workbook.save_as("tests/output/background_test_bmp.xlsx")?;
+
+        let content_types = read_part("tests/output/background_test_bmp.xlsx", "[Content_Types].xml")?;
+        assert!(content_types.contains(r#"Extension="bmp" ContentType="image/bmp""#));
+        let workbook = Workbook::from_path("tests/output/background_test_bmp.xlsx")?;
+        assert_eq!(workbook.get_worksheet(1)?.get_background(), Some(std::fs::read("examples/pics/capybara.bmp")?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_error() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        assert!(matches!(worksheet.set_background("examples/pics/rust.svg"), Err(WorkSheetError::FormatError)));
+        assert_eq!(worksheet.get_background(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/background_capybara.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        assert!(worksheet.get_background().is_some());
+        worksheet.remove_background();
+        assert_eq!(worksheet.get_background(), None);
+        workbook.save_as("tests/output/background_test_remove.xlsx")?;
+
+        assert!(read_part("tests/output/background_test_remove.xlsx", "xl/media/image1.png").is_err());
+        let rels = read_part("tests/output/background_test_remove.xlsx", "xl/worksheets/_rels/sheet1.xml.rels").unwrap_or_default();
+        assert!(!rels.contains("media/"));
+        let workbook = Workbook::from_path("tests/output/background_test_remove.xlsx")?;
+        assert_eq!(workbook.get_worksheet(1)?.get_background(), None);
+        Ok(())
+    }
+}
@@ -1,6 +1,9 @@
+mod common;
+
 #[cfg(test)]
 mod tests {
-    use edit_xlsx::{Format, FormatBorder, FormatBorderElement, FormatBorderType, FormatColor, Workbook, WorkbookResult, WorkSheetCol, WorkSheetRow};
+    use edit_xlsx::{Format, FormatBorder, FormatBorderElement, FormatBorderType, FormatColor, Read, Workbook, WorkbookResult, WorkSheetCol, WorkSheetRow, Write};
+    use crate::common::read_part;
 
     #[test]
     fn test_new() -> WorkbookResult<()> {
@@ -37,4 +40,93 @@ mod tests {
         workbook.save_as("tests/output/border_test_from.xlsx")?;
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_diagonal() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let format = Format::default()
+            .set_border_diagonal(FormatBorderType::Dashed, true, true)
+            .set_border_color(FormatColor::RGB(255, 0, 0));
+        worksheet.write_with_format("B2", "diagonal", &format)?;
+        let mut format = Format::default().set_border(FormatBorderType::Thin);
+        format.border.outline = false;
+        format.border.left.color = FormatColor::Theme(4, 0.0);
+        worksheet.write_with_format("B3", "no outline", &format)?;
+        workbook.save_as("tests/output/border_test_diagonal.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/border_test_diagonal.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        let border = worksheet.read_cell("B2")?.format.unwrap().border;
+        assert!(border.diagonal_up);
+        assert!(border.diagonal_down);
+        assert!(border.outline);
+        assert_eq!(border.diagonal.border_type, FormatBorderType::Dashed);
+        assert_eq!(border.diagonal.color, FormatColor::RGB(255, 0, 0));
+        assert_eq!(border.top.color, FormatColor::RGB(255, 0, 0));
+        let border = worksheet.read_cell("B3")?.format.unwrap().border;
+        assert!(!border.diagonal_up);
+        assert!(!border.outline);
+        assert_eq!(border.left, FormatBorderElement::new(&FormatBorderType::Thin, &FormatColor::Theme(4, 0.0)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_draw_range() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let bold = Format::default().set_bold();
+        worksheet.write_with_format("C3", "keep", &bold)?;
+        let thick = FormatBorderElement::from_border_type(&FormatBorderType::Thick);
+        let thin = FormatBorderElement::from_border_type(&FormatBorderType::Thin);
+        worksheet.draw_border_outline("B2:D4", &thick);
+        worksheet.draw_border_grid("F2:G3", &thin);
+        workbook.save_as("tests/output/border_test_draw_range.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/border_test_draw_range.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        let none = FormatBorderElement::default();
+        let border = worksheet.read_cell("B2")?.format.unwrap().border;
+        assert_eq!((border.top, border.left, border.bottom, border.right), (thick, thick, none, none));
+        let border = worksheet.read_cell("D4")?.format.unwrap().border;
+        assert_eq!((border.top, border.left, border.bottom, border.right), (none, none, thick, thick));
+        let border = worksheet.read_cell("C2")?.format.unwrap().border;
+        assert_eq!((border.top, border.left, border.bottom, border.right), (thick, none, none, none));
+        let cell = worksheet.read_cell("C3")?;
+        assert_eq!(cell.text, Some("keep".to_string()));
+        let format = cell.format.unwrap();
+        assert!(format.is_bold());
+        assert_eq!(format.border, FormatBorder::default());
+        let border = worksheet.read_cell("G3")?.format.unwrap().border;
+        assert_eq!((border.top, border.left, border.bottom, border.right), (thin, thin, thin, thin));
+        Ok(())
+    }
+
+    #[test]
+    fn test_draw_range_num_format() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/checkbook-register.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let thick = FormatBorderElement::from_border_type(&FormatBorderType::Thick);
+        worksheet.draw_border_outline("A18:H18", &thick);
+        worksheet.draw_border_grid("H3:H3", &thick);
+        workbook.save_as("tests/output/border_test_draw_range_num_format.xlsx")?;
+
+        let file_name = "tests/output/border_test_draw_range_num_format.xlsx";
+        let (sheet, styles) = (read_part(file_name, "xl/worksheets/sheet1.xml")?, read_part(file_name, "xl/styles.xml")?);
+        let cell_xfs = styles.split("<cellXfs").nth(1).unwrap().split("</cellXfs>").next().unwrap();
+        let num_fmt_id = |loc: &str| {
+            let style: usize = sheet.split(&format!(r#"<c r="{loc}" s=""#)).nth(1).unwrap()
+                .split('"').next().unwrap().parse().unwrap();
+            cell_xfs.split("<xf ").nth(style + 1).unwrap()
+                .split(r#"numFmtId=""#).nth(1).unwrap()
+                .split('"').next().unwrap().to_string()
+        };
+        // The dates and the amounts keep their number formats
+        assert_eq!(num_fmt_id("A18"), "164");
+        assert_eq!(num_fmt_id("H3"), "4");
+        let workbook = Workbook::from_path(file_name)?;
+        let border = workbook.get_worksheet(1)?.read_cell("A18")?.format.unwrap().border;
+        assert_eq!((border.top, border.left, border.bottom), (thick, thick, thick));
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{Format, Read, WorkSheetCol, WorkSheetError, WorkSheetRow, Workbook, WorkbookResult, Write};
+    use crate::common::read_part;
+
+    #[test]
+    fn test_new() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.merge_range_with_format("A1:B1", "Title", &Format::default().set_bold())?;
+        worksheet.write("A2", 1)?;
+        worksheet.write("B2", 2)?;
+        worksheet.write_formula("C2", "A2+B2*$A$2")?;
+        worksheet.write_url("A3", "https://github.com")?;
+        worksheet.set_row_height(2, 30.0)?;
+        worksheet.set_columns_width("B:B", 20.0)?;
+        worksheet.write("F11", "Replaced")?;
+
+        worksheet.copy_range("A1:C3", "E10")?;
+        assert_eq!(worksheet.read_cell("E10")?.text.as_deref(), Some("Title"));
+        assert!(worksheet.read_cell("E10")?.format.is_some_and(|format| format.font.bold));
+        assert_eq!(worksheet.read_cell("F11")?.text.as_deref(), Some("2"));
+        assert!(worksheet.merged_ranges().contains(&(10, 5, 10, 6)));
+        assert_eq!(worksheet.read_cell("E12")?.hyperlink.as_deref(), Some("https://github.com"));
+        assert_eq!(worksheet.get_row_height(11)?, Some(30.0));
+        assert_eq!(worksheet.get_columns_width("F:F")?.get("F:F").unwrap().unwrap(), 20.0);
+        // The source is kept
+        assert_eq!(worksheet.read_cell("A1")?.text.as_deref(), Some("Title"));
+        assert!(matches!(worksheet.copy_range("A1:C3", (1048575, 1)), Err(WorkSheetError::OutOfRange)));
+        workbook.save_as("tests/output/copy_range_test_new.xlsx")?;
+        let sheet = read_part("tests/output/copy_range_test_new.xlsx", "xl/worksheets/sheet1.xml")?;
+        assert!(sheet.contains(r#"<f t="array" ref="G11">E11+F11*$A$2</f>"#));
+        Ok(())
+    }
+
+    #[test]
+    fn test_move() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.merge_range("A1:B1", "Title")?;
+        worksheet.write_url("A2", "https://github.com")?;
+        worksheet.write_formula("B2", "A1+E5")?;
+        worksheet.write_formula("D1", "A1")?;
+        worksheet.write_formula("D4", "C3")?;
+
+        // Overlapping the source
+        worksheet.move_range("A1:B2", "B2")?;
+        assert!(worksheet.read_cell("A1").is_err());
+        assert_eq!(worksheet.read_cell("B2")?.text.as_deref(), Some("Title"));
+        assert_eq!(worksheet.merged_ranges(), vec![(2, 2, 2, 3)]);
+        let hyperlinks = worksheet.hyperlinks();
+        assert_eq!(hyperlinks.len(), 1);
+        assert_eq!(hyperlinks[0].cell, "B3");
+        workbook.save_as("tests/output/copy_range_test_move.xlsx")?;
+        let sheet = read_part("tests/output/copy_range_test_move.xlsx", "xl/worksheets/sheet1.xml")?;
+        // The references to the moved cells follow them, the others are kept
+        assert!(sheet.contains(r#"ref="C3">B2+E5</f>"#));
+        assert!(sheet.contains(r#"ref="D1">B2</f>"#));
+        // C3 was replaced by the move
+        assert!(sheet.contains(r#"ref="D4">#REF!</f>"#));
+        let rels = read_part("tests/output/copy_range_test_move.xlsx", "xl/worksheets/_rels/sheet1.xml.rels")?;
+        assert_eq!(rels.matches("https://github.com").count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_between_sheets() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/checkbook-register.xlsx")?;
+        let id = workbook.add_worksheet()?.id();
+        workbook.copy_range_between_sheets(1, "A18:H19", id, "B3")?;
+        workbook.save_as("tests/output/copy_range_test_between_sheets.xlsx")?;
+        let sheet = read_part("tests/output/copy_range_test_between_sheets.xlsx", "xl/worksheets/sheet5.xml")?;
+        // The master and a follower of a shared formula
+        assert!(sheet.contains("<f>IF(ISBLANK(B3),&quot; - &quot;,SUM(OFFSET(I3,-1,0),H3,-G3))</f>"));
+        assert!(sheet.contains("<f>IF(ISBLANK(B4),&quot; - &quot;,SUM(OFFSET(I4,-1,0),H4,-G4))</f>"));
+        // The data validations are clipped to the range
+        assert!(sheet.contains(r#"sqref="E3:E4""#));
+        assert!(sheet.contains("<formula1>categoryList</formula1>"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_comments() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/checkbook-register.xlsx")?;
+        workbook.get_worksheet_mut(1)?.copy_range("L1:L1", "L5")?;
+        let id = workbook.add_worksheet()?.id();
+        workbook.copy_range_between_sheets(1, "L1:L1", id, "B3")?;
+        workbook.save_as("tests/output/copy_range_test_comments.xlsx")?;
+        let comments = read_part("tests/output/copy_range_test_comments.xlsx", "xl/comments1.xml")?;
+        assert!(comments.contains(r#"<comment ref="L1" authorId="0""#));
+        assert!(comments.contains(r#"<comment ref="L5" authorId="0" shapeId="0"><text>"#));
+        let vml = read_part("tests/output/copy_range_test_comments.xlsx", "xl/drawings/vmlDrawing1.vml")?;
+        assert!(vml.contains("_x0000_s1027"));
+        assert!(vml.contains("<x:Row>4</x:Row>"));
+        // The other worksheet gets a comments part and a vml drawing of its own
+        let rels = read_part("tests/output/copy_range_test_comments.xlsx", "xl/worksheets/_rels/sheet5.xml.rels")?;
+        assert!(rels.contains("../comments3.xml"));
+        assert!(rels.contains("vmlDrawing"));
+        let comments = read_part("tests/output/copy_range_test_comments.xlsx", "xl/comments3.xml")?;
+        assert!(comments.contains(r#"<comment ref="B3" authorId="0" shapeId="0"><text>"#));
+        assert!(comments.contains("<author>Vertex42.com Templates</author>"));
+        let content_types = read_part("tests/output/copy_range_test_comments.xlsx", "[Content_Types].xml")?;
+        assert!(content_types.contains(r#"PartName="/xl/comments3.xml""#));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from() -> WorkbookResult<()> {
+        let mut source = Workbook::new();
+        let italic = Format::default().set_italic().set_size(20);
+        source.get_worksheet_mut(1)?.write_with_format("A1", "From another workbook", &italic)?;
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.copy_range_from(source.get_worksheet(1)?, "A1:A1", "C3")?;
+        let format = worksheet.read_cell("C3")?.format.unwrap();
+        assert!(format.font.italic);
+        assert_eq!(format.font.size, 20.0);
+        assert_eq!(worksheet.read_cell("C3")?.text.as_deref(), Some("From another workbook"));
+        workbook.save_as("tests/output/copy_range_test_from.xlsx")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_num_format() -> WorkbookResult<()> {
+        let source = Workbook::from_path("tests/xlsx/checkbook-register.xlsx")?;
+        let register = source.get_worksheet(1)?;
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.copy_range_from(register, "A1:H40", "A1")?;
+        for loc in ["A18", "B17", "H3", "H18"] {
+            assert_eq!(worksheet.read_cell(loc)?.format, register.read_cell(loc)?.format);
+        }
+        workbook.save_as("tests/output/copy_range_test_from_num_format.xlsx")?;
+
+        // The custom number format of the dates is added to the style sheet
+        let xml = read_part("tests/output/copy_range_test_from_num_format.xlsx", "xl/styles.xml")?;
+        assert!(xml.contains(r#"<numFmt numFmtId="164" formatCode="m/dd/yy;@"/>"#));
+        let workbook = Workbook::from_path("tests/output/copy_range_test_from_num_format.xlsx")?;
+        assert_eq!(workbook.get_worksheet(1)?.read_cell("A18")?.format, register.read_cell("A18")?.format);
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{FillPattern, Format, FormatColor, GradientFill, GradientType, Read, Workbook, WorkbookResult, Write};
+
+    #[test]
+    fn test_new() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let patterns = [
+            FillPattern::Solid, FillPattern::MediumGray, FillPattern::DarkGray, FillPattern::LightGray,
+            FillPattern::DarkHorizontal, FillPattern::DarkVertical, FillPattern::DarkDown, FillPattern::DarkUp,
+            FillPattern::DarkGrid, FillPattern::DarkTrellis, FillPattern::LightHorizontal, FillPattern::LightVertical,
+            FillPattern::LightDown, FillPattern::LightUp, FillPattern::LightGrid, FillPattern::LightTrellis,
+            FillPattern::Gray125, FillPattern::Gray0625,
+        ];
+        for (i, pattern) in patterns.iter().enumerate() {
+            let format = Format::default().set_pattern(*pattern, FormatColor::RGB(255, 0, 0), FormatColor::RGB(255, 255, 0));
+            worksheet.write_with_format((i as u32 + 1, 1), format!("{:?}", pattern), &format)?;
+        }
+        let linear = Format::default().set_gradient(GradientFill::linear(45.0, &[
+            (0.0, FormatColor::RGB(255, 255, 255)),
+            (0.5, FormatColor::Theme(4, 0.5)),
+            (1.0, FormatColor::RGB(0, 112, 192)),
+        ]));
+        let path = Format::default().set_gradient(GradientFill::path(0.2, 0.8, 0.2, 0.8, &[
+            (0.0, FormatColor::Index(9)),
+            (1.0, FormatColor::RGB(0, 112, 192)),
+        ]));
+        worksheet.write_with_format("C1", "Linear", &linear)?;
+        worksheet.write_with_format("C2", "Path", &path)?;
+        workbook.save_as("tests/output/fill_test_new.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/fill_test_new.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        for (i, pattern) in patterns.iter().enumerate() {
+            let fill = worksheet.read_cell((i as u32 + 1, 1))?.format.unwrap().fill;
+            assert_eq!(fill.pattern_type, *pattern);
+            assert_eq!(fill.fg_color, FormatColor::RGB(255, 0, 0));
+            assert_eq!(fill.bg_color, FormatColor::RGB(255, 255, 0));
+        }
+        let fill = worksheet.read_cell("C1")?.format.unwrap().fill;
+        assert_eq!(fill.gradient, linear.fill.gradient);
+        let gradient = worksheet.read_cell("C2")?.format.unwrap().fill.gradient.unwrap();
+        assert_eq!(gradient.gradient_type, GradientType::Path);
+        assert_eq!(Some(gradient), path.fill.gradient);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/accounting.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let gradient = Format::default().set_gradient(GradientFill::linear(90.0, &[
+            (0.0, FormatColor::RGB(255, 255, 255)),
+            (1.0, FormatColor::RGB(0, 112, 192)),
+        ]));
+        worksheet.write_with_format("A1", "Gradient", &gradient)?;
+        workbook.save_as("tests/output/fill_test_from.xlsx")?;
+
+        // read the gradient back from the saved styles, then overwrite it by a solid fill
+        let mut workbook = Workbook::from_path("tests/output/fill_test_from.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let format = worksheet.read_cell("A1")?.format.unwrap();
+        assert_eq!(format.fill.gradient, gradient.fill.gradient);
+        let solid = format.set_background_color(FormatColor::RGB(255, 0, 0));
+        worksheet.write_with_format("A1", "Solid", &solid)?;
+        let fill = worksheet.read_cell("A1")?.format.unwrap().fill;
+        assert_eq!(fill.pattern_type, FillPattern::Solid);
+        assert!(fill.gradient.is_none());
+        Ok(())
+    }
+}
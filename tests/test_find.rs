@@ -0,0 +1,122 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{FindOptions, FoundCell, Read, Workbook, WorkbookError, WorkbookResult, Write};
+    use crate::common::read_part;
+
+    fn refs(found: &[FoundCell]) -> Vec<String> {
+        found.iter().map(|cell| cell.to_ref()).collect()
+    }
+
+    #[test]
+    fn test_find() -> WorkbookResult<()> {
+        let workbook = Workbook::from_path("tests/xlsx/accounting.xlsx")?;
+        let found = workbook.find("trial balance", &FindOptions::default())?;
+        assert_eq!(found.len(), 2);
+        assert!(found.iter().all(|cell| cell.sheet == "worksheet"));
+        let options = FindOptions { whole_cell: true, ..Default::default() };
+        assert_eq!(refs(&workbook.find("total", &options)?), vec!["A153"]);
+        let options = FindOptions { whole_cell: true, match_case: true, ..Default::default() };
+        assert!(workbook.find("TOTAL", &options)?.is_empty());
+        let options = FindOptions { regex: true, ..Default::default() };
+        let found = workbook.find(r"^Plant .* depreciation$", &options)?;
+        assert!(!found.is_empty());
+        let worksheet = workbook.get_worksheet(1)?;
+        for cell in found {
+            assert_eq!(worksheet.read_cell(cell.to_ref().as_str())?.text.as_deref(), Some("Plant & m/cy depreciation"));
+        }
+
+        // The formulas shared by the cells are searched as each cell sees them
+        let options = FindOptions { in_formulas: true, ..Default::default() };
+        assert_eq!(refs(&workbook.find("SUM(F6:F152)", &options)?), vec!["F153"]);
+        assert!(workbook.find("SUM(F6:F152)", &FindOptions::default())?.is_empty());
+
+        let options = FindOptions { sheets: Some(vec!["missing".to_string()]), ..Default::default() };
+        assert!(workbook.find("total", &options).is_err());
+        let options = FindOptions { regex: true, ..Default::default() };
+        assert!(matches!(workbook.find("(total", &options), Err(WorkbookError::PatternError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/accounting.xlsx")?;
+        let debits = workbook.find("Debit", &FindOptions::default())?;
+        let replaced = workbook.replace("Debit", "Soll", &FindOptions::default())?;
+        assert_eq!(replaced.as_ref(), debits.first());
+        assert_eq!(workbook.find("Debit", &FindOptions::default())?.len(), debits.len() - 1);
+        let replaced = workbook.replace_all("Debit", "Soll", &FindOptions::default())?;
+        assert_eq!(replaced, debits[1..]);
+        let options = FindOptions { whole_cell: true, ..Default::default() };
+        workbook.replace_all("total", "Summe", &options)?;
+
+        // Only the master of the shared formula is rewritten, the cells sharing it keep their formulas
+        let options = FindOptions { in_formulas: true, ..Default::default() };
+        assert_eq!(refs(&workbook.replace_all("E152", "E150", &options)?), vec!["E153"]);
+
+        let worksheet = workbook.get_worksheet(1)?;
+        assert_eq!(worksheet.read_cell("A153")?.text.as_deref(), Some("Summe"));
+        assert_eq!(worksheet.read_cell(debits[0].to_ref().as_str())?.text.as_deref(), Some("Soll"));
+        workbook.save_as("tests/output/find_test_replace.xlsx")?;
+        let sheet = read_part("tests/output/find_test_replace.xlsx", "xl/worksheets/sheet1.xml")?;
+        assert!(sheet.contains("<f>SUM(E6:E150)</f>"));
+        assert!(sheet.contains("<f>SUM(F6:F152)</f>"));
+        assert!(!sheet.contains(r#"si="0""#));
+        // The rewritten strings stay shared strings
+        assert!(sheet.contains(r#"<c r="A153" s="3" t="s">"#));
+        let shared_strings = read_part("tests/output/find_test_replace.xlsx", "xl/sharedStrings.xml")?;
+        assert!(shared_strings.contains(r#"<si><t xml:space="preserve">Summe</t></si>"#));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rich_text() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/rich-text.xlsx")?;
+        // The match spans the three runs of the rich text "AB" "C" "DEFG", the run left empty is removed
+        let replaced = workbook.replace_all("bcd", "x", &FindOptions::default())?;
+        assert_eq!(refs(&replaced), vec!["A3"]);
+        let options = FindOptions { regex: true, whole_cell: true, ..Default::default() };
+        assert_eq!(refs(&workbook.replace_all("b+", "", &options)?), vec!["A2"]);
+        workbook.save_as("tests/output/find_test_rich_text.xlsx")?;
+        let sheet = read_part("tests/output/find_test_rich_text.xlsx", "xl/worksheets/sheet1.xml")?;
+        assert!(sheet.contains(r#"<c r="A3" s="3" t="s">"#));
+
+        let workbook = Workbook::from_path("tests/output/find_test_rich_text.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        let rich_text = worksheet.read_cell("A2")?.rich_text.unwrap();
+        assert!(rich_text.words.iter().all(|word| word.text.is_empty()));
+        let rich_text = worksheet.read_cell("A3")?.rich_text.unwrap();
+        let texts: Vec<&str> = rich_text.words.iter().map(|word| word.text.as_str()).collect();
+        assert_eq!(texts, vec!["Ax", "EFG"]);
+        let font = rich_text.words[1].font.as_ref().unwrap();
+        assert!(font.bold);
+        assert_eq!(font.size, 14.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_values() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet_by_name("Labels")?;
+        worksheet.write("A1", "Item 12")?;
+        worksheet.write("A2", 120)?;
+        worksheet.write("A3", true)?;
+        worksheet.write_formula("A4", r#""Item "&A2"#)?;
+        let options = FindOptions { regex: true, sheets: Some(vec!["Labels".to_string()]), ..Default::default() };
+        assert_eq!(refs(&workbook.find(r"\d", &options)?), vec!["A1", "A2"]);
+        workbook.replace_all(r"Item (\d+)", "Artikel $1", &options)?;
+        workbook.replace_all("12", "1x", &options)?;
+
+        let options = FindOptions { in_formulas: true, ..options };
+        assert_eq!(refs(&workbook.replace_all("Item", "Artikel", &options)?), vec!["A4"]);
+        let worksheet = workbook.get_worksheet_by_name("Labels")?;
+        assert_eq!(worksheet.read_cell("A1")?.text.as_deref(), Some("Artikel 1x"));
+        assert_eq!(worksheet.read_cell("A2")?.text.as_deref(), Some("1x0"));
+        workbook.save_as("tests/output/find_test_values.xlsx")?;
+        let sheet = read_part("tests/output/find_test_values.xlsx", "xl/worksheets/sheet2.xml")?;
+        assert!(sheet.contains(r#"<c r="A2" t="str"><v>1x0</v></c>"#));
+        assert!(sheet.contains("&quot;Artikel &quot;&amp;A2</f>"));
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{Format, FormatFontScheme, FormatUnderlineType, FormatVertAlignType, Read, Workbook, WorkbookResult, Write};
+
+    #[test]
+    fn test_new() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let strike = Format::default().set_strikethrough();
+        let superscript = Format::default().set_superscript();
+        let subscript = Format::default().set_subscript();
+        let double = Format::default().set_underline_type(FormatUnderlineType::Double);
+        let accounting = Format::default().set_underline_type(FormatUnderlineType::SingleAccounting);
+        let outline = Format::default().set_outline().set_shadow();
+        let scheme = Format::default()
+            .set_font("Calibri Light")
+            .set_font_family(2)
+            .set_font_charset(134)
+            .set_font_scheme(FormatFontScheme::Major);
+        worksheet.write_with_format("A1", "Strikethrough", &strike)?;
+        worksheet.write_with_format("A2", "Superscript", &superscript)?;
+        worksheet.write_with_format("A3", "Subscript", &subscript)?;
+        worksheet.write_with_format("A4", "Double underline", &double)?;
+        worksheet.write_with_format("A5", "Single accounting underline", &accounting)?;
+        worksheet.write_with_format("A6", "Outline and shadow", &outline)?;
+        worksheet.write_with_format("A7", "Major scheme", &scheme)?;
+        workbook.save_as("tests/output/font_test_new.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/font_test_new.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        assert!(worksheet.read_cell("A1")?.format.unwrap().is_strikethrough());
+        assert_eq!(worksheet.read_cell("A2")?.format.unwrap().font.vert_align, FormatVertAlignType::Superscript);
+        assert_eq!(worksheet.read_cell("A3")?.format.unwrap().font.vert_align, FormatVertAlignType::Subscript);
+        assert_eq!(worksheet.read_cell("A4")?.format.unwrap().font.underline, FormatUnderlineType::Double);
+        assert_eq!(worksheet.read_cell("A5")?.format.unwrap().font.underline, FormatUnderlineType::SingleAccounting);
+        let font = worksheet.read_cell("A6")?.format.unwrap().font;
+        assert!(font.outline && font.shadow);
+        assert_eq!(worksheet.read_cell("A7")?.format.unwrap().font, scheme.font);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/accounting.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let underline = Format::default().set_underline();
+        worksheet.write_with_format("A1", "Single underline", &underline)?;
+        workbook.save_as("tests/output/font_test_from.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/font_test_from.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        let format = worksheet.read_cell("A1")?.format.unwrap();
+        assert!(format.is_underline());
+        assert_eq!(format.get_underline_type(), &FormatUnderlineType::Single);
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{FormControl, FormControlType, Workbook, WorkbookResult, Write};
+    use crate::common::read_part;
+
+    #[test]
+    fn test_new() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write_column("F1", &["Mon", "Tue", "Wed"])?;
+        worksheet.insert_form_control("A1:B1", &FormControl::new(FormControlType::CheckBox).set_text("Done").set_linked_cell("C1").set_checked(true))?;
+        worksheet.insert_form_control("A2:B2", &FormControl::new(FormControlType::OptionButton).set_linked_cell("C2"))?;
+        worksheet.insert_form_control("A3:B3", &FormControl::new(FormControlType::ComboBox).set_input_range("F1:F3").set_linked_cell("C3").set_value(2))?;
+        worksheet.insert_form_control("A4:B6", &FormControl::new(FormControlType::ListBox).set_input_range("F1:F3").set_linked_cell("C4"))?;
+        worksheet.insert_form_control("A7:A8", &FormControl::new(FormControlType::Spinner).set_linked_cell("C7").set_range(0, 24).set_value(8).set_increment(2))?;
+        worksheet.insert_form_control("A9:B10", &FormControl::new(FormControlType::Button).set_name("Submit").set_macro("Module1.Submit"))?;
+        workbook.save_as("tests/output/form_control_test_new.xlsx")?;
+
+        let file_name = "tests/output/form_control_test_new.xlsx";
+        let check_box = read_part(file_name, "xl/ctrlProps/ctrlProp1.xml")?;
+        assert!(check_box.contains(r#"objectType="CheckBox" checked="Checked" fmlaLink="$C$1" lockText="1" noThreeD="1"/>"#));
+        let combo_box = read_part(file_name, "xl/ctrlProps/ctrlProp3.xml")?;
+        assert!(combo_box.contains(r#"objectType="Drop" dropLines="8" dropStyle="combo" dx="22" fmlaLink="$C$3" fmlaRange="$F$1:$F$3" noThreeD="1" sel="2" val="0"/>"#));
+        let spinner = read_part(file_name, "xl/ctrlProps/ctrlProp5.xml")?;
+        assert!(spinner.contains(r#"objectType="Spin" dx="22" fmlaLink="$C$7" inc="2" max="24" min="0" page="10" val="8"/>"#));
+
+        let vml = read_part(file_name, "xl/drawings/vmlDrawing1.vml")?;
+        assert!(vml.contains(r#"<o:idmap v:ext="edit" data="1"/>"#));
+        assert_eq!(vml.matches(r#"<v:shapetype id="_x0000_t201""#).count(), 1);
+        assert_eq!(vml.matches(r##"type="#_x0000_t201""##).count(), 6);
+        assert!(vml.contains(r#"<x:ClientData ObjectType="Checkbox"><x:Anchor>0, 0, 0, 0, 2, 0, 1, 0</x:Anchor>"#));
+        assert!(vml.contains(r#"<x:FmlaLink>$C$1</x:FmlaLink><x:Checked>1</x:Checked><x:NoThreeD/>"#));
+        assert!(vml.contains(r#"<font face="Segoe UI" size="160" color="auto">Done</font>"#));
+        assert!(vml.contains(r#"<x:FmlaRange>$F$1:$F$3</x:FmlaRange><x:Sel>2</x:Sel>"#));
+        assert!(vml.contains(r#"<x:FmlaMacro>[0]!Module1.Submit</x:FmlaMacro>"#));
+
+        let sheet = read_part(file_name, "xl/worksheets/sheet1.xml")?;
+        assert!(sheet.contains(r#"<legacyDrawing r:id="rId"#));
+        assert!(sheet.contains(r#"<mc:AlternateContent xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006"><mc:Choice Requires="x14"><controls>"#));
+        assert_eq!(sheet.matches("<control ").count(), 6);
+        assert!(sheet.contains(r#"<control shapeId="1025" r:id="rId"#));
+        assert!(sheet.contains(r#"name="Check Box 1"><controlPr defaultSize="0" autoFill="0" autoLine="0" autoPict="0"><anchor moveWithCells="1"><from><xdr:col>0</xdr:col><xdr:colOff>0</xdr:colOff><xdr:row>0</xdr:row><xdr:rowOff>0</xdr:rowOff></from><to><xdr:col>2</xdr:col><xdr:colOff>0</xdr:colOff><xdr:row>1</xdr:row><xdr:rowOff>0</xdr:rowOff></to></anchor></controlPr></control>"#));
+        assert!(sheet.contains(r#"name="Submit"><controlPr defaultSize="0" autoFill="0" autoLine="0" autoPict="0" macro="[0]!Module1.Submit">"#));
+
+        let content_types = read_part(file_name, "[Content_Types].xml")?;
+        assert!(content_types.contains(r#"<Default Extension="vml" ContentType="application/vnd.openxmlformats-officedocument.vmlDrawing"/>"#));
+        assert!(content_types.contains(r#"<Override PartName="/xl/ctrlProps/ctrlProp6.xml" ContentType="application/vnd.ms-excel.controlproperties+xml"/>"#));
+        let sheet_rel = read_part(file_name, "xl/worksheets/_rels/sheet1.xml.rels")?;
+        assert!(sheet_rel.contains(r#"Target="../drawings/vmlDrawing1.vml""#));
+        assert!(sheet_rel.contains(r#"Type="http://schemas.microsoft.com/office/2007/relationships/ctrlProp" Target="../ctrlProps/ctrlProp6.xml""#));
+
+        let workbook = Workbook::from_path(file_name)?;
+        workbook.save_as("tests/output/form_control_test_new_readonly.xlsx")?;
+        assert_eq!(read_part("tests/output/form_control_test_new_readonly.xlsx", "xl/worksheets/sheet1.xml")?, sheet);
+        assert_eq!(read_part("tests/output/form_control_test_new_readonly.xlsx", "xl/drawings/vmlDrawing1.vml")?, vml);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from() -> WorkbookResult<()> {
+        // The first worksheet has the vml drawing of the comments
+        let mut workbook = Workbook::from_path("tests/xlsx/checkbook-register.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.insert_form_control("N2:N2", &FormControl::new(FormControlType::CheckBox).set_linked_cell("O2"))?;
+        workbook.save_as("tests/output/form_control_test_from.xlsx")?;
+
+        let vml = read_part("tests/output/form_control_test_from.xlsx", "xl/drawings/vmlDrawing1.vml")?;
+        assert!(vml.contains(r##"<v:shape id="_x0000_s1026" type="#_x0000_t202""##));
+        assert!(vml.contains(r##"<v:shape id="_x0000_s1027" type="#_x0000_t201""##));
+        assert!(vml.ends_with("</x:ClientData></v:shape></xml>"));
+        let sheet = read_part("tests/output/form_control_test_from.xlsx", "xl/worksheets/sheet1.xml")?;
+        assert!(sheet.contains(r#"name="Check Box 3""#));
+        // The controls come before the table parts of the worksheet
+        assert!(sheet.contains(r#"</mc:AlternateContent><tableParts count="1">"#));
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.insert_form_control("B2:C2", &FormControl::new(FormControlType::CheckBox).set_linked_cell("D2"))?;
+        workbook.duplicate_worksheet(1)?;
+        workbook.save_as("tests/output/form_control_test_duplicate.xlsx")?;
+
+        let file_name = "tests/output/form_control_test_duplicate.xlsx";
+        let sheet_rel = read_part(file_name, "xl/worksheets/_rels/sheet2.xml.rels")?;
+        assert!(sheet_rel.contains(r#"Target="../drawings/vmlDrawing2.vml""#));
+        assert!(sheet_rel.contains(r#"Target="../ctrlProps/ctrlProp2.xml""#));
+        // The shapes of the copy get a block of shape ids of their own
+        let vml = read_part(file_name, "xl/drawings/vmlDrawing2.vml")?;
+        assert!(vml.contains(r#"<o:idmap v:ext="edit" data="2"/>"#));
+        assert!(vml.contains(r#"<v:shape id="_x0000_s2049""#));
+        assert!(!vml.contains("_x0000_s1025"));
+        assert!(read_part(file_name, "xl/drawings/vmlDrawing1.vml")?.contains(r#"<v:shape id="_x0000_s1025""#));
+        assert!(read_part(file_name, "xl/worksheets/sheet1.xml")?.contains(r#"<control shapeId="1025""#));
+        assert!(read_part(file_name, "xl/worksheets/sheet2.xml")?.contains(r#"<control shapeId="2049""#));
+        assert!(read_part(file_name, "xl/ctrlProps/ctrlProp2.xml")?.contains(r#"fmlaLink="$D$2""#));
+        Ok(())
+    }
+}
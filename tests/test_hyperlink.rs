@@ -0,0 +1,104 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{Read, WorkSheetError, Workbook, WorkbookResult, Write};
+    use crate::common::read_part;
+
+    #[test]
+    fn test_new() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        workbook.add_worksheet_by_name("Sheet2")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write_url("A1", "https://www.rust-lang.org")?;
+        worksheet.write_internal_link("A2", "Sheet2!B3", "Go to Sheet2")?;
+        worksheet.write_mailto("A3", "someone@example.com", "Mail me")?;
+        worksheet.write_file_link("A4", "C:\\Reports\\2024.xlsx", "Report")?;
+        worksheet.write_file_link("A5", "data/notes.txt", "Notes")?;
+        worksheet.set_hyperlink_tooltip("A1", "The Rust home page")?;
+        assert!(matches!(worksheet.set_hyperlink_tooltip("B1", "None"), Err(WorkSheetError::HyperlinkNotFound)));
+
+        let hyperlinks = worksheet.hyperlinks();
+        assert_eq!(hyperlinks.len(), 5);
+        assert_eq!(hyperlinks[0].cell, "A1");
+        assert_eq!(hyperlinks[0].url.as_deref(), Some("https://www.rust-lang.org"));
+        assert_eq!(hyperlinks[0].tooltip.as_deref(), Some("The Rust home page"));
+        assert_eq!(hyperlinks[1].url, None);
+        assert_eq!(hyperlinks[1].location.as_deref(), Some("Sheet2!B3"));
+        assert_eq!(hyperlinks[2].url.as_deref(), Some("mailto:someone@example.com"));
+        assert_eq!(hyperlinks[3].url.as_deref(), Some("file:///C:\\Reports\\2024.xlsx"));
+        assert_eq!(hyperlinks[4].url.as_deref(), Some("data/notes.txt"));
+        workbook.save_as("tests/output/hyperlink_test_new.xlsx")?;
+
+        let file_name = "tests/output/hyperlink_test_new.xlsx";
+        let sheet = read_part(file_name, "xl/worksheets/sheet1.xml")?;
+        assert!(sheet.contains(r#"<hyperlink ref="A1" r:id="rId1" tooltip="The Rust home page"/>"#));
+        assert!(sheet.contains(r#"<hyperlink ref="A2" location="Sheet2!B3"/>"#));
+        let sheet_rel = read_part(file_name, "xl/worksheets/_rels/sheet1.xml.rels")?;
+        assert_eq!(sheet_rel.matches("TargetMode=\"External\"").count(), 4);
+        assert!(sheet_rel.contains(r#"Target="mailto:someone@example.com" TargetMode="External""#));
+
+        let workbook = Workbook::from_path(file_name)?;
+        let worksheet = workbook.get_worksheet(1)?;
+        assert_eq!(worksheet.hyperlinks().len(), 5);
+        assert_eq!(worksheet.read_cell("A2")?.hyperlink.as_deref(), Some("#Sheet2!B3"));
+        assert_eq!(worksheet.read_cell("A3")?.hyperlink.as_deref(), Some("mailto:someone@example.com"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_update() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write_url("A1", "https://www.rust-lang.org")?;
+        worksheet.write_url("A2", "https://crates.io")?;
+        // A new link replaces the old one and its relationship
+        worksheet.write_internal_link("A1", "Sheet1!C1", "Go to C1")?;
+        worksheet.write_url("A2", "https://docs.rs")?;
+        let hyperlinks = worksheet.hyperlinks();
+        assert_eq!(hyperlinks.len(), 2);
+        assert_eq!(hyperlinks[0].location.as_deref(), Some("Sheet1!C1"));
+        assert_eq!(hyperlinks[1].cell, "A2");
+        assert_eq!(hyperlinks[1].url.as_deref(), Some("https://docs.rs"));
+
+        worksheet.remove_hyperlink("A1")?;
+        assert!(matches!(worksheet.remove_hyperlink("A1"), Err(WorkSheetError::HyperlinkNotFound)));
+        assert_eq!(worksheet.read_cell("A1")?.text.as_deref(), Some("Go to C1"));
+        workbook.save_as("tests/output/hyperlink_test_update.xlsx")?;
+
+        let file_name = "tests/output/hyperlink_test_update.xlsx";
+        let sheet_rel = read_part(file_name, "xl/worksheets/_rels/sheet1.xml.rels")?;
+        assert_eq!(sheet_rel.matches("<Relationship ").count(), 1);
+        assert!(sheet_rel.contains(r#"Target="https://docs.rs""#));
+
+        let mut workbook = Workbook::from_path(file_name)?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.remove_hyperlink("A2")?;
+        assert!(worksheet.hyperlinks().is_empty());
+        workbook.save_as("tests/output/hyperlink_test_update.xlsx")?;
+        let sheet = read_part(file_name, "xl/worksheets/sheet1.xml")?;
+        assert!(!sheet.contains("<hyperlinks"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/appraisal_score.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut_by_name("Details")?;
+        let hyperlinks = worksheet.hyperlinks();
+        assert_eq!(hyperlinks.len(), 2);
+        assert_eq!(hyperlinks[1].cell, "I24:K24");
+        assert_eq!(hyperlinks[1].url.as_deref(), Some("https://analysistabs.com/?ref=fxlpt"));
+        assert_eq!(hyperlinks[1].display.as_deref(), Some("htps://analysistabs.com"));
+        // A cell in the range of a link
+        worksheet.remove_hyperlink("J24")?;
+        assert_eq!(worksheet.hyperlinks().len(), 1);
+        workbook.save_as("tests/output/hyperlink_test_from.xlsx")?;
+
+        let file_name = "tests/output/hyperlink_test_from.xlsx";
+        let sheet_rel = read_part(file_name, "xl/worksheets/_rels/sheet1.xml.rels")?;
+        assert!(!sheet_rel.contains("https://analysistabs.com/?ref=fxlpt"));
+        assert!(sheet_rel.contains("http://www.analysistabs.com/"));
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
 use edit_xlsx::Workbook;
 
+mod common;
+
 #[cfg(test)]
 mod tests {
-    use edit_xlsx::WorkbookResult;
+    use edit_xlsx::{ImageEditAs, ImageFormat, ImageOptions, WorkbookResult, WorkSheetError};
     use crate::Workbook;
+    use crate::common::{read_part, part_names};
 
     #[test]
     fn test_new() -> WorkbookResult<()> {
@@ -28,6 +31,42 @@ mod tests {
     fn test_from_readonly() -> WorkbookResult<()> {
         let mut workbook = Workbook::from_path("tests/xlsx/image_nao.xlsx")?;
         workbook.save_as("tests/output/image_test_from_readonly.xlsx")?;
+        let xml = read_part("tests/output/image_test_from_readonly.xlsx", "xl/drawings/drawing1.xml")?;
+        assert_eq!(xml.matches("<xdr:twoCellAnchor").count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_buffer() -> WorkbookResult<()> {
+        let buffer = std::fs::read("./examples/pics/rust.png")?;
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.insert_image_from_buffer("B2", &buffer, ImageFormat::Png)?;
+        let options = ImageOptions::default().set_offset(10, 5).set_scale(0.5, 0.25);
+        worksheet.insert_image_with_options((10, 2), &buffer, ImageFormat::Png, &options)?;
+        worksheet.insert_image_absolute(400, 100, &buffer, ImageFormat::Png, &ImageOptions::default())?;
+        let result = worksheet.insert_image_from_buffer("B2", &buffer, ImageFormat::Jpeg);
+        assert!(matches!(result, Err(WorkSheetError::FormatError)));
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10"></svg>"#;
+        let result = worksheet.insert_image_from_buffer("B2", svg, ImageFormat::Svg);
+        assert!(matches!(result, Err(WorkSheetError::FormatError)));
+        workbook.save_as("tests/output/image_test_from_buffer.xlsx")?;
+
+        let xml = read_part("tests/output/image_test_from_buffer.xlsx", "xl/drawings/drawing1.xml")?;
+        assert_eq!(xml.matches("<xdr:oneCellAnchor>").count(), 2);
+        assert_eq!(xml.matches("<xdr:absoluteAnchor>").count(), 1);
+        // 200 x 200 pixels at 96 dpi
+        assert!(xml.contains(r#"<xdr:ext cx="1905000" cy="1905000"/>"#));
+        assert!(xml.contains(r#"<xdr:ext cx="952500" cy="476250"/>"#));
+        assert!(xml.contains("<xdr:colOff>95250</xdr:colOff>"));
+        assert!(xml.contains(r#"<xdr:pos x="3810000" y="952500"/>"#));
+
+        let mut workbook = Workbook::from_path("tests/output/image_test_from_buffer.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.insert_image_from_buffer("F2", &buffer, ImageFormat::Png)?;
+        workbook.save_as("tests/output/image_test_from_buffer_twice.xlsx")?;
+        let xml = read_part("tests/output/image_test_from_buffer_twice.xlsx", "xl/drawings/drawing1.xml")?;
+        assert_eq!(xml.matches("<xdr:oneCellAnchor>").count(), 3);
         Ok(())
     }
 
@@ -40,4 +79,139 @@ mod tests {
         workbook.save_as("tests/output/image_test_from_png.xlsx")?;
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_images() -> WorkbookResult<()> {
+        let workbook = Workbook::from_path("tests/xlsx/image_nao.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        let images = worksheet.images();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].from, Some((7, 4)));
+        assert_eq!(images[0].to, Some((50, 15)));
+        assert!(images[0].format.is_some());
+        assert!(!images[0].buffer.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_image() -> WorkbookResult<()> {
+        let logo = std::fs::read("./examples/pics/rust.png")?;
+        let mut workbook = Workbook::from_path("tests/xlsx/image_nao.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let old_image = worksheet.images().remove(0);
+        worksheet.replace_image(0, &logo)?;
+        assert!(matches!(worksheet.replace_image(1, &logo), Err(WorkSheetError::ImageNotFound)));
+        assert!(matches!(worksheet.replace_image(0, b"logo"), Err(WorkSheetError::FormatError)));
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10"></svg>"#;
+        assert!(matches!(worksheet.replace_image(0, svg), Err(WorkSheetError::FormatError)));
+        workbook.save_as("tests/output/image_test_replace_image.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/image_test_replace_image.xlsx")?;
+        let images = workbook.get_worksheet(1)?.images();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].buffer, logo);
+        assert_eq!((&images[0].name, images[0].from, images[0].to), (&old_image.name, old_image.from, old_image.to));
+        let media_count = part_names("tests/output/image_test_replace_image.xlsx")?.iter()
+            .filter(|name| name.starts_with("xl/media/") && !name.ends_with('/'))
+            .count();
+        assert_eq!(media_count, 1);
+        let rels = read_part("tests/output/image_test_replace_image.xlsx", "xl/drawings/_rels/drawing1.xml.rels")?;
+        assert_eq!(rels.matches("<Relationship ").count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_image() -> WorkbookResult<()> {
+        let buffer = std::fs::read("./examples/pics/rust.png")?;
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.insert_image_from_buffer("A1", &buffer, ImageFormat::Png)?;
+        worksheet.insert_image_from_buffer("E1", &buffer, ImageFormat::Png)?;
+        worksheet.remove_image(0)?;
+        assert!(matches!(worksheet.remove_image(1), Err(WorkSheetError::ImageNotFound)));
+        workbook.save_as("tests/output/image_test_remove_image.xlsx")?;
+
+        let mut workbook = Workbook::from_path("tests/output/image_test_remove_image.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let images = worksheet.images();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].from, Some((1, 5)));
+        worksheet.remove_image(0)?;
+        workbook.save_as("tests/output/image_test_remove_image_all.xlsx")?;
+        assert!(!part_names("tests/output/image_test_remove_image_all.xlsx")?.iter().any(|name| name.starts_with("xl/media/image")));
+        let content_types = read_part("tests/output/image_test_remove_image_all.xlsx", "[Content_Types].xml")?;
+        assert!(!content_types.contains(r#"Extension="png""#));
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_worksheet() -> WorkbookResult<()> {
+        let logo = std::fs::read("./examples/pics/rust.png")?;
+        let mut workbook = Workbook::from_path("tests/xlsx/image_nao.xlsx")?;
+        let original = workbook.get_worksheet(1)?.images().remove(0);
+        let worksheet = workbook.duplicate_worksheet(1)?;
+        let id = worksheet.id();
+        worksheet.replace_image(0, &logo)?;
+        worksheet.insert_image_from_buffer("A1", &logo, ImageFormat::Png)?;
+        workbook.save_as("tests/output/image_test_duplicate_worksheet.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/image_test_duplicate_worksheet.xlsx")?;
+        let images = workbook.get_worksheet(1)?.images();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].buffer, original.buffer);
+        let images = workbook.get_worksheet(id)?.images();
+        assert_eq!(images.len(), 2);
+        assert_eq!(images[0].buffer, logo);
+        read_part("tests/output/image_test_duplicate_worksheet.xlsx", "xl/drawings/drawing1.xml")?;
+        read_part("tests/output/image_test_duplicate_worksheet.xlsx", "xl/drawings/drawing2.xml")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_drawing_ids() -> WorkbookResult<()> {
+        let logo = std::fs::read("./examples/pics/rust.png")?;
+        let mut workbook = Workbook::from_path("tests/xlsx/image_nao.xlsx")?;
+        workbook.add_worksheet()?.insert_image_from_buffer("A1", &logo, ImageFormat::Png)?;
+        workbook.add_worksheet()?.insert_image_from_buffer("B2", &logo, ImageFormat::Png)?;
+        workbook.get_worksheet_mut(1)?.insert_image_from_buffer("C3", &logo, ImageFormat::Png)?;
+        workbook.save_as("tests/output/image_test_drawing_ids.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/image_test_drawing_ids.xlsx")?;
+        let counts: Vec<usize> = workbook.worksheets().map(|worksheet| worksheet.images().len()).collect();
+        assert_eq!(counts, vec![2, 1, 1]);
+        let froms: Vec<_> = workbook.worksheets().map(|worksheet| worksheet.images().last().unwrap().from).collect();
+        assert_eq!(froms, vec![Some((3, 3)), Some((1, 1)), Some((2, 2))]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_image_properties() -> WorkbookResult<()> {
+        let logo = std::fs::read("./examples/pics/rust.png")?;
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let options = ImageOptions::default()
+            .set_description("The Rust logo")
+            .set_title("Rust")
+            .set_hyperlink("https://www.rust-lang.org")
+            .set_prints_with_sheet(false);
+        worksheet.insert_image_with_options("B2", &logo, ImageFormat::Png, &options)?;
+        let options = ImageOptions::default().set_edit_as(ImageEditAs::Absolute).set_locks_with_sheet(false);
+        worksheet.insert_image_over_range("D2:F8", &logo, ImageFormat::Png, &options)?;
+        workbook.save_as("tests/output/image_test_image_properties.xlsx")?;
+
+        let xml = read_part("tests/output/image_test_image_properties.xlsx", "xl/drawings/drawing1.xml")?;
+        assert!(xml.contains(r#"descr="The Rust logo" title="Rust""#));
+        assert!(xml.contains("<a:hlinkClick"));
+        assert!(xml.contains(r#"editAs="absolute""#));
+        assert!(xml.contains(r#"fPrintsWithSheet="0""#));
+        assert!(xml.contains(r#"fLocksWithSheet="0""#));
+        let rels = read_part("tests/output/image_test_image_properties.xlsx", "xl/drawings/_rels/drawing1.xml.rels")?;
+        assert!(rels.contains(r#"Target="https://www.rust-lang.org" TargetMode="External""#));
+
+        let workbook = Workbook::from_path("tests/output/image_test_image_properties.xlsx")?;
+        let images = workbook.get_worksheet(1)?.images();
+        assert_eq!(images[0].description.as_deref(), Some("The Rust logo"));
+        assert_eq!((images[1].from, images[1].to), (Some((2, 4)), Some((8, 6))));
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{Read, Workbook, WorkbookResult};
+    use crate::common::{read_part, part_names};
+
+    ///
+    /// Every relationship inside the package points to a part of the package.
+    ///
+    fn assert_targets_exist(file_name: &str) -> WorkbookResult<()> {
+        let names = part_names(file_name)?;
+        for rels in names.iter().filter(|name| name.ends_with(".rels")) {
+            let xml = read_part(file_name, rels)?;
+            let dir = rels.split("_rels/").next().unwrap().trim_end_matches('/');
+            for relationship in xml.split("<Relationship ").skip(1).filter(|r| !r.contains("TargetMode=\"External\"")) {
+                let target = relationship.split(" Target=\"").nth(1).unwrap().split('"').next().unwrap();
+                let mut segments: Vec<&str> = dir.split('/').filter(|s| !s.is_empty()).collect();
+                for segment in target.split('/') {
+                    match segment {
+                        ".." => { segments.pop(); }
+                        segment => segments.push(segment),
+                    }
+                }
+                let part = segments.join("/");
+                assert!(names.contains(&part), "{rels} points to the missing {part}");
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_new() -> WorkbookResult<()> {
+        let source = Workbook::from_path("tests/xlsx/checkbook-register.xlsx")?;
+        let mut workbook = Workbook::new();
+        workbook.import_worksheet(&source, 1, "January")?;
+        workbook.import_worksheet(&source, 1, "February")?;
+        let register = source.get_worksheet(1)?;
+        for worksheet in [workbook.get_worksheet_by_name("January")?, workbook.get_worksheet_by_name("February")?] {
+            for loc in ["A17", "B17", "A18", "C18", "E18", "H18"] {
+                let (cell, source_cell) = (worksheet.read_cell(loc)?, register.read_cell(loc)?);
+                assert_eq!(cell.text, source_cell.text);
+                assert_eq!(cell.format, source_cell.format);
+            }
+        }
+        assert_eq!(workbook.get_defined_local_name("numList", 2)?, "January!$L$1:$L$17");
+        assert_eq!(workbook.get_defined_local_name("numList", 3)?, "February!$L$1:$L$17");
+        assert!(workbook.import_worksheet(&source, 1, "January").is_err());
+        workbook.save_as("tests/output/import_worksheet_test_new.xlsx")?;
+        assert_targets_exist("tests/output/import_worksheet_test_new.xlsx")?;
+
+        let file_name = "tests/output/import_worksheet_test_new.xlsx";
+        // The formulas referring to the worksheet by its name refer to the copy
+        let sheet = read_part(file_name, "xl/worksheets/sheet3.xml")?;
+        assert!(sheet.contains("<f>IFERROR(MAX(February!B:B)+1,1)</f>"));
+        let rels = read_part(file_name, "xl/worksheets/_rels/sheet3.xml.rels")?;
+        assert!(rels.contains("../tables/table2.xml"));
+        assert!(rels.contains("../comments2.xml"));
+        // The tables get new ids and names
+        let table = read_part(file_name, "xl/tables/table1.xml")?;
+        assert!(table.contains(r#" id="1" "#) && table.contains(r#" name="Table1" displayName="Table1" "#));
+        let table = read_part(file_name, "xl/tables/table2.xml")?;
+        assert!(table.contains(r#" id="2" "#) && table.contains(r#" name="Table2" displayName="Table2" "#));
+        let content_types = read_part(file_name, "[Content_Types].xml")?;
+        assert!(content_types.contains("/xl/tables/table2.xml"));
+        assert!(content_types.contains("/xl/comments2.xml"));
+        // The shapes of the comments of each copy have shape ids of their own
+        let vml = read_part(file_name, "xl/drawings/vmlDrawing2.vml")?;
+        assert!(vml.contains(r#"data="2""#) && vml.contains("_x0000_s2049"));
+        // The strings are added once to the shared strings of the workbook
+        let shared_strings = read_part(file_name, "xl/sharedStrings.xml")?;
+        assert!(shared_strings.contains(r#"uniqueCount="35""#));
+        assert!(sheet.contains(r#"t="s"><v>0</v></c>"#));
+        assert!(read_part(file_name, "xl/_rels/workbook.xml.rels")?.contains("Target=\"sharedStrings.xml\""));
+        assert!(content_types.contains("/xl/sharedStrings.xml"));
+        // The dxfs of the conditional formats, the identical ones and those of the second import are reused
+        let styles = read_part(file_name, "xl/styles.xml")?;
+        assert!(styles.contains("<dxfs count=\"10\">"));
+        // The dates keep their custom number format
+        assert!(styles.contains(r#"<numFmt numFmtId="164" formatCode="m/dd/yy;@"/>"#));
+        let style = sheet.split(r#"<c r="A18" s=""#).nth(1).unwrap().split('"').next().unwrap();
+        let cell_xfs = styles.split("<cellXfs").nth(1).unwrap();
+        let xf = cell_xfs.split("<xf ").nth(style.parse::<usize>().unwrap() + 1).unwrap();
+        assert!(xf.starts_with(r#"numFmtId="164""#));
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_strings() -> WorkbookResult<()> {
+        let source = Workbook::from_path("tests/xlsx/rich-text.xlsx")?;
+        let mut workbook = Workbook::from_path("tests/xlsx/checkbook-register.xlsx")?;
+        workbook.import_worksheet(&source, 1, "Rich")?;
+        workbook.save_as("tests/output/import_worksheet_test_shared_strings.xlsx")?;
+        // The strings are added at the end of the shared strings as they are written in the other workbook
+        let shared_strings = read_part("tests/output/import_worksheet_test_shared_strings.xlsx", "xl/sharedStrings.xml")?;
+        assert!(shared_strings.contains(r#"uniqueCount="208""#));
+        assert!(shared_strings.contains(r#"<t xml:space="preserve">aaa        </t>"#));
+        let workbook = Workbook::from_path("tests/output/import_worksheet_test_shared_strings.xlsx")?;
+        let (register, source_register) = (workbook.get_worksheet(1)?, Workbook::from_path("tests/xlsx/checkbook-register.xlsx")?);
+        assert_eq!(register.read_cell("A1")?.text, source_register.get_worksheet(1)?.read_cell("A1")?.text);
+        let worksheet = workbook.get_worksheet_by_name("Rich")?;
+        assert_eq!(worksheet.read_cell("A1")?.rich_text.unwrap().words.len(), 5);
+        let rich_text = worksheet.read_cell("A3")?.rich_text.unwrap();
+        let texts: Vec<&str> = rich_text.words.iter().map(|word| word.text.as_str()).collect();
+        assert_eq!(texts, vec!["AB", "C", "DEFG"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_images() -> WorkbookResult<()> {
+        let source = Workbook::from_path("tests/xlsx/image_nao.xlsx")?;
+        let mut workbook = Workbook::from_path("tests/xlsx/background_capybara.xlsx")?;
+        let worksheet = workbook.import_worksheet(&source, 1, "Nao")?;
+        assert_eq!(worksheet.images().len(), 1);
+        workbook.save_as("tests/output/import_worksheet_test_images.xlsx")?;
+        assert_targets_exist("tests/output/import_worksheet_test_images.xlsx")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_charts() -> WorkbookResult<()> {
+        let source = Workbook::from_path("tests/xlsx/world-meeting-planner.xlsx")?;
+        let mut workbook = Workbook::from_path("tests/xlsx/world-meeting-planner.xlsx")?;
+        workbook.import_worksheet(&source, 11, "Clocks Copy")?;
+        workbook.save_as("tests/output/import_worksheet_test_charts.xlsx")?;
+        assert_targets_exist("tests/output/import_worksheet_test_charts.xlsx")?;
+        let file_name = "tests/output/import_worksheet_test_charts.xlsx";
+        let rels = read_part(file_name, "xl/charts/_rels/chart16.xml.rels")?;
+        assert!(rels.contains("style") && rels.contains("colors"));
+        // The charts plot the copy
+        let chart = read_part(file_name, "xl/charts/chart16.xml")?;
+        assert!(chart.contains("<c:f>'Clocks Copy'!$C$31:$C$42</c:f>"));
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{Format, Read, WorkSheetError, Workbook, WorkbookResult, Write};
+    use crate::common::read_part;
+
+    #[test]
+    fn test_new() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write("B2", "Old")?;
+        worksheet.merge_range_with_format("A1:C3", "Merged", &Format::default().set_bold())?;
+        worksheet.merge_range("E1:F1", 42)?;
+        assert!(matches!(worksheet.merge_range("C3:D4", "Overlapped"), Err(WorkSheetError::MergeOverlapped)));
+        assert!(matches!(worksheet.merge_range("F1:F1", "Inside"), Err(WorkSheetError::MergeOverlapped)));
+
+        assert_eq!(worksheet.merged_ranges(), vec![(1, 1, 3, 3), (1, 5, 1, 6)]);
+        assert_eq!(worksheet.is_merged("B2"), Some((1, 1, 3, 3)));
+        assert_eq!(worksheet.is_merged("D1"), None);
+        // Only the first cell holds the value, the others keep the format
+        assert_eq!(worksheet.read_cell("A1")?.text.as_deref(), Some("Merged"));
+        let blank = worksheet.read_cell("B2")?;
+        assert_eq!(blank.text, None);
+        assert!(blank.format.is_some_and(|format| format.font.bold));
+        workbook.save_as("tests/output/merge_test_new.xlsx")?;
+
+        let sheet = read_part("tests/output/merge_test_new.xlsx", "xl/worksheets/sheet1.xml")?;
+        assert!(sheet.contains(r#"<mergeCells count="2"><mergeCell ref="A1:C3"/><mergeCell ref="E1:F1"/></mergeCells>"#));
+        assert!(sheet.contains(r#"<c r="B2" s="1"/>"#));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unmerge() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/appraisal_score.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut_by_name("Details")?;
+        assert_eq!(worksheet.merged_ranges().len(), 12);
+        assert_eq!(worksheet.is_merged("K10"), Some((8, 3, 10, 12)));
+        // Every merged range intersecting the range is unmerged
+        worksheet.unmerge("B8:C12");
+        assert_eq!(worksheet.merged_ranges().len(), 8);
+        assert_eq!(worksheet.is_merged("K10"), None);
+        worksheet.merge_range("B8:L10", "Merged again")?;
+        workbook.save_as("tests/output/merge_test_unmerge.xlsx")?;
+
+        let sheet = read_part("tests/output/merge_test_unmerge.xlsx", "xl/worksheets/sheet1.xml")?;
+        assert!(sheet.contains(r#"<mergeCells count="9">"#));
+        assert!(sheet.contains(r#"<mergeCell ref="B8:L10"/>"#));
+
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.merge_range("A1:B1", "Merged")?;
+        worksheet.unmerge("A1:B1");
+        assert!(worksheet.merged_ranges().is_empty());
+        workbook.save_as("tests/output/merge_test_unmerge.xlsx")?;
+        let sheet = read_part("tests/output/merge_test_unmerge.xlsx", "xl/worksheets/sheet1.xml")?;
+        assert!(!sheet.contains("<mergeCells"));
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{Format, ProtectionOptions, Read, Workbook, WorkbookResult, Write};
+
+    #[test]
+    fn test_new() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let unlocked = Format::default().set_unlocked();
+        let hidden = Format::default().set_hidden();
+        worksheet.write_with_format("A1", "Unlocked", &unlocked)?;
+        worksheet.write_formula_with_format("A2", "1+1", &hidden)?;
+        worksheet.write("A3", "Locked")?;
+        let mut options = ProtectionOptions::default();
+        options.allow_format_cells(true)
+            .allow_insert_rows(true)
+            .allow_sort(true)
+            .allow_autofilter(true)
+            .allow_select_locked_cells(false);
+        worksheet.protect("password", &options);
+        let worksheet = workbook.add_worksheet()?;
+        let mut legacy = ProtectionOptions::default();
+        legacy.use_legacy_password();
+        worksheet.protect("password", &legacy);
+        workbook.protect_structure("password");
+        workbook.save_as("tests/output/protection_test_new.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/protection_test_new.xlsx")?;
+        assert!(workbook.is_structure_protected());
+        let worksheet = workbook.get_worksheet(1)?;
+        let protection = worksheet.get_protection().unwrap();
+        assert_eq!(protection, options);
+        let format = worksheet.read_cell("A1")?.format.unwrap();
+        assert!(!format.is_locked());
+        let format = worksheet.read_cell("A2")?.format.unwrap();
+        assert!(format.is_locked() && format.is_hidden());
+        let worksheet = workbook.get_worksheet(2)?;
+        assert_eq!(worksheet.get_protection().unwrap(), legacy);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/accounting.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        assert!(worksheet.get_protection().is_none());
+        worksheet.protect("", &ProtectionOptions::default());
+        workbook.protect_structure_legacy("password");
+        workbook.save_as("tests/output/protection_test_from.xlsx")?;
+
+        let mut workbook = Workbook::from_path("tests/output/protection_test_from.xlsx")?;
+        assert!(workbook.is_structure_protected());
+        workbook.unprotect_structure();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        assert_eq!(worksheet.get_protection(), Some(ProtectionOptions::default()));
+        worksheet.unprotect();
+        assert!(worksheet.get_protection().is_none());
+        Ok(())
+    }
+}
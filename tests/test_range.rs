@@ -0,0 +1,109 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{Format, Range, Read, SeriesType, WorkSheetError, Workbook, WorkbookResult, Write};
+    use crate::common::read_part;
+
+    #[test]
+    fn test_range() {
+        let range = Range::parse("'My Sheet'!$B$2:D4").unwrap();
+        assert_eq!(range.sheet(), Some("My Sheet"));
+        assert_eq!((range.first(), range.last()), ((2, 2), (4, 4)));
+        assert_eq!((range.height(), range.width()), (3, 3));
+        assert_eq!(range.to_string(), "'My Sheet'!B2:D4");
+        assert!(range.contains("C3"));
+        assert!(!range.contains((5, 2)));
+
+        let other = Range::parse("C3:F10").unwrap();
+        assert_eq!(range.intersection(&other).unwrap().to_string(), "'My Sheet'!C3:D4");
+        assert_eq!(range.union(&other).unwrap().to_string(), "'My Sheet'!B2:F10");
+        assert_eq!(Range::parse("A1:B2").unwrap().intersection(&Range::parse("C3").unwrap()), None);
+        let elsewhere = Range::parse("Sheet2!A1:Z100").unwrap();
+        assert_eq!(range.intersection(&elsewhere), None);
+        assert_eq!(range.union(&elsewhere), None);
+        assert_eq!(Range::new(2, 1, 1, 2).unwrap().cells().collect::<Vec<_>>(), vec![(1, 1), (1, 2), (2, 1), (2, 2)]);
+        // The corners must be cells of a worksheet
+        assert_eq!(Range::new(0, 1, 2, 2), None);
+        assert_eq!(Range::new(1, 1, 1048577, 1), None);
+        assert_eq!(Range::new(1, 16385, 1, 1), None);
+        assert_eq!(Range::new(1048576, 16384, 1, 1).map(|range| range.to_string()), Some("A1:XFD1048576".to_string()));
+    }
+
+    #[test]
+    fn test_bulk() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let bold = Format::default().set_bold();
+        worksheet.write_with_format("A1", "Keep", &bold)?;
+        Range::parse("A1:C2").unwrap().fill_value(worksheet, "Filled")?;
+        assert_eq!(worksheet.read_cell("C2")?.text.as_deref(), Some("Filled"));
+        // The format of A1 is kept
+        assert!(worksheet.read_cell("A1")?.format.is_some_and(|format| format.font.bold));
+
+        Range::parse("A1:B2").unwrap().clear_contents(worksheet)?;
+        assert_eq!(worksheet.read_cell("A1")?.text, None);
+        assert!(worksheet.read_cell("A1")?.format.is_some_and(|format| format.font.bold));
+        Range::parse("A1:C1").unwrap().clear_formats(worksheet)?;
+        assert!(worksheet.read_cell("A1")?.format.is_none_or(|format| !format.font.bold));
+
+        let italic = Format::default().set_italic();
+        Range::parse("E1:E3").unwrap().set_format(worksheet, &italic)?;
+        assert!(worksheet.read_cell("E3")?.format.is_some_and(|format| format.font.italic));
+        // A range of another worksheet is refused, the sheet name is compared without case
+        assert!(matches!(Range::parse("Sheet2!A1:A2").unwrap().fill_value(worksheet, 1), Err(WorkSheetError::SheetMismatch)));
+        assert!(matches!(Range::parse("Sheet2!A1").unwrap().copy_to(worksheet, "B1"), Err(WorkSheetError::SheetMismatch)));
+        Range::parse("sheet1!F1").unwrap().fill_value(worksheet, "Here")?;
+        assert_eq!(worksheet.read_cell("F1")?.text.as_deref(), Some("Here"));
+        workbook.save_as("tests/output/range_test_bulk.xlsx")?;
+        let sheet = read_part("tests/output/range_test_bulk.xlsx", "xl/worksheets/sheet1.xml")?;
+        assert_eq!(sheet.matches(r#"<c r="E"#).count(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_series() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        Range::parse("A1:A4").unwrap().fill_series(worksheet, 1.0, 2.0, SeriesType::Linear)?;
+        Range::parse("B1:B4").unwrap().fill_series(worksheet, 1.0, 3.0, SeriesType::Growth)?;
+        // From Friday 2024-01-05
+        Range::parse("C1:C3").unwrap().fill_series(worksheet, 45296.0, 1.0, SeriesType::Weekday)?;
+        // From 2024-01-31
+        Range::parse("D1:D3").unwrap().fill_series(worksheet, 45322.0, 1.0, SeriesType::Month)?;
+        Range::parse("A6:C6").unwrap().fill_series(worksheet, 10.0, -5.0, SeriesType::Linear)?;
+        let column = |col: &str, rows: u32| -> Vec<String> {
+            (1..=rows).map(|row| worksheet.read_cell(format!("{col}{row}").as_str()).unwrap().text.unwrap()).collect()
+        };
+        assert_eq!(column("A", 4), ["1", "3", "5", "7"]);
+        assert_eq!(column("B", 4), ["1", "3", "9", "27"]);
+        assert_eq!(column("C", 3), ["45296", "45299", "45300"]);
+        assert_eq!(column("D", 3), ["45322", "45351", "45382"]);
+        assert_eq!(worksheet.read_cell("C6")?.text.as_deref(), Some("0"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_move() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write_with_format("A1", "Title", &Format::default().set_bold())?;
+        worksheet.write("B2", 42)?;
+        worksheet.write("E5", "Replaced")?;
+        let range = Range::parse("A1:B2").unwrap();
+        range.copy_to(worksheet, "D4")?;
+        assert_eq!(worksheet.read_cell("D4")?.text.as_deref(), Some("Title"));
+        assert!(worksheet.read_cell("D4")?.format.is_some_and(|format| format.font.bold));
+        assert_eq!(worksheet.read_cell("E5")?.text.as_deref(), Some("42"));
+        assert_eq!(worksheet.read_cell("A1")?.text.as_deref(), Some("Title"));
+
+        // Overlapping the source
+        range.move_to(worksheet, "B2")?;
+        assert_eq!(worksheet.read_cell("B2")?.text.as_deref(), Some("Title"));
+        assert_eq!(worksheet.read_cell("C3")?.text.as_deref(), Some("42"));
+        assert!(worksheet.read_cell("A1").is_err());
+        assert!(matches!(range.copy_to(worksheet, "XFD1"), Err(WorkSheetError::OutOfRange)));
+        workbook.save_as("tests/output/range_test_copy_move.xlsx")?;
+        Ok(())
+    }
+}
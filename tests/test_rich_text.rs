@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use edit_xlsx::{Format, FormatColor, FormatFont, Read, RichText, Word, Workbook, WorkbookResult, Write};
+    use edit_xlsx::{Format, FormatColor, FormatFont, FormatUnderlineType, FormatVertAlignType, Read, RichText, Word, Workbook, WorkbookResult, Write};
 
     #[test]
     fn test_new() -> WorkbookResult<()> {
@@ -59,7 +59,7 @@ mod tests {
         let mut format_font = FormatFont::default();
         format_font.bold = true;
         format_font.italic = false;
-        format_font.underline = true;
+        format_font.underline = FormatUnderlineType::Single;
         rich_text.words.iter_mut().for_each(|w| {
             w.font = Some(format_font.clone())
         });
@@ -91,4 +91,33 @@ mod tests {
         workbook.save_as("tests/output/rich_text_test_read_from.xlsx")?;
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_new_script() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let normal = FormatFont::default();
+        let mut subscript = FormatFont::default();
+        subscript.vert_align = FormatVertAlignType::Subscript;
+        let mut superscript = FormatFont::default();
+        superscript.vert_align = FormatVertAlignType::Superscript;
+        let mut strike = FormatFont::default();
+        strike.strike = true;
+        strike.underline = FormatUnderlineType::Double;
+        let water = RichText::new_word("H", &normal) + Word::new("2", &subscript) + Word::new("O", &normal);
+        let footnote = RichText::new_word("Footnote", &strike) + Word::new("1", &superscript);
+        worksheet.write_rich_string("A1", &water)?;
+        worksheet.write_rich_string("A2", &footnote)?;
+        workbook.save_as("tests/output/rich_text_test_new_script.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/rich_text_test_new_script.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        let rich_text = worksheet.read_cell("A1")?.rich_text.unwrap();
+        assert_eq!(rich_text.words[1].text, "2");
+        assert_eq!(rich_text.words[1].font.as_ref().unwrap().vert_align, FormatVertAlignType::Subscript);
+        let rich_text = worksheet.read_cell("A2")?.rich_text.unwrap();
+        assert_eq!(rich_text.words[0].font, Some(strike));
+        assert_eq!(rich_text.words[1].font, Some(superscript));
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{FormatAlignHorizontal, FormatAlignVertical, FormatColor, FormatFont, RichText, Shape, ShapeType, Workbook, WorkbookResult, Word};
+    use crate::common::read_part;
+
+    #[test]
+    fn test_new() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let mut bold = FormatFont::default();
+        bold.bold = true;
+        bold.color = FormatColor::RGB(255, 255, 255);
+        let label = Shape::new(ShapeType::RoundedRectangle)
+            .set_name("Revenue")
+            .set_rich_text(&(RichText::new_word("Revenue\n", &bold) + Word::new("+12%", &FormatFont::default())))
+            .set_fill_color(FormatColor::Theme(5, -0.25))
+            .set_line_color(FormatColor::Default)
+            .set_align_horizontal(FormatAlignHorizontal::Center)
+            .set_align_vertical(FormatAlignVertical::Center);
+        worksheet.insert_shape("B2:D4", &label)?;
+        worksheet.insert_shape("F2:H4", &Shape::new(ShapeType::TextBox).set_text("Note"))?;
+        worksheet.insert_shape("B6:C7", &Shape::new(ShapeType::RightArrow).set_fill_color(FormatColor::Index(10)))?;
+        worksheet.insert_shape("D4:F8", &Shape::new(ShapeType::StraightConnector).set_end_arrow(true).set_line_width(2.0))?;
+        workbook.save_as("tests/output/shape_test_new.xlsx")?;
+
+        let xml = read_part("tests/output/shape_test_new.xlsx", "xl/drawings/drawing1.xml")?;
+        assert_eq!(xml.matches("<xdr:twoCellAnchor>").count(), 4);
+        assert!(xml.contains(r#"<xdr:cNvPr id="1" name="Revenue"/>"#));
+        assert!(xml.contains(r#"<a:prstGeom prst="roundRect">"#));
+        assert!(xml.contains(r#"<a:schemeClr val="accent2"><a:lumMod val="75000"/></a:schemeClr>"#));
+        assert!(xml.contains(r#"<a:ln w="12700"><a:noFill/></a:ln>"#));
+        assert!(xml.contains(r#"<a:bodyPr vertOverflow="clip" horzOverflow="clip" rtlCol="0" anchor="ctr"/>"#));
+        assert_eq!(xml.matches(r#"<a:pPr algn="ctr"/>"#).count(), 2);
+        assert!(xml.contains(r#"b="1"><a:solidFill><a:srgbClr val="FFFFFF"/></a:solidFill><a:latin typeface="Calibri"/></a:rPr><a:t>Revenue</a:t>"#));
+        assert!(xml.contains(r#"<xdr:cNvSpPr txBox="1"/>"#));
+        assert!(xml.contains(r#"<a:srgbClr val="FF0000"/>"#));
+        assert!(xml.contains(r#"<xdr:cxnSp macro=""><xdr:nvCxnSpPr><xdr:cNvPr id="4" name="Connector 4"/>"#));
+        assert!(xml.contains(r#"<a:ln w="25400">"#));
+        assert!(xml.contains(r#"<a:tailEnd type="triangle"/>"#));
+
+        let workbook = Workbook::from_path("tests/output/shape_test_new.xlsx")?;
+        workbook.save_as("tests/output/shape_test_new_readonly.xlsx")?;
+        assert_eq!(read_part("tests/output/shape_test_new_readonly.xlsx", "xl/drawings/drawing1.xml")?, xml);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/image_nao.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.insert_shape("A1:C3", &Shape::new(ShapeType::Callout).set_text("Look here"))?;
+        workbook.save_as("tests/output/shape_test_from.xlsx")?;
+
+        let xml = read_part("tests/output/shape_test_from.xlsx", "xl/drawings/drawing1.xml")?;
+        assert!(xml.contains(r#"<xdr:cNvPr id="4" name="Shape 4"/>"#));
+        let workbook = Workbook::from_path("tests/output/shape_test_from.xlsx")?;
+        assert_eq!(workbook.get_worksheet(1)?.images().len(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{Format, FormatColor, Read, SortBy, SortKey, SortOrder, WorkSheetError, Workbook, WorkbookResult, Write};
+    use crate::common::read_part;
+
+    #[test]
+    fn test_new() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write_row("A1", &["Name", "Team", "Score"])?;
+        worksheet.write_row("A2", &["bob", "Red"])?;
+        worksheet.write("C2", 30)?;
+        worksheet.write_row("A3", &["Alice", "Blue"])?;
+        worksheet.write("C3", 10)?;
+        worksheet.write_row("A4", &["carol", "Red"])?;
+        worksheet.write("C4", 20)?;
+        worksheet.write_row("A5", &["Dave", "Blue"])?;
+        worksheet.write("C5", 10)?;
+        worksheet.write_with_format("A3", "Alice", &Format::default().set_bold())?;
+        worksheet.write_formula("D2", "C2*2")?;
+
+        worksheet.sort_range("A1:D5", &[SortKey::new(1)], true)?;
+        let names: Vec<_> = (2..=5).map(|row| worksheet.read_cell((row, 1)).unwrap().text.unwrap()).collect();
+        assert_eq!(names, ["Alice", "bob", "carol", "Dave"]);
+        assert_eq!(worksheet.read_cell("A1")?.text.as_deref(), Some("Name"));
+        // The formats and the formulas move with the rows
+        assert!(worksheet.read_cell("A2")?.format.is_some_and(|format| format.font.bold));
+        assert_eq!(worksheet.read_cell("D3")?.text, None);
+
+        // The second key breaks the ties of the first one
+        worksheet.sort_range("A1:D5", &[SortKey::new(3).descending(), SortKey::new(1)], true)?;
+        let names: Vec<_> = (2..=5).map(|row| worksheet.read_cell((row, 1)).unwrap().text.unwrap()).collect();
+        assert_eq!(names, ["bob", "carol", "Alice", "Dave"]);
+
+        let by_team = SortKey { col: 2, order: SortOrder::Ascending, by: SortBy::CustomList(vec!["Red".to_string(), "Blue".to_string()]) };
+        worksheet.sort_range("A1:D5", &[by_team, SortKey::new(1).descending()], true)?;
+        let names: Vec<_> = (2..=5).map(|row| worksheet.read_cell((row, 1)).unwrap().text.unwrap()).collect();
+        assert_eq!(names, ["carol", "bob", "Dave", "Alice"]);
+
+        assert!(matches!(worksheet.sort_range("A1:D5", &[SortKey::new(5)], true), Err(WorkSheetError::SortKeyOutOfRange)));
+        worksheet.merge_range("F1:G1", "Note")?;
+        workbook.save_as("tests/output/sort_range_test_new.xlsx")?;
+        let sheet = read_part("tests/output/sort_range_test_new.xlsx", "xl/worksheets/sheet1.xml")?;
+        assert!(sheet.contains(r#"<sortState ref="A2:D5"><sortCondition ref="B2:B5" customList="Red,Blue"/><sortCondition descending="1" ref="A2:A5"/></sortState><mergeCells"#));
+        // The formula of bob, who is back on the third row
+        assert!(sheet.contains(r#"ref="D3">C3*2</f>"#));
+        Ok(())
+    }
+
+    #[test]
+    fn test_order() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write("A1", true)?;
+        worksheet.write("A2", "text")?;
+        worksheet.write("A4", 2)?;
+        worksheet.write("A5", -1.5)?;
+        worksheet.write("A6", "Apple")?;
+
+        worksheet.sort_range("A1:A6", &[SortKey::new(1)], false)?;
+        let values: Vec<_> = (1..=6).map(|row| worksheet.read_cell((row, 1)).ok().and_then(|cell| cell.text)).collect();
+        assert_eq!(values, [Some("-1.5"), Some("2"), Some("Apple"), Some("text"), Some("true"), None].map(|value| value.map(String::from)));
+
+        // The blanks stay at the bottom
+        worksheet.sort_range("A1:A6", &[SortKey::new(1).descending()], false)?;
+        let values: Vec<_> = (1..=6).map(|row| worksheet.read_cell((row, 1)).ok().and_then(|cell| cell.text)).collect();
+        assert_eq!(values, [Some("true"), Some("text"), Some("Apple"), Some("2"), Some("-1.5"), None].map(|value| value.map(String::from)));
+
+        worksheet.merge_range("B2:B3", "Merged")?;
+        assert!(matches!(worksheet.sort_range("A1:B6", &[SortKey::new(1)], false), Err(WorkSheetError::SortMergedCells)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_color() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        let red = FormatColor::RGB(255, 0, 0);
+        worksheet.autofilter("A1:A4");
+        worksheet.write("A1", "Items")?;
+        worksheet.write("A2", "plain")?;
+        worksheet.write_with_format("A3", "red", &Format::default().set_background_color(red))?;
+        worksheet.write("A4", "other")?;
+
+        let by_color = SortKey { col: 1, order: SortOrder::Ascending, by: SortBy::CellColor(red) };
+        worksheet.sort_range("A1:A4", &[by_color.clone()], true)?;
+        // Sorting again by the same color reuses its dxf
+        worksheet.sort_range("A1:A4", &[by_color], true)?;
+        let values: Vec<_> = (2..=4).map(|row| worksheet.read_cell((row, 1)).unwrap().text.unwrap()).collect();
+        assert_eq!(values, ["red", "plain", "other"]);
+        workbook.save_as("tests/output/sort_range_test_color.xlsx")?;
+        let file_name = "tests/output/sort_range_test_color.xlsx";
+        // The sort is recorded in the autofilter of the range
+        let sheet = read_part(file_name, "xl/worksheets/sheet1.xml")?;
+        assert!(sheet.contains(r#"<autoFilter ref="A1:A4"><sortState ref="A2:A4"><sortCondition sortBy="cellColor" ref="A2:A4" dxfId="0"/></sortState></autoFilter>"#));
+        let styles = read_part(file_name, "xl/styles.xml")?;
+        assert!(styles.contains(r#"<dxf><fill><patternFill patternType="solid"><fgColor rgb="FFFF0000"/><bgColor rgb="FFFF0000"/></patternFill></fill></dxf>"#));
+        assert_eq!(styles.matches("<dxf>").count(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{FormatColor, SparklineAxis, SparklineEmptyCells, SparklineOptions, SparklineType, Workbook, WorkbookResult, WorkSheetError, Write};
+    use crate::common::read_part;
+
+    #[test]
+    fn test_new() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write_row("A1", &[-2, 2, 3, -1, 0])?;
+        worksheet.write_row("A2", &[4, 1, 5, 2, 6])?;
+        worksheet.write_column("H1", &[1, 0, -1])?;
+        worksheet.add_sparkline("F1", "A1:E1", &SparklineOptions::default())?;
+        worksheet.add_sparkline("F2", "A2:E2", &SparklineOptions::default())?;
+        let mut options = SparklineOptions::default();
+        options.set_type(SparklineType::WinLoss)
+            .set_negative_points(true)
+            .set_negative_color(FormatColor::RGB(0xFF, 0, 0))
+            .set_show_axis(true)
+            .set_empty_cells(SparklineEmptyCells::Connect)
+            .set_min_axis(SparklineAxis::Custom(-2.0))
+            .set_max_axis(SparklineAxis::Group);
+        worksheet.add_sparkline("H4", "H1:H3", &options)?;
+        let mut options = SparklineOptions::default();
+        options.set_type(SparklineType::Column).set_high_point(true).set_line_weight(1.5);
+        assert!(matches!(worksheet.add_sparkline("G1", "A1:E2", &options), Err(WorkSheetError::FormatError)));
+        worksheet.add_sparkline("G1", "A1:E1", &options)?;
+        workbook.save_as("tests/output/sparkline_test_new.xlsx")?;
+
+        let xml = read_part("tests/output/sparkline_test_new.xlsx", &format!("xl/worksheets/sheet{}.xml", 1))?;
+        assert_eq!(xml.matches("<x14:sparklineGroup ").count(), 3);
+        assert!(xml.contains("<x14:sparkline><xm:f>Sheet1!A1:E1</xm:f><xm:sqref>F1</xm:sqref></x14:sparkline><x14:sparkline><xm:f>Sheet1!A2:E2</xm:f>"));
+        assert!(xml.contains(r#"manualMin="-2" type="stacked" displayEmptyCellsAs="span" negative="1" displayXAxis="1" minAxisType="custom" maxAxisType="group""#));
+        assert!(xml.contains(r#"<x14:colorNegative rgb="FFFF0000"/>"#));
+        assert!(xml.contains(r#"lineWeight="1.5" type="column" displayEmptyCellsAs="gap" high="1""#));
+        assert!(xml.trim_end().ends_with("</extLst></worksheet>"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write_row("A1", &[1, 2, 3])?;
+        worksheet.add_sparkline("D1", "A1:C1", &SparklineOptions::default())?;
+        workbook.save_as("tests/output/sparkline_test_from.xlsx")?;
+
+        let mut workbook = Workbook::from_path("tests/output/sparkline_test_from.xlsx")?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write_row("A2", &[3, 2, 1])?;
+        worksheet.add_sparkline("D2", "A2:C2", &SparklineOptions::default())?;
+        worksheet.set_name("Sales 2024")?;
+        workbook.duplicate_worksheet(1)?;
+        workbook.save_as("tests/output/sparkline_test_from_add.xlsx")?;
+
+        let xml = read_part("tests/output/sparkline_test_from_add.xlsx", &format!("xl/worksheets/sheet{}.xml", 1))?;
+        assert_eq!(xml.matches("<x14:sparklineGroup ").count(), 1);
+        assert!(xml.contains("<xm:f>&apos;Sales 2024&apos;!A1:C1</xm:f><xm:sqref>D1</xm:sqref>"));
+        assert!(xml.contains("<xm:f>&apos;Sales 2024&apos;!A2:C2</xm:f><xm:sqref>D2</xm:sqref>"));
+        let name = workbook.get_worksheet(2)?.get_name().to_string();
+        let xml = read_part("tests/output/sparkline_test_from_add.xlsx", &format!("xl/worksheets/sheet{}.xml", 2))?;
+        assert!(xml.contains(&format!("<xm:f>{name}!A1:C1</xm:f>")));
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use edit_xlsx::{BuiltinStyle, Format, FormatColor, Read, Workbook, WorkbookResult, WorkSheetError, Write};
+    use crate::common::read_part;
+
+    #[test]
+    fn test_named_style() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let format = Format::default().set_bold().set_color(FormatColor::RGB(0, 97, 0));
+        workbook.add_named_style("Currency Bold", &format)?;
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write("A1", 100)?;
+        worksheet.apply_named_style("A1:B2", "Currency Bold")?;
+        assert!(matches!(worksheet.apply_named_style("A1:A1", "Missing"), Err(WorkSheetError::StyleNotFound)));
+        workbook.save_as("tests/output/style_test_named_style.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/style_test_named_style.xlsx")?;
+        assert!(workbook.named_styles().contains(&"Currency Bold".to_string()));
+        let style_format = workbook.get_named_style("Currency Bold").unwrap();
+        assert!(style_format.is_bold());
+        assert_eq!(style_format.get_color(), &FormatColor::RGB(0, 97, 0));
+        let worksheet = workbook.get_worksheet(1)?;
+        let cell = worksheet.read_cell("A1")?;
+        assert_eq!(cell.text, Some("100".to_string()));
+        let cell_format = cell.format.unwrap();
+        assert_eq!(cell_format.get_style_name(), Some("Currency Bold"));
+        assert!(cell_format.is_bold());
+        let cell_format = worksheet.read_cell("B2")?.format.unwrap();
+        assert_eq!(cell_format.get_style_name(), Some("Currency Bold"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_builtin_style() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        workbook.add_builtin_style(BuiltinStyle::Good)?;
+        workbook.add_builtin_style(BuiltinStyle::Accent20(1))?;
+        workbook.add_builtin_style(BuiltinStyle::Heading1)?;
+        let format = workbook.get_named_style("Good").unwrap().set_italic();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write_with_format("A1", "Good", &format)?;
+        worksheet.write("A2", "Heading")?;
+        worksheet.apply_named_style("A2:A2", "Heading 1")?;
+        workbook.save_as("tests/output/style_test_builtin_style.xlsx")?;
+
+        let xml = read_part("tests/output/style_test_builtin_style.xlsx", "xl/styles.xml")?;
+        assert!(xml.contains(r#"name="Good" xfId="1" builtinId="26""#));
+        assert!(xml.contains(r#"name="20% - Accent1" xfId="2" builtinId="30""#));
+        assert!(xml.contains(r#"name="Heading 1" xfId="3" builtinId="16""#));
+
+        let workbook = Workbook::from_path("tests/output/style_test_builtin_style.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        let cell_format = worksheet.read_cell("A1")?.format.unwrap();
+        assert_eq!(cell_format.get_style_name(), Some("Good"));
+        assert!(cell_format.is_italic());
+        assert_eq!(cell_format.get_background_color(), &FormatColor::RGB(0xC6, 0xEF, 0xCE));
+        let cell_format = worksheet.read_cell("A2")?.format.unwrap();
+        assert_eq!(cell_format.get_style_name(), Some("Heading 1"));
+        assert_eq!(cell_format.get_size(), 15.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_styles() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        for size in 1..=50 {
+            worksheet.write_with_format("A1", size, &Format::default().set_size(size))?;
+        }
+        for row in 1..=1000 {
+            worksheet.write_with_format((row, 2), row, &Format::default().set_bold())?;
+        }
+        workbook.compact_styles()?;
+        workbook.save_as("tests/output/style_test_compact_styles.xlsx")?;
+
+        let xml = read_part("tests/output/style_test_compact_styles.xlsx", "xl/styles.xml")?;
+        assert!(xml.contains(r#"<cellXfs count="3">"#));
+
+        let workbook = Workbook::from_path("tests/output/style_test_compact_styles.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        assert_eq!(worksheet.read_cell("A1")?.format.unwrap().get_size(), 50.0);
+        assert!(worksheet.read_cell("B1000")?.format.unwrap().is_bold());
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_styles_from() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/accounting.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        let (max_row, max_col) = (worksheet.max_row(), worksheet.max_column());
+        let mut formats = vec![];
+        for row in 1..=max_row {
+            for col in 1..=max_col {
+                formats.push(worksheet.read_cell((row, col)).ok().and_then(|cell| cell.format));
+            }
+        }
+        workbook.compact_styles()?;
+        workbook.save_as("tests/output/style_test_compact_styles_from.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/style_test_compact_styles_from.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        let mut compacted_formats = vec![];
+        for row in 1..=max_row {
+            for col in 1..=max_col {
+                compacted_formats.push(worksheet.read_cell((row, col)).ok().and_then(|cell| cell.format));
+            }
+        }
+        assert_eq!(formats, compacted_formats);
+        Ok(())
+    }
+
+    #[test]
+    fn test_keep_num_format() -> WorkbookResult<()> {
+        let workbook = Workbook::from_path("tests/xlsx/checkbook-register.xlsx")?;
+        let date_format = workbook.get_worksheet(1)?.read_cell("A18")?.format.unwrap();
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write_with_format("A1", 45000, &date_format.clone().set_bold())?;
+        worksheet.write_with_format("A2", 45001, &date_format)?;
+        workbook.save_as("tests/output/style_test_keep_num_format.xlsx")?;
+
+        let xml = read_part("tests/output/style_test_keep_num_format.xlsx", "xl/styles.xml")?;
+        assert!(xml.contains(r#"<numFmts count="1"><numFmt numFmtId="164" formatCode="m/dd/yy;@"/></numFmts>"#));
+        let workbook = Workbook::from_path("tests/output/style_test_keep_num_format.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        assert_eq!(worksheet.read_cell("A2")?.format.unwrap(), date_format);
+        assert!(worksheet.read_cell("A1")?.format.unwrap().is_bold());
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_style_num_format() -> WorkbookResult<()> {
+        let workbook = Workbook::from_path("tests/xlsx/checkbook-register.xlsx")?;
+        let mut date_format = workbook.get_worksheet(1)?.read_cell("A18")?.format.unwrap();
+        let mut workbook = Workbook::new();
+        workbook.add_builtin_style(BuiltinStyle::Percent)?;
+        date_format.style_name = Some("Percent".to_string());
+        let worksheet = workbook.get_worksheet_mut(1)?;
+        worksheet.write_with_format("A1", 45000, &date_format)?;
+        worksheet.write("A2", 0.5)?;
+        worksheet.apply_named_style("A2:A2", "Percent")?;
+        workbook.save_as("tests/output/style_test_named_style_num_format.xlsx")?;
+
+        let xml = read_part("tests/output/style_test_named_style_num_format.xlsx", "xl/styles.xml")?;
+        assert!(xml.contains(r#"numFmtId="164""#));
+        assert!(xml.contains(r#"numFmtId="9""#));
+        let workbook = Workbook::from_path("tests/output/style_test_named_style_num_format.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        assert_eq!(worksheet.read_cell("A1")?.format.unwrap(), date_format);
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use edit_xlsx::{Read, Workbook, WorkbookResult};
+    use edit_xlsx::{FormatColor, FormatFontScheme, Read, Theme, Workbook, WorkbookResult};
 
     #[test]
     fn test_new() -> WorkbookResult<()> {
@@ -41,6 +41,69 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_theme_colors() -> WorkbookResult<()> {
+        let workbook = Workbook::from_path("tests/xlsx/accounting.xlsx")?;
+        let worksheet = workbook.get_worksheet(1)?;
+        let theme = worksheet.get_theme(0);
+        // lt1 and dk1 are swapped
+        assert_eq!(theme.get_color(0), FormatColor::RGB(255, 255, 255));
+        assert_eq!(theme.get_color(1), FormatColor::RGB(0, 0, 0));
+        assert_eq!(theme.get_color(3), FormatColor::RGB(0x44, 0x54, 0x6A));
+        assert_eq!(theme.theme_to_rgb(0, -0.1499984740745262), FormatColor::RGB(0xD9, 0xD9, 0xD9));
+        assert_eq!(theme.theme_to_rgb(5, 0.5999938962981048), FormatColor::RGB(0xF8, 0xCB, 0xAD));
+        assert_eq!(theme.to_rgb(&FormatColor::Theme(4, -0.249977111117893)), FormatColor::RGB(0x2E, 0x75, 0xB6));
+        assert_eq!(theme.to_rgb(&FormatColor::Index(2)), FormatColor::RGB(255, 0, 0));
+        assert_eq!(theme.theme_to_rgb(42, 0.5), FormatColor::Default);
+        assert_eq!(theme.get_font(FormatFontScheme::Major), Some("Calibri Light"));
+        assert_eq!(theme.get_minor_font(), "Calibri");
+        assert_eq!(worksheet.get_theme(42), Theme::default());
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_theme() -> WorkbookResult<()> {
+        let mut workbook = Workbook::from_path("tests/xlsx/accounting.xlsx")?;
+        let mut theme = workbook.get_theme();
+        theme.set_color(4, FormatColor::RGB(0x00, 0x5A, 0x9C))
+            .set_color(1, FormatColor::RGB(0x10, 0x10, 0x10))
+            .set_major_font("Georgia")
+            .set_minor_font("Verdana");
+        workbook.set_theme(&theme)?;
+        workbook.save_as("tests/output/theme_test_set_theme.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/theme_test_set_theme.xlsx")?;
+        let theme = workbook.get_theme();
+        assert_eq!(theme.get_color(4), FormatColor::RGB(0x00, 0x5A, 0x9C));
+        assert_eq!(theme.get_color(1), FormatColor::RGB(0x10, 0x10, 0x10));
+        // untouched slots are kept
+        assert_eq!(theme.get_color(0), FormatColor::RGB(255, 255, 255));
+        assert_eq!(theme.get_color(5), FormatColor::RGB(0xED, 0x7D, 0x31));
+        assert_eq!(theme.get_major_font(), "Georgia");
+        assert_eq!(theme.get_minor_font(), "Verdana");
+        let worksheet = workbook.get_worksheet(1)?;
+        assert_eq!(worksheet.get_theme(0).to_rgb(&FormatColor::Theme(4, 0.0)), FormatColor::RGB(0x00, 0x5A, 0x9C));
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_theme() -> WorkbookResult<()> {
+        let mut workbook = Workbook::new();
+        workbook.load_theme("tests/xlsx/corporate.thmx")?;
+        let theme = workbook.get_theme();
+        assert_eq!(theme.get_color(4), FormatColor::RGB(0x00, 0x5A, 0x9C));
+        workbook.save_as("tests/output/theme_test_load_theme.xlsx")?;
+
+        let workbook = Workbook::from_path("tests/output/theme_test_load_theme.xlsx")?;
+        let theme = workbook.get_theme();
+        assert_eq!(theme.get_color(3), FormatColor::RGB(0x1F, 0x2A, 0x44));
+        assert_eq!(theme.get_color(4), FormatColor::RGB(0x00, 0x5A, 0x9C));
+        assert_eq!(theme.get_color(5), FormatColor::RGB(0xE2, 0x00, 0x1A));
+        assert_eq!(theme.get_font(FormatFontScheme::Major), Some("Georgia"));
+        assert_eq!(theme.get_font(FormatFontScheme::Minor), Some("Verdana"));
+        Ok(())
+    }
+
     #[test]
     fn test_beautify() -> WorkbookResult<()> {
         #[cfg(feature = "ansi_term_support")]